@@ -6,9 +6,11 @@ use httlib_huffman::{DecoderError as HuffmanError};
 /// of an HPACK header set.
 #[derive(Debug, PartialEq)]
 pub enum DecoderError {
-    /// Indicates that the decoder received an invalid (Huffman) buffer. This
-    /// should never happen if the input is encoded according to the HPACK spec.
-    InvalidInput,
+    /// Indicates that the decoder received an invalid Huffman encoded string
+    /// literal. The Huffman error tells the cause and the bit offset inside the
+    /// string literal. This should never happen if the input is encoded
+    /// according to the HPACK spec.
+    InvalidHuffman(HuffmanError),
 
     /// Indicates that an invalid index was provided. According to the HPACK
     /// specification, the index `0` must be treated as an invalid index number.
@@ -33,16 +35,14 @@ pub enum DecoderError {
 
 impl From<HuffmanError> for DecoderError {
     fn from(err: HuffmanError) -> Self {
-        match err {
-            HuffmanError::InvalidInput => Self::InvalidInput
-        }
+        Self::InvalidHuffman(err)
     }
 }
 
 impl fmt::Display for DecoderError {
     fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
         match self {
-            Self::InvalidInput => write!(fmt, "Invalid input character."),
+            Self::InvalidHuffman(err) => write!(fmt, "Invalid Huffman sequence. {}", err),
             Self::InvalidIndex => write!(fmt, "Invalid index."),
             Self::InvalidPrefix => write!(fmt, "Invalid prefix."),
             Self::IntegerOverflow => write!(fmt, "Too many bytes."),
@@ -52,4 +52,11 @@ impl fmt::Display for DecoderError {
     }
 }
 
-impl error::Error for DecoderError {}
+impl error::Error for DecoderError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Self::InvalidHuffman(err) => Some(err),
+            _ => None,
+        }
+    }
+}
@@ -46,7 +46,7 @@ use super::*;
 /// 
 /// [5.1.]: https://tools.ietf.org/html/rfc7541#section-5.1
 pub(crate) fn decode_integer(buf: &[u8], dst: &mut u32, prefix_size: u8) -> Result<usize, DecoderError> {
-    if prefix_size < 1 || prefix_size > 8 { // invalid prefix
+    if !(1..=8).contains(&prefix_size) { // invalid prefix
         return Err(DecoderError::InvalidPrefix);
     }
 
@@ -168,8 +168,18 @@ mod test {
         ];
         for (value, bytes) in examples {
             let mut dst = Vec::new();
-            decode_string(&bytes, DecoderSpeed::FourBits, &mut dst).unwrap();
+            decode_string(&bytes, DecoderSpeed::FiveBits, &mut dst).unwrap();
             assert_eq!(dst, value);
         }
     }
+
+    /// Should keep the details of a Huffman decoding error.
+    #[test]
+    fn fails_on_invalid_huffman_string() {
+        let mut dst = Vec::new();
+        assert_eq!(
+            decode_string(&[130, 0b11111111, 0b11111111], DecoderSpeed::FiveBits, &mut dst),
+            Err(DecoderError::InvalidHuffman(httlib_huffman::DecoderError::PaddingTooLong(0))),
+        );
+    }
 }
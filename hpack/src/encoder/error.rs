@@ -23,12 +23,21 @@ pub enum EncoderError {
     /// threshold (5 bytes are chosen by this implementation). This can also
     /// happen while encoding too long string.
     IntegerOverflow,
+
+    /// Indicates that the size of the header list exceeds the limit advertised
+    /// by the peer (e.g. `SETTINGS_MAX_HEADER_LIST_SIZE` in HTTP/2).
+    HeaderListOverflow,
+
+    /// Indicates that data was written into a header block which has already
+    /// been finished.
+    BlockFinished,
 }
 
 impl From<HuffmanError> for EncoderError {
     fn from(err: HuffmanError) -> Self {
         match err {
-            HuffmanError::InvalidInput => Self::InvalidInput
+            HuffmanError::InvalidInput => Self::InvalidInput,
+            HuffmanError::InsufficientSpace => Self::InvalidInput, // buffers are always pre-sized
         }
     }
 }
@@ -40,6 +49,8 @@ impl fmt::Display for EncoderError {
             Self::InvalidIndex => write!(fmt, "Invalid index."),
             Self::InvalidPrefix => write!(fmt, "Invalid prefix."),
             Self::IntegerOverflow => write!(fmt, "Too many bytes."),
+            Self::HeaderListOverflow => write!(fmt, "Header list size exceeds limit."),
+            Self::BlockFinished => write!(fmt, "Header block already finished."),
         }
     }
 }
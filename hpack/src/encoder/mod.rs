@@ -8,45 +8,45 @@
 //! primitive types.
 //! 
 //! * [Integer representation] defines the rules for encoding integer numbers.
-//! Integers are used to represent name indexes, header field indexes, or
-//! character string lengths.
+//!   Integers are used to represent name indexes, header field indexes, or
+//!   character string lengths.
 //! 
 //! * [String literal representation] defines the rules for encoding string
-//! literals. With these, we encode the header name and value literals. The
-//! content of these rules can be written in plain text format or encoded with
-//! the [Huffman algorithm]. 
+//!   literals. With these, we encode the header name and value literals. The
+//!   content of these rules can be written in plain text format or encoded with
+//!   the [Huffman algorithm]. 
 //! 
 //! With these basic rules, HPACK defines the binary formats for the
 //! representation of the actual headers. 
 //! 
 //! * [Indexed header field representation] represents fully indexed headers.
-//! These are the headers that are stored in the indexing table under specific
-//! index numbers. Since both the header name and value are stored in the
-//! indexing table, only this index number is encoded. Such headers are really
-//! minimal and therefore optimal in terms of performance. 
+//!   These are the headers that are stored in the indexing table under specific
+//!   index numbers. Since both the header name and value are stored in the
+//!   indexing table, only this index number is encoded. Such headers are really
+//!   minimal and therefore optimal in terms of performance. 
 //! 
 //! * [Literal header field representation] defines headers that are not or
-//! only partially indexed. If the header field name matches the header field
-//! name of an entry stored in the static or dynamic table, the header field
-//! name can be displayed using the index of this entry. Otherwise, the header
-//! field name is displayed as a string literal. Header values are always
-//! displayed as a string literal. Such headers can be marked as "index", "do
-//! not index" or  "never index". The latter tells us that the data is
-//! sensitive and that the entity should handle it with some restrictions
-//! (e.g.: protect it with a password). 
+//!   only partially indexed. If the header field name matches the header field
+//!   name of an entry stored in the static or dynamic table, the header field
+//!   name can be displayed using the index of this entry. Otherwise, the header
+//!   field name is displayed as a string literal. Header values are always
+//!   displayed as a string literal. Such headers can be marked as "index", "do
+//!   not index" or  "never index". The latter tells us that the data is
+//!   sensitive and that the entity should handle it with some restrictions
+//!   (e.g.: protect it with a password). 
 //! 
 //! HPACK is designed as a single-standing mechanism that can also be used
 //! outside the HTTP/2 protocol. For this reason, the specification provides a
 //! rule for signaling changes related to the allowed size of the dynamic table. 
 //! 
 //! * [Dynamic table size update] defines the rule for signaling changes in the
-//! size of the dynamic table. Such a change is signaled by the encoder, while
-//! the limit must be less than or equal to the limit determined by the protocol
-//! using HPACK. In HTTP/2 this limit is the last value of the
-//! [SETTINGS_HEADER_TABLE_SIZE] received by the decoder and acknowledged by the
-//! encoder. Encoder and decoder use the HTTP/2 protocol to communicate the
-//! change in table size and if the change is accepted at both ends, the encoder
-//! applies the change and reports it to the decoder using the HPACK mechanism.
+//!   size of the dynamic table. Such a change is signaled by the encoder, while
+//!   the limit must be less than or equal to the limit determined by the protocol
+//!   using HPACK. In HTTP/2 this limit is the last value of the
+//!   [SETTINGS_HEADER_TABLE_SIZE] received by the decoder and acknowledged by the
+//!   encoder. Encoder and decoder use the HTTP/2 protocol to communicate the
+//!   change in table size and if the change is accepted at both ends, the encoder
+//!   applies the change and reports it to the decoder using the HPACK mechanism.
 //! 
 //! These five rules, with some additional conditional rules described by the
 //! HPACK specification, define the HPACK encoder. 
@@ -64,18 +64,20 @@
 mod error;
 mod input;
 mod primitives;
+mod sink;
 
 pub use error::*;
 pub use input::*;
 use primitives::*;
-use crate::table::Table;
+pub use sink::*;
+use crate::table::{PendingTable, Table};
 
 /// Provides the encoding engine for HTTP/2 headers.
 /// 
 /// Since headers in HPACK can be encoded in multiple ways, the encoder provides
 /// multiple methods for encoding headers. A developer is responsible to
 /// carefully choose between them to achieve the best encoding performance.
-#[derive(Debug)]
+#[derive(Debug, Default)]
 pub struct Encoder<'a> {
     /// A store for the static and the dynamic headers.
     table: Table<'a>,
@@ -173,6 +175,212 @@ impl<'a> Encoder<'a> {
         }
     }
 
+    /// Encodes a list of headers into a single header block.
+    /// 
+    /// This is a convenience function which encodes each of the provided
+    /// `fields` in order by calling the `encode` function. See the `encode`
+    /// function for how the `flags` of each field are handled.
+    /// 
+    /// **Example:**
+    /// 
+    /// ```rust
+    /// use httlib_hpack::Encoder;
+    /// 
+    /// let mut encoder = Encoder::default();
+    /// let mut dst = Vec::new();
+    /// let fields = vec![
+    ///     (b":method".to_vec(), b"GET".to_vec(), 0x10),
+    ///     (b":path".to_vec(), b"/".to_vec(), 0x10),
+    /// ];
+    /// encoder.encode_block(fields, &mut dst).unwrap();
+    /// ```
+    pub fn encode_block<F>(
+        &mut self,
+        fields: Vec<F>,
+        dst: &mut Vec<u8>,
+    ) -> Result<(), EncoderError>
+    where
+        F: Into<EncoderInput>,
+    {
+        for field in fields {
+            self.encode(field, dst)?;
+        }
+        Ok(())
+    }
+
+    /// Encodes a list of headers into a single header block only if the size
+    /// of the header list does not exceed `max_list_size`.
+    /// 
+    /// In HTTP/2, the peer advertises the maximum size of the header list it is
+    /// prepared to accept through the `SETTINGS_MAX_HEADER_LIST_SIZE` setting
+    /// ([6.5.2.]). The whole block is sized (see `field_size`) before any of
+    /// its fields is encoded. Indexes are resolved against the indexing table
+    /// together with the entries which the preceding fields of the block would
+    /// insert. If the limit is exceeded, the `HeaderListOverflow` error is
+    /// returned while `dst` and the indexing table stay untouched.
+    /// 
+    /// **Example:**
+    /// 
+    /// ```rust
+    /// use httlib_hpack::{Encoder, EncoderError};
+    /// 
+    /// let mut encoder = Encoder::default();
+    /// let mut dst = Vec::new();
+    /// let fields = vec![
+    ///     (b":method".to_vec(), b"GET".to_vec(), 0x10), // size: 42
+    ///     (b":path".to_vec(), b"/".to_vec(), 0x10), // size: 38
+    /// ];
+    /// let res = encoder.encode_block_limited(fields, 64, &mut dst);
+    /// assert_eq!(res, Err(EncoderError::HeaderListOverflow));
+    /// ```
+    /// 
+    /// [6.5.2.]: https://tools.ietf.org/html/rfc7540#section-6.5.2
+    pub fn encode_block_limited<F>(
+        &mut self,
+        fields: Vec<F>,
+        max_list_size: u32,
+        dst: &mut Vec<u8>,
+    ) -> Result<(), EncoderError>
+    where
+        F: Into<EncoderInput>,
+    {
+        let fields: Vec<EncoderInput> = fields.into_iter()
+            .map(Into::into)
+            .collect();
+
+        let mut table = PendingTable::new(&self.table);
+        let mut size = 0;
+        for field in &fields {
+            let (name, value, flags) = match field {
+                EncoderInput::Indexed(index) => match table.get(*index) {
+                    Some((name, value)) => (name, value, 0x0),
+                    None => return Err(EncoderError::InvalidIndex),
+                },
+                EncoderInput::IndexedName(index, value, flags) => match table.get(*index) {
+                    Some((name, _)) => (name, &value[..], *flags),
+                    None => return Err(EncoderError::InvalidIndex),
+                },
+                EncoderInput::Literal(name, value, flags) => {
+                    (&name[..], &value[..], *flags)
+                },
+            };
+
+            size += name.len() + value.len() + 32;
+            if size > max_list_size as usize {
+                return Err(EncoderError::HeaderListOverflow);
+            }
+
+            let indexed = matches!(field, EncoderInput::Literal(..))
+                && flags & 0x10 == 0x10
+                && matches!(table.find(name, value), Some((_, true))); // encoded as `Indexed`
+            if flags & 0x4 == 0x4 && !indexed {
+                table.insert(name, value);
+            }
+        }
+
+        for field in fields {
+            self.encode(field, dst)?;
+        }
+        Ok(())
+    }
+
+    /// Encodes the longest leading part of the provided list of headers which
+    /// fits into the `max_list_size` limit and returns the number of encoded
+    /// headers.
+    /// 
+    /// Headers are encoded in order until the next header would push the size
+    /// of the header list over the limit. That header and all the headers that
+    /// follow are skipped. It is up to the caller to decide what to do with the
+    /// headers that were left out (e.g. send them in a trailer or reset the
+    /// stream).
+    /// 
+    /// **Example:**
+    /// 
+    /// ```rust
+    /// use httlib_hpack::Encoder;
+    /// 
+    /// let mut encoder = Encoder::default();
+    /// let mut dst = Vec::new();
+    /// let fields = vec![
+    ///     (b":method".to_vec(), b"GET".to_vec(), 0x10), // size: 42
+    ///     (b":path".to_vec(), b"/".to_vec(), 0x10), // size: 38
+    /// ];
+    /// let total = encoder.encode_block_truncated(fields, 64, &mut dst).unwrap();
+    /// assert_eq!(total, 1);
+    /// ```
+    pub fn encode_block_truncated<F>(
+        &mut self,
+        fields: Vec<F>,
+        max_list_size: u32,
+        dst: &mut Vec<u8>,
+    ) -> Result<usize, EncoderError>
+    where
+        F: Into<EncoderInput>,
+    {
+        let mut total = 0;
+        let mut size = 0;
+
+        for field in fields {
+            let field = field.into();
+            size += self.field_size(&field)?;
+            if size > max_list_size as usize {
+                break;
+            }
+
+            self.encode(field, dst)?;
+            total += 1;
+        }
+
+        Ok(total)
+    }
+
+    /// Returns the size of a header list as defined by the HTTP/2
+    /// `SETTINGS_MAX_HEADER_LIST_SIZE` setting ([6.5.2.]).
+    /// 
+    /// The size of a header list is the sum of sizes of all its header fields
+    /// (see `field_size`). Indexes are resolved against the current state of
+    /// the indexing table.
+    /// 
+    /// [6.5.2.]: https://tools.ietf.org/html/rfc7540#section-6.5.2
+    pub fn header_list_size(
+        &self,
+        fields: &[EncoderInput],
+    ) -> Result<usize, EncoderError> {
+        let mut size = 0;
+        for field in fields {
+            size += self.field_size(field)?;
+        }
+        Ok(size)
+    }
+
+    /// Returns the size of a header field as used for calculating the size of
+    /// a header list ([6.5.2.]).
+    /// 
+    /// The size of a header field is calculated based on the uncompressed size
+    /// of the field, including the length of the name and value in octets plus
+    /// an overhead of 32 octets. Indexed names and values are looked up in the
+    /// indexing table thus the `InvalidIndex` error is returned for an unknown
+    /// index.
+    /// 
+    /// [6.5.2.]: https://tools.ietf.org/html/rfc7540#section-6.5.2
+    pub fn field_size(
+        &self,
+        field: &EncoderInput,
+    ) -> Result<usize, EncoderError> {
+        let (name_len, value_len) = match field {
+            EncoderInput::Indexed(index) => match self.table.get(*index) {
+                Some((name, value)) => (name.len(), value.len()),
+                None => return Err(EncoderError::InvalidIndex),
+            },
+            EncoderInput::IndexedName(index, value, _) => match self.table.get(*index) {
+                Some((name, _)) => (name.len(), value.len()),
+                None => return Err(EncoderError::InvalidIndex),
+            },
+            EncoderInput::Literal(name, value, _) => (name.len(), value.len()),
+        };
+        Ok(name_len + value_len + 32)
+    }
+
     /// Encodes a header that exists at `index` in the indexing table.
     /// 
     /// The function converts the header index into HPACK's indexed header field
@@ -400,14 +608,6 @@ impl<'a> Encoder<'a> {
     }
 }
 
-impl<'a> Default for Encoder<'a> {
-    fn default() -> Self {
-        Self {
-            table: Table::default(),
-        }
-    }
-}
-
 #[cfg(test)]
 mod test {
     use super::*;
@@ -580,4 +780,107 @@ mod test {
         assert_eq!(dst, vec![63, 19]); // encoded size
         assert_eq!(encoder.table.dynamic_len(), 1); // 1 header evicted
     }
+
+    /// Should calculate the size of a header list as defined by the HTTP/2
+    /// specification ([6.5.2.]).
+    /// 
+    /// [6.5.2.]: https://tools.ietf.org/html/rfc7540#section-6.5.2
+    #[test]
+    fn calculates_header_list_size() {
+        let encoder = Encoder::default();
+        let fields = vec![
+            EncoderInput::Indexed(2), // (:method, GET) => 7 + 3 + 32
+            EncoderInput::IndexedName(4, b"/foo".to_vec(), 0x0), // (:path, /foo) => 5 + 4 + 32
+            EncoderInput::Literal(b"a".to_vec(), b"bc".to_vec(), 0x0), // (a, bc) => 1 + 2 + 32
+        ];
+        assert_eq!(encoder.header_list_size(&fields), Ok(42 + 41 + 35));
+        let fields = vec![
+            EncoderInput::Indexed(70), // not in indexing table
+        ];
+        assert_eq!(encoder.header_list_size(&fields), Err(EncoderError::InvalidIndex));
+    }
+
+    /// Should encode a header block only when the header list size is within
+    /// the provided limit.
+    #[test]
+    fn encodes_block_limited() {
+        let mut encoder = Encoder::default();
+        let fields = vec![
+            (b":method".to_vec(), b"GET".to_vec(), 0x10), // size: 42
+            (b"foo".to_vec(), b"bar".to_vec(), 0x4), // size: 38
+        ];
+        let mut dst = Vec::new();
+        let res = encoder.encode_block_limited(fields.clone(), 79, &mut dst);
+        assert_eq!(res, Err(EncoderError::HeaderListOverflow));
+        assert!(dst.is_empty()); // nothing encoded
+        assert_eq!(encoder.table.len(), 61); // table not altered
+        encoder.encode_block_limited(fields, 80, &mut dst).unwrap();
+        assert_eq!(dst, vec![130, 64, 3, 102, 111, 111, 3, 98, 97, 114]);
+        assert_eq!(encoder.table.len(), 62);
+    }
+
+    /// Should size the fields of a limited header block against the entries
+    /// which the preceding fields of the same block insert into the dynamic
+    /// table.
+    #[test]
+    fn encodes_block_limited_with_dynamic_indexes() {
+        let mut encoder = Encoder::default();
+        encoder.encode((b"a".to_vec(), b"b".to_vec(), 0x4), &mut Vec::new()).unwrap(); // 62: (a, b)
+        let fields = || vec![
+            EncoderInput::Literal(b"long-name".to_vec(), b"long-value".to_vec(), 0x4), // size: 51, becomes 62
+            EncoderInput::Indexed(62), // (long-name, long-value) => 51
+            EncoderInput::IndexedName(63, b"c".to_vec(), 0x0), // (a, c) => 34
+        ];
+        let mut dst = Vec::new();
+        let res = encoder.encode_block_limited(fields(), 135, &mut dst);
+        assert_eq!(res, Err(EncoderError::HeaderListOverflow));
+        assert!(dst.is_empty());
+        assert_eq!(encoder.table.len(), 62);
+        encoder.encode_block_limited(fields(), 136, &mut dst).unwrap();
+        assert_eq!(dst, vec![64, 9, 108, 111, 110, 103, 45, 110, 97, 109, 101, 10, 108, 111, 110, 103, 45, 118, 97, 108, 117, 101, 190, 15, 48, 1, 99]);
+        assert_eq!(encoder.table.len(), 63);
+        assert_eq!(encoder.table.get(62), Some((&b"long-name"[..], &b"long-value"[..])));
+
+        let mut encoder = Encoder::default();
+        let fields = vec![
+            EncoderInput::Literal(b"a".to_vec(), b"b".to_vec(), 0x4), // becomes 62
+            EncoderInput::Indexed(62), // only valid after the insert
+        ];
+        let mut dst = Vec::new();
+        encoder.encode_block_limited(fields, 100, &mut dst).unwrap();
+        assert_eq!(dst, vec![64, 1, 97, 1, 98, 190]);
+        assert_eq!(encoder.table.len(), 62);
+
+        let mut encoder = Encoder::default();
+        let fields = vec![
+            EncoderInput::Literal(b"a".to_vec(), b"b".to_vec(), 0x14), // becomes 62
+            EncoderInput::Literal(b"a".to_vec(), b"b".to_vec(), 0x14), // found at 62, not inserted
+            EncoderInput::Indexed(63), // not in indexing table
+        ];
+        let mut dst = Vec::new();
+        let res = encoder.encode_block_limited(fields, 1000, &mut dst);
+        assert_eq!(res, Err(EncoderError::InvalidIndex));
+        assert!(dst.is_empty());
+        assert_eq!(encoder.table.len(), 61);
+    }
+
+    /// Should encode only the headers which fit into the provided header list
+    /// size limit.
+    #[test]
+    fn encodes_block_truncated() {
+        let mut encoder = Encoder::default();
+        let fields = vec![
+            (b":method".to_vec(), b"GET".to_vec(), 0x10), // size: 42
+            (b"foo".to_vec(), b"bar".to_vec(), 0x4), // size: 38
+            (b"a".to_vec(), b"b".to_vec(), 0x0), // size: 34
+        ];
+        let mut dst = Vec::new();
+        let total = encoder.encode_block_truncated(fields.clone(), 113, &mut dst).unwrap();
+        assert_eq!(total, 2);
+        assert_eq!(dst, vec![130, 64, 3, 102, 111, 111, 3, 98, 97, 114]);
+        let mut dst = Vec::new();
+        let total = encoder.encode_block_truncated(fields, 10, &mut dst).unwrap();
+        assert_eq!(total, 0);
+        assert!(dst.is_empty());
+    }
 }
@@ -47,12 +47,12 @@ pub(crate) fn encode_integer(
     prefix_size: u8,
     dst: &mut Vec<u8>,
 ) -> Result<(), EncoderError> {
-    if prefix_size < 1 || prefix_size > 8 {
+    if !(1..=8).contains(&prefix_size) {
         return Err(EncoderError::InvalidPrefix);
     }
 
     let mask = ((1 << prefix_size) - 1) as u8; // max possible value of the first byte
-    let flags = flags & 255 - mask as u8; // remove invalid flags
+    let flags = flags & (255 - mask); // remove invalid flags
 
     if value < mask as u32 { // small enought to fit intothe first byte
         dst.push(flags | value as u8);
@@ -60,7 +60,7 @@ pub(crate) fn encode_integer(
     }
 
     let mut value = value - mask as u32;
-    dst.push(flags | mask as u8); // first byte
+    dst.push(flags | mask); // first byte
     while value >= 128 {
         dst.push(0b10000000 | value as u8); // byte with continuation flag
         value >>= 7;
@@ -92,23 +92,26 @@ pub(crate) fn encode_string(
     huffman: bool,
     dst: &mut Vec<u8>,
 ) -> Result<(), EncoderError> {
-    let (flags, bytes) = if huffman {
-        let mut dst = Vec::new();
-        httlib_huffman::encode(&data, &mut dst)?;
-        (0x80, dst) // set MSB to 1 indicating Huffman encoded literal
+    let (flags, len) = if huffman {
+        (0x80, httlib_huffman::encoded_len(&data)) // set MSB to 1 indicating Huffman encoded literal
     } else {
-        (0, data.to_vec()) // set MSB to 0 indicating plain text
+        (0, data.len()) // set MSB to 0 indicating plain text
     };
 
-    let len = bytes.len();
     if len > u32::MAX as usize {
         return Err(EncoderError::IntegerOverflow);
     }
 
     encode_integer(len as u32, flags, 7, dst)?; // first byte
-    dst.append(&mut bytes.to_vec()); // the rest of bytes
+    if huffman { // write Huffman sequence directly into the output
+        let start = dst.len();
+        dst.resize(start + len, 0);
+        httlib_huffman::encode_into(&data, &mut dst[start..])?;
+    } else {
+        dst.extend_from_slice(&data); // the rest of bytes
+    }
 
-    return Ok(());
+    Ok(())
 }
 
 #[cfg(test)]
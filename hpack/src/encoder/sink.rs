@@ -0,0 +1,255 @@
+use std::collections::VecDeque;
+use std::mem;
+use super::{Encoder, EncoderError, EncoderInput};
+
+/// Provides an encoder output sink which splits the encoded header block into
+/// fragments of a limited size.
+///
+/// In [HTTP/2], a header block that does not fit into a single `HEADERS` frame
+/// is transmitted as a sequence of fragments where the first fragment is sent
+/// in a `HEADERS` (or `PUSH_PROMISE`) frame and the rest in `CONTINUATION`
+/// frames. The size of each fragment is limited by the
+/// `SETTINGS_MAX_FRAME_SIZE` setting ([4.2.]) and the last fragment must carry
+/// the `END_HEADERS` flag.
+///
+/// The sink keeps at most one incomplete fragment in its buffer. Completed
+/// fragments are queued until they are returned by iterating over the sink,
+/// so a large header list can be streamed out without being put together in
+/// one big vector as long as the caller drains the sink after each write. The
+/// queue is not bounded. Each returned item is a tuple of fragment bytes and
+/// a boolean which is `true` for the last fragment (`END_HEADERS`). The last
+/// fragment is returned only after the sink is `finish`ed.
+///
+/// **Example:**
+///
+/// ```rust
+/// use httlib_hpack::{Encoder, FragmentSink};
+///
+/// let mut encoder = Encoder::default();
+/// let mut sink = FragmentSink::with_max_size(16384);
+/// sink.encode(&mut encoder, (b":method".to_vec(), b"GET".to_vec(), 0x10)).unwrap();
+/// sink.finish();
+///
+/// for (fragment, end_headers) in sink {
+///     // write HEADERS or CONTINUATION frame
+/// }
+/// ```
+///
+/// [HTTP/2]: https://tools.ietf.org/html/rfc7540
+/// [4.2.]: https://tools.ietf.org/html/rfc7540#section-4.2
+#[derive(Debug)]
+pub struct FragmentSink {
+    /// The maximum number of bytes in a single fragment.
+    max_size: usize,
+
+    /// The encoded bytes which have not been turned into a fragment yet.
+    buf: Vec<u8>,
+
+    /// Completed fragments waiting to be returned.
+    fragments: VecDeque<Vec<u8>>,
+
+    /// A flag indicating that no more data will be written to the sink.
+    finished: bool,
+}
+
+impl FragmentSink {
+    /// Returns a new sink instance which produces fragments of at most
+    /// `max_size` bytes.
+    ///
+    /// The function panics if the `max_size` is `0`.
+    pub fn with_max_size(max_size: usize) -> Self {
+        assert!(max_size > 0, "fragment size must be greater than 0");
+
+        Self {
+            max_size,
+            buf: Vec::with_capacity(max_size),
+            fragments: VecDeque::new(),
+            finished: false,
+        }
+    }
+
+    /// Returns the maximum number of bytes in a single fragment.
+    pub fn max_size(&self) -> usize {
+        self.max_size
+    }
+
+    /// Returns `true` when the sink has been finished.
+    pub fn is_finished(&self) -> bool {
+        self.finished
+    }
+
+    /// Encodes a header `field` with the provided `encoder` and writes the
+    /// result into the sink.
+    ///
+    /// See the `Encoder::encode` function for how the field is encoded. In case
+    /// of an error, the bytes of the failed field are dropped from the sink.
+    /// The `encoder` may however have already updated its dynamic table, so
+    /// its state can diverge from the peer's decoder and the connection should
+    /// be treated as broken.
+    pub fn encode<F>(
+        &mut self,
+        encoder: &mut Encoder,
+        field: F,
+    ) -> Result<(), EncoderError>
+    where
+        F: Into<EncoderInput>,
+    {
+        self.write_with(|dst| encoder.encode(field, dst))
+    }
+
+    /// Updates the maximum size of the dynamic table of the `encoder` and
+    /// writes the dynamic table size signal into the sink.
+    ///
+    /// See the `Encoder::update_max_dynamic_size` function for details. In case
+    /// of an error, the bytes of the failed signal are dropped from the sink.
+    /// The `encoder` may however have already resized its dynamic table, so
+    /// its state can diverge from the peer's decoder and the connection should
+    /// be treated as broken.
+    pub fn update_max_dynamic_size(
+        &mut self,
+        encoder: &mut Encoder,
+        size: u32,
+    ) -> Result<(), EncoderError> {
+        self.write_with(|dst| encoder.update_max_dynamic_size(size, dst))
+    }
+
+    /// Writes already encoded header block `bytes` into the sink.
+    pub fn write(&mut self, bytes: &[u8]) -> Result<(), EncoderError> {
+        self.write_with(|dst| {
+            dst.extend_from_slice(bytes);
+            Ok(())
+        })
+    }
+
+    /// Marks the end of the header block.
+    ///
+    /// The remaining buffered bytes become the last fragment. An empty last
+    /// fragment is returned when nothing has been written into the sink since
+    /// a header block always consists of at least one frame.
+    pub fn finish(&mut self) {
+        if !self.finished {
+            self.fragments.push_back(mem::take(&mut self.buf));
+            self.finished = true;
+        }
+    }
+
+    /// Calls `f` with the internal buffer and splits off completed fragments.
+    /// Each byte is copied into a fragment only once.
+    ///
+    /// A fragment of exactly `max_size` bytes is kept in the buffer until more
+    /// data arrives because it could turn out to be the last fragment.
+    fn write_with<F>(&mut self, f: F) -> Result<(), EncoderError>
+    where
+        F: FnOnce(&mut Vec<u8>) -> Result<(), EncoderError>,
+    {
+        if self.finished {
+            return Err(EncoderError::BlockFinished);
+        }
+
+        let len = self.buf.len();
+        if let Err(err) = f(&mut self.buf) {
+            self.buf.truncate(len); // drop partially encoded field
+            return Err(err);
+        }
+
+        if self.buf.len() > self.max_size {
+            let buf = mem::replace(&mut self.buf, Vec::with_capacity(self.max_size));
+            let complete = (buf.len() - 1) / self.max_size * self.max_size; // keep at least one byte
+            for fragment in buf[..complete].chunks(self.max_size) {
+                self.fragments.push_back(fragment.to_vec());
+            }
+            self.buf.extend_from_slice(&buf[complete..]);
+        }
+
+        Ok(())
+    }
+}
+
+impl Iterator for FragmentSink {
+    type Item = (Vec<u8>, bool);
+
+    /// Returns the next completed fragment and a flag indicating whether this
+    /// is the last fragment of the header block.
+    fn next(&mut self) -> Option<Self::Item> {
+        let fragment = self.fragments.pop_front()?;
+        let end_headers = self.finished && self.fragments.is_empty();
+        Some((fragment, end_headers))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Should split the encoded header block into fragments of the maximum
+    /// size where the last fragment is marked as the end of headers.
+    #[test]
+    fn splits_block_into_fragments() {
+        let mut encoder = Encoder::default();
+        let mut sink = FragmentSink::with_max_size(4);
+        sink.encode(&mut encoder, (b"foo".to_vec(), b"bar".to_vec(), 0x0)).unwrap(); // 9 bytes
+        assert_eq!(sink.next(), Some((vec![0, 3, 102, 111], false)));
+        assert_eq!(sink.next(), Some((vec![111, 3, 98, 97], false)));
+        assert_eq!(sink.next(), None); // last byte is buffered
+        sink.encode(&mut encoder, 2).unwrap(); // 1 byte
+        assert_eq!(sink.next(), None); // fragment of max size is buffered
+        sink.finish();
+        assert_eq!(sink.collect::<Vec<_>>(), vec![
+            (vec![114, 130], true),
+        ]);
+    }
+
+    /// Should split a single large write into many fragments.
+    #[test]
+    fn splits_large_writes() {
+        let mut sink = FragmentSink::with_max_size(3);
+        sink.write(&[1, 2]).unwrap();
+        sink.write(&[3, 4, 5, 6, 7, 8, 9]).unwrap();
+        assert_eq!(sink.next(), Some((vec![1, 2, 3], false)));
+        assert_eq!(sink.next(), Some((vec![4, 5, 6], false)));
+        assert_eq!(sink.next(), None); // full fragment is buffered
+        sink.write(&[10]).unwrap();
+        sink.finish();
+        assert_eq!(sink.collect::<Vec<_>>(), vec![
+            (vec![7, 8, 9], false),
+            (vec![10], true),
+        ]);
+    }
+
+    /// Should keep a full fragment until it is known whether it is the last
+    /// one.
+    #[test]
+    fn holds_full_fragment_until_finished() {
+        let mut encoder = Encoder::default();
+        let mut sink = FragmentSink::with_max_size(2);
+        sink.encode(&mut encoder, 2).unwrap();
+        sink.encode(&mut encoder, 4).unwrap();
+        assert_eq!(sink.next(), None);
+        sink.finish();
+        assert_eq!(sink.next(), Some((vec![130, 132], true)));
+        assert_eq!(sink.next(), None);
+    }
+
+    /// Should return a single empty fragment for an empty header block and
+    /// refuse writing after the sink has been finished.
+    #[test]
+    fn finishes_empty_block() {
+        let mut encoder = Encoder::default();
+        let mut sink = FragmentSink::with_max_size(16384);
+        sink.finish();
+        sink.finish(); // no-op
+        assert_eq!(sink.encode(&mut encoder, 2), Err(EncoderError::BlockFinished));
+        assert_eq!(sink.collect::<Vec<_>>(), vec![(vec![], true)]);
+    }
+
+    /// Should leave the sink untouched when the encoding fails.
+    #[test]
+    fn ignores_failed_fields() {
+        let mut encoder = Encoder::default();
+        let mut sink = FragmentSink::with_max_size(16384);
+        sink.encode(&mut encoder, 2).unwrap();
+        assert_eq!(sink.encode(&mut encoder, 0), Err(EncoderError::InvalidIndex));
+        sink.finish();
+        assert_eq!(sink.collect::<Vec<_>>(), vec![(vec![130], true)]);
+    }
+}
@@ -23,16 +23,16 @@
 //! The implementation of [HPACK] contains three main parts of the process:
 //! 
 //! * `Indexing table` is a list, to which the HPACK saves the commonly used
-//! headers. Each entity indexes headers per connection, separately for incoming
-//! (decoding) and for outgoing (encoding) data.
+//!   headers. Each entity indexes headers per connection, separately for incoming
+//!   (decoding) and for outgoing (encoding) data.
 //! 
 //! * `Encoder` performs the task of data compression. It converts the data from
-//! its original readable form into an optimized byte sequence by applying the
-//! rules defined in the HPACK specification.
+//!   its original readable form into an optimized byte sequence by applying the
+//!   rules defined in the HPACK specification.
 //! 
 //! * `Decoder` takes over the task of the decompressor. It executes the
-//! commands inversely to the encoder. It converts the data back into its
-//! readable form.
+//!   commands inversely to the encoder. It converts the data back into its
+//!   readable form.
 //! 
 //! ## Usage
 //!
@@ -68,9 +68,11 @@
 
 mod dynamic;
 mod iter;
+mod pending;
 mod r#static;
 
 pub use iter::TableIter;
+pub(crate) use pending::PendingTable;
 use dynamic::DynamicTable;
 use r#static::{StaticTable, STATIC_TABLE};
 
@@ -85,6 +87,7 @@ pub struct Table<'a> {
     dynamic_table: DynamicTable,
 }
 
+#[allow(clippy::len_without_is_empty)]
 impl<'a> Table<'a> {
     /// Returns a new header table instance with the provided maximum allowed
     /// size of the dynamic table.
@@ -130,7 +133,7 @@ impl<'a> Table<'a> {
     /// single address space (first the headers in the static table, followed by
     /// headers in the dynamic table).
     pub fn iter(&'a self) -> TableIter<'a> {
-        TableIter{ index: 1, table: &self }
+        TableIter{ index: 1, table: self }
     }
 
     /// Finds a header by its index.
@@ -173,10 +176,7 @@ impl<'a> Table<'a> {
             }
         }
 
-        match name_match {
-            Some(i) => Some((i, false)),
-            None => None,
-        }
+        name_match.map(|i| (i, false))
     }
 
     /// Inserts a new header at the beginning of the dynamic table.
@@ -252,6 +252,7 @@ mod test {
     /// one of the headers in the table. If no such header exists, then it
     /// should fall back to the one that matched only the name.
     #[test]
+    #[allow(clippy::bool_assert_comparison)]
     fn find_header_match() {
         let mut tbl = Table::default();
         tbl.insert(b"a".to_vec(), b"b".to_vec()); // index: 63
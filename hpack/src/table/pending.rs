@@ -0,0 +1,133 @@
+use std::collections::VecDeque;
+use super::Table;
+
+/// A read-only view of the indexing table with entries that are about to be
+/// inserted into the dynamic table.
+///
+/// The view is used for resolving indexes of a header block before the block
+/// is encoded. Inserted entries are placed in front of the live dynamic
+/// entries and the entries which the inserts would evict are hidden. The
+/// underlying table is never altered.
+#[derive(Debug)]
+pub(crate) struct PendingTable<'t, 'a> {
+    /// The live indexing table.
+    table: &'t Table<'a>,
+
+    /// Entries inserted into the view where the newest entry is at the lowest
+    /// index.
+    inserted: VecDeque<(&'t [u8], &'t [u8])>,
+
+    /// The number of the oldest live dynamic entries that have been evicted.
+    evicted: usize,
+
+    /// The size of the dynamic part of the view.
+    size: usize,
+}
+
+impl<'t, 'a> PendingTable<'t, 'a> {
+    /// Returns a new view of the provided indexing table.
+    pub fn new(table: &'t Table<'a>) -> Self {
+        Self {
+            table,
+            inserted: VecDeque::new(),
+            evicted: 0,
+            size: table.dynamic_size() as usize,
+        }
+    }
+
+    /// Returns the number of live dynamic entries that are still visible.
+    fn live_len(&self) -> usize {
+        self.table.dynamic_len() - self.evicted
+    }
+
+    /// Finds a header by its index in the view. The index of `0` will always
+    /// return `None`.
+    pub fn get(&self, index: u32) -> Option<(&'t [u8], &'t [u8])> {
+        let static_len = self.table.static_table.len() as u32;
+        if index <= static_len {
+            return self.table.get(index);
+        }
+
+        let index = (index - static_len - 1) as usize;
+        if index < self.inserted.len() {
+            Some(self.inserted[index])
+        } else if index - self.inserted.len() < self.live_len() {
+            self.table.dynamic_table.get((index - self.inserted.len()) as u32)
+        } else {
+            None
+        }
+    }
+
+    /// Searches the view for the provided header the same way as
+    /// `Table::find` does.
+    pub fn find(&self, name: &[u8], value: &[u8]) -> Option<(usize, bool)> {
+        let len = self.table.static_table.len()
+            + self.inserted.len()
+            + self.live_len();
+        let mut name_match = None;
+
+        for i in 1..=len {
+            let h = self.get(i as u32)?;
+            if name == h.0 {
+                if value == h.1 {
+                    return Some((i, true)); // name and value matched
+                } else if name_match.is_none() {
+                    name_match = Some(i); // only name mached
+                }
+            }
+        }
+
+        name_match.map(|i| (i, false))
+    }
+
+    /// Inserts a new header at the beginning of the dynamic part of the view.
+    ///
+    /// Entries are evicted exactly like `DynamicTable::insert` does, starting
+    /// with the oldest live entry and continuing with the oldest inserted one.
+    pub fn insert(&mut self, name: &'t [u8], value: &'t [u8]) {
+        self.size += name.len() + value.len() + 32;
+        self.inserted.push_front((name, value));
+
+        let max_size = self.table.max_dynamic_size() as usize;
+        while self.size > max_size {
+            let header = if self.live_len() > 0 {
+                self.evicted += 1;
+                self.table.dynamic_table.get(self.live_len() as u32)
+            } else {
+                self.inserted.pop_back()
+            };
+            if let Some(header) = header {
+                self.size -= header.0.len() + header.1.len() + 32;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Should resolve indexes against the inserted entries followed by the
+    /// live dynamic entries which were not evicted.
+    #[test]
+    fn resolves_inserted_entries() {
+        let mut table = Table::with_dynamic_size(70);
+        table.insert(b"a".to_vec(), b"a".to_vec()); // size: +34
+        let mut view = PendingTable::new(&table);
+        assert_eq!(view.get(62), Some((&b"a"[..], &b"a"[..])));
+        view.insert(b"b", b"b"); // size: +34
+        assert_eq!(view.get(62), Some((&b"b"[..], &b"b"[..])));
+        assert_eq!(view.get(63), Some((&b"a"[..], &b"a"[..])));
+        assert_eq!(view.find(b"a", b"a"), Some((63, true)));
+        view.insert(b"c", b"c"); // evicts (a, a)
+        assert_eq!(view.get(62), Some((&b"c"[..], &b"c"[..])));
+        assert_eq!(view.get(63), Some((&b"b"[..], &b"b"[..])));
+        assert_eq!(view.get(64), None);
+        assert_eq!(view.find(b"a", b"x"), None);
+        view.insert(b"d", b"d"); // evicts (b, b)
+        assert_eq!(view.get(63), Some((&b"c"[..], &b"c"[..])));
+        assert_eq!(view.get(64), None);
+        assert_eq!(view.get(0), None);
+        assert_eq!(table.dynamic_len(), 1); // table not altered
+    }
+}
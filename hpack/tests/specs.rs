@@ -1,4 +1,3 @@
-use hex;
 use httlib_hpack::{Encoder, Decoder};
 
 /// Should encode and decode example requests provided by the HPACK
@@ -0,0 +1,161 @@
+//! Benchmarks decoding through the translation tables with transition tuples
+//! against the tables with packed `u32` transitions, the decoder for each
+//! enabled speed, and the batch decoding against decoding each string
+//! separately.
+//! 
+//! ```txt
+//! cargo bench --bench decoder
+//! ```
+
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use httlib_huffman::{decode, decode_batch, encode, encode_batch, DecoderSpeed};
+use httlib_huffman::encoder::table::ENCODE_TABLE;
+use httlib_huffman::flattener::{
+    flatten, flatten_packed, Transition, PACKED_ASCII, PACKED_ASCII2, PACKED_NEXT_ID,
+};
+
+/// Returns a sample of header values encoded into a Huffman sequence.
+fn sample() -> Vec<u8> {
+    let text = b"text/html,application/xhtml+xml,application/xml;q=0.9,*/*;q=0.8\
+        Mozilla/5.0 (Macintosh; Intel Mac OS X 10_15_7) AppleWebKit/537.36\
+        gzip, deflate, br; en-US,en;q=0.9; max-age=0; https://www.example.com/";
+    let mut dst = Vec::new();
+    encode(&text.repeat(16), &mut dst).unwrap();
+    dst
+}
+
+/// Decodes the `src` sequence through a table with transition tuples which
+/// decode one symbol per chunk of `bits` bits.
+fn decode_tuples(table: &[Vec<Transition>], bits: usize, src: &[u8], dst: &mut Vec<u8>) {
+    let mut id = 0;
+    let mut buf: u64 = 0;
+    let mut buf_size = 0;
+    for &byte in src {
+        buf = buf << 8 | byte as u64;
+        buf_size += 8;
+        while buf_size >= bits {
+            let key = (buf >> (buf_size - bits)) as usize & ((1 << bits) - 1);
+            let (next_id, ascii, leftover) = table[id][key];
+            buf_size -= bits - leftover as usize;
+            match ascii {
+                Some(ascii) => {
+                    if ascii < 256 {
+                        dst.push(ascii as u8);
+                    }
+                    id = 0;
+                },
+                None => id = next_id.unwrap_or(0) as usize,
+            }
+        }
+    }
+}
+
+/// Decodes the `src` sequence through a table with packed `u32` transitions
+/// which decode up to two symbols per chunk of `bits` bits.
+fn decode_packed(table: &[Vec<u32>], bits: usize, src: &[u8], dst: &mut Vec<u8>) {
+    let mut id = 0;
+    let mut buf: u64 = 0;
+    let mut buf_size = 0;
+    for &byte in src {
+        buf = buf << 8 | byte as u64;
+        buf_size += 8;
+        while buf_size >= bits {
+            let key = (buf >> (buf_size - bits)) as usize & ((1 << bits) - 1);
+            let target = table[id][key];
+            buf_size -= bits - (target >> 26 & 0x7) as usize;
+            if target & PACKED_ASCII != 0 {
+                for ascii in [target & 0x1ff, target >> 9 & 0x1ff].iter().take(1 + (target & PACKED_ASCII2 != 0) as usize) {
+                    if *ascii < 256 {
+                        dst.push(*ascii as u8);
+                    }
+                }
+                id = 0;
+            } else if target & PACKED_NEXT_ID != 0 {
+                id = (target >> 18 & 0xff) as usize;
+            } else {
+                id = 0;
+            }
+        }
+    }
+}
+
+fn bench_tables(c: &mut Criterion) {
+    let src = sample();
+    let mut group = c.benchmark_group("tables");
+    for speed in DecoderSpeed::known().into_iter().filter(|s| *s != DecoderSpeed::Canonical) {
+        let bits = speed as usize;
+        let tuples = flatten(&ENCODE_TABLE, speed);
+        let packed = flatten_packed(&ENCODE_TABLE, speed);
+        let (mut expected, mut from_tuples, mut from_packed) = (Vec::new(), Vec::new(), Vec::new());
+        decode(&src, &mut expected, DecoderSpeed::Canonical).unwrap();
+        decode_tuples(&tuples, bits, &src, &mut from_tuples);
+        decode_packed(&packed, bits, &src, &mut from_packed);
+        assert_eq!(from_tuples, from_packed);
+        assert!(expected.starts_with(&from_tuples)); // the walks skip the last partial chunk
+
+        group.bench_with_input(BenchmarkId::new("tuple", bits), &src, |b, src| {
+            b.iter(|| {
+                let mut dst = Vec::with_capacity(src.len() * 2);
+                decode_tuples(&tuples, bits, black_box(src), &mut dst);
+                dst
+            })
+        });
+        group.bench_with_input(BenchmarkId::new("packed", bits), &src, |b, src| {
+            b.iter(|| {
+                let mut dst = Vec::with_capacity(src.len() * 2);
+                decode_packed(&packed, bits, black_box(src), &mut dst);
+                dst
+            })
+        });
+    }
+    group.finish();
+}
+
+fn bench_decode(c: &mut Criterion) {
+    let src = sample();
+    let mut group = c.benchmark_group("decode");
+    for speed in DecoderSpeed::enabled() {
+        group.bench_with_input(BenchmarkId::from_parameter(speed as usize), &src, |b, src| {
+            b.iter(|| {
+                let mut dst = Vec::with_capacity(src.len() * 2);
+                decode(black_box(src), &mut dst, speed).unwrap();
+                dst
+            })
+        });
+    }
+    group.finish();
+}
+
+fn bench_batch(c: &mut Criterion) {
+    let values: Vec<&[u8]> = vec![
+        b":authority", b"www.example.com", b"user-agent", b"Mozilla/5.0", b"accept",
+        b"text/html", b"accept-encoding", b"gzip, deflate, br", b"cache-control", b"max-age=0",
+    ];
+    let mut encoded = Vec::new();
+    let ends = encode_batch(&values, &mut encoded).unwrap();
+    let mut group = c.benchmark_group("batch");
+    for speed in DecoderSpeed::enabled() {
+        group.bench_function(BenchmarkId::new("separate", speed as usize), |b| {
+            b.iter(|| {
+                let mut dst = Vec::new();
+                let mut start = 0;
+                for &end in &ends {
+                    decode(black_box(&encoded[start..end]), &mut dst, speed).unwrap();
+                    start = end;
+                }
+                dst
+            })
+        });
+        group.bench_function(BenchmarkId::new("batch", speed as usize), |b| {
+            b.iter(|| {
+                let mut dst = Vec::new();
+                decode_batch(black_box(&encoded), &ends, &mut dst, speed).unwrap();
+                dst
+            })
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_tables, bench_decode, bench_batch);
+criterion_main!(benches);
@@ -0,0 +1,35 @@
+//! Generates the decoding translation tables (`decoder::tableN` modules) from
+//! the `ENCODE_TABLE` for each decoding speed enabled through the `speed-N`
+//! cargo features.
+
+use std::env;
+use std::fs;
+use std::path::Path;
+
+#[path = "src/decoder/speed.rs"]
+#[allow(dead_code)]
+mod speed;
+#[path = "src/encoder/table.rs"]
+mod table;
+#[path = "src/flattener/mod.rs"]
+#[allow(dead_code)]
+mod flattener;
+
+use speed::DecoderSpeed;
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-changed=src/decoder/speed.rs");
+    println!("cargo:rerun-if-changed=src/encoder/table.rs");
+    println!("cargo:rerun-if-changed=src/flattener/mod.rs");
+
+    let out_dir = env::var("OUT_DIR").expect("OUT_DIR not set");
+    for speed in DecoderSpeed::known() {
+        let bits = speed as usize;
+        if env::var_os(format!("CARGO_FEATURE_SPEED_{}", bits)).is_none() {
+            continue;
+        }
+        let path = Path::new(&out_dir).join(format!("table{}.rs", bits));
+        fs::write(path, flattener::generate_source(&table::ENCODE_TABLE, speed)).expect("failed to write table");
+    }
+}
@@ -0,0 +1,211 @@
+//! Command-line interface for encoding and decoding HPACK Huffman sequences and
+//! for generating the static tables of this crate.
+//! 
+//! ```txt
+//! httlib-huffman encode [TEXT]
+//! httlib-huffman decode [--speed N] [HEX]
+//! httlib-huffman gen-encode-table [PATH]
+//! httlib-huffman gen-decode-table --speed N
+//! httlib-huffman verify
+//! httlib-huffman train [--max-len N] PATH...
+//! ```
+//! 
+//! When the `TEXT` or `HEX` argument is missing, the input is read from the
+//! standard input. When the `PATH` argument is missing, the HPACK table shipped
+//! with the crate sources is used.
+
+use std::env;
+use std::fs;
+use std::io::{self, Read, Write};
+use std::process;
+use httlib_huffman::{decode, encode, DecoderSpeed, HuffmanCode};
+use httlib_huffman::encoder::table::ENCODE_TABLE;
+use httlib_huffman::{flattener, parser};
+use httlib_huffman::trainer::Trainer;
+
+/// The HPACK Huffman table in the crate sources. The path does not depend on
+/// the working directory the binary is started from.
+const HPACK_TABLE_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/assets/hpack-huffman.txt");
+
+const USAGE: &str = "Usage:
+    httlib-huffman encode [TEXT]                  Encodes text into a hex Huffman sequence.
+    httlib-huffman decode [--speed N] [HEX]       Decodes a hex Huffman sequence into text.
+    httlib-huffman gen-encode-table [PATH]        Prints the source of the ENCODE_TABLE (txt, csv, json).
+    httlib-huffman gen-decode-table --speed N     Prints the source of the DECODE_TABLE.
+    httlib-huffman verify                         Round-trips every symbol at every speed.
+    httlib-huffman train [--max-len N] PATH...    Trains a code on stories (json), HAR files (har)
+                                                  or values (one per line) and prints it as CSV.
+
+Speed N is the number of bits read at a time (1-8) or `canonical`.";
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    if let Err(err) = run(&args) {
+        eprintln!("{}", err);
+        process::exit(1);
+    }
+}
+
+/// Runs the subcommand provided by `args`.
+fn run(args: &[String]) -> Result<(), String> {
+    let (command, args) = match args.split_first() {
+        Some((command, args)) => (command.as_str(), args),
+        None => return Err(USAGE.to_string()),
+    };
+    let (speed, args) = parse_speed(args)?;
+
+    match (command, args.as_slice()) {
+        ("encode", args) if args.len() <= 1 => {
+            let text = read_input(args.first())?;
+            let mut dst = Vec::new();
+            encode(text.trim_end_matches('\n').as_bytes(), &mut dst).map_err(|e| e.to_string())?;
+            println!("{}", to_hex(&dst));
+            Ok(())
+        },
+        ("decode", args) if args.len() <= 1 => {
+            let src = from_hex(&read_input(args.first())?)?;
+            let mut dst = Vec::new();
+            decode(&src, &mut dst, speed.unwrap_or(DecoderSpeed::FiveBits)).map_err(|e| e.to_string())?;
+            io::stdout().write_all(&dst).map_err(|e| e.to_string())?;
+            println!();
+            Ok(())
+        },
+        ("gen-encode-table", args) if args.len() <= 1 => {
+            let path = args.first().map(|p| p.as_str()).unwrap_or(HPACK_TABLE_PATH);
+            let data = fs::read_to_string(path).map_err(|e| format!("Can't read {}: {}", path, e))?;
+            let codings = if path.ends_with(".json") {
+                parser::parse_json(&data)
+            } else if path.ends_with(".csv") {
+                parser::parse_csv(&data)
+            } else {
+                parser::parse(&data)
+            };
+            print!("{}", parser::generate_source(&codings.map_err(|e| e.to_string())?));
+            Ok(())
+        },
+        ("gen-decode-table", []) => match speed {
+            Some(DecoderSpeed::Canonical) | None => Err("Speed between 1 and 8 is required.".to_string()),
+            Some(speed) => {
+                print!("{}", flattener::generate_source(&ENCODE_TABLE, speed));
+                Ok(())
+            },
+        },
+        ("verify", []) => verify(),
+        ("train", args) if !args.is_empty() => train(args),
+        _ => Err(USAGE.to_string()),
+    }
+}
+
+/// Encodes and decodes each symbol at every enabled speed.
+fn verify() -> Result<(), String> {
+    let mut failures = 0;
+    for speed in DecoderSpeed::enabled() {
+        for symbol in 0..=255u8 {
+            let mut encoded = Vec::new();
+            encode(&[symbol], &mut encoded).map_err(|e| e.to_string())?;
+            let mut decoded = Vec::new();
+            let res = decode(&encoded, &mut decoded, speed);
+            if res.is_err() || decoded != [symbol] {
+                eprintln!("Symbol {} failed at speed {:?}: {:?}", symbol, speed, res);
+                failures += 1;
+            }
+        }
+        println!("{:?}: ok", speed);
+    }
+
+    if failures > 0 {
+        Err(format!("{} symbols failed.", failures))
+    } else {
+        Ok(())
+    }
+}
+
+/// Trains a code on the corpus files and compares it with the HPACK code.
+fn train(args: &[String]) -> Result<(), String> {
+    let mut trainer = Trainer::new();
+    let mut max_len = 30;
+    let mut args = args.iter();
+    while let Some(path) = args.next() {
+        if path == "--max-len" {
+            let value = args.next().ok_or("Missing max length value.")?;
+            max_len = value.parse().map_err(|_| format!("Invalid max length {}.", value))?;
+            continue;
+        }
+        let data = fs::read_to_string(path).map_err(|e| format!("Can't read {}: {}", path, e))?;
+        if path.ends_with(".har") {
+            trainer.add_har(&data)
+        } else if path.ends_with(".json") {
+            trainer.add_story(&data)
+        } else {
+            Ok(data.lines().map(|line| trainer.add(line.as_bytes())).count())
+        }.map_err(|e| format!("Can't parse {}: {}", path, e))?;
+    }
+
+    let code = trainer.train(max_len).map_err(|e| e.to_string())?;
+    let total = trainer.total();
+    let hpack = trainer.encoded_len(&HuffmanCode::hpack()).unwrap_or(0);
+    let trained = trainer.encoded_len(&code).unwrap_or(0);
+    let ratio = |len: u64| 100.0 * len as f64 / total.max(1) as f64;
+    eprintln!("Corpus:  {} bytes", total);
+    eprintln!("HPACK:   {} bytes ({:.2}%)", hpack, ratio(hpack));
+    eprintln!("Trained: {} bytes ({:.2}%)", trained, ratio(trained));
+    eprintln!("Gain:    {:.2}%", 100.0 - 100.0 * trained as f64 / hpack.max(1) as f64);
+
+    println!("symbol,bits,length");
+    for (symbol, (len, msb)) in code.codings().iter().enumerate() {
+        println!("{},0x{:x},{}", symbol, msb, len);
+    }
+    Ok(())
+}
+
+/// Extracts the `--speed N` option from `args`.
+fn parse_speed(args: &[String]) -> Result<(Option<DecoderSpeed>, Vec<String>), String> {
+    let mut speed = None;
+    let mut rest = Vec::new();
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        if arg != "--speed" {
+            rest.push(arg.clone());
+            continue;
+        }
+        let value = args.next().ok_or("Missing speed value.")?;
+        let found = DecoderSpeed::known().into_iter().find(|s| match s {
+            DecoderSpeed::Canonical => value == "canonical",
+            s => value == &(*s as usize).to_string(),
+        });
+        speed = Some(found.ok_or(format!("Unknown speed {}.", value))?);
+    }
+    Ok((speed, rest))
+}
+
+/// Returns the provided argument or reads the standard input.
+fn read_input(arg: Option<&String>) -> Result<String, String> {
+    match arg {
+        Some(arg) => Ok(arg.clone()),
+        None => {
+            let mut input = String::new();
+            io::stdin().read_to_string(&mut input).map_err(|e| e.to_string())?;
+            Ok(input)
+        },
+    }
+}
+
+/// Returns the lower case hex representation of `bytes`.
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+/// Parses a hex string where whitespace is ignored.
+#[allow(clippy::manual_is_multiple_of)] // `is_multiple_of` needs Rust 1.87
+fn from_hex(hex: &str) -> Result<Vec<u8>, String> {
+    let digits: Vec<char> = hex.chars().filter(|c| !c.is_whitespace()).collect();
+    if digits.len() % 2 != 0 {
+        return Err("Odd number of hex digits.".to_string());
+    }
+    digits.chunks(2)
+        .map(|pair| {
+            let byte: String = pair.iter().collect();
+            u8::from_str_radix(&byte, 16).map_err(|_| format!("Invalid hex byte {}.", byte))
+        })
+        .collect()
+}
@@ -0,0 +1,36 @@
+use std::error;
+use std::fmt;
+
+/// Contains error options that can be encountered while building a custom
+/// Huffman code.
+#[derive(Debug, PartialEq)]
+pub enum CodeError {
+    /// Indicates that the number of provided symbols is not valid. A code can
+    /// hold up to 256 symbols and the EOS symbol.
+    InvalidAlphabet,
+
+    /// Indicates that a code length is not valid. The EOS symbol must have the
+    /// longest code and no code can be longer than 32 bits.
+    InvalidLength,
+
+    /// Indicates that the code lengths do not describe a complete prefix code
+    /// (the Kraft sum is not equal to 1).
+    IncompleteCode,
+
+    /// Indicates that the symbols can not be encoded with codes limited to the
+    /// requested maximum length.
+    MaxLengthTooSmall,
+}
+
+impl fmt::Display for CodeError {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::InvalidAlphabet => write!(fmt, "Invalid number of symbols."),
+            Self::InvalidLength => write!(fmt, "Invalid code length."),
+            Self::IncompleteCode => write!(fmt, "Code lengths do not form a complete prefix code."),
+            Self::MaxLengthTooSmall => write!(fmt, "Maximum code length is too small."),
+        }
+    }
+}
+
+impl error::Error for CodeError {}
@@ -0,0 +1,353 @@
+//! Provides a general-purpose [canonical Huffman] code which can be built for
+//! an arbitrary alphabet of bytes.
+//! 
+//! The [HPACK] Huffman code was generated from statistics obtained on a large
+//! sample of HTTP headers. A different kind of data (e.g. messages of a binary
+//! protocol) has different statistics, so a code built from the frequencies of
+//! symbols in that data will usually compress better.
+//! 
+//! A code is built in two steps. First, the length of the code for each symbol
+//! is calculated from the symbol frequencies. The classic Huffman algorithm
+//! can produce very long codes for rare symbols which is why the lengths are
+//! calculated by the [package-merge] algorithm which finds optimal code
+//! lengths where no code exceeds the provided maximum length. Each symbol is
+//! first represented by a coin of a width `2^-len` and a value equal to the
+//! symbol frequency. Coins of the same width are paired into packages, merged
+//! with the coins of the next width and the process repeats. The cheapest
+//! `2n-2` items of the final list tell how many times each symbol was used,
+//! which is the length of its code.
+//! 
+//! ```txt
+//! Frequencies:  A=1 B=1 C=2 D=4
+//! Max length:   3
+//! Lengths:      A=3 B=3 C=2 D=1
+//! ```
+//! 
+//! Second, the lengths are turned into a [canonical Huffman] code. Symbols are
+//! sorted by the code length and then by the symbol value, and each symbol
+//! gets the next available code of its length. Such a code is fully described
+//! by the code lengths and is the same kind of code as the one in the [HPACK]
+//! specification.
+//! 
+//! ```txt
+//! D => 0
+//! C => 10
+//! A => 110
+//! B => 111
+//! ```
+//! 
+//! Like in [HPACK], each code includes the EOS symbol (`256`) which is used for
+//! padding the last octet of the encoded sequence. The EOS symbol always gets
+//! the longest code which consists of all ones. In a custom code, the EOS code
+//! can be shorter than 8 bits in which case whole EOS codes can be a part of
+//! the padding.
+//! 
+//! [HPACK]: https://tools.ietf.org/html/rfc7541
+//! [canonical Huffman]: https://en.wikipedia.org/wiki/Canonical_Huffman_code
+//! [package-merge]: https://en.wikipedia.org/wiki/Package-merge_algorithm
+
+mod error;
+
+pub use error::*;
+use crate::{DecoderError, DecoderSpeed, EncoderError, HuffmanDecoder};
+use crate::encoder::encode_words;
+use crate::encoder::table::ENCODE_TABLE;
+use crate::flattener::Transition;
+
+/// Provides a [canonical Huffman] code for encoding and decoding bytes.
+/// 
+/// **Example:**
+/// 
+/// ```rust
+/// use httlib_huffman::{DecoderSpeed, HuffmanCode};
+/// 
+/// let mut frequencies = [0; 256];
+/// for &byte in b"abracadabra" {
+///     frequencies[byte as usize] += 1;
+/// }
+/// let code = HuffmanCode::from_frequencies(&frequencies, 15).unwrap();
+/// 
+/// let mut encoded = Vec::new();
+/// code.encode(b"abracadabra", &mut encoded).unwrap();
+/// let mut decoded = Vec::new();
+/// code.decode(&encoded, &mut decoded, DecoderSpeed::FourBits).unwrap();
+/// assert_eq!(decoded, b"abracadabra");
+/// ```
+/// 
+/// [canonical Huffman]: https://en.wikipedia.org/wiki/Canonical_Huffman_code
+#[derive(Clone, Debug, PartialEq)]
+pub struct HuffmanCode {
+    /// The code length and the code value (length, msb) of each of the 257
+    /// symbols. Symbols without a code have the length of `0`.
+    codings: Vec<(u8, u32)>,
+}
+
+impl HuffmanCode {
+    /// The symbol used for padding.
+    pub const EOS: usize = 256;
+
+    /// The maximum length of a single code.
+    pub const MAX_LENGTH: u8 = 32;
+
+    /// Returns the [HPACK] Huffman code.
+    /// 
+    /// [HPACK]: https://tools.ietf.org/html/rfc7541
+    pub fn hpack() -> Self {
+        Self {
+            codings: ENCODE_TABLE.to_vec(),
+        }
+    }
+
+    /// Builds a length-limited code from the provided symbol `frequencies`.
+    /// 
+    /// The `frequencies` are indexed by the byte value and can hold up to 256
+    /// items. Symbols with the frequency of `0` get no code. No code, including
+    /// the EOS code, will be longer than `max_len` bits.
+    pub fn from_frequencies(frequencies: &[u64], max_len: u8) -> Result<Self, CodeError> {
+        if frequencies.len() > Self::EOS {
+            return Err(CodeError::InvalidAlphabet);
+        } else if max_len == 0 || max_len > Self::MAX_LENGTH {
+            return Err(CodeError::InvalidLength);
+        }
+
+        let mut symbols: Vec<(u64, usize)> = frequencies.iter()
+            .enumerate()
+            .filter(|(_, &frequency)| frequency > 0)
+            .map(|(symbol, &frequency)| (frequency, symbol))
+            .collect();
+        if symbols.is_empty() {
+            return Err(CodeError::InvalidAlphabet);
+        }
+        symbols.push((0, Self::EOS)); // the rarest symbol
+        if symbols.len() as u64 > 1 << max_len {
+            return Err(CodeError::MaxLengthTooSmall);
+        }
+        symbols.sort_unstable();
+
+        let weights: Vec<u64> = symbols.iter().map(|s| s.0).collect();
+        let mut lengths = vec![0; Self::EOS + 1];
+        for (&(_, symbol), len) in symbols.iter().zip(package_merge(&weights, max_len)) {
+            lengths[symbol] = len;
+        }
+
+        Self::from_lengths(&lengths)
+    }
+
+    /// Builds a canonical code from the code `lengths` of all 257 symbols
+    /// where the last one represents the EOS symbol.
+    /// 
+    /// Symbols with the length of `0` get no code. The lengths must form a
+    /// complete prefix code and the EOS symbol must have the longest code.
+    pub fn from_lengths(lengths: &[u8]) -> Result<Self, CodeError> {
+        if lengths.len() != Self::EOS + 1 {
+            return Err(CodeError::InvalidAlphabet);
+        }
+
+        let eos_len = lengths[Self::EOS];
+        if eos_len == 0 || lengths.iter().any(|&len| len > eos_len || len > Self::MAX_LENGTH) {
+            return Err(CodeError::InvalidLength);
+        }
+
+        let kraft: u64 = lengths.iter()
+            .filter(|&&len| len > 0)
+            .map(|&len| 1 << (Self::MAX_LENGTH - len))
+            .sum();
+        if kraft != 1 << Self::MAX_LENGTH {
+            return Err(CodeError::IncompleteCode);
+        }
+
+        let mut symbols: Vec<usize> = (0..lengths.len())
+            .filter(|&symbol| lengths[symbol] > 0)
+            .collect();
+        symbols.sort_by_key(|&symbol| (lengths[symbol], symbol));
+
+        let mut codings = vec![(0, 0); lengths.len()];
+        let mut code: u64 = 0;
+        let mut code_len = 0;
+        for symbol in symbols { // assign consecutive codes
+            let len = lengths[symbol];
+            code <<= len - code_len;
+            code_len = len;
+            codings[symbol] = (len, code as u32);
+            code += 1;
+        }
+
+        Ok(Self {
+            codings,
+        })
+    }
+
+    /// Returns the code length and the code value (length, msb) of all 257
+    /// symbols. This is the same format as the one of the `ENCODE_TABLE`.
+    pub fn codings(&self) -> &[(u8, u32)] {
+        &self.codings
+    }
+
+    /// Returns the number of bytes the `encode` function will produce for the
+    /// provided `src` bytes, including the EOS padding.
+    pub fn encoded_len(&self, src: &[u8]) -> Result<usize, EncoderError> {
+        let mut bits = 0;
+        for &byte in src {
+            match self.codings[byte as usize].0 {
+                0 => return Err(EncoderError::InvalidInput),
+                len => bits += len as usize,
+            }
+        }
+        Ok(bits.div_ceil(8)) // round up to full octets
+    }
+
+    /// Encodes the provided `src` bytes and populates the `dst` with the
+    /// sequence of Huffman codes.
+    /// 
+    /// The function returns the `InvalidInput` error when `src` holds a byte
+    /// without a code.
+    pub fn encode(&self, src: &[u8], dst: &mut Vec<u8>) -> Result<(), EncoderError> {
+        dst.reserve(self.encoded_len(src)?);
+        encode_words(&self.codings, src, |bytes| -> Result<(), EncoderError> {
+            dst.extend_from_slice(bytes);
+            Ok(())
+        })
+    }
+
+    /// Generates the translation matrix of this code for the provided `speed`.
+    /// See the `flattener` module for details.
+    pub fn flatten(&self, speed: DecoderSpeed) -> Vec<Vec<Transition>> {
+        crate::flattener::flatten(&self.codings, speed)
+    }
+
+    /// Returns a streaming decoder for this code which reads `speed` bits at a
+    /// time.
+    pub fn decoder(&self, speed: DecoderSpeed) -> HuffmanDecoder {
+        HuffmanDecoder::with_code(self, speed)
+    }
+
+    /// Decodes the Huffman `src` sequence into `dst` vector of bytes.
+    /// 
+    /// The translation matrix is generated on each call. Use the `decoder`
+    /// function when decoding many sequences with the same code.
+    pub fn decode(&self, src: &[u8], dst: &mut Vec<u8>, speed: DecoderSpeed) -> Result<(), DecoderError> {
+        let mut decoder = self.decoder(speed);
+        decoder.feed(src, dst)?;
+        decoder.finish(dst)
+    }
+}
+
+/// Calculates the optimal code lengths of symbols with the provided `weights`
+/// sorted in ascending order where no length exceeds `max_len`.
+/// 
+/// The function expects at least 2 and at most `2^max_len` weights.
+fn package_merge(weights: &[u64], max_len: u8) -> Vec<u8> {
+    let leaves: Vec<(u128, Vec<usize>)> = weights.iter()
+        .enumerate()
+        .map(|(symbol, &weight)| (weight as u128, vec![symbol]))
+        .collect();
+
+    let mut items = leaves.clone();
+    for _ in 1..max_len {
+        let mut packages = items.chunks_exact(2)
+            .map(|pair| (pair[0].0 + pair[1].0, [&pair[0].1[..], &pair[1].1[..]].concat()))
+            .peekable();
+
+        let mut merged = Vec::with_capacity(leaves.len() * 2);
+        let mut leaves = leaves.iter().cloned().peekable();
+        loop { // merge sorted leaves and packages
+            let next = match (leaves.peek(), packages.peek()) {
+                (Some(leaf), Some(package)) if leaf.0 <= package.0 => leaves.next(),
+                (_, Some(_)) => packages.next(),
+                (Some(_), None) => leaves.next(),
+                (None, None) => break,
+            };
+            merged.extend(next);
+        }
+        items = merged;
+    }
+
+    let mut lengths = vec![0; weights.len()];
+    for (_, symbols) in &items[..2 * weights.len() - 2] {
+        for &symbol in symbols {
+            lengths[symbol] += 1;
+        }
+    }
+    lengths
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Should build the HPACK code from the HPACK code lengths since the HPACK
+    /// code is canonical.
+    #[test]
+    fn builds_code_from_lengths() {
+        let lengths: Vec<u8> = ENCODE_TABLE.iter().map(|c| c.0).collect();
+        let code = HuffmanCode::from_lengths(&lengths).unwrap();
+        assert_eq!(code, HuffmanCode::hpack());
+        let mut lengths = lengths;
+        lengths[0] += 1;
+        assert_eq!(HuffmanCode::from_lengths(&lengths), Err(CodeError::IncompleteCode));
+        lengths[0] = 31;
+        assert_eq!(HuffmanCode::from_lengths(&lengths), Err(CodeError::InvalidLength));
+        assert_eq!(HuffmanCode::from_lengths(&lengths[1..]), Err(CodeError::InvalidAlphabet));
+    }
+
+    /// Should calculate optimal code lengths which do not exceed the maximum
+    /// length.
+    #[test]
+    fn builds_length_limited_code() {
+        assert_eq!(package_merge(&[1, 1, 2, 4], 3), vec![3, 3, 2, 1]);
+        assert_eq!(package_merge(&[1, 1, 2, 4], 2), vec![2, 2, 2, 2]);
+
+        let frequencies: Vec<u64> = (0..32).map(|i| 1 << i).collect(); // Fibonacci-like skew
+        let code = HuffmanCode::from_frequencies(&frequencies, 12).unwrap();
+        let codings = code.codings();
+        assert!(codings.iter().all(|c| c.0 <= 12));
+        assert_eq!(codings[31].0, 1);
+        assert_eq!(codings[HuffmanCode::EOS], (12, 0xfff)); // all ones
+
+        assert_eq!(HuffmanCode::from_frequencies(&frequencies, 5), Err(CodeError::MaxLengthTooSmall));
+        assert_eq!(HuffmanCode::from_frequencies(&[0; 4], 5), Err(CodeError::InvalidAlphabet));
+        assert_eq!(HuffmanCode::from_frequencies(&[1; 257], 15), Err(CodeError::InvalidAlphabet));
+        assert_eq!(HuffmanCode::from_frequencies(&[1], 33), Err(CodeError::InvalidLength));
+    }
+
+    /// Should encode and decode data with a custom code by using any decoding
+    /// speed.
+    #[test]
+    fn encodes_and_decodes_with_custom_code() {
+        let mut frequencies = vec![1; 256];
+        for (i, frequency) in frequencies.iter_mut().take(16).enumerate() {
+            *frequency = 1000 - i as u64 * 50;
+        }
+        let tiny = vec![10, 5]; // EOS code is 2 bits long
+        let data: Vec<u8> = (0..=255).chain(0..16).chain(0..16).collect();
+
+        for (frequencies, data) in [(frequencies, data), (tiny, vec![0, 1, 0, 0])] {
+            let code = HuffmanCode::from_frequencies(&frequencies, 15).unwrap();
+            for speed in DecoderSpeed::known() {
+                let mut decoder = code.decoder(speed);
+                for len in 0..data.len() {
+                    let mut encoded = Vec::new();
+                    code.encode(&data[..len], &mut encoded).unwrap();
+                    assert_eq!(code.encoded_len(&data[..len]), Ok(encoded.len()));
+                    let mut decoded = Vec::new();
+                    decoder.feed(&encoded, &mut decoded).unwrap();
+                    decoder.finish(&mut decoded).unwrap();
+                    assert_eq!(decoded, &data[..len]);
+                }
+            }
+        }
+    }
+
+    /// Should refuse encoding bytes without a code and decoding invalid
+    /// padding.
+    #[test]
+    fn fails_on_invalid_data() {
+        let code = HuffmanCode::from_frequencies(&[10, 5], 15).unwrap(); // 0 => 0, 1 => 10, EOS => 11
+        assert_eq!(code.encode(&[2], &mut Vec::new()), Err(EncoderError::InvalidInput));
+        for speed in DecoderSpeed::known() {
+            assert_eq!(code.decode(&[0b01111111], &mut Vec::new(), speed), Ok(()));
+            assert_eq!(code.decode(&[0b01111111, 0b11111111], &mut Vec::new(), speed), Err(DecoderError::PaddingTooLong(1))); // padding too long
+            assert_eq!(code.decode(&[0b11011111], &mut Vec::new(), speed), Err(DecoderError::EosInString(0))); // symbol after EOS
+        }
+    }
+}
@@ -0,0 +1,198 @@
+use super::{
+    decoded_len_upper_bound, CanonicalReader, CanonicalTable, DecodeReader, DecoderError,
+    DecoderSpeed, Transitions,
+};
+#[cfg(any(
+    feature = "speed-1", feature = "speed-2", feature = "speed-3", feature = "speed-4",
+    feature = "speed-5", feature = "speed-6", feature = "speed-7", feature = "speed-8",
+))]
+use super::StaticTable;
+
+/// Decodes a batch of Huffman sequences stored one after another in `src` and
+/// appends the decoded strings to `dst`. The `ends` hold the end position of
+/// each sequence in `src`, as returned by the `encode_batch` function.
+/// 
+/// The function returns the end position of each decoded string, counted from
+/// the first byte written to `dst`. The translation table of the `speed` is
+/// picked once for the whole batch and the decoder is specialized for it at
+/// compile time, so no per-chunk speed checks take place.
+/// 
+/// **Example:**
+/// 
+/// ```rust
+/// use httlib_huffman::{DecoderSpeed, decode_batch, encode_batch};
+/// 
+/// let mut encoded = Vec::new();
+/// let ends = encode_batch(&[b"content-type", b"text/html"], &mut encoded).unwrap();
+/// 
+/// let mut dst = Vec::new();
+/// let ends = decode_batch(&encoded, &ends, &mut dst, DecoderSpeed::Canonical).unwrap();
+/// assert_eq!(&dst[..ends[0]], b"content-type");
+/// assert_eq!(&dst[ends[0]..ends[1]], b"text/html");
+/// ```
+pub fn decode_batch(
+    src: &[u8],
+    ends: &[usize],
+    dst: &mut Vec<u8>,
+    speed: DecoderSpeed,
+) -> Result<Vec<usize>, DecoderError> {
+    let mut segments = Vec::with_capacity(ends.len());
+    let mut start = 0;
+    for &end in ends {
+        if end < start || end > src.len() {
+            return Err(DecoderError::InvalidBatch(start * 8));
+        }
+        segments.push((start, end));
+        start = end;
+    }
+
+    decode_segments(src, &segments, dst, speed)
+}
+
+/// Decodes a batch of Huffman sequences where each sequence in `src` is
+/// prefixed with its length, as written by the `encode_batch_prefixed`
+/// function, and appends the decoded strings to `dst`.
+/// 
+/// The length is encoded as an unsigned LEB128 variable-length integer (7 bits
+/// per byte, least significant group first). The function returns the end
+/// position of each decoded string, counted from the first byte written to
+/// `dst`.
+pub fn decode_batch_prefixed(
+    src: &[u8],
+    dst: &mut Vec<u8>,
+    speed: DecoderSpeed,
+) -> Result<Vec<usize>, DecoderError> {
+    let mut segments = Vec::new();
+    let mut pos = 0;
+    while pos < src.len() {
+        let (len, size) = read_varint(&src[pos..]).ok_or(DecoderError::InvalidBatch(pos * 8))?;
+        let start = pos + size;
+        match start.checked_add(len) {
+            Some(end) if end <= src.len() => {
+                segments.push((start, end));
+                pos = end;
+            },
+            _ => return Err(DecoderError::InvalidBatch(pos * 8)),
+        }
+    }
+
+    decode_segments(src, &segments, dst, speed)
+}
+
+/// Picks the reader for the `speed` and decodes all `segments` of `src`.
+fn decode_segments(
+    src: &[u8],
+    segments: &[(usize, usize)],
+    dst: &mut Vec<u8>,
+    speed: DecoderSpeed,
+) -> Result<Vec<usize>, DecoderError> {
+    dst.reserve(decoded_len_upper_bound(src));
+
+    match speed {
+        #[cfg(feature = "speed-1")]
+        DecoderSpeed::OneBit => decode_with(DecodeReader::with_table(StaticTable::<1>), src, segments, dst),
+        #[cfg(feature = "speed-2")]
+        DecoderSpeed::TwoBits => decode_with(DecodeReader::with_table(StaticTable::<2>), src, segments, dst),
+        #[cfg(feature = "speed-3")]
+        DecoderSpeed::ThreeBits => decode_with(DecodeReader::with_table(StaticTable::<3>), src, segments, dst),
+        #[cfg(feature = "speed-4")]
+        DecoderSpeed::FourBits => decode_with(DecodeReader::with_table(StaticTable::<4>), src, segments, dst),
+        #[cfg(feature = "speed-5")]
+        DecoderSpeed::FiveBits => decode_with(DecodeReader::with_table(StaticTable::<5>), src, segments, dst),
+        #[cfg(feature = "speed-6")]
+        DecoderSpeed::SixBits => decode_with(DecodeReader::with_table(StaticTable::<6>), src, segments, dst),
+        #[cfg(feature = "speed-7")]
+        DecoderSpeed::SevenBits => decode_with(DecodeReader::with_table(StaticTable::<7>), src, segments, dst),
+        #[cfg(feature = "speed-8")]
+        DecoderSpeed::EightBits => decode_with(DecodeReader::with_table(StaticTable::<8>), src, segments, dst),
+        DecoderSpeed::Canonical => decode_with(CanonicalReader::new(CanonicalTable::hpack()), src, segments, dst),
+        #[allow(unreachable_patterns)]
+        _ => Err(DecoderError::UnsupportedSpeed), // table not compiled in
+    }
+}
+
+/// Decodes all `segments` of `src` with the provided `reader`. This function
+/// is monomorphised for each reader type.
+fn decode_with<R: SequenceReader>(
+    mut reader: R,
+    src: &[u8],
+    segments: &[(usize, usize)],
+    dst: &mut Vec<u8>,
+) -> Result<Vec<usize>, DecoderError> {
+    let start = dst.len();
+    let mut ends = Vec::with_capacity(segments.len());
+
+    for &(from, to) in segments {
+        let res = src[from..to].iter()
+            .try_for_each(|byte| reader.decode(*byte, dst))
+            .and_then(|_| reader.finalize(dst));
+        if let Err(err) = res {
+            return Err(shift_offset(err, from * 8));
+        }
+        ends.push(dst.len() - start);
+    }
+
+    Ok(ends)
+}
+
+/// Moves the bit offset of the `err` from the beginning of a sequence to the
+/// beginning of the whole batch.
+fn shift_offset(err: DecoderError, bits: usize) -> DecoderError {
+    match err {
+        DecoderError::InvalidCode(offset) => DecoderError::InvalidCode(offset + bits),
+        DecoderError::EosInString(offset) => DecoderError::EosInString(offset + bits),
+        DecoderError::PaddingTooLong(offset) => DecoderError::PaddingTooLong(offset + bits),
+        DecoderError::InvalidPadding(offset) => DecoderError::InvalidPadding(offset + bits),
+        err => err,
+    }
+}
+
+/// Reads an unsigned LEB128 integer and returns its value and the number of
+/// bytes it takes.
+fn read_varint(src: &[u8]) -> Option<(usize, usize)> {
+    let mut value: usize = 0;
+    for (i, byte) in src.iter().enumerate() {
+        let bits = usize::from(byte & 0x7f).checked_shl(7 * i as u32)?;
+        if bits >> (7 * i) != usize::from(byte & 0x7f) { // overflow
+            return None;
+        }
+        value |= bits;
+        if byte & 0x80 == 0 {
+            return Some((value, i + 1));
+        }
+    }
+    None
+}
+
+/// Provides the operations shared by the table-driven and canonical readers.
+trait SequenceReader {
+    /// Decodes the next byte of the sequence.
+    fn decode(&mut self, byte: u8, dst: &mut Vec<u8>) -> Result<(), DecoderError>;
+
+    /// Decodes the remaining bits, validates the padding and resets the reader.
+    fn finalize(&mut self, dst: &mut Vec<u8>) -> Result<(), DecoderError>;
+}
+
+impl<T: Transitions> SequenceReader for DecodeReader<T> {
+    #[inline]
+    fn decode(&mut self, byte: u8, dst: &mut Vec<u8>) -> Result<(), DecoderError> {
+        DecodeReader::decode(self, byte, dst)
+    }
+
+    #[inline]
+    fn finalize(&mut self, dst: &mut Vec<u8>) -> Result<(), DecoderError> {
+        DecodeReader::finalize(self, dst)
+    }
+}
+
+impl SequenceReader for CanonicalReader {
+    #[inline]
+    fn decode(&mut self, byte: u8, dst: &mut Vec<u8>) -> Result<(), DecoderError> {
+        CanonicalReader::decode(self, byte, dst)
+    }
+
+    #[inline]
+    fn finalize(&mut self, dst: &mut Vec<u8>) -> Result<(), DecoderError> {
+        CanonicalReader::finalize(self, dst)
+    }
+}
@@ -0,0 +1,229 @@
+use std::sync::{Arc, OnceLock};
+use super::{DecoderError};
+use crate::encoder::table::ENCODE_TABLE;
+
+/// Provides a mechanics for decoding Huffman sequence by using the canonical
+/// property of the code instead of a translation matrix.
+/// 
+/// In a [canonical Huffman] code, the codes of the same length are consecutive
+/// numbers and, when aligned on the most significant bit, shorter codes are
+/// always smaller than longer ones. It is thus enough to know the first code
+/// and the number of codes of each length. The reader keeps a window of the
+/// next 32 bits aligned on the most significant bit and searches for the
+/// shortest length whose limit (the first code after the last code of that
+/// length) is greater than the window. The symbol is then found at the offset
+/// of that length in the list of symbols sorted by their codes.
+/// 
+/// ```txt
+/// Window:     1011 1010 ... (32 bits)
+/// Limits:     len=5 => 0101 0000 ...
+///             len=6 => 1011 1000 ...
+///             len=7 => 1111 1100 ... (window < limit)
+/// Symbol:     symbols[offsets[7] + 1011101 - firsts[7]]
+/// ```
+/// 
+/// The tables take less than 1KB of memory but the decoding requires more
+/// operations per symbol than the table-driven decoding.
+/// 
+/// [canonical Huffman]: https://en.wikipedia.org/wiki/Canonical_Huffman_code
+#[derive(Debug)]
+pub(crate) struct CanonicalReader {
+    /// The canonical tables of the code.
+    table: Arc<CanonicalTable>,
+
+    /// Internal buffer of received bits.
+    buf: u64,
+
+    /// The number of bits stored in the internal buffer.
+    buf_size: usize,
+
+    /// The number of bits of complete EOS codes found at the end of the
+    /// sequence.
+    padding: usize,
+
+    /// The bit offset of the first EOS code in the sequence.
+    padding_offset: usize,
+
+    /// The number of bits consumed from the sequence.
+    offset: usize,
+}
+
+/// Holds the first code and the number of codes of each length of a canonical
+/// code.
+#[derive(Debug)]
+pub(crate) struct CanonicalTable {
+    /// Symbols sorted by their codes.
+    symbols: Vec<u16>,
+
+    /// The first code of each length.
+    firsts: [u32; 33],
+
+    /// The first code after the codes of each length aligned on the most
+    /// significant bit of a 32-bit window.
+    limits: [u64; 33],
+
+    /// The index of the first symbol of each length in `symbols`.
+    offsets: [u16; 33],
+
+    /// The length of the shortest and the longest code.
+    lengths: (usize, usize),
+}
+
+impl CanonicalTable {
+    /// Returns the tables of the HPACK code. The tables are built only once.
+    pub fn hpack() -> Arc<Self> {
+        static TABLE: OnceLock<Arc<CanonicalTable>> = OnceLock::new();
+        TABLE.get_or_init(|| Arc::new(Self::new(&ENCODE_TABLE))).clone()
+    }
+
+    /// Returns the tables for the provided canonical `codings` in the (length,
+    /// msb) format where the symbol `256` represents the EOS.
+    pub fn new(codings: &[(u8, u32)]) -> Self {
+        let mut symbols: Vec<u16> = (0..codings.len() as u16)
+            .filter(|&symbol| codings[symbol as usize].0 > 0)
+            .collect();
+        symbols.sort_by_key(|&symbol| codings[symbol as usize]);
+
+        let mut counts = [0u32; 33];
+        for &symbol in &symbols {
+            counts[codings[symbol as usize].0 as usize] += 1;
+        }
+
+        let mut firsts = [0; 33];
+        let mut limits = [0; 33];
+        let mut offsets = [0; 33];
+        let mut first = 0u64;
+        let mut offset = 0;
+        for len in 1..=32 {
+            first = (first + counts[len - 1] as u64) << 1;
+            offset += counts[len - 1] as u16;
+            firsts[len] = first as u32;
+            limits[len] = (first + counts[len] as u64) << (32 - len);
+            offsets[len] = offset;
+        }
+
+        let min_len = (1..=32).find(|&len| counts[len] > 0).unwrap_or(32);
+        let max_len = (1..=32).rev().find(|&len| counts[len] > 0).unwrap_or(32);
+
+        Self {
+            symbols,
+            firsts,
+            limits,
+            offsets,
+            lengths: (min_len, max_len),
+        }
+    }
+}
+
+impl CanonicalReader {
+    /// Returns a new reader instance which uses the provided canonical tables.
+    pub fn new(table: Arc<CanonicalTable>) -> Self {
+        Self {
+            table,
+            buf: 0,
+            buf_size: 0,
+            padding: 0,
+            padding_offset: 0,
+            offset: 0,
+        }
+    }
+
+    /// Decodes all complete codes in the buffer.
+    pub fn decode(&mut self, byte: u8, dst: &mut Vec<u8>) -> Result<(), DecoderError> {
+        self.buf = self.buf << 8 | byte as u64;
+        self.buf_size += 8;
+
+        while self.decode_next(dst)? {}
+
+        Ok(())
+    }
+
+    /// Validates the remaining bits in the buffer which represent the padding.
+    pub fn finalize(&mut self, _dst: &mut Vec<u8>) -> Result<(), DecoderError> {
+        let padding_offset = if self.padding > 0 { // EOS codes are part of padding
+            self.padding_offset
+        } else {
+            self.offset
+        };
+        if self.padding + self.buf_size > 7 { // padding too long
+            return Err(DecoderError::PaddingTooLong(padding_offset));
+        } else if self.buf != (1 << self.buf_size) - 1 { // padding must be all ones
+            return Err(DecoderError::InvalidPadding(padding_offset));
+        }
+
+        self.reset(); // make object reusable
+
+        Ok(())
+    }
+
+    /// Resets the reader to its initial state so it can be used for decoding
+    /// a new sequence.
+    pub fn reset(&mut self) {
+        self.buf = 0;
+        self.buf_size = 0;
+        self.padding = 0;
+        self.padding_offset = 0;
+        self.offset = 0;
+    }
+
+    /// Tries to decode the next code in the buffer. The function returns
+    /// `false` when the buffer does not hold a complete code.
+    fn decode_next(&mut self, dst: &mut Vec<u8>) -> Result<bool, DecoderError> {
+        let table = &self.table;
+        if self.buf_size < table.lengths.0 {
+            return Ok(false);
+        }
+
+        let window = if self.buf_size >= 32 { // the next 32 bits aligned on MSB
+            self.buf >> (self.buf_size - 32)
+        } else {
+            self.buf << (32 - self.buf_size)
+        } & 0xffffffff;
+
+        let len = match (table.lengths.0..=table.lengths.1).find(|&len| window < table.limits[len]) {
+            Some(len) if len <= self.buf_size => len,
+            Some(_) => return Ok(false), // needs more bits
+            None => return Err(DecoderError::InvalidCode(self.offset)),
+        };
+
+        let code = (window >> (32 - len)) as u32;
+        let symbol = table.symbols[table.offsets[len] as usize + (code - table.firsts[len]) as usize];
+        let code_offset = self.offset;
+        self.buf_size -= len;
+        self.buf &= (1 << self.buf_size) - 1; // remove code from buffer
+        self.offset += len;
+
+        if symbol == 256 && self.padding == 0 { // EOS can only be a part of padding
+            self.padding_offset = code_offset;
+        }
+        if self.padding > 0 || symbol == 256 {
+            self.padding += len;
+            if symbol != 256 || len > 7 { // EOS inside the string
+                return Err(DecoderError::EosInString(self.padding_offset));
+            } else if self.padding > 7 {
+                return Err(DecoderError::PaddingTooLong(self.padding_offset));
+            }
+        } else {
+            dst.push(symbol as u8);
+        }
+
+        Ok(true)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Should build the canonical tables from the HPACK code.
+    #[test]
+    fn builds_canonical_tables() {
+        let table = CanonicalTable::hpack();
+        assert_eq!(table.lengths, (5, 30));
+        assert_eq!(table.symbols.len(), 257);
+        assert_eq!(&table.symbols[..3], &[48, 49, 50]); // 0, 1, 2
+        assert_eq!(table.firsts[5], 0);
+        assert_eq!(table.offsets[6], 10);
+        assert_eq!(table.limits[30], 1 << 32);
+    }
+}
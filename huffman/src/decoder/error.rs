@@ -3,17 +3,61 @@ use std::fmt;
 
 /// Contains error options that can be encountered while performing the decoding
 /// operations.
+/// 
+/// Errors caused by an invalid sequence hold the bit offset (counted from the
+/// beginning of the sequence) of the code or padding that caused the error.
 #[derive(Debug, PartialEq)]
 pub enum DecoderError {
-    /// Indicates that the decoder received an invalid Huffman code. This should
-    /// never happen in the input is encoded according to the HPACK spec.
-    InvalidInput,
+    /// Indicates that the decoder received a sequence of bits which does not
+    /// represent any Huffman code. This should never happen if the input is
+    /// encoded according to the HPACK spec.
+    InvalidCode(usize),
+
+    /// Indicates that the EOS symbol was found inside the string. According to
+    /// the HPACK spec, the EOS symbol must be treated as a decoding error.
+    EosInString(usize),
+
+    /// Indicates that the padding at the end of the sequence is longer than 7
+    /// bits.
+    PaddingTooLong(usize),
+
+    /// Indicates that the padding at the end of the sequence does not consist
+    /// of the most significant bits of the EOS code (all ones).
+    InvalidPadding(usize),
+
+    /// Indicates that a length prefix or an end position of a batch points
+    /// outside of the input. The offset is the bit position of the sequence
+    /// that could not be read.
+    InvalidBatch(usize),
+
+    /// Indicates that the translation table for the requested decoding speed
+    /// has not been compiled in. Enable the corresponding `speed-N` feature.
+    UnsupportedSpeed,
+}
+
+impl DecoderError {
+    /// Returns the bit offset at which the decoding failed.
+    pub fn offset(&self) -> Option<usize> {
+        match self {
+            Self::InvalidCode(offset)
+            | Self::EosInString(offset)
+            | Self::PaddingTooLong(offset)
+            | Self::InvalidPadding(offset)
+            | Self::InvalidBatch(offset) => Some(*offset),
+            Self::UnsupportedSpeed => None,
+        }
+    }
 }
 
 impl fmt::Display for DecoderError {
     fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
         match self {
-            Self::InvalidInput => write!(fmt, "Invalid Huffman sequence."),
+            Self::InvalidCode(offset) => write!(fmt, "Invalid Huffman code at bit {}.", offset),
+            Self::EosInString(offset) => write!(fmt, "EOS symbol inside string at bit {}.", offset),
+            Self::PaddingTooLong(offset) => write!(fmt, "Padding longer than 7 bits at bit {}.", offset),
+            Self::InvalidPadding(offset) => write!(fmt, "Padding not all ones at bit {}.", offset),
+            Self::InvalidBatch(offset) => write!(fmt, "Invalid batch sequence length at bit {}.", offset),
+            Self::UnsupportedSpeed => write!(fmt, "Unsupported decoding speed."),
         }
     }
 }
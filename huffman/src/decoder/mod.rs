@@ -69,19 +69,47 @@
 //! time mean faster decoding but at the same time a larger translation table
 //! and with it a higher memory footprint.
 //! 
+//! When reading more than 5 bits at a time, a single chunk can complete one
+//! code and contain another complete code right after it. The translation
+//! matrix therefore holds up to two decoded characters per transition which
+//! means that the decoder can emit two characters with a single lookup.
+//! 
+//! Translation matrices can be too big for memory constrained targets. The
+//! `Canonical` decoding speed does not use them at all. It relies on the fact
+//! that the HPACK code is a [canonical Huffman] code where the codes of the
+//! same length are consecutive numbers, so the decoder needs only the first
+//! code and the number of codes of each length.
+//! 
 //! [canonical Huffman]: https://en.wikipedia.org/wiki/Canonical_Huffman_code
 
+mod batch;
+mod canonical;
 mod error;
 mod reader;
 mod speed;
+mod stream;
+#[cfg(feature = "speed-1")]
 pub mod table1;
+#[cfg(feature = "speed-2")]
 pub mod table2;
+#[cfg(feature = "speed-3")]
 pub mod table3;
+#[cfg(feature = "speed-4")]
 pub mod table4;
+#[cfg(feature = "speed-5")]
 pub mod table5;
+#[cfg(feature = "speed-6")]
+pub mod table6;
+#[cfg(feature = "speed-7")]
+pub mod table7;
+#[cfg(feature = "speed-8")]
+pub mod table8;
 
+pub use batch::*;
 pub use error::*;
 pub use speed::*;
+pub use stream::*;
+use canonical::*;
 use reader::*;
 
 /// Decodes Huffman's `src` sequence into `dst` vector of bytes. The `speed`
@@ -93,7 +121,7 @@ use reader::*;
 /// ```rust
 /// use httlib_huffman::{DecoderSpeed, decode};
 ///
-/// let speed = DecoderSpeed::ThreeBits;
+/// let speed = DecoderSpeed::Canonical;
 /// let mut dst = Vec::new();
 /// let src = vec![135];
 /// decode(&src, &mut dst, speed).unwrap();
@@ -103,20 +131,36 @@ pub fn decode(
     dst: &mut Vec<u8>,
     speed: DecoderSpeed,
 ) -> Result<(), DecoderError> {
-    let mut reader = DecodeReader::new(speed as usize);
-
-    for byte in src {
-        reader.decode(*byte, dst)?;
-    }
-    reader.finalize(dst)?;
+    let mut decoder = HuffmanDecoder::new(speed);
+    decoder.feed(src, dst)?;
+    decoder.finish(dst)
+}
 
-    Ok(())
+/// Returns the maximum number of bytes the `decode` function can produce for
+/// the provided `src` sequence.
+/// 
+/// The shortest Huffman code in the HPACK table is 5 bits long, so every byte
+/// of the sequence can hold at most 8/5 symbols. The actual decoded length is
+/// usually smaller. This is useful for checking the result against size limits
+/// before the decoding takes place.
+/// 
+/// **Example:**
+/// 
+/// ```rust
+/// use httlib_huffman::decoded_len_upper_bound;
+/// 
+/// let src = vec![198, 90, 40, 58, 158, 15, 101, 18, 127, 31]; // Hello world!
+/// assert_eq!(decoded_len_upper_bound(&src), 16);
+/// ```
+pub fn decoded_len_upper_bound(src: &[u8]) -> usize {
+    src.len() * 8 / 5
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
 
+    #[allow(clippy::needless_borrow)]
     fn decode(bytes: &[u8], speed: DecoderSpeed) -> Result<Vec<u8>, DecoderError> {
         let mut dst = Vec::new();
         super::decode(&bytes, &mut dst, speed)?;
@@ -459,17 +503,18 @@ mod test {
         )]
     }
 
-    fn invalid_encodings() -> Vec<Vec<u8>> {
+    fn invalid_encodings() -> Vec<(Vec<u8>, DecoderError)> {
         vec![
-            vec![0, 23, 122],
-            vec![73, 124, 165, 137, 211, 77, 31, 67, 174, 186, 12, 65, 164, 199, 169, 143, 51, 166, 154, 63, 223, 154, 104, 250, 29, 117, 208, 98, 13, 38, 61, 76, 121, 166, 143, 190, 208, 1, 119, 254, 190, 88, 249, 251, 237, 0, 23, 122],
-            vec![0b11111111, 0b11111111], // EOS (padding > 7 bits)
-            vec![0b00011111, 0b11111111, 0b11111111, 0b11111111, 0b11100000], // a, EOS, +5
-            vec![0b11111111, 0b10011111, 0b11111111, 0b11111111, 0b11111111, 0b10000000], // |, EOS, +7
-            vec![0b11111111, 0b00111111, 0b11111111, 0b11111111, 0b11111111], // ?, EOS
-            vec![0b11111111, 0b11111111, 0b11111111, 0b11111100], // EOS, +2
-            vec![0b11111111, 0b00111111, 0b11111111, 0b11111111, 0b11111111, 0b0], // ?, EOS, +8
-            vec![0b11111111, 0b11111111, 0b11111111, 0b11111100, 0b0], // EOS, +10
+            (vec![0, 23, 122], DecoderError::InvalidPadding(22)),
+            (vec![73, 124, 165, 137, 211, 77, 31, 67, 174, 186, 12, 65, 164, 199, 169, 143, 51, 166, 154, 63, 223, 154, 104, 250, 29, 117, 208, 98, 13, 38, 61, 76, 121, 166, 143, 190, 208, 1, 119, 254, 190, 88, 249, 251, 237, 0, 23, 122], DecoderError::InvalidPadding(382)),
+            (vec![0b11111111, 0b11111111], DecoderError::PaddingTooLong(0)), // EOS (padding > 7 bits)
+            (vec![0b00011111, 0b11111111, 0b11111111, 0b11111111, 0b11100000], DecoderError::EosInString(5)), // a, EOS, +5
+            (vec![0b11111111, 0b10011111, 0b11111111, 0b11111111, 0b11111111, 0b10000000], DecoderError::EosInString(11)), // |, EOS, +7
+            (vec![0b11111111, 0b00111111, 0b11111111, 0b11111111, 0b11111111], DecoderError::EosInString(10)), // ?, EOS
+            (vec![0b11111111, 0b11111111, 0b11111111, 0b11111100], DecoderError::EosInString(0)), // EOS, +2
+            (vec![0b11111111, 0b00111111, 0b11111111, 0b11111111, 0b11111111, 0b0], DecoderError::EosInString(10)), // ?, EOS, +8
+            (vec![0b11111111, 0b11111111, 0b11111111, 0b11111100, 0b0], DecoderError::EosInString(0)), // EOS, +10
+            (vec![0b00000000], DecoderError::InvalidPadding(5)), // 0, +000
         ]
     }
 
@@ -478,7 +523,7 @@ mod test {
     /// valid decoding speed.
     #[test]
     fn decodes_characters() {
-        for speed in DecoderSpeed::known() {
+        for speed in DecoderSpeed::enabled() {
             for (data, code) in valid_characters() { // passes
                 assert_eq!(data, decode(&code, speed).unwrap());
             }
@@ -492,12 +537,120 @@ mod test {
     /// using any valid decoding speed.
     #[test]
     fn decodes_literals() { 
-        for speed in DecoderSpeed::known() {
+        for speed in DecoderSpeed::enabled() {
             for (data, code) in valid_literals() { // passes
                 assert_eq!(data, decode(&code, speed).unwrap());
             }
-            for encoding in invalid_encodings() { // throws
-                assert_eq!(Err(DecoderError::InvalidInput), decode(&encoding, speed));
+            for (encoding, err) in invalid_encodings() { // throws
+                assert_eq!(Err(err), decode(&encoding, speed));
+            }
+        }
+    }
+
+    /// Should calculate the number of decoded bytes which is never exceeded by
+    /// the decoder.
+    #[test]
+    fn calculates_decoded_len_upper_bound() {
+        for (data, code) in valid_literals() {
+            assert!(data.len() <= decoded_len_upper_bound(&code));
+        }
+        assert_eq!(decoded_len_upper_bound(&[0b00000000, 0b00000001]), 3); // 00000|00000|00000|1
+        for speed in DecoderSpeed::enabled() {
+            assert_eq!(decode(&[0b00000000, 0b00000001], speed).unwrap(), b"000");
+        }
+    }
+
+    /// Should provide translation tables which match the output of the
+    /// flattener for the `ENCODE_TABLE`.
+    #[cfg(any(
+        feature = "speed-1", feature = "speed-2", feature = "speed-3", feature = "speed-4",
+        feature = "speed-5", feature = "speed-6", feature = "speed-7", feature = "speed-8",
+    ))]
+    #[test]
+    fn generates_tables_from_encode_table() {
+        use crate::encoder::table::ENCODE_TABLE;
+        use crate::flattener::flatten_packed;
+
+        fn check<const N: usize>(table: &[[u32; N]], speed: DecoderSpeed) {
+            let expected = flatten_packed(&ENCODE_TABLE, speed);
+            assert_eq!(table.iter().map(|t| t.to_vec()).collect::<Vec<_>>(), expected);
+        }
+
+        #[cfg(feature = "speed-1")]
+        check(&table1::DECODE_TABLE, DecoderSpeed::OneBit);
+        #[cfg(feature = "speed-2")]
+        check(&table2::DECODE_TABLE, DecoderSpeed::TwoBits);
+        #[cfg(feature = "speed-3")]
+        check(&table3::DECODE_TABLE, DecoderSpeed::ThreeBits);
+        #[cfg(feature = "speed-4")]
+        check(&table4::DECODE_TABLE, DecoderSpeed::FourBits);
+        #[cfg(feature = "speed-5")]
+        check(&table5::DECODE_TABLE, DecoderSpeed::FiveBits);
+        #[cfg(feature = "speed-6")]
+        check(&table6::DECODE_TABLE, DecoderSpeed::SixBits);
+        #[cfg(feature = "speed-7")]
+        check(&table7::DECODE_TABLE, DecoderSpeed::SevenBits);
+        #[cfg(feature = "speed-8")]
+        check(&table8::DECODE_TABLE, DecoderSpeed::EightBits);
+    }
+
+    /// Should refuse decoding with a speed which has not been compiled in.
+    #[test]
+    fn fails_on_disabled_speed() {
+        for speed in DecoderSpeed::known().into_iter().filter(|s| !s.is_enabled()) {
+            assert_eq!(decode(&[135], speed), Err(DecoderError::UnsupportedSpeed));
+        }
+    }
+
+    /// Should decode a batch of sequences encoded with end positions or with
+    /// length prefixes and report errors at the bit offset of the batch.
+    #[test]
+    fn decodes_batches() {
+        let long = [b'a'; 300];
+        let values: Vec<&[u8]> = vec![b"content-type", b"", b"text/html", &long];
+        let mut ends = Vec::new();
+        let mut offset = 0;
+        for value in &values {
+            offset += value.len();
+            ends.push(offset);
+        }
+
+        let mut encoded = Vec::new();
+        let encoded_ends = crate::encode_batch(&values, &mut encoded).unwrap();
+        let mut prefixed = Vec::new();
+        crate::encode_batch_prefixed(&values, &mut prefixed).unwrap();
+        assert_eq!(prefixed[prefixed.len() - 190..][..2], [0xbc, 0x01]); // 188 bytes
+        for speed in DecoderSpeed::enabled() {
+            let mut dst = vec![0];
+            assert_eq!(decode_batch(&encoded, &encoded_ends, &mut dst, speed), Ok(ends.clone()));
+            assert_eq!(dst[1..], values.concat()[..]);
+            let mut dst = Vec::new();
+            assert_eq!(decode_batch_prefixed(&prefixed, &mut dst, speed), Ok(ends.clone()));
+            assert_eq!(dst, values.concat());
+        }
+
+        let mut dst = Vec::new();
+        let speed = DecoderSpeed::enabled()[0];
+        assert_eq!(decode_batch(&[0x1f, 0x00], &[1, 2], &mut dst, speed), Err(DecoderError::InvalidPadding(13)));
+        assert_eq!(decode_batch(&[0x21], &[2], &mut dst, speed), Err(DecoderError::InvalidBatch(0)));
+        assert_eq!(decode_batch_prefixed(&[0x01, 0x21, 0x02, 0x21], &mut dst, speed), Err(DecoderError::InvalidBatch(16)));
+        assert_eq!(decode_batch_prefixed(&[0x80], &mut dst, speed), Err(DecoderError::InvalidBatch(0)));
+    }
+
+    /// Should decode two characters with a single lookup when reading more
+    /// than 5 bits at a time.
+    #[cfg(feature = "speed-8")]
+    #[test]
+    fn decodes_multiple_characters_per_lookup() {
+        let (_, ascii, ascii2, leftover) = crate::flattener::unpack(table8::DECODE_TABLE[1][0b00000001]); // ?0 after 8 bits of `?`
+        assert_eq!((ascii, ascii2, leftover), (Some(63), Some(48), 1));
+        for a in 0..=255u8 {
+            for b in 0..=255u8 {
+                let mut code = Vec::new();
+                crate::encode(&[a, b], &mut code).unwrap();
+                for speed in DecoderSpeed::enabled() {
+                    assert_eq!(decode(&code, speed).unwrap(), vec![a, b]);
+                }
             }
         }
     }
@@ -1,11 +1,138 @@
+use std::sync::Arc;
 use super::{DecoderError};
+use crate::encoder::table::ENCODE_TABLE;
+use crate::flattener::{PACKED_ASCII, PACKED_ASCII2, PACKED_NEXT_ID};
 
-/// Provides a mechanics for decoding Huffman sequence back to the original
-/// form.
-pub(crate) struct DecodeReader {
+/// Provides the translation matrix and the code lengths to the reader.
+/// 
+/// The reader is generic over this trait so the table of a known speed can be
+/// picked at compile time (see `StaticTable`) while the streaming decoder picks
+/// it at runtime (see `DynamicTable`).
+pub(crate) trait Transitions {
+    /// Returns the number of bits that the reader should read at a time.
+    fn speed(&self) -> usize;
+
+    /// Returns the packed transition for the chunk `key` in the row `id` or
+    /// `None` when the table has no such transition.
+    fn target(&self, id: usize, key: usize) -> Result<Option<u32>, DecoderError>;
+
+    /// Returns the code length of the provided symbol.
+    fn coding_len(&self, symbol: usize) -> u8;
+}
+
+/// Represents the static HPACK translation matrix for the `SPEED` known at
+/// compile time.
+#[derive(Debug, Clone, Copy, Default)]
+#[cfg(any(
+    feature = "speed-1", feature = "speed-2", feature = "speed-3", feature = "speed-4",
+    feature = "speed-5", feature = "speed-6", feature = "speed-7", feature = "speed-8",
+))]
+pub(crate) struct StaticTable<const SPEED: usize>;
+
+#[cfg(any(
+    feature = "speed-1", feature = "speed-2", feature = "speed-3", feature = "speed-4",
+    feature = "speed-5", feature = "speed-6", feature = "speed-7", feature = "speed-8",
+))]
+impl<const SPEED: usize> Transitions for StaticTable<SPEED> {
+    #[inline]
+    fn speed(&self) -> usize {
+        SPEED
+    }
+
+    #[inline]
+    fn target(&self, id: usize, key: usize) -> Result<Option<u32>, DecoderError> {
+        static_target(SPEED, id, key)
+    }
+
+    #[inline]
+    fn coding_len(&self, symbol: usize) -> u8 {
+        ENCODE_TABLE[symbol].0
+    }
+}
+
+/// Holds the codings of a custom code together with its packed translation
+/// matrix.
+pub(crate) type CodeTable = Arc<(Vec<(u8, u32)>, Vec<Vec<u32>>)>;
+
+/// Represents a translation matrix for the speed known only at runtime. It is
+/// either one of the static HPACK tables or a table of a custom code.
+#[derive(Debug, Clone)]
+pub(crate) struct DynamicTable {
     /// The number of bits that the reader should read at a time.
     speed: usize,
 
+    /// A custom code and its packed translation matrix. The static HPACK
+    /// tables are used when not provided.
+    code: Option<CodeTable>,
+}
+
+impl Transitions for DynamicTable {
+    fn speed(&self) -> usize {
+        self.speed
+    }
+
+    fn target(&self, id: usize, key: usize) -> Result<Option<u32>, DecoderError> {
+        match &self.code {
+            Some(code) => Ok(code.1.get(id).and_then(|row| row.get(key)).copied()),
+            None => static_target(self.speed, id, key),
+        }
+    }
+
+    fn coding_len(&self, symbol: usize) -> u8 {
+        match &self.code {
+            Some(code) => code.0[symbol].0,
+            None => ENCODE_TABLE[symbol].0,
+        }
+    }
+}
+
+/// Returns the packed transition from the static table of the `speed`. When
+/// the `speed` is a constant, the match is resolved at compile time.
+#[cfg(any(
+    feature = "speed-1", feature = "speed-2", feature = "speed-3", feature = "speed-4",
+    feature = "speed-5", feature = "speed-6", feature = "speed-7", feature = "speed-8",
+))]
+#[inline]
+fn static_target(speed: usize, id: usize, key: usize) -> Result<Option<u32>, DecoderError> {
+    let row: Option<&[u32]> = match speed {
+        #[cfg(feature = "speed-1")]
+        1 => crate::decoder::table1::DECODE_TABLE.get(id).map(|row| &row[..]),
+        #[cfg(feature = "speed-2")]
+        2 => crate::decoder::table2::DECODE_TABLE.get(id).map(|row| &row[..]),
+        #[cfg(feature = "speed-3")]
+        3 => crate::decoder::table3::DECODE_TABLE.get(id).map(|row| &row[..]),
+        #[cfg(feature = "speed-4")]
+        4 => crate::decoder::table4::DECODE_TABLE.get(id).map(|row| &row[..]),
+        #[cfg(feature = "speed-5")]
+        5 => crate::decoder::table5::DECODE_TABLE.get(id).map(|row| &row[..]),
+        #[cfg(feature = "speed-6")]
+        6 => crate::decoder::table6::DECODE_TABLE.get(id).map(|row| &row[..]),
+        #[cfg(feature = "speed-7")]
+        7 => crate::decoder::table7::DECODE_TABLE.get(id).map(|row| &row[..]),
+        #[cfg(feature = "speed-8")]
+        8 => crate::decoder::table8::DECODE_TABLE.get(id).map(|row| &row[..]),
+        _ => return Err(DecoderError::UnsupportedSpeed), // table not compiled in
+    };
+    Ok(row.and_then(|row| row.get(key)).copied())
+}
+
+/// Returns the `UnsupportedSpeed` error since no static table is compiled in.
+#[cfg(not(any(
+    feature = "speed-1", feature = "speed-2", feature = "speed-3", feature = "speed-4",
+    feature = "speed-5", feature = "speed-6", feature = "speed-7", feature = "speed-8",
+)))]
+fn static_target(_speed: usize, _id: usize, _key: usize) -> Result<Option<u32>, DecoderError> {
+    Err(DecoderError::UnsupportedSpeed)
+}
+
+/// Provides a mechanics for decoding Huffman sequence back to the original
+/// form.
+#[derive(Debug)]
+pub(crate) struct DecodeReader<T = DynamicTable> {
+    /// The translation matrix and the number of bits that the reader should
+    /// read at a time.
+    table: T,
+
     /// The ID of the last row in the translation matrix from where the
     /// decoding should continue.
     id: usize,
@@ -23,18 +150,50 @@ pub(crate) struct DecodeReader {
 
     /// The number of bits stored in the tail variable.
     tail_size: usize,
+
+    /// The number of bits of complete EOS codes found at the end of the
+    /// sequence. A custom code can have an EOS code shorter than 8 bits which
+    /// can thus become a part of the padding.
+    padding: usize,
+
+    /// The bit offset of the first EOS code in the sequence.
+    padding_offset: usize,
+
+    /// The number of bits consumed from the sequence. Together with the tail
+    /// it tells the position of the current code for error reporting.
+    offset: usize,
 }
 
 impl DecodeReader {
     /// Returns a new reader instance.
     pub fn new(speed: usize) -> Self {
+        Self::with_table(DynamicTable { speed, code: None })
+    }
+
+    /// Returns a new reader instance which decodes a custom code with the
+    /// provided codings and the translation matrix generated by the flattener
+    /// for the same `speed`.
+    pub fn with_code(
+        speed: usize,
+        code: CodeTable,
+    ) -> Self {
+        Self::with_table(DynamicTable { speed, code: Some(code) })
+    }
+}
+
+impl<T: Transitions> DecodeReader<T> {
+    /// Returns a new reader instance which reads the provided `table`.
+    pub fn with_table(table: T) -> Self {
         Self {
-            speed,
+            table,
             id: 0,
             buf: 0,
             buf_size: 0,
             tail: 0,
             tail_size: 0,
+            padding: 0,
+            padding_offset: 0,
+            offset: 0,
         }
     }
 
@@ -47,7 +206,7 @@ impl DecodeReader {
         self.buf |= byte as usize; // apply new chunk
 
         loop {
-            if self.buf_size < self.speed { // has chunks to process
+            if self.buf_size < self.table.speed() { // has chunks to process
                 break;
             } else {
                 self.decode_next(dst)?;
@@ -65,119 +224,174 @@ impl DecodeReader {
     /// can be processed by the `decode` method. The extended bits are treated
     /// as a buffer bits of value 1.
     pub fn finalize(&mut self, dst: &mut Vec<u8>) -> Result<(), DecoderError> {
-        let shift_len = (self.buf_size as f64 / self.speed as f64).ceil() as usize * self.speed as usize - self.buf_size; // how much missing to chunk size
-        
-        self.buf <<= shift_len; // expand buffer to chunk size
-        self.buf_size += shift_len;
-
-        if self.buf_size >= self.speed { // has chunks to process
-            if let Ok((_, _, leftover)) = self.find_target(self.buf) {
-                if shift_len <= leftover as usize { // has another character
-                    self.decode_next(dst)?;
+        while self.buf_size > 0 { // has bits to process
+            let shift_len = self.table.speed() - self.buf_size; // how much missing to chunk size
+            let target = match self.find_target(self.buf << shift_len) {
+                Ok(target) => target,
+                Err(_) => break,
+            };
+            let leftover = leftover(target);
+
+            self.buf <<= shift_len; // expand buffer to chunk size
+            self.buf_size += shift_len;
+
+            let decoded = if shift_len <= leftover { // has another character
+                self.decode_next(dst)?;
+                true
+            } else if target & PACKED_ASCII2 != 0 { // only the first character is not padding
+                let leftover = leftover + self.coding_len(ascii2(target)) as usize;
+                if shift_len <= leftover {
+                    let target = target & !(PACKED_ASCII2 | 0x7 << 26) | (leftover as u32) << 26;
+                    self.apply_target(self.buf, target, dst)?;
+                    true
+                } else {
+                    false
                 }
+            } else {
+                false
+            };
+
+            self.buf >>= shift_len; // remove leftover
+            self.buf_size -= shift_len;
+
+            if !decoded {
+                break;
             }
         }
 
-        self.buf >>= shift_len; // remove leftover
-        self.buf_size -= shift_len;
-
         self.tail <<= self.buf_size; // append buffer to tail
         self.tail_size += self.buf_size;
         self.tail |= self.buf;
+        self.offset += self.buf_size;
         self.buf = 0;
         self.buf_size = 0;
 
-        if !vec![0, 1, 3, 7, 15, 31, 63, 127].iter().any(|p| *p == self.tail) { // validate padding
-            return Err(DecoderError::InvalidInput);
+        let padding_offset = if self.padding > 0 { // EOS codes are part of padding
+            self.padding_offset
+        } else {
+            self.offset - self.tail_size
+        };
+        if self.padding + self.tail_size > 7 { // validate padding
+            return Err(DecoderError::PaddingTooLong(padding_offset));
+        } else if self.tail != (1 << self.tail_size) - 1 {
+            return Err(DecoderError::InvalidPadding(padding_offset));
         }
 
-        self.tail = 0; // reset (make object reusable)
-        self.tail_size = 0;
+        self.reset(); // make object reusable
 
         Ok(())
     }
 
+    /// Resets the reader to its initial state so it can be used for decoding
+    /// a new sequence.
+    pub fn reset(&mut self) {
+        self.id = 0;
+        self.buf = 0;
+        self.buf_size = 0;
+        self.tail = 0;
+        self.tail_size = 0;
+        self.padding = 0;
+        self.padding_offset = 0;
+        self.offset = 0;
+    }
+
     /// Tries to decode the next chunk of N bits where N represents the speed.
     /// 
     /// This function expects that the `buf_size` is grater or equal to 1. You
     /// should not call this function if this condition is not meet.
     fn decode_next(&mut self, dst: &mut Vec<u8>) -> Result<(), DecoderError> {
-        let key = self.buf >> self.buf_size - self.speed;
-        let (next_id, ascii, leftover) = self.find_target(key)?;
+        let key = self.buf >> (self.buf_size - self.table.speed());
+        let target = self.find_target(key)?;
+        self.apply_target(key, target, dst)
+    }
+
+    /// Consumes the chunk `key` from the buffer based on the packed translation
+    /// `target` and writes decoded characters into `dst`.
+    /// 
+    /// A target can hold up to two characters. The second character is
+    /// possible only when reading more than 5 bits at a time.
+    #[inline]
+    fn apply_target(
+        &mut self,
+        key: usize,
+        target: u32,
+        dst: &mut Vec<u8>,
+    ) -> Result<(), DecoderError> {
+        let leftover = leftover(target);
+        let used = self.table.speed() - leftover; // the number of consumed bits
+        let mut code_offset = self.offset - self.tail_size; // where the current code starts
+        self.offset += used;
 
-        self.buf -= key >> leftover << self.buf_size - self.speed + leftover as usize; // remove key from buffer
-        self.buf_size -= self.speed - leftover as usize;
+        self.buf -= key >> leftover << (self.buf_size - used); // remove key from buffer
+        self.buf_size -= used;
 
-        self.tail <<= self.speed - leftover as usize; // append chunk to tail
+        self.tail <<= used; // append chunk to tail
         self.tail |= key >> leftover;
-        self.tail_size += self.speed - leftover as usize;
+        self.tail_size += used;
 
-        if let Some(ascii) = ascii {
+        if target & PACKED_ASCII != 0 {
             self.id = 0;
             self.tail = 0;
             self.tail_size = 0;
-            if ascii < 256 { // valid character
-                dst.push(ascii as u8);
-                Ok(())
+            let second = if target & PACKED_ASCII2 != 0 {
+                Some(ascii2(target))
             } else {
-                Err(DecoderError::InvalidInput)
+                None
+            };
+            for ascii in Some(ascii(target)).into_iter().chain(second) {
+                if ascii == 256 && self.padding == 0 { // EOS can only be a part of padding
+                    self.padding_offset = code_offset;
+                }
+                if self.padding > 0 || ascii == 256 {
+                    self.padding += self.coding_len(256) as usize;
+                    if ascii != 256 || self.coding_len(256) > 7 { // EOS inside the string
+                        return Err(DecoderError::EosInString(self.padding_offset));
+                    } else if self.padding > 7 {
+                        return Err(DecoderError::PaddingTooLong(self.padding_offset));
+                    }
+                } else {
+                    dst.push(ascii as u8);
+                }
+                code_offset += self.coding_len(ascii) as usize;
             }
-        } else if let Some(next_id) = next_id { // transition
-            self.id = next_id as usize;
+            Ok(())
+        } else if target & PACKED_NEXT_ID != 0 { // transition
+            self.id = (target >> 18 & 0xff) as usize;
             Ok(())
         } else {
-            Err(DecoderError::InvalidInput)
+            Err(DecoderError::InvalidCode(code_offset))
         }
-    }    
-
-    /// Returns the translation target tuple based on reader speed.
-    fn find_target(&self, key: usize) -> Result<(Option<u8>, Option<u16>, u8), DecoderError> {
-        match self.speed {
-            2 => {
-                match crate::decoder::table2::DECODE_TABLE.get(self.id) {
-                    Some(transitions) => match transitions.get(key as usize) {
-                        Some(target) => Ok(*target),
-                        None => Err(DecoderError::InvalidInput),
-                    },
-                    None => Err(DecoderError::InvalidInput),
-                }
-            },
-            3 => {
-                match crate::decoder::table3::DECODE_TABLE.get(self.id) {
-                    Some(transitions) => match transitions.get(key as usize) {
-                        Some(target) => Ok(*target),
-                        None => Err(DecoderError::InvalidInput),
-                    },
-                    None => Err(DecoderError::InvalidInput),
-                }
-            },
-            4 => {
-                match crate::decoder::table4::DECODE_TABLE.get(self.id) {
-                    Some(transitions) => match transitions.get(key as usize) {
-                        Some(target) => Ok(*target),
-                        None => Err(DecoderError::InvalidInput),
-                    },
-                    None => Err(DecoderError::InvalidInput),
-                }
-            },
-            5 => {
-                match crate::decoder::table5::DECODE_TABLE.get(self.id) {
-                    Some(transitions) => match transitions.get(key as usize) {
-                        Some(target) => Ok(*target),
-                        None => Err(DecoderError::InvalidInput),
-                    },
-                    None => Err(DecoderError::InvalidInput),
-                }
-            },
-            _ => {
-                match crate::decoder::table1::DECODE_TABLE.get(self.id) {
-                    Some(transitions) => match transitions.get(key as usize) {
-                        Some(target) => Ok(*target),
-                        None => Err(DecoderError::InvalidInput),
-                    },
-                    None => Err(DecoderError::InvalidInput),
-                }
-            }
+    }
+
+    /// Returns the code length of the provided symbol.
+    fn coding_len(&self, ascii: usize) -> u8 {
+        self.table.coding_len(ascii)
+    }
+
+    /// Returns the packed translation target based on reader speed.
+    #[inline]
+    fn find_target(&self, key: usize) -> Result<u32, DecoderError> {
+        match self.table.target(self.id, key)? {
+            Some(target) => Ok(target),
+            None => Err(DecoderError::InvalidCode(self.offset - self.tail_size)),
         }
     }
 }
+
+/// Returns the first symbol of a packed transition.
+#[inline]
+fn ascii(target: u32) -> usize {
+    (target & 0x1ff) as usize
+}
+
+/// Returns the second symbol of a packed transition.
+#[inline]
+fn ascii2(target: u32) -> usize {
+    (target >> 9 & 0x1ff) as usize
+}
+
+/// Returns the number of leftover bits of a packed transition.
+#[inline]
+fn leftover(target: u32) -> usize {
+    (target >> 26 & 0x7) as usize
+}
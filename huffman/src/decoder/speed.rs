@@ -1,5 +1,10 @@
 /// Provides available decoding speed options which represent the number of bits
 /// that the decoder can read at a time.
+/// 
+/// The translation table for each speed is generated at build time only when
+/// the corresponding `speed-N` cargo feature (e.g. `speed-5`) is enabled. All
+/// speeds are enabled by default. Decoding with a speed that has not been
+/// enabled results in the `UnsupportedSpeed` error.
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub enum DecoderSpeed {
     /// Indicates that a decoder should read 1-bit at a time.
@@ -16,10 +21,24 @@ pub enum DecoderSpeed {
 
     /// Indicates that a decoder should read 5-bits at a time.
     FiveBits = 5,
+
+    /// Indicates that a decoder should read 6-bits at a time.
+    SixBits = 6,
+
+    /// Indicates that a decoder should read 7-bits at a time.
+    SevenBits = 7,
+
+    /// Indicates that a decoder should read 8-bits (a byte) at a time.
+    EightBits = 8,
+
+    /// Indicates that a decoder should not use a translation table but should
+    /// rely on the canonical property of the code. This option uses the least
+    /// memory and is always enabled.
+    Canonical = 0,
 }
 
 impl DecoderSpeed {
-    /// Returns a vector of all available decoding speed options.
+    /// Returns a vector of all decoding speed options.
     pub fn known() -> Vec<DecoderSpeed> {
         vec![
             DecoderSpeed::OneBit,
@@ -27,6 +46,32 @@ impl DecoderSpeed {
             DecoderSpeed::ThreeBits,
             DecoderSpeed::FourBits,
             DecoderSpeed::FiveBits,
+            DecoderSpeed::SixBits,
+            DecoderSpeed::SevenBits,
+            DecoderSpeed::EightBits,
+            DecoderSpeed::Canonical,
         ]
     }
+
+    /// Returns a vector of decoding speed options enabled through the `speed-N`
+    /// cargo features.
+    pub fn enabled() -> Vec<DecoderSpeed> {
+        Self::known().into_iter().filter(|s| s.is_enabled()).collect()
+    }
+
+    /// Returns `true` if the translation table for this speed has been
+    /// compiled in.
+    pub fn is_enabled(&self) -> bool {
+        match self {
+            Self::OneBit => cfg!(feature = "speed-1"),
+            Self::TwoBits => cfg!(feature = "speed-2"),
+            Self::ThreeBits => cfg!(feature = "speed-3"),
+            Self::FourBits => cfg!(feature = "speed-4"),
+            Self::FiveBits => cfg!(feature = "speed-5"),
+            Self::SixBits => cfg!(feature = "speed-6"),
+            Self::SevenBits => cfg!(feature = "speed-7"),
+            Self::EightBits => cfg!(feature = "speed-8"),
+            Self::Canonical => true,
+        }
+    }
 }
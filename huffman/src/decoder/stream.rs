@@ -0,0 +1,160 @@
+use std::sync::Arc;
+use super::{CanonicalReader, CanonicalTable, DecodeReader, DecoderError, DecoderSpeed};
+use crate::HuffmanCode;
+use crate::flattener::flatten_packed;
+
+/// Provides a streaming decoder for Huffman sequences.
+/// 
+/// Unlike the `decode` function, which expects the whole sequence up front,
+/// the streaming decoder accepts the sequence in chunks. The decoder keeps the
+/// state between the calls, so a sequence can be split at an arbitrary byte
+/// (e.g. an HPACK string literal split across `CONTINUATION` frames). The
+/// padding is validated once, when the decoding is finished.
+/// 
+/// **Example:**
+/// 
+/// ```rust
+/// use httlib_huffman::{DecoderSpeed, HuffmanDecoder};
+/// 
+/// let mut decoder = HuffmanDecoder::new(DecoderSpeed::Canonical); // always enabled
+/// let mut dst = Vec::new();
+/// decoder.feed(&[198, 90, 40, 58, 158], &mut dst).unwrap();
+/// decoder.feed(&[15, 101, 18, 127, 31], &mut dst).unwrap();
+/// decoder.finish(&mut dst).unwrap();
+/// assert_eq!(dst, b"Hello world!");
+/// ```
+#[derive(Debug)]
+pub struct HuffmanDecoder {
+    /// The underlying reader holding the decoding state.
+    reader: Reader,
+}
+
+/// Holds the reader for the selected decoding speed.
+#[derive(Debug)]
+enum Reader {
+    /// The table-driven reader.
+    Table(DecodeReader),
+
+    /// The canonical reader which needs no translation matrix.
+    Canonical(CanonicalReader),
+}
+
+impl Reader {
+    /// Decodes the next byte of the sequence.
+    fn decode(&mut self, byte: u8, dst: &mut Vec<u8>) -> Result<(), DecoderError> {
+        match self {
+            Self::Table(reader) => reader.decode(byte, dst),
+            Self::Canonical(reader) => reader.decode(byte, dst),
+        }
+    }
+
+    /// Decodes the remaining bits and validates the padding.
+    fn finalize(&mut self, dst: &mut Vec<u8>) -> Result<(), DecoderError> {
+        match self {
+            Self::Table(reader) => reader.finalize(dst),
+            Self::Canonical(reader) => reader.finalize(dst),
+        }
+    }
+
+    /// Resets the reader to its initial state.
+    fn reset(&mut self) {
+        match self {
+            Self::Table(reader) => reader.reset(),
+            Self::Canonical(reader) => reader.reset(),
+        }
+    }
+}
+
+impl HuffmanDecoder {
+    /// Returns a new decoder instance which reads `speed` bits at a time.
+    pub fn new(speed: DecoderSpeed) -> Self {
+        Self {
+            reader: match speed {
+                DecoderSpeed::Canonical => Reader::Canonical(CanonicalReader::new(CanonicalTable::hpack())),
+                speed => Reader::Table(DecodeReader::new(speed as usize)),
+            },
+        }
+    }
+
+    /// Returns a new decoder instance for the custom `code` which reads `speed`
+    /// bits at a time.
+    /// 
+    /// The translation matrix is generated from the code on the fly so the
+    /// decoder does not depend on the enabled `speed-N` features. Creating the
+    /// decoder is expensive thus it should be reused when possible.
+    pub fn with_code(code: &HuffmanCode, speed: DecoderSpeed) -> Self {
+        if speed == DecoderSpeed::Canonical {
+            return Self {
+                reader: Reader::Canonical(CanonicalReader::new(Arc::new(CanonicalTable::new(code.codings())))),
+            };
+        }
+
+        let table = (code.codings().to_vec(), flatten_packed(code.codings(), speed));
+        Self {
+            reader: Reader::Table(DecodeReader::with_code(speed as usize, Arc::new(table))),
+        }
+    }
+
+    /// Decodes the next chunk of the Huffman sequence and writes the decoded
+    /// bytes into `dst`.
+    /// 
+    /// Bits that do not yet form a complete symbol are kept by the decoder and
+    /// are decoded when the next chunk arrives or when the decoder finishes.
+    pub fn feed(&mut self, src: &[u8], dst: &mut Vec<u8>) -> Result<(), DecoderError> {
+        for byte in src {
+            if let Err(err) = self.reader.decode(*byte, dst) {
+                self.reader.reset();
+                return Err(err);
+            }
+        }
+        Ok(())
+    }
+
+    /// Decodes the remaining bits of the sequence and validates the padding.
+    /// 
+    /// The decoder is reset afterwards, whether or not the sequence was valid,
+    /// so the same instance can be used for decoding the next sequence.
+    pub fn finish(&mut self, dst: &mut Vec<u8>) -> Result<(), DecoderError> {
+        let res = self.reader.finalize(dst);
+        self.reader.reset();
+        res
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Should decode a Huffman sequence fed in arbitrary chunks by using any
+    /// valid decoding speed.
+    #[test]
+    fn decodes_chunks() {
+        let code = vec![198, 90, 40, 58, 158, 15, 101, 18, 127, 31]; // Hello world!
+        for speed in DecoderSpeed::enabled() {
+            for split in 0..code.len() {
+                let mut decoder = HuffmanDecoder::new(speed);
+                let mut dst = Vec::new();
+                decoder.feed(&code[..split], &mut dst).unwrap();
+                decoder.feed(&code[split..], &mut dst).unwrap();
+                decoder.finish(&mut dst).unwrap();
+                assert_eq!(dst, b"Hello world!");
+            }
+        }
+    }
+
+    /// Should validate the padding when finished and reset the decoder so it
+    /// can be reused.
+    #[test]
+    fn validates_padding_on_finish() {
+        for speed in DecoderSpeed::enabled() {
+            let mut decoder = HuffmanDecoder::new(speed);
+            let mut dst = Vec::new();
+            decoder.feed(&[0b11111111], &mut dst).unwrap();
+            decoder.feed(&[0b11111111], &mut dst).unwrap();
+            assert_eq!(decoder.finish(&mut dst), Err(DecoderError::PaddingTooLong(0))); // EOS (padding > 7 bits)
+            decoder.feed(&[135], &mut dst).unwrap();
+            decoder.finish(&mut dst).unwrap();
+            assert_eq!(dst, b"A");
+        }
+    }
+}
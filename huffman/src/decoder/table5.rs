@@ -1,2078 +1,5 @@
-/// Represents a static translation table for decoding Huffman sequence by
-/// reading 5-bits at at time.
-pub const DECODE_TABLE: [[(Option<u8>, Option<u16>, u8); 32]; 61] = [ // (next_id, ascii, leftover)
-    [ // 0
-        (None, Some(48), 0),
-        (None, Some(49), 0),
-        (None, Some(50), 0),
-        (None, Some(97), 0),
-        (None, Some(99), 0),
-        (None, Some(101), 0),
-        (None, Some(105), 0),
-        (None, Some(111), 0),
-        (None, Some(115), 0),
-        (None, Some(116), 0),
-        (Some(11), None, 0),
-        (Some(14), None, 0),
-        (Some(15), None, 0),
-        (Some(16), None, 0),
-        (Some(17), None, 0),
-        (Some(18), None, 0),
-        (Some(20), None, 0),
-        (Some(26), None, 0),
-        (Some(27), None, 0),
-        (Some(28), None, 0),
-        (Some(30), None, 0),
-        (Some(31), None, 0),
-        (Some(32), None, 0),
-        (Some(19), None, 0),
-        (Some(21), None, 0),
-        (Some(22), None, 0),
-        (Some(23), None, 0),
-        (Some(24), None, 0),
-        (Some(25), None, 0),
-        (Some(29), None, 0),
-        (Some(33), None, 0),
-        (Some(1), None, 0)
-    ],
-    [ // 1
-        (None, Some(38), 2),
-        (None, Some(38), 2),
-        (None, Some(38), 2),
-        (None, Some(38), 2),
-        (None, Some(42), 2),
-        (None, Some(42), 2),
-        (None, Some(42), 2),
-        (None, Some(42), 2),
-        (None, Some(44), 2),
-        (None, Some(44), 2),
-        (None, Some(44), 2),
-        (None, Some(44), 2),
-        (None, Some(59), 2),
-        (None, Some(59), 2),
-        (None, Some(59), 2),
-        (None, Some(59), 2),
-        (None, Some(88), 2),
-        (None, Some(88), 2),
-        (None, Some(88), 2),
-        (None, Some(88), 2),
-        (None, Some(90), 2),
-        (None, Some(90), 2),
-        (None, Some(90), 2),
-        (None, Some(90), 2),
-        (None, Some(33), 0),
-        (None, Some(34), 0),
-        (None, Some(40), 0),
-        (None, Some(41), 0),
-        (None, Some(63), 0),
-        (Some(13), None, 0),
-        (Some(12), None, 0),
-        (Some(2), None, 0)
-    ],
-    [ // 2
-        (None, Some(0), 2),
-        (None, Some(0), 2),
-        (None, Some(0), 2),
-        (None, Some(0), 2),
-        (None, Some(36), 2),
-        (None, Some(36), 2),
-        (None, Some(36), 2),
-        (None, Some(36), 2),
-        (None, Some(64), 2),
-        (None, Some(64), 2),
-        (None, Some(64), 2),
-        (None, Some(64), 2),
-        (None, Some(91), 2),
-        (None, Some(91), 2),
-        (None, Some(91), 2),
-        (None, Some(91), 2),
-        (None, Some(93), 2),
-        (None, Some(93), 2),
-        (None, Some(93), 2),
-        (None, Some(93), 2),
-        (None, Some(126), 2),
-        (None, Some(126), 2),
-        (None, Some(126), 2),
-        (None, Some(126), 2),
-        (None, Some(94), 1),
-        (None, Some(94), 1),
-        (None, Some(125), 1),
-        (None, Some(125), 1),
-        (None, Some(60), 0),
-        (None, Some(96), 0),
-        (None, Some(123), 0),
-        (Some(3), None, 0)
-    ],
-    [ // 3
-        (None, Some(92), 1),
-        (None, Some(92), 1),
-        (None, Some(195), 1),
-        (None, Some(195), 1),
-        (None, Some(208), 1),
-        (None, Some(208), 1),
-        (None, Some(128), 0),
-        (None, Some(130), 0),
-        (None, Some(131), 0),
-        (None, Some(162), 0),
-        (None, Some(184), 0),
-        (None, Some(194), 0),
-        (None, Some(224), 0),
-        (None, Some(226), 0),
-        (Some(37), None, 0),
-        (Some(41), None, 0),
-        (Some(42), None, 0),
-        (Some(44), None, 0),
-        (Some(53), None, 0),
-        (Some(56), None, 0),
-        (Some(34), None, 0),
-        (Some(35), None, 0),
-        (Some(38), None, 0),
-        (Some(39), None, 0),
-        (Some(43), None, 0),
-        (Some(45), None, 0),
-        (Some(47), None, 0),
-        (Some(4), None, 0),
-        (Some(36), None, 0),
-        (Some(40), None, 0),
-        (Some(8), None, 0),
-        (Some(5), None, 0)
-    ],
-    [ // 4
-        (None, Some(1), 2),
-        (None, Some(1), 2),
-        (None, Some(1), 2),
-        (None, Some(1), 2),
-        (None, Some(135), 2),
-        (None, Some(135), 2),
-        (None, Some(135), 2),
-        (None, Some(135), 2),
-        (None, Some(137), 2),
-        (None, Some(137), 2),
-        (None, Some(137), 2),
-        (None, Some(137), 2),
-        (None, Some(138), 2),
-        (None, Some(138), 2),
-        (None, Some(138), 2),
-        (None, Some(138), 2),
-        (None, Some(139), 2),
-        (None, Some(139), 2),
-        (None, Some(139), 2),
-        (None, Some(139), 2),
-        (None, Some(140), 2),
-        (None, Some(140), 2),
-        (None, Some(140), 2),
-        (None, Some(140), 2),
-        (None, Some(141), 2),
-        (None, Some(141), 2),
-        (None, Some(141), 2),
-        (None, Some(141), 2),
-        (None, Some(143), 2),
-        (None, Some(143), 2),
-        (None, Some(143), 2),
-        (None, Some(143), 2)
-    ],
-    [ // 5
-        (None, Some(171), 1),
-        (None, Some(171), 1),
-        (None, Some(206), 1),
-        (None, Some(206), 1),
-        (None, Some(215), 1),
-        (None, Some(215), 1),
-        (None, Some(225), 1),
-        (None, Some(225), 1),
-        (None, Some(236), 1),
-        (None, Some(236), 1),
-        (None, Some(237), 1),
-        (None, Some(237), 1),
-        (None, Some(199), 0),
-        (None, Some(207), 0),
-        (None, Some(234), 0),
-        (None, Some(235), 0),
-        (Some(46), None, 0),
-        (Some(48), None, 0),
-        (Some(49), None, 0),
-        (Some(51), None, 0),
-        (Some(54), None, 0),
-        (Some(57), None, 0),
-        (Some(58), None, 0),
-        (Some(50), None, 0),
-        (Some(52), None, 0),
-        (Some(55), None, 0),
-        (Some(59), None, 0),
-        (Some(60), None, 0),
-        (Some(6), None, 0),
-        (Some(7), None, 0),
-        (Some(10), None, 0),
-        (Some(9), None, 0)
-    ],
-    [ // 6
-        (None, Some(254), 3),
-        (None, Some(254), 3),
-        (None, Some(254), 3),
-        (None, Some(254), 3),
-        (None, Some(254), 3),
-        (None, Some(254), 3),
-        (None, Some(254), 3),
-        (None, Some(254), 3),
-        (None, Some(2), 2),
-        (None, Some(2), 2),
-        (None, Some(2), 2),
-        (None, Some(2), 2),
-        (None, Some(3), 2),
-        (None, Some(3), 2),
-        (None, Some(3), 2),
-        (None, Some(3), 2),
-        (None, Some(4), 2),
-        (None, Some(4), 2),
-        (None, Some(4), 2),
-        (None, Some(4), 2),
-        (None, Some(5), 2),
-        (None, Some(5), 2),
-        (None, Some(5), 2),
-        (None, Some(5), 2),
-        (None, Some(6), 2),
-        (None, Some(6), 2),
-        (None, Some(6), 2),
-        (None, Some(6), 2),
-        (None, Some(7), 2),
-        (None, Some(7), 2),
-        (None, Some(7), 2),
-        (None, Some(7), 2)
-    ],
-    [ // 7
-        (None, Some(8), 2),
-        (None, Some(8), 2),
-        (None, Some(8), 2),
-        (None, Some(8), 2),
-        (None, Some(11), 2),
-        (None, Some(11), 2),
-        (None, Some(11), 2),
-        (None, Some(11), 2),
-        (None, Some(12), 2),
-        (None, Some(12), 2),
-        (None, Some(12), 2),
-        (None, Some(12), 2),
-        (None, Some(14), 2),
-        (None, Some(14), 2),
-        (None, Some(14), 2),
-        (None, Some(14), 2),
-        (None, Some(15), 2),
-        (None, Some(15), 2),
-        (None, Some(15), 2),
-        (None, Some(15), 2),
-        (None, Some(16), 2),
-        (None, Some(16), 2),
-        (None, Some(16), 2),
-        (None, Some(16), 2),
-        (None, Some(17), 2),
-        (None, Some(17), 2),
-        (None, Some(17), 2),
-        (None, Some(17), 2),
-        (None, Some(18), 2),
-        (None, Some(18), 2),
-        (None, Some(18), 2),
-        (None, Some(18), 2)
-    ],
-    [ // 8
-        (None, Some(188), 2),
-        (None, Some(188), 2),
-        (None, Some(188), 2),
-        (None, Some(188), 2),
-        (None, Some(191), 2),
-        (None, Some(191), 2),
-        (None, Some(191), 2),
-        (None, Some(191), 2),
-        (None, Some(197), 2),
-        (None, Some(197), 2),
-        (None, Some(197), 2),
-        (None, Some(197), 2),
-        (None, Some(231), 2),
-        (None, Some(231), 2),
-        (None, Some(231), 2),
-        (None, Some(231), 2),
-        (None, Some(239), 2),
-        (None, Some(239), 2),
-        (None, Some(239), 2),
-        (None, Some(239), 2),
-        (None, Some(9), 1),
-        (None, Some(9), 1),
-        (None, Some(142), 1),
-        (None, Some(142), 1),
-        (None, Some(144), 1),
-        (None, Some(144), 1),
-        (None, Some(145), 1),
-        (None, Some(145), 1),
-        (None, Some(148), 1),
-        (None, Some(148), 1),
-        (None, Some(159), 1),
-        (None, Some(159), 1)
-    ],
-    [ // 9
-        (None, Some(28), 2),
-        (None, Some(28), 2),
-        (None, Some(28), 2),
-        (None, Some(28), 2),
-        (None, Some(29), 2),
-        (None, Some(29), 2),
-        (None, Some(29), 2),
-        (None, Some(29), 2),
-        (None, Some(30), 2),
-        (None, Some(30), 2),
-        (None, Some(30), 2),
-        (None, Some(30), 2),
-        (None, Some(31), 2),
-        (None, Some(31), 2),
-        (None, Some(31), 2),
-        (None, Some(31), 2),
-        (None, Some(127), 2),
-        (None, Some(127), 2),
-        (None, Some(127), 2),
-        (None, Some(127), 2),
-        (None, Some(220), 2),
-        (None, Some(220), 2),
-        (None, Some(220), 2),
-        (None, Some(220), 2),
-        (None, Some(249), 2),
-        (None, Some(249), 2),
-        (None, Some(249), 2),
-        (None, Some(249), 2),
-        (None, Some(10), 0),
-        (None, Some(13), 0),
-        (None, Some(22), 0),
-        (None, Some(256), 0)
-    ],
-    [ // 10
-        (None, Some(19), 2),
-        (None, Some(19), 2),
-        (None, Some(19), 2),
-        (None, Some(19), 2),
-        (None, Some(20), 2),
-        (None, Some(20), 2),
-        (None, Some(20), 2),
-        (None, Some(20), 2),
-        (None, Some(21), 2),
-        (None, Some(21), 2),
-        (None, Some(21), 2),
-        (None, Some(21), 2),
-        (None, Some(23), 2),
-        (None, Some(23), 2),
-        (None, Some(23), 2),
-        (None, Some(23), 2),
-        (None, Some(24), 2),
-        (None, Some(24), 2),
-        (None, Some(24), 2),
-        (None, Some(24), 2),
-        (None, Some(25), 2),
-        (None, Some(25), 2),
-        (None, Some(25), 2),
-        (None, Some(25), 2),
-        (None, Some(26), 2),
-        (None, Some(26), 2),
-        (None, Some(26), 2),
-        (None, Some(26), 2),
-        (None, Some(27), 2),
-        (None, Some(27), 2),
-        (None, Some(27), 2),
-        (None, Some(27), 2)
-    ],
-    [ // 11
-        (None, Some(32), 4),
-        (None, Some(32), 4),
-        (None, Some(32), 4),
-        (None, Some(32), 4),
-        (None, Some(32), 4),
-        (None, Some(32), 4),
-        (None, Some(32), 4),
-        (None, Some(32), 4),
-        (None, Some(32), 4),
-        (None, Some(32), 4),
-        (None, Some(32), 4),
-        (None, Some(32), 4),
-        (None, Some(32), 4),
-        (None, Some(32), 4),
-        (None, Some(32), 4),
-        (None, Some(32), 4),
-        (None, Some(37), 4),
-        (None, Some(37), 4),
-        (None, Some(37), 4),
-        (None, Some(37), 4),
-        (None, Some(37), 4),
-        (None, Some(37), 4),
-        (None, Some(37), 4),
-        (None, Some(37), 4),
-        (None, Some(37), 4),
-        (None, Some(37), 4),
-        (None, Some(37), 4),
-        (None, Some(37), 4),
-        (None, Some(37), 4),
-        (None, Some(37), 4),
-        (None, Some(37), 4),
-        (None, Some(37), 4)
-    ],
-    [ // 12
-        (None, Some(124), 4),
-        (None, Some(124), 4),
-        (None, Some(124), 4),
-        (None, Some(124), 4),
-        (None, Some(124), 4),
-        (None, Some(124), 4),
-        (None, Some(124), 4),
-        (None, Some(124), 4),
-        (None, Some(124), 4),
-        (None, Some(124), 4),
-        (None, Some(124), 4),
-        (None, Some(124), 4),
-        (None, Some(124), 4),
-        (None, Some(124), 4),
-        (None, Some(124), 4),
-        (None, Some(124), 4),
-        (None, Some(35), 3),
-        (None, Some(35), 3),
-        (None, Some(35), 3),
-        (None, Some(35), 3),
-        (None, Some(35), 3),
-        (None, Some(35), 3),
-        (None, Some(35), 3),
-        (None, Some(35), 3),
-        (None, Some(62), 3),
-        (None, Some(62), 3),
-        (None, Some(62), 3),
-        (None, Some(62), 3),
-        (None, Some(62), 3),
-        (None, Some(62), 3),
-        (None, Some(62), 3),
-        (None, Some(62), 3)
-    ],
-    [ // 13
-        (None, Some(39), 4),
-        (None, Some(39), 4),
-        (None, Some(39), 4),
-        (None, Some(39), 4),
-        (None, Some(39), 4),
-        (None, Some(39), 4),
-        (None, Some(39), 4),
-        (None, Some(39), 4),
-        (None, Some(39), 4),
-        (None, Some(39), 4),
-        (None, Some(39), 4),
-        (None, Some(39), 4),
-        (None, Some(39), 4),
-        (None, Some(39), 4),
-        (None, Some(39), 4),
-        (None, Some(39), 4),
-        (None, Some(43), 4),
-        (None, Some(43), 4),
-        (None, Some(43), 4),
-        (None, Some(43), 4),
-        (None, Some(43), 4),
-        (None, Some(43), 4),
-        (None, Some(43), 4),
-        (None, Some(43), 4),
-        (None, Some(43), 4),
-        (None, Some(43), 4),
-        (None, Some(43), 4),
-        (None, Some(43), 4),
-        (None, Some(43), 4),
-        (None, Some(43), 4),
-        (None, Some(43), 4),
-        (None, Some(43), 4)
-    ],
-    [ // 14
-        (None, Some(45), 4),
-        (None, Some(45), 4),
-        (None, Some(45), 4),
-        (None, Some(45), 4),
-        (None, Some(45), 4),
-        (None, Some(45), 4),
-        (None, Some(45), 4),
-        (None, Some(45), 4),
-        (None, Some(45), 4),
-        (None, Some(45), 4),
-        (None, Some(45), 4),
-        (None, Some(45), 4),
-        (None, Some(45), 4),
-        (None, Some(45), 4),
-        (None, Some(45), 4),
-        (None, Some(45), 4),
-        (None, Some(46), 4),
-        (None, Some(46), 4),
-        (None, Some(46), 4),
-        (None, Some(46), 4),
-        (None, Some(46), 4),
-        (None, Some(46), 4),
-        (None, Some(46), 4),
-        (None, Some(46), 4),
-        (None, Some(46), 4),
-        (None, Some(46), 4),
-        (None, Some(46), 4),
-        (None, Some(46), 4),
-        (None, Some(46), 4),
-        (None, Some(46), 4),
-        (None, Some(46), 4),
-        (None, Some(46), 4)
-    ],
-    [ // 15
-        (None, Some(47), 4),
-        (None, Some(47), 4),
-        (None, Some(47), 4),
-        (None, Some(47), 4),
-        (None, Some(47), 4),
-        (None, Some(47), 4),
-        (None, Some(47), 4),
-        (None, Some(47), 4),
-        (None, Some(47), 4),
-        (None, Some(47), 4),
-        (None, Some(47), 4),
-        (None, Some(47), 4),
-        (None, Some(47), 4),
-        (None, Some(47), 4),
-        (None, Some(47), 4),
-        (None, Some(47), 4),
-        (None, Some(51), 4),
-        (None, Some(51), 4),
-        (None, Some(51), 4),
-        (None, Some(51), 4),
-        (None, Some(51), 4),
-        (None, Some(51), 4),
-        (None, Some(51), 4),
-        (None, Some(51), 4),
-        (None, Some(51), 4),
-        (None, Some(51), 4),
-        (None, Some(51), 4),
-        (None, Some(51), 4),
-        (None, Some(51), 4),
-        (None, Some(51), 4),
-        (None, Some(51), 4),
-        (None, Some(51), 4)
-    ],
-    [ // 16
-        (None, Some(52), 4),
-        (None, Some(52), 4),
-        (None, Some(52), 4),
-        (None, Some(52), 4),
-        (None, Some(52), 4),
-        (None, Some(52), 4),
-        (None, Some(52), 4),
-        (None, Some(52), 4),
-        (None, Some(52), 4),
-        (None, Some(52), 4),
-        (None, Some(52), 4),
-        (None, Some(52), 4),
-        (None, Some(52), 4),
-        (None, Some(52), 4),
-        (None, Some(52), 4),
-        (None, Some(52), 4),
-        (None, Some(53), 4),
-        (None, Some(53), 4),
-        (None, Some(53), 4),
-        (None, Some(53), 4),
-        (None, Some(53), 4),
-        (None, Some(53), 4),
-        (None, Some(53), 4),
-        (None, Some(53), 4),
-        (None, Some(53), 4),
-        (None, Some(53), 4),
-        (None, Some(53), 4),
-        (None, Some(53), 4),
-        (None, Some(53), 4),
-        (None, Some(53), 4),
-        (None, Some(53), 4),
-        (None, Some(53), 4)
-    ],
-    [ // 17
-        (None, Some(54), 4),
-        (None, Some(54), 4),
-        (None, Some(54), 4),
-        (None, Some(54), 4),
-        (None, Some(54), 4),
-        (None, Some(54), 4),
-        (None, Some(54), 4),
-        (None, Some(54), 4),
-        (None, Some(54), 4),
-        (None, Some(54), 4),
-        (None, Some(54), 4),
-        (None, Some(54), 4),
-        (None, Some(54), 4),
-        (None, Some(54), 4),
-        (None, Some(54), 4),
-        (None, Some(54), 4),
-        (None, Some(55), 4),
-        (None, Some(55), 4),
-        (None, Some(55), 4),
-        (None, Some(55), 4),
-        (None, Some(55), 4),
-        (None, Some(55), 4),
-        (None, Some(55), 4),
-        (None, Some(55), 4),
-        (None, Some(55), 4),
-        (None, Some(55), 4),
-        (None, Some(55), 4),
-        (None, Some(55), 4),
-        (None, Some(55), 4),
-        (None, Some(55), 4),
-        (None, Some(55), 4),
-        (None, Some(55), 4)
-    ],
-    [ // 18
-        (None, Some(56), 4),
-        (None, Some(56), 4),
-        (None, Some(56), 4),
-        (None, Some(56), 4),
-        (None, Some(56), 4),
-        (None, Some(56), 4),
-        (None, Some(56), 4),
-        (None, Some(56), 4),
-        (None, Some(56), 4),
-        (None, Some(56), 4),
-        (None, Some(56), 4),
-        (None, Some(56), 4),
-        (None, Some(56), 4),
-        (None, Some(56), 4),
-        (None, Some(56), 4),
-        (None, Some(56), 4),
-        (None, Some(57), 4),
-        (None, Some(57), 4),
-        (None, Some(57), 4),
-        (None, Some(57), 4),
-        (None, Some(57), 4),
-        (None, Some(57), 4),
-        (None, Some(57), 4),
-        (None, Some(57), 4),
-        (None, Some(57), 4),
-        (None, Some(57), 4),
-        (None, Some(57), 4),
-        (None, Some(57), 4),
-        (None, Some(57), 4),
-        (None, Some(57), 4),
-        (None, Some(57), 4),
-        (None, Some(57), 4)
-    ],
-    [ // 19
-        (None, Some(58), 3),
-        (None, Some(58), 3),
-        (None, Some(58), 3),
-        (None, Some(58), 3),
-        (None, Some(58), 3),
-        (None, Some(58), 3),
-        (None, Some(58), 3),
-        (None, Some(58), 3),
-        (None, Some(66), 3),
-        (None, Some(66), 3),
-        (None, Some(66), 3),
-        (None, Some(66), 3),
-        (None, Some(66), 3),
-        (None, Some(66), 3),
-        (None, Some(66), 3),
-        (None, Some(66), 3),
-        (None, Some(67), 3),
-        (None, Some(67), 3),
-        (None, Some(67), 3),
-        (None, Some(67), 3),
-        (None, Some(67), 3),
-        (None, Some(67), 3),
-        (None, Some(67), 3),
-        (None, Some(67), 3),
-        (None, Some(68), 3),
-        (None, Some(68), 3),
-        (None, Some(68), 3),
-        (None, Some(68), 3),
-        (None, Some(68), 3),
-        (None, Some(68), 3),
-        (None, Some(68), 3),
-        (None, Some(68), 3)
-    ],
-    [ // 20
-        (None, Some(61), 4),
-        (None, Some(61), 4),
-        (None, Some(61), 4),
-        (None, Some(61), 4),
-        (None, Some(61), 4),
-        (None, Some(61), 4),
-        (None, Some(61), 4),
-        (None, Some(61), 4),
-        (None, Some(61), 4),
-        (None, Some(61), 4),
-        (None, Some(61), 4),
-        (None, Some(61), 4),
-        (None, Some(61), 4),
-        (None, Some(61), 4),
-        (None, Some(61), 4),
-        (None, Some(61), 4),
-        (None, Some(65), 4),
-        (None, Some(65), 4),
-        (None, Some(65), 4),
-        (None, Some(65), 4),
-        (None, Some(65), 4),
-        (None, Some(65), 4),
-        (None, Some(65), 4),
-        (None, Some(65), 4),
-        (None, Some(65), 4),
-        (None, Some(65), 4),
-        (None, Some(65), 4),
-        (None, Some(65), 4),
-        (None, Some(65), 4),
-        (None, Some(65), 4),
-        (None, Some(65), 4),
-        (None, Some(65), 4)
-    ],
-    [ // 21
-        (None, Some(69), 3),
-        (None, Some(69), 3),
-        (None, Some(69), 3),
-        (None, Some(69), 3),
-        (None, Some(69), 3),
-        (None, Some(69), 3),
-        (None, Some(69), 3),
-        (None, Some(69), 3),
-        (None, Some(70), 3),
-        (None, Some(70), 3),
-        (None, Some(70), 3),
-        (None, Some(70), 3),
-        (None, Some(70), 3),
-        (None, Some(70), 3),
-        (None, Some(70), 3),
-        (None, Some(70), 3),
-        (None, Some(71), 3),
-        (None, Some(71), 3),
-        (None, Some(71), 3),
-        (None, Some(71), 3),
-        (None, Some(71), 3),
-        (None, Some(71), 3),
-        (None, Some(71), 3),
-        (None, Some(71), 3),
-        (None, Some(72), 3),
-        (None, Some(72), 3),
-        (None, Some(72), 3),
-        (None, Some(72), 3),
-        (None, Some(72), 3),
-        (None, Some(72), 3),
-        (None, Some(72), 3),
-        (None, Some(72), 3)
-    ],
-    [ // 22
-        (None, Some(73), 3),
-        (None, Some(73), 3),
-        (None, Some(73), 3),
-        (None, Some(73), 3),
-        (None, Some(73), 3),
-        (None, Some(73), 3),
-        (None, Some(73), 3),
-        (None, Some(73), 3),
-        (None, Some(74), 3),
-        (None, Some(74), 3),
-        (None, Some(74), 3),
-        (None, Some(74), 3),
-        (None, Some(74), 3),
-        (None, Some(74), 3),
-        (None, Some(74), 3),
-        (None, Some(74), 3),
-        (None, Some(75), 3),
-        (None, Some(75), 3),
-        (None, Some(75), 3),
-        (None, Some(75), 3),
-        (None, Some(75), 3),
-        (None, Some(75), 3),
-        (None, Some(75), 3),
-        (None, Some(75), 3),
-        (None, Some(76), 3),
-        (None, Some(76), 3),
-        (None, Some(76), 3),
-        (None, Some(76), 3),
-        (None, Some(76), 3),
-        (None, Some(76), 3),
-        (None, Some(76), 3),
-        (None, Some(76), 3)
-    ],
-    [ // 23
-        (None, Some(77), 3),
-        (None, Some(77), 3),
-        (None, Some(77), 3),
-        (None, Some(77), 3),
-        (None, Some(77), 3),
-        (None, Some(77), 3),
-        (None, Some(77), 3),
-        (None, Some(77), 3),
-        (None, Some(78), 3),
-        (None, Some(78), 3),
-        (None, Some(78), 3),
-        (None, Some(78), 3),
-        (None, Some(78), 3),
-        (None, Some(78), 3),
-        (None, Some(78), 3),
-        (None, Some(78), 3),
-        (None, Some(79), 3),
-        (None, Some(79), 3),
-        (None, Some(79), 3),
-        (None, Some(79), 3),
-        (None, Some(79), 3),
-        (None, Some(79), 3),
-        (None, Some(79), 3),
-        (None, Some(79), 3),
-        (None, Some(80), 3),
-        (None, Some(80), 3),
-        (None, Some(80), 3),
-        (None, Some(80), 3),
-        (None, Some(80), 3),
-        (None, Some(80), 3),
-        (None, Some(80), 3),
-        (None, Some(80), 3)
-    ],
-    [ // 24
-        (None, Some(81), 3),
-        (None, Some(81), 3),
-        (None, Some(81), 3),
-        (None, Some(81), 3),
-        (None, Some(81), 3),
-        (None, Some(81), 3),
-        (None, Some(81), 3),
-        (None, Some(81), 3),
-        (None, Some(82), 3),
-        (None, Some(82), 3),
-        (None, Some(82), 3),
-        (None, Some(82), 3),
-        (None, Some(82), 3),
-        (None, Some(82), 3),
-        (None, Some(82), 3),
-        (None, Some(82), 3),
-        (None, Some(83), 3),
-        (None, Some(83), 3),
-        (None, Some(83), 3),
-        (None, Some(83), 3),
-        (None, Some(83), 3),
-        (None, Some(83), 3),
-        (None, Some(83), 3),
-        (None, Some(83), 3),
-        (None, Some(84), 3),
-        (None, Some(84), 3),
-        (None, Some(84), 3),
-        (None, Some(84), 3),
-        (None, Some(84), 3),
-        (None, Some(84), 3),
-        (None, Some(84), 3),
-        (None, Some(84), 3)
-    ],
-    [ // 25
-        (None, Some(85), 3),
-        (None, Some(85), 3),
-        (None, Some(85), 3),
-        (None, Some(85), 3),
-        (None, Some(85), 3),
-        (None, Some(85), 3),
-        (None, Some(85), 3),
-        (None, Some(85), 3),
-        (None, Some(86), 3),
-        (None, Some(86), 3),
-        (None, Some(86), 3),
-        (None, Some(86), 3),
-        (None, Some(86), 3),
-        (None, Some(86), 3),
-        (None, Some(86), 3),
-        (None, Some(86), 3),
-        (None, Some(87), 3),
-        (None, Some(87), 3),
-        (None, Some(87), 3),
-        (None, Some(87), 3),
-        (None, Some(87), 3),
-        (None, Some(87), 3),
-        (None, Some(87), 3),
-        (None, Some(87), 3),
-        (None, Some(89), 3),
-        (None, Some(89), 3),
-        (None, Some(89), 3),
-        (None, Some(89), 3),
-        (None, Some(89), 3),
-        (None, Some(89), 3),
-        (None, Some(89), 3),
-        (None, Some(89), 3)
-    ],
-    [ // 26
-        (None, Some(95), 4),
-        (None, Some(95), 4),
-        (None, Some(95), 4),
-        (None, Some(95), 4),
-        (None, Some(95), 4),
-        (None, Some(95), 4),
-        (None, Some(95), 4),
-        (None, Some(95), 4),
-        (None, Some(95), 4),
-        (None, Some(95), 4),
-        (None, Some(95), 4),
-        (None, Some(95), 4),
-        (None, Some(95), 4),
-        (None, Some(95), 4),
-        (None, Some(95), 4),
-        (None, Some(95), 4),
-        (None, Some(98), 4),
-        (None, Some(98), 4),
-        (None, Some(98), 4),
-        (None, Some(98), 4),
-        (None, Some(98), 4),
-        (None, Some(98), 4),
-        (None, Some(98), 4),
-        (None, Some(98), 4),
-        (None, Some(98), 4),
-        (None, Some(98), 4),
-        (None, Some(98), 4),
-        (None, Some(98), 4),
-        (None, Some(98), 4),
-        (None, Some(98), 4),
-        (None, Some(98), 4),
-        (None, Some(98), 4)
-    ],
-    [ // 27
-        (None, Some(100), 4),
-        (None, Some(100), 4),
-        (None, Some(100), 4),
-        (None, Some(100), 4),
-        (None, Some(100), 4),
-        (None, Some(100), 4),
-        (None, Some(100), 4),
-        (None, Some(100), 4),
-        (None, Some(100), 4),
-        (None, Some(100), 4),
-        (None, Some(100), 4),
-        (None, Some(100), 4),
-        (None, Some(100), 4),
-        (None, Some(100), 4),
-        (None, Some(100), 4),
-        (None, Some(100), 4),
-        (None, Some(102), 4),
-        (None, Some(102), 4),
-        (None, Some(102), 4),
-        (None, Some(102), 4),
-        (None, Some(102), 4),
-        (None, Some(102), 4),
-        (None, Some(102), 4),
-        (None, Some(102), 4),
-        (None, Some(102), 4),
-        (None, Some(102), 4),
-        (None, Some(102), 4),
-        (None, Some(102), 4),
-        (None, Some(102), 4),
-        (None, Some(102), 4),
-        (None, Some(102), 4),
-        (None, Some(102), 4)
-    ],
-    [ // 28
-        (None, Some(103), 4),
-        (None, Some(103), 4),
-        (None, Some(103), 4),
-        (None, Some(103), 4),
-        (None, Some(103), 4),
-        (None, Some(103), 4),
-        (None, Some(103), 4),
-        (None, Some(103), 4),
-        (None, Some(103), 4),
-        (None, Some(103), 4),
-        (None, Some(103), 4),
-        (None, Some(103), 4),
-        (None, Some(103), 4),
-        (None, Some(103), 4),
-        (None, Some(103), 4),
-        (None, Some(103), 4),
-        (None, Some(104), 4),
-        (None, Some(104), 4),
-        (None, Some(104), 4),
-        (None, Some(104), 4),
-        (None, Some(104), 4),
-        (None, Some(104), 4),
-        (None, Some(104), 4),
-        (None, Some(104), 4),
-        (None, Some(104), 4),
-        (None, Some(104), 4),
-        (None, Some(104), 4),
-        (None, Some(104), 4),
-        (None, Some(104), 4),
-        (None, Some(104), 4),
-        (None, Some(104), 4),
-        (None, Some(104), 4)
-    ],
-    [ // 29
-        (None, Some(106), 3),
-        (None, Some(106), 3),
-        (None, Some(106), 3),
-        (None, Some(106), 3),
-        (None, Some(106), 3),
-        (None, Some(106), 3),
-        (None, Some(106), 3),
-        (None, Some(106), 3),
-        (None, Some(107), 3),
-        (None, Some(107), 3),
-        (None, Some(107), 3),
-        (None, Some(107), 3),
-        (None, Some(107), 3),
-        (None, Some(107), 3),
-        (None, Some(107), 3),
-        (None, Some(107), 3),
-        (None, Some(113), 3),
-        (None, Some(113), 3),
-        (None, Some(113), 3),
-        (None, Some(113), 3),
-        (None, Some(113), 3),
-        (None, Some(113), 3),
-        (None, Some(113), 3),
-        (None, Some(113), 3),
-        (None, Some(118), 3),
-        (None, Some(118), 3),
-        (None, Some(118), 3),
-        (None, Some(118), 3),
-        (None, Some(118), 3),
-        (None, Some(118), 3),
-        (None, Some(118), 3),
-        (None, Some(118), 3)
-    ],
-    [ // 30
-        (None, Some(108), 4),
-        (None, Some(108), 4),
-        (None, Some(108), 4),
-        (None, Some(108), 4),
-        (None, Some(108), 4),
-        (None, Some(108), 4),
-        (None, Some(108), 4),
-        (None, Some(108), 4),
-        (None, Some(108), 4),
-        (None, Some(108), 4),
-        (None, Some(108), 4),
-        (None, Some(108), 4),
-        (None, Some(108), 4),
-        (None, Some(108), 4),
-        (None, Some(108), 4),
-        (None, Some(108), 4),
-        (None, Some(109), 4),
-        (None, Some(109), 4),
-        (None, Some(109), 4),
-        (None, Some(109), 4),
-        (None, Some(109), 4),
-        (None, Some(109), 4),
-        (None, Some(109), 4),
-        (None, Some(109), 4),
-        (None, Some(109), 4),
-        (None, Some(109), 4),
-        (None, Some(109), 4),
-        (None, Some(109), 4),
-        (None, Some(109), 4),
-        (None, Some(109), 4),
-        (None, Some(109), 4),
-        (None, Some(109), 4)
-    ],
-    [ // 31
-        (None, Some(110), 4),
-        (None, Some(110), 4),
-        (None, Some(110), 4),
-        (None, Some(110), 4),
-        (None, Some(110), 4),
-        (None, Some(110), 4),
-        (None, Some(110), 4),
-        (None, Some(110), 4),
-        (None, Some(110), 4),
-        (None, Some(110), 4),
-        (None, Some(110), 4),
-        (None, Some(110), 4),
-        (None, Some(110), 4),
-        (None, Some(110), 4),
-        (None, Some(110), 4),
-        (None, Some(110), 4),
-        (None, Some(112), 4),
-        (None, Some(112), 4),
-        (None, Some(112), 4),
-        (None, Some(112), 4),
-        (None, Some(112), 4),
-        (None, Some(112), 4),
-        (None, Some(112), 4),
-        (None, Some(112), 4),
-        (None, Some(112), 4),
-        (None, Some(112), 4),
-        (None, Some(112), 4),
-        (None, Some(112), 4),
-        (None, Some(112), 4),
-        (None, Some(112), 4),
-        (None, Some(112), 4),
-        (None, Some(112), 4)
-    ],
-    [ // 32
-        (None, Some(114), 4),
-        (None, Some(114), 4),
-        (None, Some(114), 4),
-        (None, Some(114), 4),
-        (None, Some(114), 4),
-        (None, Some(114), 4),
-        (None, Some(114), 4),
-        (None, Some(114), 4),
-        (None, Some(114), 4),
-        (None, Some(114), 4),
-        (None, Some(114), 4),
-        (None, Some(114), 4),
-        (None, Some(114), 4),
-        (None, Some(114), 4),
-        (None, Some(114), 4),
-        (None, Some(114), 4),
-        (None, Some(117), 4),
-        (None, Some(117), 4),
-        (None, Some(117), 4),
-        (None, Some(117), 4),
-        (None, Some(117), 4),
-        (None, Some(117), 4),
-        (None, Some(117), 4),
-        (None, Some(117), 4),
-        (None, Some(117), 4),
-        (None, Some(117), 4),
-        (None, Some(117), 4),
-        (None, Some(117), 4),
-        (None, Some(117), 4),
-        (None, Some(117), 4),
-        (None, Some(117), 4),
-        (None, Some(117), 4)
-    ],
-    [ // 33
-        (None, Some(119), 3),
-        (None, Some(119), 3),
-        (None, Some(119), 3),
-        (None, Some(119), 3),
-        (None, Some(119), 3),
-        (None, Some(119), 3),
-        (None, Some(119), 3),
-        (None, Some(119), 3),
-        (None, Some(120), 3),
-        (None, Some(120), 3),
-        (None, Some(120), 3),
-        (None, Some(120), 3),
-        (None, Some(120), 3),
-        (None, Some(120), 3),
-        (None, Some(120), 3),
-        (None, Some(120), 3),
-        (None, Some(121), 3),
-        (None, Some(121), 3),
-        (None, Some(121), 3),
-        (None, Some(121), 3),
-        (None, Some(121), 3),
-        (None, Some(121), 3),
-        (None, Some(121), 3),
-        (None, Some(121), 3),
-        (None, Some(122), 3),
-        (None, Some(122), 3),
-        (None, Some(122), 3),
-        (None, Some(122), 3),
-        (None, Some(122), 3),
-        (None, Some(122), 3),
-        (None, Some(122), 3),
-        (None, Some(122), 3)
-    ],
-    [ // 34
-        (None, Some(230), 4),
-        (None, Some(230), 4),
-        (None, Some(230), 4),
-        (None, Some(230), 4),
-        (None, Some(230), 4),
-        (None, Some(230), 4),
-        (None, Some(230), 4),
-        (None, Some(230), 4),
-        (None, Some(230), 4),
-        (None, Some(230), 4),
-        (None, Some(230), 4),
-        (None, Some(230), 4),
-        (None, Some(230), 4),
-        (None, Some(230), 4),
-        (None, Some(230), 4),
-        (None, Some(230), 4),
-        (None, Some(129), 3),
-        (None, Some(129), 3),
-        (None, Some(129), 3),
-        (None, Some(129), 3),
-        (None, Some(129), 3),
-        (None, Some(129), 3),
-        (None, Some(129), 3),
-        (None, Some(129), 3),
-        (None, Some(132), 3),
-        (None, Some(132), 3),
-        (None, Some(132), 3),
-        (None, Some(132), 3),
-        (None, Some(132), 3),
-        (None, Some(132), 3),
-        (None, Some(132), 3),
-        (None, Some(132), 3)
-    ],
-    [ // 35
-        (None, Some(133), 3),
-        (None, Some(133), 3),
-        (None, Some(133), 3),
-        (None, Some(133), 3),
-        (None, Some(133), 3),
-        (None, Some(133), 3),
-        (None, Some(133), 3),
-        (None, Some(133), 3),
-        (None, Some(134), 3),
-        (None, Some(134), 3),
-        (None, Some(134), 3),
-        (None, Some(134), 3),
-        (None, Some(134), 3),
-        (None, Some(134), 3),
-        (None, Some(134), 3),
-        (None, Some(134), 3),
-        (None, Some(136), 3),
-        (None, Some(136), 3),
-        (None, Some(136), 3),
-        (None, Some(136), 3),
-        (None, Some(136), 3),
-        (None, Some(136), 3),
-        (None, Some(136), 3),
-        (None, Some(136), 3),
-        (None, Some(146), 3),
-        (None, Some(146), 3),
-        (None, Some(146), 3),
-        (None, Some(146), 3),
-        (None, Some(146), 3),
-        (None, Some(146), 3),
-        (None, Some(146), 3),
-        (None, Some(146), 3)
-    ],
-    [ // 36
-        (None, Some(147), 2),
-        (None, Some(147), 2),
-        (None, Some(147), 2),
-        (None, Some(147), 2),
-        (None, Some(149), 2),
-        (None, Some(149), 2),
-        (None, Some(149), 2),
-        (None, Some(149), 2),
-        (None, Some(150), 2),
-        (None, Some(150), 2),
-        (None, Some(150), 2),
-        (None, Some(150), 2),
-        (None, Some(151), 2),
-        (None, Some(151), 2),
-        (None, Some(151), 2),
-        (None, Some(151), 2),
-        (None, Some(152), 2),
-        (None, Some(152), 2),
-        (None, Some(152), 2),
-        (None, Some(152), 2),
-        (None, Some(155), 2),
-        (None, Some(155), 2),
-        (None, Some(155), 2),
-        (None, Some(155), 2),
-        (None, Some(157), 2),
-        (None, Some(157), 2),
-        (None, Some(157), 2),
-        (None, Some(157), 2),
-        (None, Some(158), 2),
-        (None, Some(158), 2),
-        (None, Some(158), 2),
-        (None, Some(158), 2)
-    ],
-    [ // 37
-        (None, Some(153), 4),
-        (None, Some(153), 4),
-        (None, Some(153), 4),
-        (None, Some(153), 4),
-        (None, Some(153), 4),
-        (None, Some(153), 4),
-        (None, Some(153), 4),
-        (None, Some(153), 4),
-        (None, Some(153), 4),
-        (None, Some(153), 4),
-        (None, Some(153), 4),
-        (None, Some(153), 4),
-        (None, Some(153), 4),
-        (None, Some(153), 4),
-        (None, Some(153), 4),
-        (None, Some(153), 4),
-        (None, Some(161), 4),
-        (None, Some(161), 4),
-        (None, Some(161), 4),
-        (None, Some(161), 4),
-        (None, Some(161), 4),
-        (None, Some(161), 4),
-        (None, Some(161), 4),
-        (None, Some(161), 4),
-        (None, Some(161), 4),
-        (None, Some(161), 4),
-        (None, Some(161), 4),
-        (None, Some(161), 4),
-        (None, Some(161), 4),
-        (None, Some(161), 4),
-        (None, Some(161), 4),
-        (None, Some(161), 4)
-    ],
-    [ // 38
-        (None, Some(154), 3),
-        (None, Some(154), 3),
-        (None, Some(154), 3),
-        (None, Some(154), 3),
-        (None, Some(154), 3),
-        (None, Some(154), 3),
-        (None, Some(154), 3),
-        (None, Some(154), 3),
-        (None, Some(156), 3),
-        (None, Some(156), 3),
-        (None, Some(156), 3),
-        (None, Some(156), 3),
-        (None, Some(156), 3),
-        (None, Some(156), 3),
-        (None, Some(156), 3),
-        (None, Some(156), 3),
-        (None, Some(160), 3),
-        (None, Some(160), 3),
-        (None, Some(160), 3),
-        (None, Some(160), 3),
-        (None, Some(160), 3),
-        (None, Some(160), 3),
-        (None, Some(160), 3),
-        (None, Some(160), 3),
-        (None, Some(163), 3),
-        (None, Some(163), 3),
-        (None, Some(163), 3),
-        (None, Some(163), 3),
-        (None, Some(163), 3),
-        (None, Some(163), 3),
-        (None, Some(163), 3),
-        (None, Some(163), 3)
-    ],
-    [ // 39
-        (None, Some(164), 3),
-        (None, Some(164), 3),
-        (None, Some(164), 3),
-        (None, Some(164), 3),
-        (None, Some(164), 3),
-        (None, Some(164), 3),
-        (None, Some(164), 3),
-        (None, Some(164), 3),
-        (None, Some(169), 3),
-        (None, Some(169), 3),
-        (None, Some(169), 3),
-        (None, Some(169), 3),
-        (None, Some(169), 3),
-        (None, Some(169), 3),
-        (None, Some(169), 3),
-        (None, Some(169), 3),
-        (None, Some(170), 3),
-        (None, Some(170), 3),
-        (None, Some(170), 3),
-        (None, Some(170), 3),
-        (None, Some(170), 3),
-        (None, Some(170), 3),
-        (None, Some(170), 3),
-        (None, Some(170), 3),
-        (None, Some(173), 3),
-        (None, Some(173), 3),
-        (None, Some(173), 3),
-        (None, Some(173), 3),
-        (None, Some(173), 3),
-        (None, Some(173), 3),
-        (None, Some(173), 3),
-        (None, Some(173), 3)
-    ],
-    [ // 40
-        (None, Some(165), 2),
-        (None, Some(165), 2),
-        (None, Some(165), 2),
-        (None, Some(165), 2),
-        (None, Some(166), 2),
-        (None, Some(166), 2),
-        (None, Some(166), 2),
-        (None, Some(166), 2),
-        (None, Some(168), 2),
-        (None, Some(168), 2),
-        (None, Some(168), 2),
-        (None, Some(168), 2),
-        (None, Some(174), 2),
-        (None, Some(174), 2),
-        (None, Some(174), 2),
-        (None, Some(174), 2),
-        (None, Some(175), 2),
-        (None, Some(175), 2),
-        (None, Some(175), 2),
-        (None, Some(175), 2),
-        (None, Some(180), 2),
-        (None, Some(180), 2),
-        (None, Some(180), 2),
-        (None, Some(180), 2),
-        (None, Some(182), 2),
-        (None, Some(182), 2),
-        (None, Some(182), 2),
-        (None, Some(182), 2),
-        (None, Some(183), 2),
-        (None, Some(183), 2),
-        (None, Some(183), 2),
-        (None, Some(183), 2)
-    ],
-    [ // 41
-        (None, Some(167), 4),
-        (None, Some(167), 4),
-        (None, Some(167), 4),
-        (None, Some(167), 4),
-        (None, Some(167), 4),
-        (None, Some(167), 4),
-        (None, Some(167), 4),
-        (None, Some(167), 4),
-        (None, Some(167), 4),
-        (None, Some(167), 4),
-        (None, Some(167), 4),
-        (None, Some(167), 4),
-        (None, Some(167), 4),
-        (None, Some(167), 4),
-        (None, Some(167), 4),
-        (None, Some(167), 4),
-        (None, Some(172), 4),
-        (None, Some(172), 4),
-        (None, Some(172), 4),
-        (None, Some(172), 4),
-        (None, Some(172), 4),
-        (None, Some(172), 4),
-        (None, Some(172), 4),
-        (None, Some(172), 4),
-        (None, Some(172), 4),
-        (None, Some(172), 4),
-        (None, Some(172), 4),
-        (None, Some(172), 4),
-        (None, Some(172), 4),
-        (None, Some(172), 4),
-        (None, Some(172), 4),
-        (None, Some(172), 4)
-    ],
-    [ // 42
-        (None, Some(176), 4),
-        (None, Some(176), 4),
-        (None, Some(176), 4),
-        (None, Some(176), 4),
-        (None, Some(176), 4),
-        (None, Some(176), 4),
-        (None, Some(176), 4),
-        (None, Some(176), 4),
-        (None, Some(176), 4),
-        (None, Some(176), 4),
-        (None, Some(176), 4),
-        (None, Some(176), 4),
-        (None, Some(176), 4),
-        (None, Some(176), 4),
-        (None, Some(176), 4),
-        (None, Some(176), 4),
-        (None, Some(177), 4),
-        (None, Some(177), 4),
-        (None, Some(177), 4),
-        (None, Some(177), 4),
-        (None, Some(177), 4),
-        (None, Some(177), 4),
-        (None, Some(177), 4),
-        (None, Some(177), 4),
-        (None, Some(177), 4),
-        (None, Some(177), 4),
-        (None, Some(177), 4),
-        (None, Some(177), 4),
-        (None, Some(177), 4),
-        (None, Some(177), 4),
-        (None, Some(177), 4),
-        (None, Some(177), 4)
-    ],
-    [ // 43
-        (None, Some(178), 3),
-        (None, Some(178), 3),
-        (None, Some(178), 3),
-        (None, Some(178), 3),
-        (None, Some(178), 3),
-        (None, Some(178), 3),
-        (None, Some(178), 3),
-        (None, Some(178), 3),
-        (None, Some(181), 3),
-        (None, Some(181), 3),
-        (None, Some(181), 3),
-        (None, Some(181), 3),
-        (None, Some(181), 3),
-        (None, Some(181), 3),
-        (None, Some(181), 3),
-        (None, Some(181), 3),
-        (None, Some(185), 3),
-        (None, Some(185), 3),
-        (None, Some(185), 3),
-        (None, Some(185), 3),
-        (None, Some(185), 3),
-        (None, Some(185), 3),
-        (None, Some(185), 3),
-        (None, Some(185), 3),
-        (None, Some(186), 3),
-        (None, Some(186), 3),
-        (None, Some(186), 3),
-        (None, Some(186), 3),
-        (None, Some(186), 3),
-        (None, Some(186), 3),
-        (None, Some(186), 3),
-        (None, Some(186), 3)
-    ],
-    [ // 44
-        (None, Some(179), 4),
-        (None, Some(179), 4),
-        (None, Some(179), 4),
-        (None, Some(179), 4),
-        (None, Some(179), 4),
-        (None, Some(179), 4),
-        (None, Some(179), 4),
-        (None, Some(179), 4),
-        (None, Some(179), 4),
-        (None, Some(179), 4),
-        (None, Some(179), 4),
-        (None, Some(179), 4),
-        (None, Some(179), 4),
-        (None, Some(179), 4),
-        (None, Some(179), 4),
-        (None, Some(179), 4),
-        (None, Some(209), 4),
-        (None, Some(209), 4),
-        (None, Some(209), 4),
-        (None, Some(209), 4),
-        (None, Some(209), 4),
-        (None, Some(209), 4),
-        (None, Some(209), 4),
-        (None, Some(209), 4),
-        (None, Some(209), 4),
-        (None, Some(209), 4),
-        (None, Some(209), 4),
-        (None, Some(209), 4),
-        (None, Some(209), 4),
-        (None, Some(209), 4),
-        (None, Some(209), 4),
-        (None, Some(209), 4)
-    ],
-    [ // 45
-        (None, Some(187), 3),
-        (None, Some(187), 3),
-        (None, Some(187), 3),
-        (None, Some(187), 3),
-        (None, Some(187), 3),
-        (None, Some(187), 3),
-        (None, Some(187), 3),
-        (None, Some(187), 3),
-        (None, Some(189), 3),
-        (None, Some(189), 3),
-        (None, Some(189), 3),
-        (None, Some(189), 3),
-        (None, Some(189), 3),
-        (None, Some(189), 3),
-        (None, Some(189), 3),
-        (None, Some(189), 3),
-        (None, Some(190), 3),
-        (None, Some(190), 3),
-        (None, Some(190), 3),
-        (None, Some(190), 3),
-        (None, Some(190), 3),
-        (None, Some(190), 3),
-        (None, Some(190), 3),
-        (None, Some(190), 3),
-        (None, Some(196), 3),
-        (None, Some(196), 3),
-        (None, Some(196), 3),
-        (None, Some(196), 3),
-        (None, Some(196), 3),
-        (None, Some(196), 3),
-        (None, Some(196), 3),
-        (None, Some(196), 3)
-    ],
-    [ // 46
-        (None, Some(192), 4),
-        (None, Some(192), 4),
-        (None, Some(192), 4),
-        (None, Some(192), 4),
-        (None, Some(192), 4),
-        (None, Some(192), 4),
-        (None, Some(192), 4),
-        (None, Some(192), 4),
-        (None, Some(192), 4),
-        (None, Some(192), 4),
-        (None, Some(192), 4),
-        (None, Some(192), 4),
-        (None, Some(192), 4),
-        (None, Some(192), 4),
-        (None, Some(192), 4),
-        (None, Some(192), 4),
-        (None, Some(193), 4),
-        (None, Some(193), 4),
-        (None, Some(193), 4),
-        (None, Some(193), 4),
-        (None, Some(193), 4),
-        (None, Some(193), 4),
-        (None, Some(193), 4),
-        (None, Some(193), 4),
-        (None, Some(193), 4),
-        (None, Some(193), 4),
-        (None, Some(193), 4),
-        (None, Some(193), 4),
-        (None, Some(193), 4),
-        (None, Some(193), 4),
-        (None, Some(193), 4),
-        (None, Some(193), 4)
-    ],
-    [ // 47
-        (None, Some(198), 3),
-        (None, Some(198), 3),
-        (None, Some(198), 3),
-        (None, Some(198), 3),
-        (None, Some(198), 3),
-        (None, Some(198), 3),
-        (None, Some(198), 3),
-        (None, Some(198), 3),
-        (None, Some(228), 3),
-        (None, Some(228), 3),
-        (None, Some(228), 3),
-        (None, Some(228), 3),
-        (None, Some(228), 3),
-        (None, Some(228), 3),
-        (None, Some(228), 3),
-        (None, Some(228), 3),
-        (None, Some(232), 3),
-        (None, Some(232), 3),
-        (None, Some(232), 3),
-        (None, Some(232), 3),
-        (None, Some(232), 3),
-        (None, Some(232), 3),
-        (None, Some(232), 3),
-        (None, Some(232), 3),
-        (None, Some(233), 3),
-        (None, Some(233), 3),
-        (None, Some(233), 3),
-        (None, Some(233), 3),
-        (None, Some(233), 3),
-        (None, Some(233), 3),
-        (None, Some(233), 3),
-        (None, Some(233), 3)
-    ],
-    [ // 48
-        (None, Some(200), 4),
-        (None, Some(200), 4),
-        (None, Some(200), 4),
-        (None, Some(200), 4),
-        (None, Some(200), 4),
-        (None, Some(200), 4),
-        (None, Some(200), 4),
-        (None, Some(200), 4),
-        (None, Some(200), 4),
-        (None, Some(200), 4),
-        (None, Some(200), 4),
-        (None, Some(200), 4),
-        (None, Some(200), 4),
-        (None, Some(200), 4),
-        (None, Some(200), 4),
-        (None, Some(200), 4),
-        (None, Some(201), 4),
-        (None, Some(201), 4),
-        (None, Some(201), 4),
-        (None, Some(201), 4),
-        (None, Some(201), 4),
-        (None, Some(201), 4),
-        (None, Some(201), 4),
-        (None, Some(201), 4),
-        (None, Some(201), 4),
-        (None, Some(201), 4),
-        (None, Some(201), 4),
-        (None, Some(201), 4),
-        (None, Some(201), 4),
-        (None, Some(201), 4),
-        (None, Some(201), 4),
-        (None, Some(201), 4)
-    ],
-    [ // 49
-        (None, Some(202), 4),
-        (None, Some(202), 4),
-        (None, Some(202), 4),
-        (None, Some(202), 4),
-        (None, Some(202), 4),
-        (None, Some(202), 4),
-        (None, Some(202), 4),
-        (None, Some(202), 4),
-        (None, Some(202), 4),
-        (None, Some(202), 4),
-        (None, Some(202), 4),
-        (None, Some(202), 4),
-        (None, Some(202), 4),
-        (None, Some(202), 4),
-        (None, Some(202), 4),
-        (None, Some(202), 4),
-        (None, Some(205), 4),
-        (None, Some(205), 4),
-        (None, Some(205), 4),
-        (None, Some(205), 4),
-        (None, Some(205), 4),
-        (None, Some(205), 4),
-        (None, Some(205), 4),
-        (None, Some(205), 4),
-        (None, Some(205), 4),
-        (None, Some(205), 4),
-        (None, Some(205), 4),
-        (None, Some(205), 4),
-        (None, Some(205), 4),
-        (None, Some(205), 4),
-        (None, Some(205), 4),
-        (None, Some(205), 4)
-    ],
-    [ // 50
-        (None, Some(255), 4),
-        (None, Some(255), 4),
-        (None, Some(255), 4),
-        (None, Some(255), 4),
-        (None, Some(255), 4),
-        (None, Some(255), 4),
-        (None, Some(255), 4),
-        (None, Some(255), 4),
-        (None, Some(255), 4),
-        (None, Some(255), 4),
-        (None, Some(255), 4),
-        (None, Some(255), 4),
-        (None, Some(255), 4),
-        (None, Some(255), 4),
-        (None, Some(255), 4),
-        (None, Some(255), 4),
-        (None, Some(203), 3),
-        (None, Some(203), 3),
-        (None, Some(203), 3),
-        (None, Some(203), 3),
-        (None, Some(203), 3),
-        (None, Some(203), 3),
-        (None, Some(203), 3),
-        (None, Some(203), 3),
-        (None, Some(204), 3),
-        (None, Some(204), 3),
-        (None, Some(204), 3),
-        (None, Some(204), 3),
-        (None, Some(204), 3),
-        (None, Some(204), 3),
-        (None, Some(204), 3),
-        (None, Some(204), 3)
-    ],
-    [ // 51
-        (None, Some(210), 4),
-        (None, Some(210), 4),
-        (None, Some(210), 4),
-        (None, Some(210), 4),
-        (None, Some(210), 4),
-        (None, Some(210), 4),
-        (None, Some(210), 4),
-        (None, Some(210), 4),
-        (None, Some(210), 4),
-        (None, Some(210), 4),
-        (None, Some(210), 4),
-        (None, Some(210), 4),
-        (None, Some(210), 4),
-        (None, Some(210), 4),
-        (None, Some(210), 4),
-        (None, Some(210), 4),
-        (None, Some(213), 4),
-        (None, Some(213), 4),
-        (None, Some(213), 4),
-        (None, Some(213), 4),
-        (None, Some(213), 4),
-        (None, Some(213), 4),
-        (None, Some(213), 4),
-        (None, Some(213), 4),
-        (None, Some(213), 4),
-        (None, Some(213), 4),
-        (None, Some(213), 4),
-        (None, Some(213), 4),
-        (None, Some(213), 4),
-        (None, Some(213), 4),
-        (None, Some(213), 4),
-        (None, Some(213), 4)
-    ],
-    [ // 52
-        (None, Some(211), 3),
-        (None, Some(211), 3),
-        (None, Some(211), 3),
-        (None, Some(211), 3),
-        (None, Some(211), 3),
-        (None, Some(211), 3),
-        (None, Some(211), 3),
-        (None, Some(211), 3),
-        (None, Some(212), 3),
-        (None, Some(212), 3),
-        (None, Some(212), 3),
-        (None, Some(212), 3),
-        (None, Some(212), 3),
-        (None, Some(212), 3),
-        (None, Some(212), 3),
-        (None, Some(212), 3),
-        (None, Some(214), 3),
-        (None, Some(214), 3),
-        (None, Some(214), 3),
-        (None, Some(214), 3),
-        (None, Some(214), 3),
-        (None, Some(214), 3),
-        (None, Some(214), 3),
-        (None, Some(214), 3),
-        (None, Some(221), 3),
-        (None, Some(221), 3),
-        (None, Some(221), 3),
-        (None, Some(221), 3),
-        (None, Some(221), 3),
-        (None, Some(221), 3),
-        (None, Some(221), 3),
-        (None, Some(221), 3)
-    ],
-    [ // 53
-        (None, Some(216), 4),
-        (None, Some(216), 4),
-        (None, Some(216), 4),
-        (None, Some(216), 4),
-        (None, Some(216), 4),
-        (None, Some(216), 4),
-        (None, Some(216), 4),
-        (None, Some(216), 4),
-        (None, Some(216), 4),
-        (None, Some(216), 4),
-        (None, Some(216), 4),
-        (None, Some(216), 4),
-        (None, Some(216), 4),
-        (None, Some(216), 4),
-        (None, Some(216), 4),
-        (None, Some(216), 4),
-        (None, Some(217), 4),
-        (None, Some(217), 4),
-        (None, Some(217), 4),
-        (None, Some(217), 4),
-        (None, Some(217), 4),
-        (None, Some(217), 4),
-        (None, Some(217), 4),
-        (None, Some(217), 4),
-        (None, Some(217), 4),
-        (None, Some(217), 4),
-        (None, Some(217), 4),
-        (None, Some(217), 4),
-        (None, Some(217), 4),
-        (None, Some(217), 4),
-        (None, Some(217), 4),
-        (None, Some(217), 4)
-    ],
-    [ // 54
-        (None, Some(218), 4),
-        (None, Some(218), 4),
-        (None, Some(218), 4),
-        (None, Some(218), 4),
-        (None, Some(218), 4),
-        (None, Some(218), 4),
-        (None, Some(218), 4),
-        (None, Some(218), 4),
-        (None, Some(218), 4),
-        (None, Some(218), 4),
-        (None, Some(218), 4),
-        (None, Some(218), 4),
-        (None, Some(218), 4),
-        (None, Some(218), 4),
-        (None, Some(218), 4),
-        (None, Some(218), 4),
-        (None, Some(219), 4),
-        (None, Some(219), 4),
-        (None, Some(219), 4),
-        (None, Some(219), 4),
-        (None, Some(219), 4),
-        (None, Some(219), 4),
-        (None, Some(219), 4),
-        (None, Some(219), 4),
-        (None, Some(219), 4),
-        (None, Some(219), 4),
-        (None, Some(219), 4),
-        (None, Some(219), 4),
-        (None, Some(219), 4),
-        (None, Some(219), 4),
-        (None, Some(219), 4),
-        (None, Some(219), 4)
-    ],
-    [ // 55
-        (None, Some(222), 3),
-        (None, Some(222), 3),
-        (None, Some(222), 3),
-        (None, Some(222), 3),
-        (None, Some(222), 3),
-        (None, Some(222), 3),
-        (None, Some(222), 3),
-        (None, Some(222), 3),
-        (None, Some(223), 3),
-        (None, Some(223), 3),
-        (None, Some(223), 3),
-        (None, Some(223), 3),
-        (None, Some(223), 3),
-        (None, Some(223), 3),
-        (None, Some(223), 3),
-        (None, Some(223), 3),
-        (None, Some(241), 3),
-        (None, Some(241), 3),
-        (None, Some(241), 3),
-        (None, Some(241), 3),
-        (None, Some(241), 3),
-        (None, Some(241), 3),
-        (None, Some(241), 3),
-        (None, Some(241), 3),
-        (None, Some(244), 3),
-        (None, Some(244), 3),
-        (None, Some(244), 3),
-        (None, Some(244), 3),
-        (None, Some(244), 3),
-        (None, Some(244), 3),
-        (None, Some(244), 3),
-        (None, Some(244), 3)
-    ],
-    [ // 56
-        (None, Some(227), 4),
-        (None, Some(227), 4),
-        (None, Some(227), 4),
-        (None, Some(227), 4),
-        (None, Some(227), 4),
-        (None, Some(227), 4),
-        (None, Some(227), 4),
-        (None, Some(227), 4),
-        (None, Some(227), 4),
-        (None, Some(227), 4),
-        (None, Some(227), 4),
-        (None, Some(227), 4),
-        (None, Some(227), 4),
-        (None, Some(227), 4),
-        (None, Some(227), 4),
-        (None, Some(227), 4),
-        (None, Some(229), 4),
-        (None, Some(229), 4),
-        (None, Some(229), 4),
-        (None, Some(229), 4),
-        (None, Some(229), 4),
-        (None, Some(229), 4),
-        (None, Some(229), 4),
-        (None, Some(229), 4),
-        (None, Some(229), 4),
-        (None, Some(229), 4),
-        (None, Some(229), 4),
-        (None, Some(229), 4),
-        (None, Some(229), 4),
-        (None, Some(229), 4),
-        (None, Some(229), 4),
-        (None, Some(229), 4)
-    ],
-    [ // 57
-        (None, Some(238), 4),
-        (None, Some(238), 4),
-        (None, Some(238), 4),
-        (None, Some(238), 4),
-        (None, Some(238), 4),
-        (None, Some(238), 4),
-        (None, Some(238), 4),
-        (None, Some(238), 4),
-        (None, Some(238), 4),
-        (None, Some(238), 4),
-        (None, Some(238), 4),
-        (None, Some(238), 4),
-        (None, Some(238), 4),
-        (None, Some(238), 4),
-        (None, Some(238), 4),
-        (None, Some(238), 4),
-        (None, Some(240), 4),
-        (None, Some(240), 4),
-        (None, Some(240), 4),
-        (None, Some(240), 4),
-        (None, Some(240), 4),
-        (None, Some(240), 4),
-        (None, Some(240), 4),
-        (None, Some(240), 4),
-        (None, Some(240), 4),
-        (None, Some(240), 4),
-        (None, Some(240), 4),
-        (None, Some(240), 4),
-        (None, Some(240), 4),
-        (None, Some(240), 4),
-        (None, Some(240), 4),
-        (None, Some(240), 4)
-    ],
-    [ // 58
-        (None, Some(242), 4),
-        (None, Some(242), 4),
-        (None, Some(242), 4),
-        (None, Some(242), 4),
-        (None, Some(242), 4),
-        (None, Some(242), 4),
-        (None, Some(242), 4),
-        (None, Some(242), 4),
-        (None, Some(242), 4),
-        (None, Some(242), 4),
-        (None, Some(242), 4),
-        (None, Some(242), 4),
-        (None, Some(242), 4),
-        (None, Some(242), 4),
-        (None, Some(242), 4),
-        (None, Some(242), 4),
-        (None, Some(243), 4),
-        (None, Some(243), 4),
-        (None, Some(243), 4),
-        (None, Some(243), 4),
-        (None, Some(243), 4),
-        (None, Some(243), 4),
-        (None, Some(243), 4),
-        (None, Some(243), 4),
-        (None, Some(243), 4),
-        (None, Some(243), 4),
-        (None, Some(243), 4),
-        (None, Some(243), 4),
-        (None, Some(243), 4),
-        (None, Some(243), 4),
-        (None, Some(243), 4),
-        (None, Some(243), 4)
-    ],
-    [ // 59
-        (None, Some(245), 3),
-        (None, Some(245), 3),
-        (None, Some(245), 3),
-        (None, Some(245), 3),
-        (None, Some(245), 3),
-        (None, Some(245), 3),
-        (None, Some(245), 3),
-        (None, Some(245), 3),
-        (None, Some(246), 3),
-        (None, Some(246), 3),
-        (None, Some(246), 3),
-        (None, Some(246), 3),
-        (None, Some(246), 3),
-        (None, Some(246), 3),
-        (None, Some(246), 3),
-        (None, Some(246), 3),
-        (None, Some(247), 3),
-        (None, Some(247), 3),
-        (None, Some(247), 3),
-        (None, Some(247), 3),
-        (None, Some(247), 3),
-        (None, Some(247), 3),
-        (None, Some(247), 3),
-        (None, Some(247), 3),
-        (None, Some(248), 3),
-        (None, Some(248), 3),
-        (None, Some(248), 3),
-        (None, Some(248), 3),
-        (None, Some(248), 3),
-        (None, Some(248), 3),
-        (None, Some(248), 3),
-        (None, Some(248), 3)
-    ],
-    [ // 60
-        (None, Some(250), 3),
-        (None, Some(250), 3),
-        (None, Some(250), 3),
-        (None, Some(250), 3),
-        (None, Some(250), 3),
-        (None, Some(250), 3),
-        (None, Some(250), 3),
-        (None, Some(250), 3),
-        (None, Some(251), 3),
-        (None, Some(251), 3),
-        (None, Some(251), 3),
-        (None, Some(251), 3),
-        (None, Some(251), 3),
-        (None, Some(251), 3),
-        (None, Some(251), 3),
-        (None, Some(251), 3),
-        (None, Some(252), 3),
-        (None, Some(252), 3),
-        (None, Some(252), 3),
-        (None, Some(252), 3),
-        (None, Some(252), 3),
-        (None, Some(252), 3),
-        (None, Some(252), 3),
-        (None, Some(252), 3),
-        (None, Some(253), 3),
-        (None, Some(253), 3),
-        (None, Some(253), 3),
-        (None, Some(253), 3),
-        (None, Some(253), 3),
-        (None, Some(253), 3),
-        (None, Some(253), 3),
-        (None, Some(253), 3)
-    ]
-];
+//! Provides the translation table for decoding Huffman sequence by reading
+//! 5-bits at a time. The table is generated at build time from the
+//! `ENCODE_TABLE` when the `speed-5` feature is enabled.
+
+include!(concat!(env!("OUT_DIR"), "/table5.rs"));
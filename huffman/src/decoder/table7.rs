@@ -0,0 +1,5 @@
+//! Provides the translation table for decoding Huffman sequence by reading
+//! 7-bits at a time. The table is generated at build time from the
+//! `ENCODE_TABLE` when the `speed-7` feature is enabled.
+
+include!(concat!(env!("OUT_DIR"), "/table7.rs"));
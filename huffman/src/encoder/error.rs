@@ -8,12 +8,17 @@ pub enum EncoderError {
     /// Indicates that the encoder received an invalid ASCII character. Note
     /// that only ASCII characters provided in the HPACK spec should be used.
     InvalidInput,
+
+    /// Indicates that the destination buffer is too small to hold the encoded
+    /// sequence.
+    InsufficientSpace,
 }
 
 impl fmt::Display for EncoderError {
     fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
         match self {
             Self::InvalidInput => write!(fmt, "Invalid input character."),
+            Self::InsufficientSpace => write!(fmt, "Insufficient space in destination buffer."),
         }
     }
 }
@@ -89,6 +89,13 @@
 //! by rotating bits and adding the new Huffman character, we are adding numbers
 //! in the same way as we did in the simple example previously presented.
 //! 
+//! Pushing one byte at a time is still wasteful. The encoder therefore keeps a
+//! 64-bit accumulator and only flushes it when the next code would not fit.
+//! At that point the accumulator holds at least 35 bits, so all complete
+//! octets (4 to 8 bytes) are written out at once and only the remaining bits
+//! (less than 8) stay in the accumulator. This allows for writing directly
+//! into a slice of bytes or into any `io::Write` without intermediate buffers.
+//! 
 //! If looked at separately, the Huffman algorithm is quite simple. But when we
 //! don't intend to only implement it, but we are, instead, interested in the
 //! maximization of the performance and lowering of used resources, things get
@@ -100,6 +107,8 @@
 //! [canonical Huffman]: https://en.wikipedia.org/wiki/Canonical_Huffman_code
 //! [ASCII]: https://en.wikipedia.org/wiki/ASCII
 
+use std::io;
+
 mod error;
 pub mod table;
 
@@ -118,35 +127,187 @@ pub use error::*;
 /// encode(&text, &mut sequence).unwrap();
 /// ```
 pub fn encode(src: &[u8], dst: &mut Vec<u8>) -> Result<(), EncoderError> {
-    let mut bits: u64 = 0;
-    let mut bits_left = 40;
-    let codings = self::table::ENCODE_TABLE; // parsed huffman table
+    dst.reserve(encoded_len(src));
+    encode_words(&self::table::ENCODE_TABLE, src, |bytes| -> Result<(), EncoderError> {
+        dst.extend_from_slice(bytes);
+        Ok(())
+    })
+}
 
-    for &byte in src {
-        let (code_len, code) = match codings.get(byte as usize) {
-            Some(coding) => coding,
-            None => return Err(EncoderError::InvalidInput),
-        };
+/// Encodes the provided `src` bytes into the `dst` slice of bytes and returns
+/// the number of bytes written.
+/// 
+/// The function returns the `InsufficientSpace` error when the `dst` slice is
+/// too small to hold the whole sequence in which case the content of `dst` is
+/// unspecified. Use the `encoded_len` function to find out the required size
+/// in advance.
+/// 
+/// **Example:**
+/// 
+/// ```rust
+/// use httlib_huffman::encode_into;
+/// 
+/// let mut dst = [0; 16];
+/// let text = "Hello world!".as_bytes();
+/// let size = encode_into(&text, &mut dst).unwrap();
+/// assert_eq!(&dst[..size], &[198, 90, 40, 58, 158, 15, 101, 18, 127, 31]);
+/// ```
+pub fn encode_into(src: &[u8], dst: &mut [u8]) -> Result<usize, EncoderError> {
+    let mut size = 0;
+    encode_words(&self::table::ENCODE_TABLE, src, |bytes| {
+        let end = size + bytes.len();
+        match dst.get_mut(size..end) {
+            Some(target) => target.copy_from_slice(bytes),
+            None => return Err(EncoderError::InsufficientSpace),
+        }
+        size = end;
+        Ok(())
+    })?;
+    Ok(size)
+}
 
-        bits |= (*code as u64) << (bits_left - code_len); // shift and add old and new numbers
-        bits_left -= code_len;
+/// Encodes the provided `src` bytes into the `dst` writer and returns the
+/// number of bytes written.
+/// 
+/// **Example:**
+/// 
+/// ```rust
+/// use httlib_huffman::encode_to_writer;
+/// 
+/// let mut dst = Vec::new();
+/// let text = "Hello world!".as_bytes();
+/// let size = encode_to_writer(&text, &mut dst).unwrap();
+/// assert_eq!(size, 10);
+/// ```
+pub fn encode_to_writer<W: io::Write>(src: &[u8], dst: &mut W) -> io::Result<usize> {
+    let mut size = 0;
+    encode_words(&self::table::ENCODE_TABLE, src, |bytes| -> io::Result<()> {
+        dst.write_all(bytes)?;
+        size += bytes.len();
+        Ok(())
+    })?;
+    Ok(size)
+}
+
+/// Encodes a batch of `values` one after another into `dst` and returns the
+/// end position of each encoded sequence, counted from the first byte written
+/// to `dst`.
+/// 
+/// The size of the whole batch is calculated up front so `dst` grows only once.
+/// The output can be decoded with the `decode_batch` function.
+/// 
+/// **Example:**
+/// 
+/// ```rust
+/// use httlib_huffman::encode_batch;
+/// 
+/// let mut dst = Vec::new();
+/// let ends = encode_batch(&[b"content-type", b"text/html"], &mut dst).unwrap();
+/// assert_eq!(ends, vec![9, 16]);
+/// ```
+pub fn encode_batch(values: &[&[u8]], dst: &mut Vec<u8>) -> Result<Vec<usize>, EncoderError> {
+    let start = dst.len();
+    dst.reserve(values.iter().map(|value| encoded_len(value)).sum());
 
-        while bits_left <= 32 {
-            dst.push((bits >> 32) as u8);
+    let mut ends = Vec::with_capacity(values.len());
+    for value in values {
+        encode_words(&self::table::ENCODE_TABLE, value, |bytes| -> Result<(), EncoderError> {
+            dst.extend_from_slice(bytes);
+            Ok(())
+        })?;
+        ends.push(dst.len() - start);
+    }
 
-            bits <<= 8; // add more room for the next character
-            bits_left += 8;
+    Ok(ends)
+}
+
+/// Encodes a batch of `values` into `dst` where each sequence is prefixed with
+/// its length.
+/// 
+/// The length is encoded as an unsigned LEB128 variable-length integer (7 bits
+/// per byte, least significant group first). The output can be decoded with
+/// the `decode_batch_prefixed` function.
+pub fn encode_batch_prefixed(values: &[&[u8]], dst: &mut Vec<u8>) -> Result<(), EncoderError> {
+    let lens: Vec<usize> = values.iter().map(|value| encoded_len(value)).collect();
+    dst.reserve(lens.iter().map(|len| len + 2).sum()); // most prefixes take 1-2 bytes
+
+    for (value, mut len) in values.iter().zip(lens) {
+        while len >= 0x80 {
+            dst.push(len as u8 | 0x80);
+            len >>= 7;
         }
+        dst.push(len as u8);
+        encode_words(&self::table::ENCODE_TABLE, value, |bytes| -> Result<(), EncoderError> {
+            dst.extend_from_slice(bytes);
+            Ok(())
+        })?;
     }
 
-    if bits_left != 40 { // finalize with EOS
-        bits |= (1 << bits_left) - 1; // add EOS and pedding
-        dst.push((bits >> 32) as u8);
+    Ok(())
+}
+
+/// Encodes the `src` bytes with the provided `codings` by using a 64-bit
+/// accumulator and passes complete octets to the `flush` function.
+/// 
+/// The `flush` function receives 4 to 8 bytes at a time while the last call
+/// holds the remaining bytes including the EOS padding. The `codings` must
+/// hold a code for each byte in `src` and codes must not exceed 32 bits.
+pub(crate) fn encode_words<F, E>(codings: &[(u8, u32)], src: &[u8], mut flush: F) -> Result<(), E>
+where
+    F: FnMut(&[u8]) -> Result<(), E>,
+{
+    let mut bits: u64 = 0; // MSB aligned accumulator
+    let mut bits_len = 0; // number of used bits
+
+    for &byte in src {
+        let (code_len, code) = codings[byte as usize];
+        let (code_len, code) = (code_len as usize, code as u64);
+
+        if bits_len + code_len > 64 { // flush complete octets
+            let octets = bits_len / 8;
+            flush(&bits.to_be_bytes()[..octets])?;
+            bits = bits.checked_shl(octets as u32 * 8).unwrap_or(0);
+            bits_len -= octets * 8;
+        }
+
+        bits |= code << (64 - bits_len - code_len); // shift and add old and new numbers
+        bits_len += code_len;
+    }
+
+    if bits_len > 0 { // finalize with EOS
+        let octets = bits_len.div_ceil(8);
+        bits |= u64::MAX.checked_shr(bits_len as u32).unwrap_or(0); // add EOS and pedding
+        flush(&bits.to_be_bytes()[..octets])?;
     }
 
     Ok(())
 }
 
+/// Returns the number of bytes the `encode` function will produce for the
+/// provided `src` bytes, including the EOS padding.
+/// 
+/// The length is calculated from the code lengths in the `ENCODE_TABLE` without
+/// actually encoding the data. This is useful for deciding between a raw and a
+/// Huffman encoded literal or for pre-sizing buffers.
+/// 
+/// **Example:**
+/// 
+/// ```rust
+/// use httlib_huffman::encoded_len;
+/// 
+/// let text = "Hello world!".as_bytes();
+/// assert_eq!(encoded_len(&text), 10);
+/// ```
+pub fn encoded_len(src: &[u8]) -> usize {
+    let codings = self::table::ENCODE_TABLE; // parsed huffman table
+
+    let bits: usize = src.iter()
+        .map(|&byte| codings[byte as usize].0 as usize)
+        .sum();
+
+    bits.div_ceil(8) // round up to full octets
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -269,4 +430,54 @@ mod test {
             198, 90, 40, 58, 158, 15, 101, 18, 127, 31,
         ]);
     }
+
+    /// Should calculate the exact number of bytes produced by the encoder.
+    #[test]
+    fn calculates_encoded_len() {
+        let examples: Vec<&[u8]> = vec![
+            b"",
+            b"a",
+            b"Hello world!",
+            b"\0\x01\x02\x03\x04\x05",
+            b"text/html,application/xhtml+xml,application/xml;q=0.9,*/*;q=0.8",
+        ];
+        for src in examples {
+            let mut dst = Vec::new();
+            encode(src, &mut dst).unwrap();
+            assert_eq!(encoded_len(src), dst.len());
+        }
+    }
+
+    /// Should encode into a slice of bytes and into a writer with the same
+    /// result as the vector encoder.
+    #[test]
+    fn encodes_into_slices_and_writers() {
+        let examples: Vec<&[u8]> = vec![
+            b"",
+            b"a",
+            b"Hello world!",
+            b"\0\x01\x02\x03\x04\x05\xff\xfe",
+            b"text/html,application/xhtml+xml,application/xml;q=0.9,*/*;q=0.8",
+        ];
+        for src in examples {
+            let mut expected = Vec::new();
+            encode(src, &mut expected).unwrap();
+
+            let mut dst = vec![0; expected.len()];
+            assert_eq!(encode_into(src, &mut dst), Ok(expected.len()));
+            assert_eq!(dst, expected);
+
+            let mut dst = Vec::new();
+            assert_eq!(encode_to_writer(src, &mut dst).unwrap(), expected.len());
+            assert_eq!(dst, expected);
+        }
+    }
+
+    /// Should return an error when the destination slice is too small.
+    #[test]
+    fn fails_on_insufficient_space() {
+        let mut dst = [0; 9];
+        assert_eq!(encode_into(b"Hello world!", &mut dst), Err(EncoderError::InsufficientSpace));
+        assert_eq!(encode_into(b"", &mut []), Ok(0));
+    }
 }
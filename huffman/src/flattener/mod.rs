@@ -117,17 +117,54 @@
 //! process of decoding looks like, using this matrix. This module uses this
 //! exact technic for creating N-bits translation matrix. 
 //! 
+//! When reading more than 5 bits at a time, a single chunk can hold more than
+//! one symbol. This happens when a row that continues a long code is reached
+//! and the last chunk of the code leaves 5 or more leftover bits. These bits
+//! are checked against the codes starting at the root row and, if they contain
+//! a whole code, the `flatten_packed` function stores the second symbol in the
+//! same transition. The leftover then represents the bits remaining after the
+//! second symbol. The `flatten` function keeps one symbol per transition and
+//! leaves the second code to the next lookup.
+//! 
+//! A transition tuple takes up to 12 bytes of memory which makes the tables
+//! for higher speeds much bigger than the CPU cache. The decoder therefore uses
+//! transitions packed into a single `u32` where flag bits tell which parts of
+//! the transition are present.
+//! 
+//! ```txt
+//! 31   30    29    28-26     25-18     17-9     8-0
+//! [N]  [A2]  [A]   leftover  next_id   ascii2   ascii
+//! ```
+//! 
 //! [HPACK]: https://tools.ietf.org/html/rfc7541
 //! [ASCII]: https://en.wikipedia.org/wiki/ASCII
 
+use std::convert::TryFrom;
+use std::fmt::Write;
 use crate::DecoderSpeed;
 
+/// Represents a transition tuple `(next_id, ascii, leftover)` of a translation
+/// matrix generated by the `flatten` function.
+pub type Transition = (Option<u8>, Option<u16>, u8);
+
+/// Represents a transition tuple `(next_id, ascii, ascii2, leftover)` where
+/// `ascii2` holds the second symbol decoded by the same chunk (if any).
+pub type PairTransition = (Option<u8>, Option<u16>, Option<u16>, u8);
+
 /// Generates a translation matrix that can be used to decode an encoded
 /// content. The function expects the `speed` attribute which represents the
 /// number of bits that the decoder will read at a time when processing bytes.
-/// The speed attribute can be between 1 and 5 bits. The higher number will
+/// The speed attribute can be between 1 and 8 bits. The higher number will
 /// have a positive effect on performance but possibly a higher memory usage.
 /// 
+/// Each transition is a tuple `(next_id, ascii, leftover)` and holds at most
+/// one symbol. See the `flatten_packed` function for a matrix where a chunk
+/// can decode two symbols at once.
+/// 
+/// Symbols with the code length of `0` have no code and are skipped. The
+/// function panics if the matrix would need more than 256 rows or when the
+/// `Canonical` speed is provided.
+/// 
 /// **Example:**
 /// 
 /// ```rust
@@ -141,22 +178,49 @@ use crate::DecoderSpeed;
 pub fn flatten(
     codings: &[(u8, u32)],
     speed: DecoderSpeed,
-) -> Vec<Vec<(Option<u8>, Option<u16>, u8)>> { // next_id, ascii, leftover
+) -> Vec<Vec<Transition>> {
+    flatten_transitions(codings, speed, false).into_iter()
+        .map(|transitions| transitions.into_iter().map(|t| (t.0, t.1, t.3)).collect())
+        .collect()
+}
+
+/// Generates a translation matrix like the `flatten` function but with each
+/// transition packed into a `u32` (see the `pack` function).
+/// 
+/// When reading more than 5 bits at a time, the leftover bits of a chunk can
+/// hold another whole code. Such a transition holds both symbols and its
+/// leftover tells the number of bits remaining after the second symbol.
+pub fn flatten_packed(codings: &[(u8, u32)], speed: DecoderSpeed) -> Vec<Vec<u32>> {
+    flatten_transitions(codings, speed, true).into_iter()
+        .map(|transitions| transitions.into_iter().map(pack).collect())
+        .collect()
+}
+
+/// Generates a translation matrix of transition tuples. The `pairs` flag tells
+/// whether the leftover bits of a chunk are checked for the second symbol.
+fn flatten_transitions(
+    codings: &[(u8, u32)],
+    speed: DecoderSpeed,
+    pairs: bool,
+) -> Vec<Vec<PairTransition>> {
     let speed = speed as usize;
+    assert!(speed > 0, "canonical decoding does not use a translation matrix");
     let blank_transition = generate_blank_transition(speed);
 
-    let mut table: Vec<Vec<(Option<u8>, Option<u16>, u8)>> = Vec::new();
+    let mut table: Vec<Vec<PairTransition>> = Vec::new();
     table.push(blank_transition.clone());
 
     for (ascii, coding) in codings.iter().enumerate() {
+        if coding.0 == 0 { // symbol without a code
+            continue;
+        }
         let leftover = (coding.0 as f32 / speed as f32).ceil() as usize * speed - coding.0 as usize;
         let mut id = 0; // current walk index in table
         
         for (path_index, keys) in generate_coding_paths(coding, speed).iter().enumerate() {
 
             if path_index == 0 { // create IDs for original path
-                for key_index in 0..keys.len() - 1 { // the last key will be handled afterward
-                    let key = keys[key_index];
+                for &key in &keys[..keys.len() - 1] { // the last key will be handled afterward
                     let target = &table[id][key]; // should always exist
     
                     let next_id = if let Some(next_id) = target.0 {
@@ -164,7 +228,7 @@ pub fn flatten(
                     } else {
                         table.push(blank_transition.clone());
 
-                        let next_id = (table.len() - 1) as u8;
+                        let next_id = u8::try_from(table.len() - 1).expect("Too many rows in translation matrix.");
                         let transitions = table.get_mut(id).unwrap();
                         let target = transitions.get_mut(key).unwrap();
                         target.0 = Some(next_id);
@@ -177,27 +241,132 @@ pub fn flatten(
             } 
             
             let key = keys.last().unwrap(); // handle the last key of all path variants
+            let next = if pairs { // leftover bits may hold another symbol
+                find_coding(codings, key & ((1 << leftover) - 1), leftover)
+            } else {
+                None
+            };
             let transitions = table.get_mut(id).unwrap();
             let target = transitions.get_mut(*key).unwrap();
             target.1 = Some(ascii as u16);
-            target.2 = leftover as u8;
+            if let Some((ascii2, len)) = next {
+                target.2 = Some(ascii2 as u16);
+                target.3 = (leftover - len) as u8;
+            } else {
+                target.3 = leftover as u8;
+            }
         }
     }
 
     table
 }
 
+/// Flag indicating that a packed transition holds the first symbol.
+pub const PACKED_ASCII: u32 = 1 << 29;
+
+/// Flag indicating that a packed transition holds the second symbol.
+pub const PACKED_ASCII2: u32 = 1 << 30;
+
+/// Flag indicating that a packed transition holds the next row ID.
+pub const PACKED_NEXT_ID: u32 = 1 << 31;
+
+/// Packs the transition tuple `(next_id, ascii, ascii2, leftover)` into a
+/// `u32`. Symbols take 9 bits, the next ID 8 bits and the leftover 3 bits.
+pub fn pack(transition: PairTransition) -> u32 {
+    let (next_id, ascii, ascii2, leftover) = transition;
+    let mut target = (leftover as u32 & 0x7) << 26;
+    if let Some(next_id) = next_id {
+        target |= PACKED_NEXT_ID | (next_id as u32) << 18;
+    }
+    if let Some(ascii) = ascii {
+        target |= PACKED_ASCII | (ascii as u32 & 0x1ff);
+    }
+    if let Some(ascii2) = ascii2 {
+        target |= PACKED_ASCII2 | (ascii2 as u32 & 0x1ff) << 9;
+    }
+    target
+}
+
+/// Unpacks a `u32` transition into the `(next_id, ascii, ascii2, leftover)`
+/// tuple.
+#[inline]
+pub fn unpack(target: u32) -> PairTransition {
+    let next_id = if target & PACKED_NEXT_ID != 0 {
+        Some((target >> 18) as u8)
+    } else {
+        None
+    };
+    let ascii = if target & PACKED_ASCII != 0 {
+        Some((target & 0x1ff) as u16)
+    } else {
+        None
+    };
+    let ascii2 = if target & PACKED_ASCII2 != 0 {
+        Some((target >> 9 & 0x1ff) as u16)
+    } else {
+        None
+    };
+    (next_id, ascii, ascii2, (target >> 26 & 0x7) as u8)
+}
+
+/// Generates the Rust source code of the static `DECODE_TABLE` with packed
+/// transitions for the provided `codings` and `speed`. This is how the tables
+/// in the `decoder::tableN` modules are generated.
+pub fn generate_source(codings: &[(u8, u32)], speed: DecoderSpeed) -> String {
+    let table = flatten_packed(codings, speed);
+    let table_len = table.len();
+    let targets_len = table[0].len();
+
+    let mut src = String::new();
+    writeln!(src, "/// Represents a static translation table for decoding Huffman sequence by").unwrap();
+    if speed as usize == 1 {
+        writeln!(src, "/// reading 1-bit at at time.").unwrap();
+    } else {
+        writeln!(src, "/// reading {}-bits at at time.", speed as usize).unwrap();
+    }
+    writeln!(src, "/// ").unwrap();
+    writeln!(src, "/// Transitions are packed into `u32` values (see `flattener::pack`).").unwrap();
+    writeln!(src, "pub static DECODE_TABLE: [[u32; {}]; {}] = [", targets_len, table_len).unwrap();
+    for (i, transitions) in table.iter().enumerate() {
+        writeln!(src, "    [ // {}", i).unwrap();
+        for transition in transitions {
+            writeln!(src, "        {:#010x}, // {:?}", transition, unpack(*transition)).unwrap();
+        }
+        writeln!(src, "    ],").unwrap();
+    }
+    writeln!(src, "];").unwrap();
+    src
+}
+
 /// Generates a black transition object based on the provided speed attribute.
-fn generate_blank_transition(speed: usize) -> Vec<(Option<u8>, Option<u16>, u8)> {
+fn generate_blank_transition(speed: usize) -> Vec<PairTransition> {
     let mut transition = Vec::new();
 
     for _ in 0..2u32.pow(speed as u32) {
-        transition.push((None, None, 0));
+        transition.push((None, None, None, 0));
     }
 
     transition
 }
 
+/// Finds the coding whose code is the prefix of the `bits` sequence of length
+/// `bits_len`. The function returns the index of the coding and its length.
+/// 
+/// ```tst
+/// Bits:       00111 (bits_len=5)
+/// Match:      00111 => 'a' (length=5)
+/// ```
+fn find_coding(codings: &[(u8, u32)], bits: usize, bits_len: usize) -> Option<(usize, usize)> {
+    codings.iter().enumerate().find_map(|(ascii, coding)| {
+        let len = coding.0 as usize;
+        if len > 0 && len <= bits_len && bits >> (bits_len - len) == coding.1 as usize {
+            Some((ascii, len))
+        } else {
+            None
+        }
+    })
+}
+
 /// Generates all key paths for the particular coding. If the key has leftovers
 /// the function will fill that gap with all possible variants.
 /// 
@@ -207,18 +376,18 @@ fn generate_blank_transition(speed: usize) -> Vec<(Option<u8>, Option<u16>, u8)>
 ///             [1100, 0000, 1110, 0111]
 /// ```
 fn generate_coding_paths(coding: &(u8, u32), speed: usize) -> Vec<Vec<usize>> {
-    let mut bits: u32 = 0; // HPACK value can be up to 32 bits
+    let mut bits: u64 = 0; // HPACK value can be up to 32 bits plus leftover
     let chunks_len = (coding.0 as f32 / speed as f32).ceil() as usize;
     let chunk_max = 2u32.pow(speed as u32) as usize - 1;
     let leftover = chunks_len * speed - coding.0 as usize;
-    bits |= coding.1;
+    bits |= coding.1 as u64;
     bits <<= leftover;
 
     let mut chunks = vec![];
     for i in 0..chunks_len {
-        let mut chunk = (bits >> (chunks_len - i - 1) * speed) as usize;
+        let mut chunk = (bits >> ((chunks_len - i - 1) * speed)) as usize;
         chunk &= chunk_max;
-        chunks.push(chunk as usize);
+        chunks.push(chunk);
     }
     
     let mut variants: Vec<Vec<usize>> = vec![];
@@ -254,6 +423,7 @@ mod test {
     /// Should generate a translation matrix that allows for decoding Huffman
     /// sequence by reading 1 bit at a time.
     #[test]
+    #[allow(clippy::needless_borrow)]
     fn flattens_1bits() { 
         let table = flatten(&sample_encoding_table(), DecoderSpeed::OneBit);
         assert_eq!(table.len(), 41);
@@ -267,6 +437,7 @@ mod test {
     /// Should generate a translation matrix that allows for decoding Huffman
     /// sequence by reading 2 bits at a time.
     #[test]
+    #[allow(clippy::needless_borrow)]
     fn flattens_2bits() { 
         let table = flatten(&sample_encoding_table(), DecoderSpeed::TwoBits);
         assert_eq!(table.len(), 20);
@@ -280,6 +451,7 @@ mod test {
     /// Should generate a translation matrix that allows for decoding Huffman
     /// sequence by reading 3 bits at a time.
     #[test]
+    #[allow(clippy::needless_borrow)]
     fn flattens_3bits() { 
         let table = flatten(&sample_encoding_table(), DecoderSpeed::ThreeBits);
         assert_eq!(table.len(), 16);
@@ -293,6 +465,7 @@ mod test {
     /// Should generate a translation matrix that allows for decoding Huffman
     /// sequence by reading 4 bits at a time.
     #[test]
+    #[allow(clippy::needless_borrow)]
     fn flattens_4bits() { 
         let table = flatten(&sample_encoding_table(), DecoderSpeed::FourBits);
         assert_eq!(table.len(), 9);
@@ -306,6 +479,7 @@ mod test {
     /// Should generate a translation matrix that allows for decoding Huffman
     /// sequence by reading 5 bits at a time.
     #[test]
+    #[allow(clippy::needless_borrow)]
     fn flattens_5bits() { 
         let table = flatten(&sample_encoding_table(), DecoderSpeed::FiveBits);
         assert_eq!(table.len(), 8);
@@ -336,4 +510,43 @@ mod test {
             vec![5, 1, 12, 7], // [0101, 0000, 1110, 0111]
         ]);
     }
+
+    /// Should pack transitions into `u32` values without losing information.
+    #[test]
+    fn packs_transitions() {
+        let transitions = [
+            (None, None, None, 0),
+            (Some(255), None, None, 0),
+            (None, Some(256), None, 7),
+            (None, Some(0), Some(256), 3),
+            (Some(1), Some(63), Some(48), 1),
+        ];
+        for transition in transitions.iter() {
+            assert_eq!(unpack(pack(*transition)), *transition);
+        }
+        assert_eq!(pack((None, Some(256), None, 7)), PACKED_ASCII | 7 << 26 | 256);
+
+        let table = flatten_transitions(sample_encoding_table(), DecoderSpeed::FourBits, true);
+        let packed = flatten_packed(sample_encoding_table(), DecoderSpeed::FourBits);
+        for (transitions, packed) in table.iter().zip(packed.iter()) {
+            assert_eq!(transitions, &packed.iter().map(|t| unpack(*t)).collect::<Vec<_>>());
+        }
+    }
+
+    /// Should build packed tables which take half the memory of the tables
+    /// with transition tuples.
+    #[test]
+    fn packs_tables_into_less_memory() {
+        let size = |rows: usize, cols: usize, item: usize| rows * cols * item;
+        for speed in DecoderSpeed::known().into_iter().take(5) {
+            let tuples = flatten(sample_encoding_table(), speed);
+            let packed = flatten_packed(sample_encoding_table(), speed);
+            assert_eq!((packed.len(), packed[0].len()), (tuples.len(), tuples[0].len()));
+            assert_eq!(
+                size(packed.len(), packed[0].len(), std::mem::size_of::<u32>()) * 2,
+                size(tuples.len(), tuples[0].len(), std::mem::size_of::<Transition>()),
+            );
+        }
+    }
 }
+
@@ -51,12 +51,29 @@
 //! ```rust
 //! use httlib_huffman::{DecoderSpeed, decode};
 //!
-//! let speed = DecoderSpeed::ThreeBits;
+//! let speed = DecoderSpeed::Canonical;
 //! let mut dst = Vec::new();
 //! let src = vec![135];
 //! decode(&src, &mut dst, speed).unwrap();
 //! ```
 //! 
+//! ## Command-line tool
+//! 
+//! The crate ships with the `httlib-huffman` binary which encodes and decodes
+//! sequences from the terminal, regenerates the static tables and verifies that
+//! every symbol round-trips at every enabled decoder speed. It can also train a
+//! custom code on a corpus of headers and report the gain over the HPACK code.
+//! 
+//! ```txt
+//! $ httlib-huffman encode "Hello world!"
+//! c65a283a9e0f65127f1f
+//! $ httlib-huffman decode --speed canonical c65a283a9e0f65127f1f
+//! Hello world!
+//! $ httlib-huffman gen-decode-table --speed 4 > table4.rs
+//! $ httlib-huffman verify
+//! $ httlib-huffman train ../hpack/fixtures/*/*.json > trained.csv
+//! ```
+//! 
 //! ## Articles
 //! 
 //! * [HPACK: Huffman encoder](https://dev.to/xpepermint/hpack-huffman-encoder-3i7c)
@@ -69,10 +86,13 @@
 //! [Huffman code]: https://en.wikipedia.org/wiki/Huffman_coding
 //! [canonical Huffman]: https://en.wikipedia.org/wiki/Canonical_Huffman_code
 
+pub mod code;
 pub mod decoder;
 pub mod encoder;
 pub mod flattener;
 pub mod parser;
+pub mod trainer;
 
+pub use code::*;
 pub use decoder::*;
 pub use encoder::*;
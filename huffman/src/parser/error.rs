@@ -0,0 +1,54 @@
+use std::error;
+use std::fmt;
+
+/// Contains error options that can be encountered while parsing and validating
+/// a Huffman code table.
+#[derive(Debug, PartialEq)]
+pub enum ParserError {
+    /// Indicates that the line at the provided number (1-based) is malformed.
+    InvalidSyntax(usize),
+
+    /// Indicates that the line at the provided number (1-based) holds a symbol
+    /// which is out of range or which has already been defined.
+    InvalidSymbol(usize),
+
+    /// Indicates that the line at the provided number (1-based) holds a code
+    /// longer than 32 bits or a code value which does not fit into its length.
+    InvalidLength(usize),
+
+    /// Indicates that the provided symbol is not defined by the table.
+    MissingSymbol(usize),
+
+    /// Indicates that JSON arrays and objects are nested more than 100 levels
+    /// deep at the line with the provided number (1-based).
+    DepthOverflow(usize),
+
+    /// Indicates that the table defines more than 257 symbols. The value holds
+    /// the number of provided codings.
+    TooManySymbols(usize),
+
+    /// Indicates that the code lengths do not describe a complete prefix code
+    /// (the Kraft sum is not equal to 1).
+    IncompleteCode,
+
+    /// Indicates that the code of the provided symbol does not follow the
+    /// canonical ordering.
+    NotCanonical(usize),
+}
+
+impl fmt::Display for ParserError {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::InvalidSyntax(line) => write!(fmt, "Invalid syntax at line {}.", line),
+            Self::InvalidSymbol(line) => write!(fmt, "Invalid symbol at line {}.", line),
+            Self::InvalidLength(line) => write!(fmt, "Invalid code length at line {}.", line),
+            Self::MissingSymbol(symbol) => write!(fmt, "Missing symbol {}.", symbol),
+            Self::DepthOverflow(line) => write!(fmt, "Values nested too deep at line {}.", line),
+            Self::TooManySymbols(count) => write!(fmt, "Too many symbols ({} instead of 257).", count),
+            Self::IncompleteCode => write!(fmt, "Code lengths do not form a complete prefix code."),
+            Self::NotCanonical(symbol) => write!(fmt, "Code of symbol {} is not canonical.", symbol),
+        }
+    }
+}
+
+impl error::Error for ParserError {}
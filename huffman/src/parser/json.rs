@@ -0,0 +1,258 @@
+use super::ParserError;
+
+/// Represents a parsed JSON value. Unsigned integers are kept exact since
+/// tables are built from them while other numbers are only read as floats.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    Null,
+    Bool(bool),
+    Number(u64),
+    Float(f64),
+    String(String),
+    Array(Vec<(usize, Value)>), // (line, value)
+    Object(Vec<(String, Value)>),
+}
+
+impl Value {
+    /// Returns the value of the field with the provided `key` when the value
+    /// is an object.
+    pub fn get(&self, key: &str) -> Option<&Value> {
+        match self {
+            Value::Object(fields) => fields.iter().find(|(k, _)| k == key).map(|(_, v)| v),
+            _ => None,
+        }
+    }
+
+    /// Returns the items when the value is an array.
+    pub fn items(&self) -> impl Iterator<Item = &Value> {
+        let items: &[(usize, Value)] = match self {
+            Value::Array(items) => items,
+            _ => &[],
+        };
+        items.iter().map(|(_, v)| v)
+    }
+
+    /// Returns the string when the value is a string.
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Value::String(s) => Some(s),
+            _ => None,
+        }
+    }
+}
+
+/// The maximum number of nested JSON arrays and objects.
+pub const JSON_DEPTH_MAX: usize = 100;
+
+/// Parses the JSON `data` into a `Value`. Errors hold the line number where
+/// the input stopped making sense.
+pub fn parse(data: &str) -> Result<Value, ParserError> {
+    let mut reader = Reader { data: data.as_bytes(), pos: 0, line: 1, depth: 0 };
+    let value = reader.read_value()?;
+    reader.skip_whitespace();
+    if reader.pos != reader.data.len() {
+        return Err(ParserError::InvalidSyntax(reader.line));
+    }
+    Ok(value)
+}
+
+/// A simple recursive JSON reader which tracks the current line number and
+/// the number of open arrays and objects.
+struct Reader<'a> {
+    data: &'a [u8],
+    pos: usize,
+    line: usize,
+    depth: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn read_value(&mut self) -> Result<Value, ParserError> {
+        self.skip_whitespace();
+        match self.peek() {
+            Some(b'[') | Some(b'{') if self.depth >= JSON_DEPTH_MAX => {
+                Err(ParserError::DepthOverflow(self.line))
+            },
+            Some(b'[') => {
+                self.depth += 1;
+                let value = self.read_array();
+                self.depth -= 1;
+                value
+            },
+            Some(b'{') => {
+                self.depth += 1;
+                let value = self.read_object();
+                self.depth -= 1;
+                value
+            },
+            Some(b'"') => Ok(Value::String(self.read_string()?)),
+            Some(b'0'..=b'9') | Some(b'-') => self.read_number(),
+            Some(b't') => self.read_literal("true", Value::Bool(true)),
+            Some(b'f') => self.read_literal("false", Value::Bool(false)),
+            Some(b'n') => self.read_literal("null", Value::Null),
+            _ => Err(ParserError::InvalidSyntax(self.line)),
+        }
+    }
+
+    fn read_array(&mut self) -> Result<Value, ParserError> {
+        self.expect(b'[')?;
+        let mut items = Vec::new();
+        self.skip_whitespace();
+        if self.peek() == Some(b']') {
+            self.pos += 1;
+            return Ok(Value::Array(items));
+        }
+        loop {
+            self.skip_whitespace();
+            let line = self.line;
+            items.push((line, self.read_value()?));
+            self.skip_whitespace();
+            match self.next() {
+                Some(b',') => continue,
+                Some(b']') => return Ok(Value::Array(items)),
+                _ => return Err(ParserError::InvalidSyntax(self.line)),
+            }
+        }
+    }
+
+    fn read_object(&mut self) -> Result<Value, ParserError> {
+        self.expect(b'{')?;
+        let mut fields = Vec::new();
+        self.skip_whitespace();
+        if self.peek() == Some(b'}') {
+            self.pos += 1;
+            return Ok(Value::Object(fields));
+        }
+        loop {
+            self.skip_whitespace();
+            let key = self.read_string()?;
+            self.skip_whitespace();
+            self.expect(b':')?;
+            fields.push((key, self.read_value()?));
+            self.skip_whitespace();
+            match self.next() {
+                Some(b',') => continue,
+                Some(b'}') => return Ok(Value::Object(fields)),
+                _ => return Err(ParserError::InvalidSyntax(self.line)),
+            }
+        }
+    }
+
+    fn read_string(&mut self) -> Result<String, ParserError> {
+        self.expect(b'"')?;
+        let mut text = String::new();
+        loop {
+            match self.next() {
+                Some(b'"') => break,
+                Some(b'\\') => {
+                    let c = match self.next() {
+                        Some(b'"') => '"',
+                        Some(b'\\') => '\\',
+                        Some(b'/') => '/',
+                        Some(b'b') => '\u{8}',
+                        Some(b'f') => '\u{c}',
+                        Some(b'n') => '\n',
+                        Some(b'r') => '\r',
+                        Some(b't') => '\t',
+                        Some(b'u') => self.read_unicode()?,
+                        _ => return Err(ParserError::InvalidSyntax(self.line)),
+                    };
+                    text.push(c);
+                },
+                Some(b'\n') | None => return Err(ParserError::InvalidSyntax(self.line)),
+                Some(_) => { // copy everything up to the next special byte
+                    let start = self.pos - 1;
+                    while let Some(b) = self.peek() {
+                        if b == b'"' || b == b'\\' || b == b'\n' {
+                            break;
+                        }
+                        self.pos += 1;
+                    }
+                    let chunk = std::str::from_utf8(&self.data[start..self.pos])
+                        .map_err(|_| ParserError::InvalidSyntax(self.line))?;
+                    text.push_str(chunk);
+                },
+            }
+        }
+        Ok(text)
+    }
+
+    /// Reads the 4 hex digits of a `\\u` escape, including the second half of
+    /// a UTF-16 surrogate pair.
+    fn read_unicode(&mut self) -> Result<char, ParserError> {
+        let high = self.read_hex4()?;
+        let code = if (0xd800..0xdc00).contains(&high) {
+            self.expect(b'\\')?;
+            self.expect(b'u')?;
+            let low = self.read_hex4()?;
+            if !(0xdc00..0xe000).contains(&low) {
+                return Err(ParserError::InvalidSyntax(self.line));
+            }
+            0x10000 + ((high - 0xd800) << 10) + (low - 0xdc00)
+        } else {
+            high
+        };
+        std::char::from_u32(code).ok_or(ParserError::InvalidSyntax(self.line))
+    }
+
+    fn read_hex4(&mut self) -> Result<u32, ParserError> {
+        let digits = self.data.get(self.pos..self.pos + 4)
+            .and_then(|d| std::str::from_utf8(d).ok())
+            .and_then(|d| u32::from_str_radix(d, 16).ok())
+            .ok_or(ParserError::InvalidSyntax(self.line))?;
+        self.pos += 4;
+        Ok(digits)
+    }
+
+    fn read_number(&mut self) -> Result<Value, ParserError> {
+        let start = self.pos;
+        while let Some(b'0'..=b'9') | Some(b'-') | Some(b'+') | Some(b'.') | Some(b'e') | Some(b'E') = self.peek() {
+            self.pos += 1;
+        }
+        let text = std::str::from_utf8(&self.data[start..self.pos])
+            .map_err(|_| ParserError::InvalidSyntax(self.line))?;
+        if let Ok(number) = text.parse() {
+            Ok(Value::Number(number))
+        } else {
+            text.parse().map(Value::Float).map_err(|_| ParserError::InvalidSyntax(self.line))
+        }
+    }
+
+    fn read_literal(&mut self, literal: &str, value: Value) -> Result<Value, ParserError> {
+        if self.data[self.pos..].starts_with(literal.as_bytes()) {
+            self.pos += literal.len();
+            Ok(value)
+        } else {
+            Err(ParserError::InvalidSyntax(self.line))
+        }
+    }
+
+    fn expect(&mut self, byte: u8) -> Result<(), ParserError> {
+        match self.next() {
+            Some(b) if b == byte => Ok(()),
+            _ => Err(ParserError::InvalidSyntax(self.line)),
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        while let Some(b) = self.peek() {
+            match b {
+                b'\n' => self.line += 1,
+                b' ' | b'\t' | b'\r' => {},
+                _ => break,
+            }
+            self.pos += 1;
+        }
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.data.get(self.pos).copied()
+    }
+
+    fn next(&mut self) -> Option<u8> {
+        let byte = self.peek();
+        if byte.is_some() {
+            self.pos += 1;
+        }
+        byte
+    }
+}
@@ -9,14 +9,33 @@
 //! the static Rust source code. This module was used to create the ENCODE_TABLE
 //! constant which can be found in the `encode::table` module.
 //! 
+//! Besides the text layout of the specification, tables can also be provided
+//! in a simple CSV or JSON format of `(symbol, bits, length)` entries. Every
+//! parsed table is checked to be a complete and canonical prefix code, so custom
+//! tables can be safely fed to the flattener.
+//! 
 //! You will probably never use this module while developing applications.
 //! 
 //! [ASCII]: https://en.wikipedia.org/wiki/ASCII
 //! [HPACK]: https://tools.ietf.org/html/rfc7541
 //! [Huffman code]: https://tools.ietf.org/html/rfc7541#appendix-B
 
+mod error;
+pub(crate) mod json;
+
+pub use error::*;
+use json::Value;
+
 /// Parses the HPACK's static Huffman table. The function expects data to be in
-/// format as provided by the spec (7.2).
+/// format as provided by the spec (7.2) where each line holds the symbol, the
+/// code as bits, the code as hex and the code length:
+/// 
+/// ```txt
+/// EOS (256)  |11111111|11111111|11111111|111111      3fffffff  [30]
+/// ```
+/// 
+/// The returned table is validated (see `validate`) so it can be safely passed
+/// to the flattener.
 /// 
 /// **Example:**
 /// 
@@ -27,39 +46,237 @@
 /// 
 /// let path = Path::new("assets/hpack-huffman.txt");
 /// let data = fs::read_to_string(path).expect("Can't read file.");
-/// let codings = parse(&data);
+/// let codings = parse(&data).unwrap();
 /// ```
-pub fn parse(data: &str) -> Vec<(u16, u32)> {
-    let lines = data.lines();
-    let mut codings = vec![];
+pub fn parse(data: &str) -> Result<Vec<(u16, u32)>, ParserError> {
+    let mut entries = vec![];
+
+    for (i, line) in data.lines().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        entries.push(parse_line(line, i + 1)?);
+    }
+
+    collect(entries)
+}
+
+/// Parses a Huffman table in CSV format where each line holds the symbol, the
+/// code value in hex and the code length. Empty lines, comments starting with
+/// `#` and the `symbol,bits,length` header are ignored.
+/// 
+/// ```txt
+/// symbol,bits,length
+/// 0,0x1ff8,13
+/// 1,0x7fffd8,23
+/// ```
+pub fn parse_csv(data: &str) -> Result<Vec<(u16, u32)>, ParserError> {
+    let mut entries = vec![];
+
+    for (i, line) in data.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') || line.starts_with("symbol") {
+            continue;
+        }
+
+        let fields: Vec<&str> = line.split(',').map(|f| f.trim()).collect();
+        let entry = match fields.as_slice() {
+            [symbol, bits, length] => {
+                let bits = bits.trim_start_matches("0x").trim_start_matches("0X");
+                (symbol.parse().ok(), u64::from_str_radix(bits, 16).ok(), length.parse().ok())
+            },
+            _ => (None, None, None),
+        };
+        match entry {
+            (Some(symbol), Some(bits), Some(length)) => entries.push((i + 1, symbol, bits, length)),
+            _ => return Err(ParserError::InvalidSyntax(i + 1)),
+        }
+    }
+
+    collect(entries)
+}
+
+/// Parses a Huffman table in JSON format. The table is an array where each item
+/// is either an object with the `symbol`, `bits` and `length` keys or an array
+/// of these three numbers. The `bits` field holds the code value.
+/// 
+/// ```txt
+/// [
+///   { "symbol": 0, "bits": 8184, "length": 13 },
+///   [1, 8388568, 23]
+/// ]
+/// ```
+pub fn parse_json(data: &str) -> Result<Vec<(u16, u32)>, ParserError> {
+    let items = match json::parse(data)? {
+        Value::Array(items) => items,
+        _ => return Err(ParserError::InvalidSyntax(1)),
+    };
+
+    let mut entries = vec![];
+    for (line, item) in items {
+        let fields = match item {
+            Value::Array(values) => values.into_iter().map(|(_, v)| v).collect(),
+            Value::Object(fields) => {
+                let mut values = vec![];
+                for key in &["symbol", "bits", "length"] {
+                    match fields.iter().position(|(k, _)| k == key) {
+                        Some(index) => values.push(fields[index].1.clone()),
+                        None => return Err(ParserError::InvalidSyntax(line)),
+                    }
+                }
+                values
+            },
+            _ => vec![],
+        };
+        match fields.as_slice() {
+            [Value::Number(symbol), Value::Number(bits), Value::Number(length)] => {
+                entries.push((line, *symbol, *bits, *length));
+            },
+            _ => return Err(ParserError::InvalidSyntax(line)),
+        }
+    }
+
+    collect(entries)
+}
+
+/// Validates that the `codings` (length, msb) of all 257 symbols describe a
+/// complete and canonical Huffman code. Symbols with the code length of `0`
+/// are considered unused. Errors which refer to a line number hold the index of
+/// the invalid coding increased by 1.
+/// 
+/// A code is complete when the Kraft sum of all code lengths equals to 1 which
+/// means that no bit sequence is left unassigned. A code is canonical when the
+/// codes of the same length are consecutive integers and they are assigned to
+/// symbols in their natural order, starting with the shortest codes. Since a
+/// canonical code is assigned without gaps and overlaps, the check also proves
+/// that the code is prefix-free.
+pub fn validate(codings: &[(u16, u32)]) -> Result<(), ParserError> {
+    if codings.len() < 257 {
+        return Err(ParserError::MissingSymbol(codings.len()));
+    } else if codings.len() > 257 {
+        return Err(ParserError::TooManySymbols(codings.len()));
+    }
+
+    for (i, &(len, msb)) in codings.iter().enumerate() {
+        if len > 32 || u64::from(msb) >> len != 0 {
+            return Err(ParserError::InvalidLength(i + 1));
+        }
+    }
+
+    let kraft: u64 = codings.iter()
+        .filter(|(len, _)| *len > 0)
+        .map(|(len, _)| 1 << (32 - len))
+        .sum();
+    if kraft != 1 << 32 {
+        return Err(ParserError::IncompleteCode);
+    }
 
-    for line in lines {
-        let coding = parse_line(line);
-        codings.push(coding);
+    let mut symbols: Vec<usize> = (0..codings.len())
+        .filter(|&symbol| codings[symbol].0 > 0)
+        .collect();
+    symbols.sort_by_key(|&symbol| (codings[symbol].0, symbol));
+
+    let mut code: u64 = 0;
+    let mut code_len = 0;
+    for symbol in symbols { // codes must be consecutive
+        let (len, msb) = codings[symbol];
+        code <<= len - code_len;
+        code_len = len;
+        if u64::from(msb) != code {
+            return Err(ParserError::NotCanonical(symbol));
+        }
+        code += 1;
     }
 
-    codings
+    Ok(())
+}
+
+/// Generates the Rust source code of the static `ENCODE_TABLE` from the parsed
+/// `codings`. This is how the `encoder::table` module is generated.
+pub fn generate_source(codings: &[(u16, u32)]) -> String {
+    let mut src = String::new();
+    src.push_str("/// Represents a static Huffman table built from the codes found in the official\n");
+    src.push_str("/// HPACK specification ([Appendix B]).\n");
+    src.push_str("/// \n");
+    src.push_str("/// [Appendix B]: https://tools.ietf.org/html/rfc7541#appendix-B\n");
+    src.push_str(&format!("pub const ENCODE_TABLE: [(u8, u32); {}] = [ // (length, msb)\n", codings.len()));
+    for (i, coding) in codings.iter().enumerate() {
+        src.push_str(&format!("  ({}, 0x{:02x})", coding.0, coding.1));
+        if i != codings.len() - 1 {
+            src.push(',');
+        }
+        src.push('\n');
+    }
+    src.push_str("];\n");
+    src
 }
 
 /// Parses a single line of the static Huffman table. The output returned
-/// contains a tuple of the number of bits for the code representing the symbol
-/// and Huffman LSB value.
-fn parse_line(line: &str) -> (u16, u32) {
+/// contains a tuple of the line number, the symbol, the Huffman LSB value and
+/// the number of bits for the code representing the symbol.
+fn parse_line(line: &str, number: usize) -> Result<(usize, u64, u64, u64), ParserError> {
+    let invalid = ParserError::InvalidSyntax(number);
+
+    let start = line.find(" (").ok_or(ParserError::InvalidSyntax(number))? + 2;
+    let end = start + line[start..].find(')').ok_or(ParserError::InvalidSyntax(number))?;
+    let symbol = line[start..end].trim().parse().map_err(|_| ParserError::InvalidSyntax(number))?;
+
+    let length = line.rfind('[').ok_or(ParserError::InvalidSyntax(number))?;
+    let fields: Vec<&str> = line[end + 1..length].split_whitespace().collect();
+    let (bits, hex) = match fields.as_slice() {
+        [bits, hex] => (bits, hex),
+        _ => return Err(invalid),
+    };
 
-    let mut msb = vec![];
-    for &b in &line.as_bytes()[12..45] {
+    let mut msb: u64 = 0;
+    let mut msb_len = 0;
+    for b in bits.bytes() {
         match b {
-            b'1' => msb.push(true),
-            b'0' => msb.push(false),
-            b'|' | b' ' => {}
-            _ => panic!("unexpected byte; {:?}", b),
+            b'0' | b'1' if msb_len < 64 => {
+                msb = (msb << 1) | u64::from(b - b'0');
+                msb_len += 1;
+            },
+            b'|' => {},
+            _ => return Err(invalid),
         }
     }
 
-    let lsb = u32::from_str_radix(&line[50..59].trim().to_string(), 16).expect("Invalid hex");
-    let len = msb.len() as u16;
+    let lsb = u64::from_str_radix(hex, 16).map_err(|_| ParserError::InvalidSyntax(number))?;
+    let len: u64 = line[length + 1..].strip_suffix(']')
+        .and_then(|l| l.trim().parse().ok())
+        .ok_or(ParserError::InvalidSyntax(number))?;
+    if len != msb_len {
+        return Err(ParserError::InvalidLength(number));
+    } else if lsb != msb {
+        return Err(invalid);
+    }
 
-    (len, lsb)
+    Ok((number, symbol, lsb, len))
+}
+
+/// Builds a validated table of codings from parsed `entries` where each entry
+/// holds the line number, the symbol, the code value and the code length.
+fn collect(entries: Vec<(usize, u64, u64, u64)>) -> Result<Vec<(u16, u32)>, ParserError> {
+    let mut codings = vec![None; 257];
+
+    for (line, symbol, bits, length) in entries {
+        let coding = match codings.get_mut(symbol as usize) {
+            Some(coding) if coding.is_none() && symbol <= 256 => coding,
+            _ => return Err(ParserError::InvalidSymbol(line)),
+        };
+        if length > 32 || bits >> length != 0 {
+            return Err(ParserError::InvalidLength(line));
+        }
+        *coding = Some((length as u16, bits as u32));
+    }
+
+    let codings = codings.into_iter()
+        .enumerate()
+        .map(|(symbol, coding)| coding.ok_or(ParserError::MissingSymbol(symbol)))
+        .collect::<Result<Vec<_>, _>>()?;
+    validate(&codings)?;
+
+    Ok(codings)
 }
 
 #[cfg(test)]
@@ -74,7 +291,7 @@ mod tests {
     fn parses_huffman_table() { 
         let path = Path::new("assets/hpack-huffman.txt");
         let data = fs::read_to_string(path).expect("Can't read file.");
-        let table = parse(&data);
+        let table = parse(&data).unwrap();
 
         assert_eq!(table.len(), 257);
 
@@ -90,4 +307,70 @@ mod tests {
         assert_eq!(item.0, 30);
         assert_eq!(item.1, 0x3fffffff);
     }
+
+    /// Should parse the same table from the text, CSV and JSON formats.
+    #[test]
+    fn parses_table_formats() {
+        let path = Path::new("assets/hpack-huffman.txt");
+        let data = fs::read_to_string(path).expect("Can't read file.");
+        let table = parse(&data).unwrap();
+
+        let mut csv = String::from("# HPACK\nsymbol,bits,length\n");
+        let mut json = vec![];
+        for (symbol, (len, msb)) in table.iter().enumerate() {
+            csv.push_str(&format!("{},0x{:x},{}\n", symbol, msb, len));
+            json.push(match symbol % 2 {
+                0 => format!("{{ \"symbol\": {}, \"bits\": {}, \"length\": {} }}", symbol, msb, len),
+                _ => format!("[{}, {}, {}]", symbol, msb, len),
+            });
+        }
+        let json = format!("[\n{}\n]", json.join(",\n"));
+
+        assert_eq!(parse_csv(&csv).unwrap(), table);
+        assert_eq!(parse_json(&json).unwrap(), table);
+    }
+
+    /// Should return errors for malformed, incomplete and non-canonical tables
+    /// instead of panicking.
+    #[test]
+    fn fails_on_invalid_tables() {
+        let path = Path::new("assets/hpack-huffman.txt");
+        let data = fs::read_to_string(path).expect("Can't read file.");
+        let mut lines: Vec<String> = data.lines().map(|l| l.to_string()).collect();
+
+        lines[3] = "    (  3)  |11111111|11".to_string();
+        assert_eq!(parse(&lines.join("\n")), Err(ParserError::InvalidSyntax(4)));
+        lines[3] = "    (  2)  |11111111|11111111|11111110|0011         fffffe3  [28]".to_string();
+        assert_eq!(parse(&lines.join("\n")), Err(ParserError::InvalidSymbol(4)));
+        lines[3] = "    (  3)  |11111111|11111111|11111110|0011         fffffe3  [27]".to_string();
+        assert_eq!(parse(&lines.join("\n")), Err(ParserError::InvalidLength(4)));
+        lines.remove(3);
+        assert_eq!(parse(&lines.join("\n")), Err(ParserError::MissingSymbol(3)));
+
+        assert_eq!(parse_json("[[0, 1, 1],\n{ \"symbol\": 1 }]"), Err(ParserError::InvalidSyntax(2)));
+        assert_eq!(parse_csv("0,0x1,1\n1,1,x"), Err(ParserError::InvalidSyntax(2)));
+        let nested = |levels: usize| format!("{}{}", "[\n".repeat(levels), "]".repeat(levels));
+        assert_eq!(parse_json(&nested(json::JSON_DEPTH_MAX)), Err(ParserError::InvalidSyntax(2))); // not a table
+        assert_eq!(parse_json(&nested(json::JSON_DEPTH_MAX + 1)), Err(ParserError::DepthOverflow(json::JSON_DEPTH_MAX + 1)));
+        assert_eq!(parse_json(&nested(200_000)), Err(ParserError::DepthOverflow(json::JSON_DEPTH_MAX + 1)));
+
+        let mut table = parse(&data).unwrap();
+        table.push((5, 0));
+        assert_eq!(validate(&table), Err(ParserError::TooManySymbols(258)));
+        table.pop();
+        table[256] = (31, 0x7ffffffe);
+        assert_eq!(validate(&table), Err(ParserError::IncompleteCode));
+        table[256] = (30, 0x3fffffff);
+        table.swap(48, 49); // '0' and '1' have codes of the same length
+        assert_eq!(validate(&table), Err(ParserError::NotCanonical(48)));
+    }
+
+    /// Should generate the source code of the `ENCODE_TABLE` constant.
+    #[test]
+    fn generates_source() {
+        let path = Path::new("assets/hpack-huffman.txt");
+        let data = fs::read_to_string(path).expect("Can't read file.");
+        let src = fs::read_to_string("src/encoder/table.rs").expect("Can't read file.");
+        assert_eq!(generate_source(&parse(&data).unwrap()), src);
+    }
 }
@@ -0,0 +1,41 @@
+use std::error;
+use std::fmt;
+use crate::parser::ParserError;
+
+/// Contains error options that can be encountered while reading a corpus.
+#[derive(Debug, PartialEq)]
+pub enum TrainerError {
+    /// Indicates that the JSON document is malformed at the line with the
+    /// provided number (1-based).
+    InvalidSyntax(usize),
+
+    /// Indicates that the JSON document nests arrays and objects too deep at
+    /// the line with the provided number (1-based).
+    DepthOverflow(usize),
+
+    /// Indicates that the JSON document is valid but it does not have the
+    /// structure of the expected corpus format.
+    InvalidFormat,
+}
+
+impl From<ParserError> for TrainerError {
+    fn from(err: ParserError) -> Self {
+        match err {
+            ParserError::InvalidSyntax(line) => Self::InvalidSyntax(line),
+            ParserError::DepthOverflow(line) => Self::DepthOverflow(line),
+            _ => Self::InvalidFormat,
+        }
+    }
+}
+
+impl fmt::Display for TrainerError {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::InvalidSyntax(line) => write!(fmt, "Invalid JSON syntax at line {}.", line),
+            Self::DepthOverflow(line) => write!(fmt, "JSON values nested too deep at line {}.", line),
+            Self::InvalidFormat => write!(fmt, "Unexpected corpus format."),
+        }
+    }
+}
+
+impl error::Error for TrainerError {}
@@ -0,0 +1,224 @@
+//! Provides features for training a custom [Huffman code] on a corpus of
+//! header names and values.
+//! 
+//! The [HPACK] static Huffman code was generated from statistics obtained on a
+//! large sample of HTTP headers back in 2012. Today's headers look different
+//! (tokens, cookies, base64 encoded values, etc.) so a code trained on our own
+//! traffic can compress noticeably better.
+//! 
+//! The `Trainer` counts how many times each byte appears in the corpus. The
+//! corpus can be provided as raw values, as [HPACK test case] stories (like the
+//! ones in `hpack/fixtures`) or as [HAR] files exported from a browser. The
+//! counts are then turned into an optimal length-limited canonical code which
+//! can be used with the flattener and the decoder like any other `HuffmanCode`.
+//! 
+//! ```rust
+//! use httlib_huffman::{DecoderSpeed, HuffmanCode};
+//! use httlib_huffman::trainer::Trainer;
+//! 
+//! let mut trainer = Trainer::new();
+//! trainer.add(b"content-type");
+//! trainer.add(b"application/json");
+//! 
+//! let code = trainer.train(30).unwrap();
+//! let hpack = trainer.encoded_len(&HuffmanCode::hpack()).unwrap();
+//! let trained = trainer.encoded_len(&code).unwrap();
+//! assert!(trained < hpack);
+//! ```
+//! 
+//! [HPACK]: https://tools.ietf.org/html/rfc7541
+//! [Huffman code]: https://en.wikipedia.org/wiki/Huffman_coding
+//! [HPACK test case]: https://github.com/http2jp/hpack-test-case
+//! [HAR]: https://w3c.github.io/web-performance/specs/HAR/Overview.html
+
+mod error;
+
+pub use error::*;
+use crate::code::{CodeError, HuffmanCode};
+use crate::parser::json::{self, Value};
+
+/// Collects symbol frequencies of a corpus and builds a Huffman code from them.
+#[derive(Debug, Clone)]
+pub struct Trainer {
+    /// The number of occurrences of each byte value.
+    frequencies: [u64; 256],
+}
+
+impl Trainer {
+    /// Returns a new trainer with an empty corpus.
+    pub fn new() -> Self {
+        Self {
+            frequencies: [0; 256],
+        }
+    }
+
+    /// Returns the number of occurrences of each byte value in the corpus.
+    pub fn frequencies(&self) -> &[u64; 256] {
+        &self.frequencies
+    }
+
+    /// Returns the total number of bytes in the corpus.
+    pub fn total(&self) -> u64 {
+        self.frequencies.iter().sum()
+    }
+
+    /// Adds a single header name or value to the corpus.
+    pub fn add(&mut self, value: &[u8]) {
+        for &byte in value {
+            self.frequencies[byte as usize] += 1;
+        }
+    }
+
+    /// Adds header names and values of the [HPACK test case] story provided as
+    /// JSON `data` and returns the number of headers found.
+    /// 
+    /// [HPACK test case]: https://github.com/http2jp/hpack-test-case
+    pub fn add_story(&mut self, data: &str) -> Result<usize, TrainerError> {
+        let story = json::parse(data)?;
+        let cases = story.get("cases").ok_or(TrainerError::InvalidFormat)?;
+
+        let mut count = 0;
+        for case in cases.items() {
+            let headers = case.get("headers").ok_or(TrainerError::InvalidFormat)?;
+            for header in headers.items() {
+                let fields = match header {
+                    Value::Object(fields) => fields,
+                    _ => return Err(TrainerError::InvalidFormat),
+                };
+                for (name, value) in fields {
+                    let value = value.as_str().ok_or(TrainerError::InvalidFormat)?;
+                    self.add(name.as_bytes());
+                    self.add(value.as_bytes());
+                    count += 1;
+                }
+            }
+        }
+
+        Ok(count)
+    }
+
+    /// Adds request and response header names and values of the [HAR] file
+    /// provided as JSON `data` and returns the number of headers found.
+    /// 
+    /// [HAR]: https://w3c.github.io/web-performance/specs/HAR/Overview.html
+    pub fn add_har(&mut self, data: &str) -> Result<usize, TrainerError> {
+        let har = json::parse(data)?;
+        let entries = har.get("log")
+            .and_then(|log| log.get("entries"))
+            .ok_or(TrainerError::InvalidFormat)?;
+
+        let mut count = 0;
+        for entry in entries.items() {
+            for message in &["request", "response"] {
+                let headers = match entry.get(message).and_then(|m| m.get("headers")) {
+                    Some(headers) => headers,
+                    None => continue,
+                };
+                for header in headers.items() {
+                    let name = header.get("name").and_then(|n| n.as_str());
+                    let value = header.get("value").and_then(|v| v.as_str());
+                    match (name, value) {
+                        (Some(name), Some(value)) => {
+                            self.add(name.to_lowercase().as_bytes()); // HTTP/2 names are lowercase
+                            self.add(value.as_bytes());
+                            count += 1;
+                        },
+                        _ => return Err(TrainerError::InvalidFormat),
+                    }
+                }
+            }
+        }
+
+        Ok(count)
+    }
+
+    /// Builds an optimal canonical code for the corpus where no code is longer
+    /// than `max_len` bits.
+    /// 
+    /// Bytes which were not seen during training still get a (long) code so the
+    /// trained code can encode any value. To keep them from stealing short codes
+    /// on small corpora, the observed counts are scaled by 256 and each byte
+    /// value is then counted once more. The EOS symbol gets the longest code.
+    pub fn train(&self, max_len: u8) -> Result<HuffmanCode, CodeError> {
+        let frequencies: Vec<u64> = self.frequencies.iter()
+            .map(|f| f.saturating_mul(256).saturating_add(1))
+            .collect();
+        HuffmanCode::from_frequencies(&frequencies, max_len)
+    }
+
+    /// Returns the number of bytes the corpus would take when each value is
+    /// encoded with the provided `code`, ignoring the EOS padding. The function
+    /// returns `None` when a byte of the corpus has no code.
+    pub fn encoded_len(&self, code: &HuffmanCode) -> Option<u64> {
+        let mut bits = 0;
+        for (symbol, &frequency) in self.frequencies.iter().enumerate() {
+            match code.codings()[symbol].0 {
+                0 if frequency > 0 => return None,
+                len => bits += frequency * u64::from(len),
+            }
+        }
+        Some(bits.div_ceil(8))
+    }
+}
+
+impl Default for Trainer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::DecoderSpeed;
+
+    /// Should train a code on stories and HAR files which compresses the corpus
+    /// better than the HPACK code and which can be used for decoding.
+    #[test]
+    fn trains_code_on_corpus() {
+        let story = r#"{
+            "cases": [
+                { "seqno": 0, "wire": "", "headers": [{ ":method": "GET" }, { "cookie": "a=é\/1" }] },
+                { "seqno": 1, "wire": "", "headers": [{ "x-request-id": "8f14e45fceea167a5a36dedd4bea2543" }] }
+            ],
+            "description": "test"
+        }"#;
+        let har = r#"{
+            "log": {
+                "version": "1.2",
+                "entries": [
+                    {
+                        "time": -1.5,
+                        "request": { "headers": [{ "name": "Accept", "value": "*/*" }] },
+                        "response": { "headers": [{ "name": "ETag", "value": "\"33a64df551\"" }], "cookies": null }
+                    }
+                ]
+            }
+        }"#;
+
+        let mut trainer = Trainer::default();
+        assert_eq!(trainer.add_story(story), Ok(3));
+        assert_eq!(trainer.add_har(har), Ok(2));
+        assert_eq!(trainer.frequencies()[0xc3], 1); // é
+        assert_eq!(trainer.frequencies()[b'A' as usize], 0); // lowercased name
+        assert_eq!(trainer.add_har(story), Err(TrainerError::InvalidFormat));
+        assert_eq!(trainer.add_story("{\n\"cases\": ["), Err(TrainerError::InvalidSyntax(2)));
+        let nested = format!("{}{}", "[".repeat(200_000), "]".repeat(200_000));
+        assert_eq!(trainer.add_har(&nested), Err(TrainerError::DepthOverflow(1)));
+        assert_eq!(trainer.add_story(&nested), Err(TrainerError::DepthOverflow(1)));
+
+        let code = trainer.train(30).unwrap();
+        let hpack = trainer.encoded_len(&HuffmanCode::hpack()).unwrap();
+        let trained = trainer.encoded_len(&code).unwrap();
+        assert!(trained < hpack);
+
+        let src = b"x-request-id: \xff\x00";
+        let mut encoded = Vec::new();
+        code.encode(src, &mut encoded).unwrap();
+        for speed in DecoderSpeed::enabled() {
+            let mut dst = Vec::new();
+            code.decode(&encoded, &mut dst, speed).unwrap();
+            assert_eq!(dst, src);
+        }
+    }
+}
@@ -0,0 +1,72 @@
+//! Command-line interface for generating Rust code from `.proto` files.
+//! 
+//! ```txt
+//! httlib-protos-build [-I DIR]... [-o PATH] [--extern-path PROTO=RUST]... [--compile-well-known-types] FILE...
+//! ```
+//! 
+//! When the `-o` argument is missing, the code is written to the standard
+//! output.
+
+use std::env;
+use std::fs;
+use std::process;
+use httlib_protos::schema::Schema;
+use httlib_protos_build::Generator;
+
+const USAGE: &str = "Usage:
+    httlib-protos-build [OPTIONS] FILE...        Generates Rust code for the files and their imports.
+
+Options:
+    -I, --include DIR                             Adds a directory where files are searched for.
+    -o, --out PATH                                Writes the code to PATH instead of the standard output.
+    --extern-path PROTO=RUST                      Takes the types under PROTO (e.g. `.common`) from RUST.
+    --compile-well-known-types                    Generates the `google.protobuf` types.";
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    if let Err(err) = run(&args) {
+        eprintln!("{}", err);
+        process::exit(1);
+    }
+}
+
+/// Generates the code for the files provided by `args`.
+fn run(args: &[String]) -> Result<(), String> {
+    let mut schema = Schema::new();
+    let mut generator = Generator::new();
+    let mut out = None;
+    let mut files = Vec::new();
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-I" | "--include" => schema.include(args.next().ok_or(USAGE)?),
+            "-o" | "--out" => out = Some(args.next().ok_or(USAGE)?),
+            "--extern-path" => {
+                let path = args.next().ok_or(USAGE)?;
+                let (proto_path, rust_path) = path.split_once('=').ok_or(USAGE)?;
+                generator.extern_path(proto_path, rust_path);
+            },
+            "--compile-well-known-types" => {
+                generator.compile_well_known_types();
+            },
+            arg if arg.starts_with('-') => return Err(USAGE.to_string()),
+            file => files.push(file),
+        }
+    }
+    if files.is_empty() {
+        return Err(USAGE.to_string());
+    }
+
+    for file in files {
+        schema.load(file).map_err(|e| e.to_string())?;
+    }
+    let code = generator.generate(&schema).map_err(|e| e.to_string())?;
+    match out {
+        Some(path) => fs::write(path, code).map_err(|e| format!("Can't write {}: {}", path, e)),
+        None => {
+            print!("{}", code);
+            Ok(())
+        },
+    }
+}
@@ -0,0 +1,36 @@
+use std::error;
+use std::fmt;
+use httlib_protos::schema::SchemaError;
+
+/// Contains error options that can be encountered while generating code.
+#[derive(Debug, Clone, PartialEq)]
+pub enum GeneratorError {
+    /// Indicates that a `.proto` file could not be loaded.
+    Schema(SchemaError),
+
+    /// Indicates that the provided message or enum is referenced but not
+    /// defined in the schema.
+    UnknownType(String),
+
+    /// Indicates that the generated code could not be written to the provided
+    /// path.
+    UnwritableFile(String),
+}
+
+impl From<SchemaError> for GeneratorError {
+    fn from(err: SchemaError) -> Self {
+        Self::Schema(err)
+    }
+}
+
+impl fmt::Display for GeneratorError {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Schema(err) => write!(fmt, "{}", err),
+            Self::UnknownType(name) => write!(fmt, "Unknown type {}.", name),
+            Self::UnwritableFile(path) => write!(fmt, "Can't write {}.", path),
+        }
+    }
+}
+
+impl error::Error for GeneratorError {}
@@ -0,0 +1,722 @@
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fs;
+use std::path::Path;
+use httlib_protos::schema::{EnumDescriptor, FieldDescriptor, FieldType, Label,
+    MessageDescriptor, OneofDescriptor, Schema};
+use crate::GeneratorError;
+use crate::names::{field_name, module_name, type_name, variant_name};
+
+/// The path of the runtime crate in the generated code.
+const RUNTIME: &str = "::httlib_protos";
+
+/// The package of the well-known types.
+const WELL_KNOWN_PACKAGE: &str = ".google.protobuf";
+
+/// The Rust path of the well-known types provided by the runtime crate.
+const WELL_KNOWN_PATH: &str = "::httlib_protos::wkt";
+
+/// The comment which starts each generated file.
+const HEADER: &str = "// This file is generated by httlib-protos-build. Do not edit.\n";
+
+/// Provides a generator of Rust code from loaded `.proto` files.
+///
+/// Each package becomes a module (e.g. `foo.bar` becomes `foo::bar`) holding
+/// a structure for each message and an enum for each enumeration. Nested
+/// types and oneofs of a message are placed in a module named after the
+/// message in snake_case. The generated types implement the `Message`,
+/// `Enumeration` and `Oneof` traits of the runtime crate `httlib_protos`.
+#[derive(Debug, Clone, Default)]
+pub struct Generator {
+    /// Types which are provided by other crates and are not generated, as
+    /// pairs of a fully-qualified `.proto` name prefix and a Rust path.
+    extern_paths: Vec<(String, String)>,
+
+    /// Tells whether the well-known types are generated instead of being
+    /// taken from `httlib_protos::wkt`.
+    compile_well_known_types: bool,
+}
+
+impl Generator {
+    /// Returns a new generator instance which takes the well-known types from
+    /// the runtime crate.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Marks the types under the fully-qualified `proto_path` (e.g. `.common`
+    /// or `.common.Money`) as provided by the Rust module or type at
+    /// `rust_path` (e.g. `::common_protos`). Such types are referenced but not
+    /// generated.
+    pub fn extern_path<P: Into<String>, R: Into<String>>(&mut self, proto_path: P, rust_path: R) -> &mut Self {
+        self.extern_paths.push((proto_path.into(), rust_path.into()));
+        self
+    }
+
+    /// Generates the well-known types of the `google.protobuf` package like
+    /// any other type instead of taking them from `httlib_protos::wkt`.
+    pub fn compile_well_known_types(&mut self) -> &mut Self {
+        self.compile_well_known_types = true;
+        self
+    }
+
+    /// Loads the `files` from the `include_paths` and writes the generated
+    /// code to the file at `out`. This is meant to be called from a build
+    /// script where the result is then included into the crate with the
+    /// `include!` macro.
+    ///
+    /// On success the function returns otherwise an error is thrown.
+    pub fn compile<I, P>(&self, include_paths: &[I], files: &[&str], out: P) -> Result<(), GeneratorError>
+    where
+        I: AsRef<Path>,
+        P: AsRef<Path>,
+    {
+        let mut schema = Schema::new();
+        for path in include_paths {
+            schema.include(path.as_ref());
+        }
+        for file in files {
+            schema.load(file)?;
+        }
+        let code = self.generate(&schema)?;
+        fs::write(&out, code).map_err(|_| GeneratorError::UnwritableFile(out.as_ref().display().to_string()))
+    }
+
+    /// Generates the code for all files of the `schema`.
+    ///
+    /// On success the source code is returned otherwise an error is thrown.
+    pub fn generate(&self, schema: &Schema) -> Result<String, GeneratorError> {
+        let mut types = HashMap::new();
+        for file in schema.files() {
+            let package = package_path(file.package.as_deref());
+            self.collect_types(&file.messages, &file.enums, &package, &mut types);
+        }
+
+        let context = Context { schema, types };
+        let mut root = Module::default();
+        for file in schema.files() {
+            let package = package_path(file.package.as_deref());
+            let messages = file.messages.iter().filter(|m| context.is_local(&m.full_name)).collect::<Vec<_>>();
+            let enums = file.enums.iter().filter(|e| context.is_local(&e.full_name)).collect::<Vec<_>>();
+            if messages.is_empty() && enums.is_empty() { // extern types only
+                continue;
+            }
+            let module = root.child(&package);
+            for message in messages {
+                push_item(&mut module.code, &context.message(message, &package)?);
+            }
+            for enumeration in enums {
+                push_item(&mut module.code, &context.enumeration(enumeration));
+            }
+        }
+
+        let mut code = Code::default();
+        code.line(HEADER.trim_end());
+        root.render(&mut code);
+        Ok(code.buf)
+    }
+
+    /// Registers the Rust paths of the provided `messages` and `enums`
+    /// defined in the module at `module` together with their nested types.
+    fn collect_types(&self, messages: &[MessageDescriptor], enums: &[EnumDescriptor], module: &[String], types: &mut HashMap<String, TypePath>) {
+        for enumeration in enums {
+            let path = self.extern_type(&enumeration.full_name)
+                .unwrap_or_else(|| TypePath::Local(module.to_vec(), type_name(&enumeration.name)));
+            types.insert(enumeration.full_name.clone(), path);
+        }
+        for message in messages {
+            let path = self.extern_type(&message.full_name)
+                .unwrap_or_else(|| TypePath::Local(module.to_vec(), type_name(&message.name)));
+            types.insert(message.full_name.clone(), path);
+
+            let mut inner = module.to_vec();
+            inner.push(module_name(&message.name));
+            self.collect_types(&message.messages, &message.enums, &inner, types);
+        }
+    }
+
+    /// Returns the Rust path of a type provided by another crate. The longest
+    /// matching extern path wins.
+    fn extern_type(&self, full_name: &str) -> Option<TypePath> {
+        let well_known = (WELL_KNOWN_PACKAGE.to_string(), WELL_KNOWN_PATH.to_string());
+        let (proto_path, rust_path) = self.extern_paths.iter()
+            .chain(Some(&well_known).filter(|_| !self.compile_well_known_types))
+            .filter(|(p, _)| full_name == p || (full_name.starts_with(p.as_str()) && full_name[p.len()..].starts_with('.')))
+            .max_by_key(|(p, _)| p.len())?;
+
+        let segments = full_name[proto_path.len()..].split('.').filter(|s| !s.is_empty()).collect::<Vec<_>>();
+        let mut path = rust_path.clone();
+        if let Some((name, modules)) = segments.split_last() {
+            for module in modules {
+                path.push_str("::");
+                path.push_str(&module_name(module));
+            }
+            path.push_str("::");
+            path.push_str(&type_name(name));
+        }
+        Some(TypePath::Extern(path))
+    }
+}
+
+/// Represents the Rust location of a `.proto` type.
+#[derive(Debug, Clone, PartialEq)]
+enum TypePath {
+    /// A generated type with the module path and the type name.
+    Local(Vec<String>, String),
+
+    /// An absolute path of a type provided by another crate.
+    Extern(String),
+}
+
+/// Holds the state of a single generation.
+struct Context<'a> {
+    /// The schema which is being generated.
+    schema: &'a Schema,
+
+    /// The Rust paths of all messages and enums by their fully-qualified name.
+    types: HashMap<String, TypePath>,
+}
+
+impl<'a> Context<'a> {
+    /// Tells whether the type with the provided name is generated.
+    fn is_local(&self, full_name: &str) -> bool {
+        matches!(self.types.get(full_name), Some(TypePath::Local(..)))
+    }
+
+    /// Returns the path of the type with the provided name relative to the
+    /// module at `from`.
+    fn path(&self, full_name: &str, from: &[String]) -> Result<String, GeneratorError> {
+        match self.types.get(full_name) {
+            Some(TypePath::Local(module, name)) => Ok(relative_path(from, module, name)),
+            Some(TypePath::Extern(path)) => Ok(path.clone()),
+            None => Err(GeneratorError::UnknownType(full_name.to_string())),
+        }
+    }
+
+    /// Tells whether the message `owner` is reachable from the field type
+    /// `typ` through non-repeated fields. Such fields have to be boxed since
+    /// the structure would otherwise have an infinite size.
+    fn is_recursive(&self, owner: &str, typ: &FieldType) -> bool {
+        let mut stack = match typ {
+            FieldType::Message(name) => vec![name.as_str()],
+            _ => return false,
+        };
+        let mut seen = HashSet::new();
+        while let Some(name) = stack.pop() {
+            if name == owner {
+                return true;
+            } else if !seen.insert(name) {
+                continue;
+            }
+            for field in self.schema.message(name).iter().flat_map(|m| &m.fields) {
+                match &field.typ {
+                    FieldType::Message(name) if field.label != Label::Repeated => stack.push(name),
+                    _ => {},
+                }
+            }
+        }
+        false
+    }
+
+    /// Returns the Rust type and the field kind of a single value of the
+    /// provided type as seen from the module at `from`.
+    fn value(&self, typ: &FieldType, from: &[String], boxed: bool) -> Result<(String, String), GeneratorError> {
+        let (ty, kind) = match typ {
+            FieldType::Double => ("f64".to_string(), "Double".to_string()),
+            FieldType::Float => ("f32".to_string(), "Float".to_string()),
+            FieldType::Int64 => ("i64".to_string(), "Int64".to_string()),
+            FieldType::UInt64 => ("u64".to_string(), "UInt64".to_string()),
+            FieldType::Int32 => ("i32".to_string(), "Int32".to_string()),
+            FieldType::Fixed64 => ("u64".to_string(), "Fixed64".to_string()),
+            FieldType::Fixed32 => ("u32".to_string(), "Fixed32".to_string()),
+            FieldType::Bool => ("bool".to_string(), "Bool".to_string()),
+            FieldType::String => ("::std::string::String".to_string(), "String".to_string()),
+            FieldType::Bytes => ("::std::vec::Vec<u8>".to_string(), "Bytes".to_string()),
+            FieldType::UInt32 => ("u32".to_string(), "UInt32".to_string()),
+            FieldType::SFixed32 => ("i32".to_string(), "SFixed32".to_string()),
+            FieldType::SFixed64 => ("i64".to_string(), "SFixed64".to_string()),
+            FieldType::SInt32 => ("i32".to_string(), "SInt32".to_string()),
+            FieldType::SInt64 => ("i64".to_string(), "SInt64".to_string()),
+            FieldType::Enum(name) => {
+                let path = self.path(name, from)?;
+                (path.clone(), format!("Enumeration<{}>", path))
+            },
+            FieldType::Message(name) => {
+                let mut path = self.path(name, from)?;
+                if boxed {
+                    path = format!("::std::boxed::Box<{}>", path);
+                }
+                (path.clone(), format!("Message<{}>", path))
+            },
+            FieldType::Named(name) => return Err(GeneratorError::UnknownType(name.clone())),
+            FieldType::Map(..) => return Err(GeneratorError::UnknownType("map".to_string())),
+        };
+        Ok((ty, format!("{}::message::kind::{}", RUNTIME, kind)))
+    }
+
+    /// Returns the Rust type of a structure field together with the names of
+    /// the `field` functions which encode and merge it.
+    fn field(&self, message: &MessageDescriptor, field: &FieldDescriptor, module: &[String]) -> Result<(String, String, String), GeneratorError> {
+        if let FieldType::Map(key, val) = &field.typ {
+            let (key_ty, key_kind) = self.value(key, module, false)?;
+            let (val_ty, val_kind) = self.value(val, module, false)?;
+            return Ok((
+                format!("::std::collections::HashMap<{}, {}>", key_ty, val_ty),
+                format!("encode_map::<{}, {}>", key_kind, val_kind),
+                format!("merge_map::<{}, {}>", key_kind, val_kind),
+            ));
+        }
+
+        match field.label {
+            Label::Repeated => {
+                let (ty, kind) = self.value(&field.typ, module, false)?;
+                let encode = if field.is_packed() { "encode_packed" } else { "encode_repeated" };
+                let merge = if field.typ.is_packable() { "merge_packed" } else { "merge_repeated" };
+                Ok((format!("::std::vec::Vec<{}>", ty), format!("{}::<{}>", encode, kind), format!("{}::<{}>", merge, kind)))
+            },
+            _ if field.label == Label::Optional || matches!(field.typ, FieldType::Message(_)) => {
+                let boxed = self.is_recursive(&message.full_name, &field.typ);
+                let (ty, kind) = self.value(&field.typ, module, boxed)?;
+                Ok((format!("::std::option::Option<{}>", ty), format!("encode_optional::<{}>", kind), format!("merge_optional::<{}>", kind)))
+            },
+            _ => {
+                let (ty, kind) = self.value(&field.typ, module, false)?;
+                Ok((ty, format!("encode::<{}>", kind), format!("merge::<{}>", kind)))
+            },
+        }
+    }
+
+    /// Generates the structure of a message defined in the module at `module`
+    /// together with the module of its nested types.
+    fn message(&self, message: &MessageDescriptor, module: &[String]) -> Result<String, GeneratorError> {
+        let name = type_name(&message.name);
+        let mut inner = module.to_vec();
+        inner.push(module_name(&message.name));
+
+        let mut members = Vec::new();
+        let mut encoders = Vec::new();
+        let mut mergers = Vec::new();
+        let mut nested = String::new();
+        for (index, field) in message.fields.iter().enumerate() {
+            match field.oneof {
+                Some(oneof) if message.fields[..index].iter().any(|f| f.oneof == Some(oneof)) => continue,
+                Some(oneof) => {
+                    let desc = &message.oneofs[oneof];
+                    let fields = message.fields.iter().filter(|f| f.oneof == Some(oneof)).collect::<Vec<_>>();
+                    let member = field_name(&desc.name);
+                    let ty = format!("{}::{}", module_name(&message.name), type_name(&desc.name));
+                    members.push(format!("pub {}: ::std::option::Option<{}>,", member, ty));
+                    encoders.push((fields.iter().map(|f| f.number).min().unwrap_or(field.number), format!(
+                        "if let ::std::option::Option::Some(oneof) = &self.{} {{\n    {}::Oneof::encode_fields(oneof, encoder)?;\n}}",
+                        member, RUNTIME,
+                    )));
+                    mergers.push(format!(
+                        "{} => {}::Oneof::merge_field(&mut self.{}, tag, typ, bytes),",
+                        tag_pattern(fields.iter().map(|f| f.number)), RUNTIME, member,
+                    ));
+                    push_item(&mut nested, &self.oneof(message, desc, &fields, &inner)?);
+                },
+                None => {
+                    let member = field_name(&field.name);
+                    let (ty, encode, merge) = self.field(message, field, module)?;
+                    members.push(format!("pub {}: {},", member, ty));
+                    encoders.push((field.number, format!(
+                        "{}::message::field::{}({}, &self.{}, encoder)?;",
+                        RUNTIME, encode, field.number, member,
+                    )));
+                    mergers.push(format!(
+                        "{} => {}::message::field::{}(&mut self.{}, typ, bytes),",
+                        field.number, RUNTIME, merge, member,
+                    ));
+                },
+            }
+        }
+        encoders.sort_by_key(|(number, _)| *number);
+
+        for child in message.messages.iter().filter(|m| self.is_local(&m.full_name)) {
+            push_item(&mut nested, &self.message(child, &inner)?);
+        }
+        for child in message.enums.iter().filter(|e| self.is_local(&e.full_name)) {
+            push_item(&mut nested, &self.enumeration(child));
+        }
+
+        let mut code = Code::default();
+        code.line("#[derive(Debug, Clone, PartialEq, Default)]");
+        if members.is_empty() {
+            code.line(format!("pub struct {} {{}}", name));
+        } else {
+            code.open(format!("pub struct {} {{", name));
+            for member in &members {
+                code.line(member);
+            }
+            code.close("}");
+        }
+        code.line("");
+
+        let unused = if members.is_empty() { "_" } else { "" };
+        code.open(format!("impl {}::Message for {} {{", RUNTIME, name));
+        code.open(format!(
+            "fn encode_fields(&self, {}encoder: &mut {}::MessageEncoder) -> ::std::result::Result<(), {}::EncoderError> {{",
+            unused, RUNTIME, RUNTIME,
+        ));
+        for (_, encoder) in &encoders {
+            code.block(encoder);
+        }
+        code.line("::std::result::Result::Ok(())");
+        code.close("}");
+        code.line("");
+        code.open(format!(
+            "fn merge_field(&mut self, {0}tag: u32, {0}typ: {1}::Typ, {0}bytes: &[u8]) -> ::std::result::Result<(), {1}::DecoderError> {{",
+            unused, RUNTIME,
+        ));
+        if mergers.is_empty() {
+            code.line("::std::result::Result::Ok(())");
+        } else {
+            code.open("match tag {");
+            for merger in &mergers {
+                code.line(merger);
+            }
+            code.line("_ => ::std::result::Result::Ok(()),");
+            code.close("}");
+        }
+        code.close("}");
+        code.close("}");
+
+        if !nested.is_empty() {
+            code.line("");
+            code.open(format!("pub mod {} {{", module_name(&message.name)));
+            code.block(&nested);
+            code.close("}");
+        }
+        Ok(code.buf)
+    }
+
+    /// Generates the enum of a oneof with the provided `fields` placed in the
+    /// module at `module`.
+    fn oneof(&self, message: &MessageDescriptor, oneof: &OneofDescriptor, fields: &[&FieldDescriptor], module: &[String]) -> Result<String, GeneratorError> {
+        let name = type_name(&oneof.name);
+        let mut variants = Vec::new();
+        let mut encoders = Vec::new();
+        let mut mergers = Vec::new();
+        for field in fields {
+            let variant = type_name(&field.name);
+            let boxed = self.is_recursive(&message.full_name, &field.typ);
+            let (ty, kind) = self.value(&field.typ, module, boxed)?;
+            variants.push(format!("{}({}),", variant, ty));
+            encoders.push(format!(
+                "Self::{}(value) => {{\n    <{} as {}::message::kind::Kind>::encode({}, value, encoder)?;\n}},",
+                variant, kind, RUNTIME, field.number,
+            ));
+            mergers.push(format!(
+                concat!(
+                    "{0} => match oneof {{\n",
+                    "    ::std::option::Option::Some(Self::{1}(value)) => <{2} as {3}::message::kind::Kind>::merge(value, typ, bytes),\n",
+                    "    _ => {{\n",
+                    "        let mut value = ::std::default::Default::default();\n",
+                    "        <{2} as {3}::message::kind::Kind>::merge(&mut value, typ, bytes)?;\n",
+                    "        *oneof = ::std::option::Option::Some(Self::{1}(value));\n",
+                    "        ::std::result::Result::Ok(())\n",
+                    "    }},\n",
+                    "}},",
+                ),
+                field.number, variant, kind, RUNTIME,
+            ));
+        }
+
+        let mut code = Code::default();
+        code.line("#[derive(Debug, Clone, PartialEq)]");
+        code.line("#[allow(clippy::enum_variant_names)]");
+        code.open(format!("pub enum {} {{", name));
+        for variant in &variants {
+            code.line(variant);
+        }
+        code.close("}");
+        code.line("");
+        code.open(format!("impl {}::Oneof for {} {{", RUNTIME, name));
+        code.open(format!(
+            "fn encode_fields(&self, encoder: &mut {0}::MessageEncoder) -> ::std::result::Result<(), {0}::EncoderError> {{",
+            RUNTIME,
+        ));
+        code.open("match self {");
+        for encoder in &encoders {
+            code.block(encoder);
+        }
+        code.close("}");
+        code.line("::std::result::Result::Ok(())");
+        code.close("}");
+        code.line("");
+        code.open(format!(
+            "fn merge_field(oneof: &mut ::std::option::Option<Self>, tag: u32, typ: {0}::Typ, bytes: &[u8]) -> ::std::result::Result<(), {0}::DecoderError> {{",
+            RUNTIME,
+        ));
+        code.open("match tag {");
+        for merger in &mergers {
+            code.block(merger);
+        }
+        code.line("_ => ::std::result::Result::Ok(()),");
+        code.close("}");
+        code.close("}");
+        code.close("}");
+        Ok(code.buf)
+    }
+
+    /// Generates the enum of an enumeration. Aliases, which share the number
+    /// with a previous value, are left out.
+    fn enumeration(&self, enumeration: &EnumDescriptor) -> String {
+        let name = type_name(&enumeration.name);
+        let mut numbers = HashSet::new();
+        let values = enumeration.values.iter()
+            .filter(|v| numbers.insert(v.number))
+            .map(|v| (variant_name(&enumeration.name, &v.name), v.number))
+            .collect::<Vec<_>>();
+
+        let mut code = Code::default();
+        code.line("#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]");
+        code.line("#[allow(clippy::enum_variant_names)]");
+        code.line("#[repr(i32)]");
+        code.open(format!("pub enum {} {{", name));
+        for (variant, number) in &values {
+            if *number == 0 {
+                code.line("#[default]");
+            }
+            code.line(format!("{} = {},", variant, number));
+        }
+        code.close("}");
+        code.line("");
+        code.open(format!("impl {}::Enumeration for {} {{", RUNTIME, name));
+        code.open("fn to_i32(&self) -> i32 {");
+        code.line("*self as i32");
+        code.close("}");
+        code.line("");
+        code.open("fn from_i32(value: i32) -> ::std::option::Option<Self> {");
+        code.open("match value {");
+        for (variant, number) in &values {
+            code.line(format!("{} => ::std::option::Option::Some(Self::{}),", number, variant));
+        }
+        code.line("_ => ::std::option::Option::None,");
+        code.close("}");
+        code.close("}");
+        code.close("}");
+        code.buf
+    }
+}
+
+/// Represents a generated module with its items and submodules.
+#[derive(Debug, Default)]
+struct Module {
+    /// The items of the module.
+    code: String,
+
+    /// The submodules by their name.
+    children: BTreeMap<String, Module>,
+}
+
+impl Module {
+    /// Returns the submodule at the provided relative `path` and creates it if
+    /// it does not exist.
+    fn child(&mut self, path: &[String]) -> &mut Module {
+        path.iter().fold(self, |module, name| module.children.entry(name.clone()).or_default())
+    }
+
+    /// Writes the items and the submodules into `code`.
+    fn render(&self, code: &mut Code) {
+        if !self.code.is_empty() {
+            code.line("");
+            code.block(&self.code);
+        }
+        for (name, child) in &self.children {
+            code.line("");
+            code.open(format!("pub mod {} {{", name));
+            let mut inner = Code::default();
+            child.render(&mut inner);
+            code.block(inner.buf.trim_start_matches('\n'));
+            code.close("}");
+        }
+    }
+}
+
+/// Provides a buffer of indented source lines.
+#[derive(Debug, Default)]
+struct Code {
+    /// The source code.
+    buf: String,
+
+    /// The current indentation level.
+    depth: usize,
+}
+
+impl Code {
+    /// Writes a single line at the current indentation level.
+    fn line<S: AsRef<str>>(&mut self, line: S) {
+        let line = line.as_ref();
+        if !line.is_empty() {
+            for _ in 0..self.depth {
+                self.buf.push_str("    ");
+            }
+            self.buf.push_str(line);
+        }
+        self.buf.push('\n');
+    }
+
+    /// Writes a line and increases the indentation level.
+    fn open<S: AsRef<str>>(&mut self, line: S) {
+        self.line(line);
+        self.depth += 1;
+    }
+
+    /// Decreases the indentation level and writes a line.
+    fn close<S: AsRef<str>>(&mut self, line: S) {
+        self.depth -= 1;
+        self.line(line);
+    }
+
+    /// Writes multiple lines at the current indentation level.
+    fn block(&mut self, block: &str) {
+        for line in block.lines() {
+            self.line(line);
+        }
+    }
+}
+
+/// Appends an item to the `code` of a module and separates it from the
+/// previous item with an empty line.
+fn push_item(code: &mut String, item: &str) {
+    if !code.is_empty() {
+        code.push('\n');
+    }
+    code.push_str(item);
+}
+
+/// Returns the module path of the provided package.
+fn package_path(package: Option<&str>) -> Vec<String> {
+    package.map(|p| p.split('.').map(module_name).collect()).unwrap_or_default()
+}
+
+/// Returns the match pattern of the provided tag numbers where consecutive
+/// numbers are written as ranges (e.g. `1..=3 | 5`).
+fn tag_pattern<I: IntoIterator<Item = u32>>(numbers: I) -> String {
+    let mut numbers = numbers.into_iter().collect::<Vec<_>>();
+    numbers.sort_unstable();
+    let mut ranges: Vec<(u32, u32)> = Vec::new();
+    for number in numbers {
+        match ranges.last_mut() {
+            Some((_, last)) if *last + 1 == number => *last = number,
+            _ => ranges.push((number, number)),
+        }
+    }
+    ranges.iter().map(|(first, last)| {
+        if first == last {
+            first.to_string()
+        } else {
+            format!("{}..={}", first, last)
+        }
+    }).collect::<Vec<_>>().join(" | ")
+}
+
+/// Returns the path of the type `name` in the module at `module` relative to
+/// the module at `from`.
+fn relative_path(from: &[String], module: &[String], name: &str) -> String {
+    let common = from.iter().zip(module).take_while(|(a, b)| a == b).count();
+    let mut segments = vec!["super"; from.len() - common];
+    segments.extend(module[common..].iter().map(|s| s.as_str()));
+    segments.push(name);
+    segments.join("::")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Should generate modules, structures, oneofs and enums from a schema.
+    #[test]
+    fn generates_code() {
+        let mut schema = Schema::new();
+        schema.add("shop.proto", r#"
+            syntax = "proto3";
+            package shop.v1;
+            import "google/protobuf/timestamp.proto";
+            message Order {
+                enum Status {
+                    STATUS_UNKNOWN = 0;
+                    STATUS_PAID = 1;
+                }
+                uint64 id = 1;
+                Status status = 2;
+                repeated sint32 amounts = 3;
+                repeated string notes = 4 [packed = false];
+                map<string, Item> items = 5;
+                optional string coupon = 6;
+                google.protobuf.Timestamp created_at = 7;
+                oneof payment {
+                    string card = 8;
+                    Order parent = 9;
+                }
+                Order previous = 10;
+                string type = 11;
+            }
+            message Item {}
+        "#).unwrap();
+
+        let code = Generator::new().generate(&schema).unwrap();
+        assert!(code.starts_with(HEADER));
+        assert!(code.contains("pub mod shop {\n    pub mod v1 {\n        #[derive(Debug, Clone, PartialEq, Default)]\n        pub struct Order {\n"));
+        assert!(code.contains("pub id: u64,"));
+        assert!(code.contains("pub status: order::Status,"));
+        assert!(code.contains("pub amounts: ::std::vec::Vec<i32>,"));
+        assert!(code.contains("pub items: ::std::collections::HashMap<::std::string::String, Item>,"));
+        assert!(code.contains("pub coupon: ::std::option::Option<::std::string::String>,"));
+        assert!(code.contains("pub created_at: ::std::option::Option<::httlib_protos::wkt::Timestamp>,"));
+        assert!(code.contains("pub payment: ::std::option::Option<order::Payment>,"));
+        assert!(code.contains("pub previous: ::std::option::Option<::std::boxed::Box<Order>>,"));
+        assert!(code.contains("pub r#type: ::std::string::String,"));
+        assert!(code.contains("::httlib_protos::message::field::encode_packed::<::httlib_protos::message::kind::SInt32>(3, &self.amounts, encoder)?;"));
+        assert!(code.contains("::httlib_protos::message::field::encode_repeated::<::httlib_protos::message::kind::String>(4, &self.notes, encoder)?;"));
+        assert!(code.contains("8..=9 => ::httlib_protos::Oneof::merge_field(&mut self.payment, tag, typ, bytes),"));
+        assert!(code.contains("pub mod order {"));
+        assert!(code.contains("Parent(::std::boxed::Box<super::Order>),"));
+        assert!(code.contains("#[default]\n                Unknown = 0,\n                Paid = 1,"));
+        assert!(code.contains("pub struct Item {}"));
+        assert!(!code.contains("pub struct Timestamp"));
+        assert!(!code.contains("pub mod google"));
+    }
+
+    /// Should reference types in other packages and extern paths.
+    #[test]
+    fn resolves_type_paths() {
+        let mut schema = Schema::new();
+        schema.add("common.proto", r#"
+            syntax = "proto3";
+            package common;
+            message Money { int64 units = 1; }
+            message Tag { string name = 1; }
+        "#).unwrap();
+        schema.add("shop.proto", r#"
+            syntax = "proto3";
+            package shop;
+            import "common.proto";
+            message Order {
+                common.Money total = 1;
+                common.Tag tag = 2;
+            }
+        "#).unwrap();
+
+        let code = Generator::new().generate(&schema).unwrap();
+        assert!(code.contains("pub total: ::std::option::Option<super::common::Money>,"));
+
+        let code = Generator::new().extern_path(".common.Money", "::money::Money").generate(&schema).unwrap();
+        assert!(code.contains("pub total: ::std::option::Option<::money::Money>,"));
+        assert!(code.contains("pub tag: ::std::option::Option<super::common::Tag>,"));
+        assert!(!code.contains("pub struct Money"));
+        assert!(code.contains("pub struct Tag"));
+    }
+
+    /// Should keep the well-known types of the runtime crate up to date.
+    #[test]
+    fn generates_well_known_types() {
+        let mut schema = Schema::new();
+        for file in ["any", "duration", "empty", "field_mask", "struct", "timestamp", "wrappers"] {
+            schema.load(&format!("google/protobuf/{}.proto", file)).unwrap();
+        }
+        let code = Generator::new().compile_well_known_types().generate(&schema).unwrap();
+        assert_eq!(code, include_str!("../../protos/src/wkt/generated.rs"));
+    }
+}
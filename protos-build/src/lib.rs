@@ -0,0 +1,59 @@
+//! This crate generates Rust code from [Protocol Buffers] `proto3` schemas for
+//! the [httlib-protos] crate. It can be used as a library from a build script
+//! or as a command-line tool.
+//! 
+//! [![Documentation](https://img.shields.io/badge/-Documentation-blue?style=for-the-badge&logo=Rust)](https://docs.rs/httlib-protos-build)
+//! [![Source](https://img.shields.io/badge/-Source-lightgrey?style=for-the-badge&logo=GitHub)](https://github.com/xpepermint/httlib-rs/tree/main/protos-build)
+//! 
+//! ## About
+//! 
+//! The generator loads `.proto` files together with their imports and writes
+//! plain Rust structures which implement the `Message` trait of the runtime
+//! crate. The generated code encodes and decodes messages through the encoder
+//! and the decoder of [httlib-protos], no other runtime dependency is needed.
+//! 
+//! * Each package becomes a module (`foo.bar` becomes `foo::bar`).
+//! * Each message becomes a structure. Nested types are placed in a module
+//!   named after the message in snake_case.
+//! * Each enum becomes a Rust enum implementing `Enumeration`.
+//! * Each oneof becomes a Rust enum implementing `Oneof` which is held by the
+//!   message as an `Option`.
+//! * Maps become `HashMap`s, repeated fields become `Vec`s, embedded messages
+//!   and `optional` fields become `Option`s. Recursive messages are boxed.
+//! * The well-known types of the `google.protobuf` package are taken from
+//!   `httlib_protos::wkt` unless they are compiled explicitly.
+//! 
+//! Services and extensions are not generated.
+//! 
+//! ## Usage
+//! 
+//! **Build script example:**
+//! 
+//! ```rust,no_run
+//! use httlib_protos_build::Generator;
+//! 
+//! let out = format!("{}/protos.rs", std::env::var("OUT_DIR").unwrap());
+//! Generator::new().compile(&["protos"], &["shop/v1/order.proto"], out).unwrap();
+//! ```
+//! 
+//! The generated file is then included into the crate:
+//! 
+//! ```rust,ignore
+//! include!(concat!(env!("OUT_DIR"), "/protos.rs"));
+//! ```
+//! 
+//! **Command-line example:**
+//! 
+//! ```txt
+//! httlib-protos-build -I protos -o src/protos.rs shop/v1/order.proto
+//! ```
+//! 
+//! [Protocol Buffers]: https://developers.google.com/protocol-buffers
+//! [httlib-protos]: https://docs.rs/httlib-protos
+
+mod error;
+mod generator;
+mod names;
+
+pub use error::*;
+pub use generator::*;
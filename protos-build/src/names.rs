@@ -0,0 +1,115 @@
+//! Provides the conversion of `.proto` names into Rust identifiers.
+
+/// Rust keywords which can not be used as identifiers without escaping.
+const KEYWORDS: &[&str] = &[
+    "abstract", "as", "async", "await", "become", "box", "break", "const",
+    "continue", "crate", "do", "dyn", "else", "enum", "extern", "false", "final",
+    "fn", "for", "gen", "if", "impl", "in", "let", "loop", "macro", "match", "mod",
+    "move", "mut", "override", "priv", "pub", "ref", "return", "self", "Self",
+    "static", "struct", "super", "trait", "true", "try", "type", "typeof",
+    "unsafe", "unsized", "use", "virtual", "where", "while", "yield",
+];
+
+/// Returns the snake_case form of the provided `name` (e.g. `HTTPRequest`
+/// becomes `http_request`).
+pub fn snake_case(name: &str) -> String {
+    let chars = name.chars().collect::<Vec<_>>();
+    let mut snake = String::with_capacity(name.len() + 4);
+    for (i, c) in chars.iter().enumerate() {
+        if c.is_ascii_uppercase() {
+            let prev = if i > 0 { Some(chars[i - 1]) } else { None };
+            let next = chars.get(i + 1);
+            let boundary = match prev {
+                Some(p) if p.is_ascii_lowercase() || p.is_ascii_digit() => true,
+                Some(p) if p.is_ascii_uppercase() => next.is_some_and(|n| n.is_ascii_lowercase()),
+                _ => false,
+            };
+            if boundary && !snake.ends_with('_') {
+                snake.push('_');
+            }
+            snake.push(c.to_ascii_lowercase());
+        } else {
+            snake.push(*c);
+        }
+    }
+    snake
+}
+
+/// Returns the UpperCamelCase form of the provided `name` (e.g. `STATUS_OK`
+/// becomes `StatusOk`).
+pub fn upper_camel_case(name: &str) -> String {
+    let mut camel = String::with_capacity(name.len());
+    for word in snake_case(name).split('_').filter(|w| !w.is_empty()) {
+        let mut chars = word.chars();
+        if let Some(c) = chars.next() {
+            camel.push(c.to_ascii_uppercase());
+            camel.extend(chars);
+        }
+    }
+    camel
+}
+
+/// Returns the provided `name` as a valid Rust identifier. Keywords are
+/// escaped as raw identifiers and those which can not be raw get an underscore
+/// suffix.
+pub fn ident(name: &str) -> String {
+    if !KEYWORDS.contains(&name) {
+        name.to_string()
+    } else if matches!(name, "crate" | "self" | "Self" | "super") {
+        format!("{}_", name)
+    } else {
+        format!("r#{}", name)
+    }
+}
+
+/// Returns the name of the module which holds the nested types of a message
+/// or the module of a package segment.
+pub fn module_name(name: &str) -> String {
+    ident(&snake_case(name))
+}
+
+/// Returns the name of the structure or enum of a message, an enum or a
+/// oneof.
+pub fn type_name(name: &str) -> String {
+    ident(&upper_camel_case(name))
+}
+
+/// Returns the name of a structure field.
+pub fn field_name(name: &str) -> String {
+    ident(&snake_case(name))
+}
+
+/// Returns the name of an enum variant. The name of the enum is stripped off
+/// the value name when the value is prefixed with it (e.g. `STATUS_OK` of the
+/// enum `Status` becomes `Ok`).
+pub fn variant_name(enum_name: &str, value_name: &str) -> String {
+    let prefix = upper_camel_case(enum_name);
+    let name = upper_camel_case(value_name);
+    match name.strip_prefix(&prefix) {
+        Some(rest) if rest.starts_with(|c: char| c.is_ascii_uppercase()) => ident(rest),
+        _ => ident(&name),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Should convert names between the `.proto` and the Rust conventions.
+    #[test]
+    fn converts_names() {
+        assert_eq!(snake_case("HTTPRequest"), "http_request");
+        assert_eq!(snake_case("Int64Value"), "int64_value");
+        assert_eq!(snake_case("user_id"), "user_id");
+        assert_eq!(snake_case("userId"), "user_id");
+        assert_eq!(upper_camel_case("STATUS_ACTIVE"), "StatusActive");
+        assert_eq!(upper_camel_case("HTTPRequest"), "HttpRequest");
+        assert_eq!(upper_camel_case("UInt64Value"), "UInt64Value");
+        assert_eq!(field_name("type"), "r#type");
+        assert_eq!(field_name("self"), "self_");
+        assert_eq!(type_name("Self"), "Self_");
+        assert_eq!(variant_name("Status", "STATUS_ACTIVE"), "Active");
+        assert_eq!(variant_name("NullValue", "NULL_VALUE"), "NullValue");
+        assert_eq!(variant_name("Level", "LEVEL_1"), "Level1");
+    }
+}
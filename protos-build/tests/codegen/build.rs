@@ -0,0 +1,10 @@
+use httlib_protos_build::Generator;
+
+fn main() {
+    println!("cargo:rerun-if-changed=protos");
+    let out = format!("{}/protos.rs", std::env::var("OUT_DIR").unwrap());
+    Generator::new()
+        .extern_path(".common", "crate::common")
+        .compile(&["protos"], &["shop/v1/order.proto"], out)
+        .unwrap();
+}
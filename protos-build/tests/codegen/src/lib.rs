@@ -0,0 +1,64 @@
+//! Includes the code which the build script generates from the `protos`
+//! directory. Compiling this crate proves that the generated code builds and
+//! its tests prove that messages survive the round-trip through the wire
+//! format.
+
+include!(concat!(env!("OUT_DIR"), "/protos.rs"));
+
+/// Provides the types of the `common` package which are mapped with
+/// `extern_path` instead of being generated.
+pub mod common {
+    use httlib_protos::Message;
+
+    #[derive(Debug, Clone, Default, PartialEq, Message)]
+    pub struct Money {
+        #[proto(tag = 1, kind = "int64")]
+        pub units: i64,
+        #[proto(tag = 2, kind = "string")]
+        pub currency: String,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use httlib_protos::Message;
+    use shop::v1::{order, Order};
+
+    /// Should encode and decode a generated message with nested packages,
+    /// nested messages, enums, maps, oneofs, recursive fields and extern types.
+    #[test]
+    fn round_trips_messages() {
+        let money = |units| common::Money { units, currency: "EUR".to_string() };
+        let parent = Order {
+            id: 1,
+            previous: Some(Box::new(Order { id: 0, ..Default::default() })),
+            ..Default::default()
+        };
+        let order = Order {
+            id: 2,
+            status: order::Status::Paid,
+            lines: vec![
+                order::Line { sku: "a".to_string(), quantity: 3, price: Some(money(5)) },
+                order::Line::default(),
+            ],
+            counters: vec![("x".to_string(), -1), ("y".to_string(), i64::MAX)].into_iter().collect(),
+            payment: Some(order::Payment::Parent(Box::new(parent))),
+            previous: None,
+            total: Some(money(15)),
+            amounts: vec![-1, 0, 1],
+            coupon: Some(String::new()),
+        };
+
+        let mut dst = Vec::new();
+        order.encode(&mut dst).unwrap();
+        assert_eq!(dst.len(), order.encoded_len().unwrap());
+        assert_eq!(Order::decode(&dst).unwrap(), order);
+
+        let order = Order { payment: Some(order::Payment::Card("1234".to_string())), ..order };
+        let mut dst = Vec::new();
+        order.encode(&mut dst).unwrap();
+        assert_eq!(Order::decode(&dst).unwrap(), order);
+        assert_eq!(Order::decode(&[]).unwrap(), Order::default());
+    }
+}
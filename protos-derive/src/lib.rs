@@ -0,0 +1,467 @@
+//! This crate provides derive macros for typed [Protocol Buffers] messages of
+//! the [httlib-protos] crate. It is usually not used directly but through the
+//! `derive` feature of [httlib-protos].
+//!
+//! [![Documentation](https://img.shields.io/badge/-Documentation-blue?style=for-the-badge&logo=Rust)](https://docs.rs/httlib-protos-derive)
+//! [![Source](https://img.shields.io/badge/-Source-lightgrey?style=for-the-badge&logo=GitHub)](https://github.com/xpepermint/httlib-rs/tree/main/protos-derive)
+//!
+//! ## About
+//!
+//! Hand-writing the encoding and decoding of every field of a message is
+//! repetitive and error-prone. The `Message` derive macro implements the
+//! `Message` trait for a structure with named fields where each field is
+//! annotated with its tag number and kind.
+//!
+//! ```txt
+//! #[proto(tag = 1, kind = "sint32")]
+//! ```
+//!
+//! Supported kinds are `bool`, `int32`, `int64`, `uint32`, `uint64`, `sint32`,
+//! `sint64`, `fixed32`, `fixed64`, `sfixed32`, `sfixed64`, `float`, `double`,
+//! `string`, `bytes`, `message` and `enum`. The cardinality of a field follows
+//! from its Rust type:
+//!
+//! * `T` is a singular field which is left out when it holds the default value.
+//! * `Option<T>` is an optional field which is written whenever it is set.
+//! * `Vec<T>` is a repeated field. Repeated numeric fields are packed unless
+//!   the attribute says `packed = false`. Note that a singular `bytes` field is
+//!   a `Vec<u8>`, thus repeated `bytes` fields are written as `Vec<Vec<u8>>`.
+//!
+//! A `oneof` is a Rust enum deriving `Oneof` where each variant wraps a single
+//! field annotated the same way. The message holds it as an `Option` and lists
+//! the tag numbers of its variants.
+//!
+//! ```txt
+//! #[proto(oneof, tags = "4, 5")]
+//! ```
+//!
+//! An enumeration is a Rust enum with unit variants deriving `Enumeration`.
+//! The discriminants of the variants are the numbers written to the wire.
+//!
+//! ## Usage
+//!
+//! ```rust
+//! use httlib_protos::{Enumeration, Message, Oneof};
+//!
+//! #[derive(Debug, Clone, Copy, Default, PartialEq, Enumeration)]
+//! enum Status {
+//!     #[default]
+//!     Unknown = 0,
+//!     Active = 1,
+//! }
+//!
+//! #[derive(Debug, PartialEq, Oneof)]
+//! enum Contact {
+//!     #[proto(tag = 4, kind = "string")]
+//!     Email(String),
+//!     #[proto(tag = 5, kind = "uint64")]
+//!     Phone(u64),
+//! }
+//!
+//! #[derive(Debug, Default, PartialEq, Message)]
+//! struct User {
+//!     #[proto(tag = 1, kind = "string")]
+//!     name: String,
+//!     #[proto(tag = 2, kind = "sint32")]
+//!     scores: Vec<i32>,
+//!     #[proto(tag = 3, kind = "enum")]
+//!     status: Status,
+//!     #[proto(oneof, tags = "4, 5")]
+//!     contact: Option<Contact>,
+//! }
+//!
+//! let user = User {
+//!     name: "John".to_string(),
+//!     scores: vec![1, -1],
+//!     status: Status::Active,
+//!     contact: Some(Contact::Phone(150)),
+//! };
+//!
+//! let mut dst = Vec::new();
+//! user.encode(&mut dst).unwrap();
+//! assert_eq!(User::decode(&dst).unwrap(), user);
+//! ```
+//!
+//! [Protocol Buffers]: https://developers.google.com/protocol-buffers
+//! [httlib-protos]: https://docs.rs/httlib-protos
+
+extern crate proc_macro;
+
+use proc_macro::TokenStream;
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::quote;
+use syn::{
+    parse_macro_input, spanned::Spanned, Attribute, Data, DeriveInput, Error,
+    Fields, GenericArgument, Ident, LitBool, LitInt, LitStr, PathArguments,
+    Type,
+};
+
+/// A constant holding the minimum `tag` number of a field.
+const TAG_MIN: u32 = 1;
+
+/// A constant holding the maximum `tag` number of a field.
+const TAG_MAX: u32 = (1 << 29) - 1;
+
+/// A constant holding the range of `tag` numbers reserved by the protocol.
+const TAG_RESERVED: (u32, u32) = (19000, 19999);
+
+/// Derives the `Message` trait for a structure with named fields.
+#[proc_macro_derive(Message, attributes(proto))]
+pub fn derive_message(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_message(input).unwrap_or_else(Error::into_compile_error).into()
+}
+
+/// Derives the `Oneof` trait for an enum whose variants wrap a single field.
+#[proc_macro_derive(Oneof, attributes(proto))]
+pub fn derive_oneof(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_oneof(input).unwrap_or_else(Error::into_compile_error).into()
+}
+
+/// Derives the `Enumeration` trait for an enum with unit variants.
+#[proc_macro_derive(Enumeration)]
+pub fn derive_enumeration(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_enumeration(input).unwrap_or_else(Error::into_compile_error).into()
+}
+
+/// Represents the arguments of a `#[proto(...)]` attribute.
+#[derive(Default)]
+struct Attr {
+    /// The tag number of the field.
+    tag: Option<u32>,
+
+    /// The kind of the field.
+    kind: Option<LitStr>,
+
+    /// Tells whether a repeated numeric field is packed.
+    packed: Option<bool>,
+
+    /// Tells whether the field holds a `oneof`.
+    oneof: bool,
+
+    /// The tag numbers of the `oneof` variants.
+    tags: Vec<u32>,
+}
+
+/// Represents the cardinality of a field.
+enum Cardinality {
+    Singular,
+    Optional,
+    Repeated,
+}
+
+/// Parses the `#[proto(...)]` attribute from the `attrs` of a field.
+fn parse_attr(attrs: &[Attribute], span: Span) -> Result<Attr, Error> {
+    let mut found = None;
+    for attr in attrs.iter().filter(|a| a.path().is_ident("proto")) {
+        if found.is_some() {
+            return Err(Error::new(attr.span(), "duplicated `proto` attribute"));
+        }
+        let mut args = Attr::default();
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("tag") {
+                let lit: LitInt = meta.value()?.parse()?;
+                args.tag = Some(parse_tag(&lit)?);
+            } else if meta.path.is_ident("kind") {
+                args.kind = Some(meta.value()?.parse()?);
+            } else if meta.path.is_ident("packed") {
+                let lit: LitBool = meta.value()?.parse()?;
+                args.packed = Some(lit.value);
+            } else if meta.path.is_ident("oneof") {
+                args.oneof = true;
+            } else if meta.path.is_ident("tags") {
+                let lit: LitStr = meta.value()?.parse()?;
+                for item in lit.value().split(',') {
+                    let item = LitInt::new(item.trim(), lit.span());
+                    args.tags.push(parse_tag(&item)?);
+                }
+            } else {
+                return Err(meta.error("unknown `proto` argument"));
+            }
+            Ok(())
+        })?;
+        found = Some(args);
+    }
+    found.ok_or_else(|| Error::new(span, "missing `#[proto(...)]` attribute"))
+}
+
+/// Parses and validates the tag number from the literal `lit`.
+fn parse_tag(lit: &LitInt) -> Result<u32, Error> {
+    let tag: u32 = lit.base10_parse()?;
+    if !(TAG_MIN..=TAG_MAX).contains(&tag) {
+        Err(Error::new(lit.span(), "tag number must be between 1 and 2^29 - 1"))
+    } else if (TAG_RESERVED.0..=TAG_RESERVED.1).contains(&tag) {
+        Err(Error::new(lit.span(), "tag numbers 19000 through 19999 are reserved"))
+    } else {
+        Ok(tag)
+    }
+}
+
+/// Marks the `tag` number as used and fails if it has been used before.
+fn claim_tag(used: &mut Vec<u32>, tag: u32, span: Span) -> Result<(), Error> {
+    if used.contains(&tag) {
+        return Err(Error::new(span, format!("tag number {} is used more than once", tag)));
+    }
+    used.push(tag);
+    Ok(())
+}
+
+/// Returns the only generic argument of the type `ty` if its name is `name`.
+fn generic_arg<'a>(ty: &'a Type, name: &str) -> Option<&'a Type> {
+    let path = match ty {
+        Type::Path(path) if path.qself.is_none() => &path.path,
+        _ => return None,
+    };
+    let segment = path.segments.last()?;
+    if segment.ident != name {
+        return None;
+    }
+    match &segment.arguments {
+        PathArguments::AngleBracketed(args) if args.args.len() == 1 => match &args.args[0] {
+            GenericArgument::Type(ty) => Some(ty),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Returns the cardinality of a field of type `ty` and the type of its values.
+fn cardinality<'a>(ty: &'a Type, kind: &str) -> (Cardinality, &'a Type) {
+    if let Some(inner) = generic_arg(ty, "Option") {
+        (Cardinality::Optional, inner)
+    } else if let Some(inner) = generic_arg(ty, "Vec") {
+        let is_byte = matches!(inner, Type::Path(p) if p.path.is_ident("u8"));
+        if kind == "bytes" && is_byte {
+            (Cardinality::Singular, ty)
+        } else {
+            (Cardinality::Repeated, inner)
+        }
+    } else {
+        (Cardinality::Singular, ty)
+    }
+}
+
+/// Returns the path to the kind type for the `kind` name and the type of values
+/// `ty`, together with the information whether the kind can be packed.
+fn kind_path(kind: &LitStr, ty: &Type) -> Result<(TokenStream2, bool), Error> {
+    let name = match kind.value().as_str() {
+        "bool" => "Bool",
+        "int32" => "Int32",
+        "int64" => "Int64",
+        "uint32" => "UInt32",
+        "uint64" => "UInt64",
+        "sint32" => "SInt32",
+        "sint64" => "SInt64",
+        "fixed32" => "Fixed32",
+        "fixed64" => "Fixed64",
+        "sfixed32" => "SFixed32",
+        "sfixed64" => "SFixed64",
+        "float" => "Float",
+        "double" => "Double",
+        "string" => return Ok((quote!(::httlib_protos::message::kind::String), false)),
+        "bytes" => return Ok((quote!(::httlib_protos::message::kind::Bytes), false)),
+        "message" => return Ok((quote!(::httlib_protos::message::kind::Message<#ty>), false)),
+        "enum" => return Ok((quote!(::httlib_protos::message::kind::Enumeration<#ty>), true)),
+        _ => return Err(Error::new(kind.span(), "unknown field kind")),
+    };
+    let ident = Ident::new(name, kind.span());
+    Ok((quote!(::httlib_protos::message::kind::#ident), true))
+}
+
+/// Generates the implementation of the `Message` trait.
+fn expand_message(input: DeriveInput) -> Result<TokenStream2, Error> {
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => return Err(Error::new(input.span(), "`Message` can only be derived for structs with named fields")),
+        },
+        _ => return Err(Error::new(input.span(), "`Message` can only be derived for structs")),
+    };
+
+    let mut used = Vec::new();
+    let mut encoders = Vec::new();
+    let mut mergers = Vec::new();
+    for field in fields {
+        let name = field.ident.as_ref().expect("named field");
+        let attr = parse_attr(&field.attrs, field.span())?;
+
+        if attr.oneof {
+            if attr.tags.is_empty() {
+                return Err(Error::new(field.span(), "`oneof` field requires `tags`"));
+            } else if generic_arg(&field.ty, "Option").is_none() {
+                return Err(Error::new(field.ty.span(), "`oneof` field must be an `Option`"));
+            }
+            for tag in &attr.tags {
+                claim_tag(&mut used, *tag, field.span())?;
+            }
+            let tags = &attr.tags;
+            encoders.push(quote! {
+                if let Some(oneof) = &self.#name {
+                    ::httlib_protos::Oneof::encode_fields(oneof, encoder)?;
+                }
+            });
+            mergers.push(quote! {
+                #(#tags)|* => ::httlib_protos::Oneof::merge_field(&mut self.#name, tag, typ, bytes),
+            });
+            continue;
+        }
+
+        let tag = attr.tag.ok_or_else(|| Error::new(field.span(), "missing `tag`"))?;
+        let kind = attr.kind.as_ref().ok_or_else(|| Error::new(field.span(), "missing `kind`"))?;
+        claim_tag(&mut used, tag, field.span())?;
+
+        let (cardinality, ty) = cardinality(&field.ty, &kind.value());
+        let (kind, packable) = kind_path(kind, ty)?;
+        let (encode, merge) = match cardinality {
+            Cardinality::Singular => (quote!(encode), quote!(merge)),
+            Cardinality::Optional => (quote!(encode_optional), quote!(merge_optional)),
+            Cardinality::Repeated => match attr.packed {
+                Some(true) if !packable => {
+                    return Err(Error::new(field.span(), "only numeric fields can be packed"));
+                },
+                Some(false) => (quote!(encode_repeated), quote!(merge_repeated)),
+                _ if packable => (quote!(encode_packed), quote!(merge_packed)),
+                _ => (quote!(encode_repeated), quote!(merge_repeated)),
+            },
+        };
+        encoders.push(quote! {
+            ::httlib_protos::message::field::#encode::<#kind>(#tag, &self.#name, encoder)?;
+        });
+        mergers.push(quote! {
+            #tag => ::httlib_protos::message::field::#merge::<#kind>(&mut self.#name, typ, bytes),
+        });
+    }
+
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::httlib_protos::Message for #ident #ty_generics #where_clause {
+            fn encode_fields(
+                &self,
+                encoder: &mut ::httlib_protos::MessageEncoder,
+            ) -> ::core::result::Result<(), ::httlib_protos::EncoderError> {
+                #(#encoders)*
+                Ok(())
+            }
+
+            fn merge_field(
+                &mut self,
+                tag: u32,
+                typ: ::httlib_protos::Typ,
+                bytes: &[u8],
+            ) -> ::core::result::Result<(), ::httlib_protos::DecoderError> {
+                match tag {
+                    #(#mergers)*
+                    _ => Ok(()),
+                }
+            }
+        }
+    })
+}
+
+/// Generates the implementation of the `Oneof` trait.
+fn expand_oneof(input: DeriveInput) -> Result<TokenStream2, Error> {
+    let variants = match &input.data {
+        Data::Enum(data) if !data.variants.is_empty() => &data.variants,
+        _ => return Err(Error::new(input.span(), "`Oneof` can only be derived for non-empty enums")),
+    };
+
+    let mut used = Vec::new();
+    let mut encoders = Vec::new();
+    let mut mergers = Vec::new();
+    for variant in variants {
+        let name = &variant.ident;
+        let ty = match &variant.fields {
+            Fields::Unnamed(fields) if fields.unnamed.len() == 1 => &fields.unnamed[0].ty,
+            _ => return Err(Error::new(variant.span(), "`Oneof` variant must wrap exactly one field")),
+        };
+        let attr = parse_attr(&variant.attrs, variant.span())?;
+        let tag = attr.tag.ok_or_else(|| Error::new(variant.span(), "missing `tag`"))?;
+        let kind = attr.kind.as_ref().ok_or_else(|| Error::new(variant.span(), "missing `kind`"))?;
+        claim_tag(&mut used, tag, variant.span())?;
+
+        if let (Cardinality::Optional, _) | (Cardinality::Repeated, _) = cardinality(ty, &kind.value()) {
+            return Err(Error::new(ty.span(), "`Oneof` variant can not be optional or repeated"));
+        }
+        let (kind, _) = kind_path(kind, ty)?;
+        encoders.push(quote! {
+            Self::#name(value) => {
+                <#kind as ::httlib_protos::message::kind::Kind>::encode(#tag, value, encoder)?;
+            },
+        });
+        mergers.push(quote! {
+            #tag => match oneof {
+                Some(Self::#name(value)) => {
+                    <#kind as ::httlib_protos::message::kind::Kind>::merge(value, typ, bytes)
+                },
+                _ => {
+                    let mut value = ::core::default::Default::default();
+                    <#kind as ::httlib_protos::message::kind::Kind>::merge(&mut value, typ, bytes)?;
+                    *oneof = Some(Self::#name(value));
+                    Ok(())
+                },
+            },
+        });
+    }
+
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::httlib_protos::Oneof for #ident #ty_generics #where_clause {
+            fn encode_fields(
+                &self,
+                encoder: &mut ::httlib_protos::MessageEncoder,
+            ) -> ::core::result::Result<(), ::httlib_protos::EncoderError> {
+                match self {
+                    #(#encoders)*
+                }
+                Ok(())
+            }
+
+            fn merge_field(
+                oneof: &mut Option<Self>,
+                tag: u32,
+                typ: ::httlib_protos::Typ,
+                bytes: &[u8],
+            ) -> ::core::result::Result<(), ::httlib_protos::DecoderError> {
+                match tag {
+                    #(#mergers)*
+                    _ => Ok(()),
+                }
+            }
+        }
+    })
+}
+
+/// Generates the implementation of the `Enumeration` trait.
+fn expand_enumeration(input: DeriveInput) -> Result<TokenStream2, Error> {
+    let variants = match &input.data {
+        Data::Enum(data) if !data.variants.is_empty() => &data.variants,
+        _ => return Err(Error::new(input.span(), "`Enumeration` can only be derived for non-empty enums")),
+    };
+    if let Some(variant) = variants.iter().find(|v| !matches!(v.fields, Fields::Unit)) {
+        return Err(Error::new(variant.span(), "`Enumeration` variant can not hold fields"));
+    }
+
+    let ident = &input.ident;
+    let names = variants.iter().map(|v| &v.ident).collect::<Vec<_>>();
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::httlib_protos::Enumeration for #ident #ty_generics #where_clause {
+            fn to_i32(&self) -> i32 {
+                *self as i32
+            }
+
+            fn from_i32(value: i32) -> Option<Self> {
+                #(
+                    if value == Self::#names as i32 {
+                        return Some(Self::#names);
+                    }
+                )*
+                None
+            }
+        }
+    })
+}
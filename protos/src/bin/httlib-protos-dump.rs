@@ -0,0 +1,72 @@
+//! Command-line interface for dumping encoded messages without a schema.
+//! 
+//! ```txt
+//! httlib-protos-dump [--offsets] [--hex] [FILE]
+//! ```
+//! 
+//! When the `FILE` argument is missing, the message is read from the standard
+//! input.
+
+use std::env;
+use std::fs;
+use std::io::{self, Read};
+use std::process;
+use httlib_protos::dump::{dump, DumpOptions};
+
+const USAGE: &str = "Usage:
+    httlib-protos-dump [OPTIONS] [FILE]           Prints the fields of an encoded message.
+
+Options:
+    --offsets                                     Prefixes each field with its byte offset.
+    --hex                                         Reads the message as hex digits (whitespace is ignored).";
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    if let Err(err) = run(&args) {
+        eprintln!("{}", err);
+        process::exit(1);
+    }
+}
+
+/// Dumps the message provided by `args`.
+fn run(args: &[String]) -> Result<(), String> {
+    let mut options = DumpOptions::default();
+    let mut hex = false;
+    let mut file = None;
+
+    for arg in args {
+        match arg.as_str() {
+            "--offsets" => options.offsets = true,
+            "--hex" => hex = true,
+            arg if arg.starts_with('-') => return Err(USAGE.to_string()),
+            arg if file.is_none() => file = Some(arg),
+            _ => return Err(USAGE.to_string()),
+        }
+    }
+
+    let mut bytes = match file {
+        Some(path) => fs::read(path).map_err(|e| format!("Can't read {}: {}", path, e))?,
+        None => {
+            let mut bytes = Vec::new();
+            io::stdin().read_to_end(&mut bytes).map_err(|e| format!("Can't read the standard input: {}", e))?;
+            bytes
+        },
+    };
+    if hex {
+        bytes = decode_hex(&bytes).ok_or("Invalid hex input.")?;
+    }
+    print!("{}", dump(&bytes, &options));
+    Ok(())
+}
+
+/// Returns the bytes of the provided hex digits. Whitespace is ignored.
+fn decode_hex(text: &[u8]) -> Option<Vec<u8>> {
+    let digits = text.iter()
+        .filter(|b| !b.is_ascii_whitespace())
+        .map(|b| (*b as char).to_digit(16))
+        .collect::<Option<Vec<_>>>()?;
+    if digits.len() % 2 != 0 {
+        return None;
+    }
+    Some(digits.chunks(2).map(|pair| (pair[0] << 4 | pair[1]) as u8).collect())
+}
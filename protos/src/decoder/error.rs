@@ -23,6 +23,19 @@ pub enum DecoderError {
     /// A tag number must be unique per message and the value can be between `1`
     /// and `2^29 - 1`.
     InvalidTag,
+
+    /// Indicates that the decoder encountered a key with the wire type `6` or
+    /// `7` which is not defined by the protocol.
+    InvalidWireType,
+
+    /// Indicates that groups or embedded messages are nested deeper than the
+    /// decoder allows. The limit protects the decoder from exhausting the
+    /// stack.
+    DepthOverflow,
+
+    /// Indicates that a message field was found with a wire type which does not
+    /// match the kind the field is declared with.
+    UnexpectedWireType,
 }
 
 impl From<io::Error> for DecoderError {
@@ -44,6 +57,9 @@ impl fmt::Display for DecoderError {
             Self::Interrupted => write!(fmt, "Read operation interrupted."),
             Self::InputUnderflow => write!(fmt, "Not enough bytes."),
             Self::InvalidTag => write!(fmt, "Found tag with invalid number."),
+            Self::InvalidWireType => write!(fmt, "Found key with invalid wire type."),
+            Self::DepthOverflow => write!(fmt, "Fields nested too deep."),
+            Self::UnexpectedWireType => write!(fmt, "Found field with unexpected wire type."),
         }
     }
 }
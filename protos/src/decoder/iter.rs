@@ -0,0 +1,127 @@
+use super::primitives::*;
+use crate::Typ;
+
+/// Provides a zero-copy iterator over `proto3` encoded fields.
+/// 
+/// Unlike the `Decoder`, which consumes a growing buffer and copies each field
+/// payload into a new vector, the iterator walks an immutable slice holding
+/// a complete message and yields tuples of format `(tag, type, bytes)` where
+/// the `bytes` borrow the encoded value directly from the slice. No memory is
+/// allocated while iterating.
+/// 
+/// The `bytes` hold the same encoded value as the ones returned by the
+/// `Decoder` thus the length of a length-delimited field is not included. The
+/// value can be parsed with the functions of the `primitives` module. The bytes
+/// of a group (`Typ::StartGroup`) hold its encoded fields which can be iterated
+/// with a new iterator.
+/// 
+/// ```rust
+/// use httlib_protos::{FieldIter, Typ};
+/// use httlib_protos::decoder::primitives::decode_int32;
+/// 
+/// let buf = [0x08, 0x96, 0x01, 0x12, 0x02, 0x68, 0x69];
+/// 
+/// for field in FieldIter::new(&buf) {
+///     let (tag, typ, byt) = field.unwrap();
+///     if tag == 1 {
+///         let mut val = 0;
+///         decode_int32(byt, &mut val).unwrap();
+///         assert_eq!(val, 150);
+///     } else if tag == 2 {
+///         assert_eq!(typ, Typ::LengthDelimited);
+///         assert_eq!(byt, b"hi");
+///     }
+/// }
+/// ```
+/// 
+/// The iterator yields an error when the slice holds an invalid or incomplete
+/// field and it ends afterwards.
+#[derive(Debug, Clone)]
+pub struct FieldIter<'a> {
+    /// The encoded message.
+    buf: &'a [u8],
+
+    /// The position of the next field in the buffer.
+    pos: usize,
+}
+
+impl<'a> FieldIter<'a> {
+    /// Returns a new iterator over the fields of the encoded message `buf`.
+    pub fn new(buf: &'a [u8]) -> Self {
+        Self {
+            buf,
+            pos: 0,
+        }
+    }
+
+    /// Returns the number of bytes read so far.
+    pub fn position(&self) -> usize {
+        self.pos
+    }
+
+    /// Reads the next field starting at the current position.
+    fn read_field(&mut self) -> Result<(u32, Typ, &'a [u8]), DecoderError> {
+        let buf = &self.buf[self.pos..];
+
+        let mut key = (0, Typ::Varint);
+        let size = decode_key(buf, &mut key)?;
+        let (start, end, len) = locate_value(&buf[size..], key, 0)?;
+        let bytes = &buf[size + start..size + end];
+        self.pos += size + len;
+
+        Ok((key.0, key.1, bytes))
+    }
+}
+
+impl<'a> Iterator for FieldIter<'a> {
+    type Item = Result<(u32, Typ, &'a [u8]), DecoderError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.pos >= self.buf.len() {
+            return None;
+        }
+
+        let field = self.read_field();
+        if field.is_err() {
+            self.pos = self.buf.len(); // stop after an error
+        }
+        Some(field)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Encoder;
+
+    /// Should iterate over encoded fields without copying their payloads and
+    /// stop at the first invalid field.
+    #[test]
+    fn iterates_fields() {
+        let encoder = Encoder;
+        let mut buf = Vec::new();
+        encoder.encode((&1, &150i32), &mut buf).unwrap();
+        encoder.encode((&2, &b"foo".to_vec()), &mut buf).unwrap();
+        encoder.encode((&3, &1.0f32), &mut buf).unwrap();
+        encoder.encode((&4, &1.0f64), &mut buf).unwrap();
+
+        let fields: Vec<_> = FieldIter::new(&buf).collect::<Result<_, _>>().unwrap();
+        assert_eq!(fields, vec![
+            (1, Typ::Varint, &[0x96, 0x01][..]),
+            (2, Typ::LengthDelimited, &b"foo"[..]),
+            (3, Typ::Bit32, &[0, 0, 128, 63][..]),
+            (4, Typ::Bit64, &[0, 0, 0, 0, 0, 0, 240, 63][..]),
+        ]);
+        assert!(std::ptr::eq(fields[1].2.as_ptr(), &buf[5]));
+
+        let mut iter = FieldIter::new(&buf[..buf.len() - 1]);
+        assert_eq!(iter.by_ref().filter(|f| f.is_ok()).count(), 3);
+        assert_eq!(iter.position(), buf.len() - 1);
+        let mut iter = FieldIter::new(&buf[..buf.len() - 1]);
+        assert_eq!(iter.nth(3), Some(Err(DecoderError::InputUnderflow)));
+        assert_eq!(iter.next(), None);
+        assert_eq!(FieldIter::new(&[0x0f]).next(), Some(Err(DecoderError::InvalidWireType)));
+        assert_eq!(FieldIter::new(&[0x08, 0xff, 0xff]).next(), Some(Err(DecoderError::InputUnderflow)));
+        assert_eq!(FieldIter::new(&[]).next(), None);
+    }
+}
@@ -276,10 +276,7 @@ impl From<DecoderLit> for String {
     fn from(lit: DecoderLit) -> Self {
         match lit {
             DecoderLit::Bytes(byt) => {
-                match String::from_utf8(byt) {
-                    Ok(s) => s,
-                    _ => String::new(),
-                }
+                String::from_utf8(byt).unwrap_or_default()
             },
             _ => String::new(),
         }
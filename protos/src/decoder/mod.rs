@@ -19,22 +19,33 @@
 //! The decoder decodes a binary stream back to the original message.
 
 mod error;
+mod iter;
 mod lit;
-mod primitives;
+pub mod primitives;
 
 use crate::Typ;
 pub use error::*;
+pub use iter::*;
 pub use lit::*;
 use primitives::*;
 
 /// Provides the decoding engine for Protocol Buffers.
+#[derive(Default)]
 pub struct Decoder {
     /// A key with tag number and wire type of the currently decoding field.
-    key: (u32, Typ),
+    /// The decoder is waiting for the next key when not set.
+    key: Option<(u32, Typ)>,
 
     /// The number of bytes that need to be read for the currently decoding
     /// length-delimited field.
     ld_len: Option<u64>,
+
+    /// The number of bytes of the currently decoding group field which have
+    /// already been walked.
+    group_pos: usize,
+
+    /// Tag numbers of the nested groups which are still open at `group_pos`.
+    group_open: Vec<u32>,
 }
 
 impl Decoder {
@@ -44,6 +55,8 @@ impl Decoder {
     /// The returned fields are tuples of format `(tag, type, bytes)` where the
     /// returned `bytes` represent the encoded value. The developer should wrap
     /// each value into the desired `DecoderLit` and call `parse` on it. 
+    /// A deprecated group field is returned as a single field of type
+    /// `Typ::StartGroup` holding the encoded fields of the group.
     /// 
     /// ```rust
     /// use httlib_protos::{Decoder, DecoderLit};
@@ -76,22 +89,29 @@ impl Decoder {
         let mut total = 0;
         loop {
             let mut _size = 0;
-            match self.key.1 {
-                Typ::Unknown => {
+            match self.key {
+                None => {
                     _size = self.decode_key(buf)?;
                 },
-                Typ::Varint => {
+                Some((_, Typ::Varint)) => {
                     _size = self.extract_varint(buf, dst)?;
                 },
-                Typ::Bit32 => {
+                Some((_, Typ::Bit32)) => {
                     _size = self.extract_bit32(buf, dst)?;
                 },
-                Typ::Bit64 => {
+                Some((_, Typ::Bit64)) => {
                     _size = self.extract_bit64(buf, dst)?;
                 },
-                Typ::LengthDelimited => {
+                Some((_, Typ::LengthDelimited)) => {
                     _size = self.extract_ld(buf, dst)?;
                 },
+                Some((_, Typ::StartGroup)) => {
+                    _size = self.extract_group(buf, dst)?;
+                },
+                Some((_, Typ::EndGroup)) => {
+                    self.reset();
+                    return Err(DecoderError::InvalidInput); // no group to close
+                },
             }
             if _size == 0 {
                 break;
@@ -110,11 +130,13 @@ impl Decoder {
     /// On success, the number of written bytes is returned otherwise an error
     /// is thrown.
     fn decode_key(&mut self, buf: &mut Vec<u8>) -> Result<usize, DecoderError> {
-        let size = match decode_key(&buf, &mut self.key) {
+        let mut key = (0, Typ::Varint);
+        let size = match decode_key(buf, &mut key) {
             Ok(size) => size,
             Err(DecoderError::InputUnderflow) => return Ok(0),
             Err(e) => return Err(e),
         };
+        self.key = Some(key);
         buf.drain(..size);
         Ok(size)
     }
@@ -134,12 +156,12 @@ impl Decoder {
         dst: &mut Vec<(u32, Typ, Vec<u8>)>,
     ) -> Result<usize, DecoderError> {
         let mut bytes = vec![];
-        let size = match extract_varint(&buf, &mut bytes) {
+        let size = match extract_varint(buf, &mut bytes) {
             Ok(size) => size,
             Err(DecoderError::InputUnderflow) => return Ok(0),
             Err(e) => return Err(e),
         };
-        dst.push((self.key.0, self.key.1, bytes));
+        dst.push((self.tag(), self.typ(), bytes));
         buf.drain(..size);
         self.reset();
         Ok(size)
@@ -160,12 +182,12 @@ impl Decoder {
         dst: &mut Vec<(u32, Typ, Vec<u8>)>,
     ) -> Result<usize, DecoderError> {
         let mut bytes = vec![];
-        let size = match extract_bit32(&buf, &mut bytes) {
+        let size = match extract_bit32(buf, &mut bytes) {
             Ok(size) => size,
             Err(DecoderError::InputUnderflow) => return Ok(0),
             Err(e) => return Err(e),
         };
-        dst.push((self.key.0, self.key.1, bytes));
+        dst.push((self.tag(), self.typ(), bytes));
         buf.drain(..size);
         self.reset();
         Ok(size)
@@ -186,12 +208,12 @@ impl Decoder {
         dst: &mut Vec<(u32, Typ, Vec<u8>)>,
     ) -> Result<usize, DecoderError> {
         let mut bytes = vec![];
-        let size = match extract_bit64(&buf, &mut bytes) {
+        let size = match extract_bit64(buf, &mut bytes) {
             Ok(size) => size,
             Err(DecoderError::InputUnderflow) => return Ok(0),
             Err(e) => return Err(e),
         };
-        dst.push((self.key.0, self.key.1, bytes));
+        dst.push((self.tag(), self.typ(), bytes));
         buf.drain(..size);
         self.reset();
         Ok(size)
@@ -232,7 +254,7 @@ impl Decoder {
         buf: &mut Vec<u8>,
     ) -> Result<usize, DecoderError> {
         let mut val = 0;
-        let size = match decode_varint(&buf, &mut val) {
+        let size = match decode_varint(buf, &mut val) {
             Ok(size) => size,
             Err(DecoderError::InputUnderflow) => return Ok(0),
             Err(e) => return Err(e),
@@ -258,30 +280,64 @@ impl Decoder {
     ) -> Result<usize, DecoderError> {
         let len = self.ld_len.unwrap();
         let mut bytes = vec![];
-        let size = match extract_ld(&buf, len, &mut bytes) {
+        let size = match extract_ld(buf, len, &mut bytes) {
             Ok(size) => size,
             Err(DecoderError::InputUnderflow) => return Ok(0),
             Err(e) => return Err(e),
         };
-        dst.push((self.key.0, self.key.1, bytes));
+        dst.push((self.tag(), self.typ(), bytes));
         buf.drain(..size);
         self.reset();
         Ok(size)
     }
 
+    /// Reads bytes of the currently handled group field (wire type `3`) from
+    /// the provided `buf` and writes the resulting bytes into `dst`.
+    /// 
+    /// The group is extracted only when the `buf` holds all its fields and the
+    /// end key. The returned bytes hold the encoded fields of the group which
+    /// can be decoded by another decoder instance. Until then, the decoder
+    /// remembers how far the group has been walked and continues from there
+    /// when called with more bytes.
+    /// 
+    /// This function consumes the buffer only if the decoding succeeds. The
+    /// provided vector will stay untouched in case of an error or insufficient
+    /// data.
+    /// 
+    /// On success, the number of written bytes is returned otherwise an error
+    /// is thrown.
+    fn extract_group(
+        &mut self,
+        buf: &mut Vec<u8>,
+        dst: &mut Vec<(u32, Typ, Vec<u8>)>,
+    ) -> Result<usize, DecoderError> {
+        let (end, size) = match scan_group(buf, self.tag(), &mut self.group_open, &mut self.group_pos) {
+            Ok(res) => res,
+            Err(DecoderError::InputUnderflow) => return Ok(0),
+            Err(e) => return Err(e),
+        };
+        dst.push((self.tag(), self.typ(), buf[..end].to_vec()));
+        buf.drain(..size);
+        self.reset();
+        Ok(size)
+    }
+
+    /// Returns the tag number of the currently decoding field.
+    fn tag(&self) -> u32 {
+        self.key.map(|key| key.0).unwrap_or(0)
+    }
+
+    /// Returns the wire type of the currently decoding field.
+    fn typ(&self) -> Typ {
+        self.key.map(|key| key.1).unwrap_or(Typ::Varint)
+    }
+
     /// Resets the decoder and flushes all memoried data.
     fn reset(&mut self) {
-        self.key = (0, Typ::Unknown);
+        self.key = None;
         self.ld_len = None;
-    }
-}
-
-impl<'a> Default for Decoder {
-    fn default() -> Self {
-        Self {
-            key: (0, Typ::Unknown),
-            ld_len: None,
-        }
+        self.group_pos = 0;
+        self.group_open.clear();
     }
 }
 
@@ -291,6 +347,7 @@ mod test {
 
     /// Should decode supported formats from Protocol Buffers bytes.
     #[test]
+    #[allow(clippy::bool_assert_comparison)]
     fn decodes_supported() {
         let mut decoder = Decoder::default();
         let mut dst = vec![];
@@ -421,4 +478,49 @@ mod test {
         let mut src = vec![0];
         assert!(decoder.decode(&mut src, &mut dst).is_err()); // handles errors
     }
+
+    /// Should extract nested groups once all their bytes are received and
+    /// refuse unmatched end keys and invalid wire types.
+    #[test]
+    fn decodes_groups() {
+        let mut decoder = Decoder::default();
+        let mut dst = vec![];
+        let mut src = vec![11, 16, 1, 27, 34, 3, 102, 111];
+        assert_eq!(decoder.decode(&mut src, &mut dst), Ok(1)); // waits for more bytes
+        assert!(dst.is_empty());
+        src.extend(vec![111, 28, 12, 16, 2]);
+        assert_eq!(decoder.decode(&mut src, &mut dst), Ok(12));
+        assert_eq!(dst, vec![
+            (1, Typ::StartGroup, vec![16, 1, 27, 34, 3, 102, 111, 111, 28]),
+            (2, Typ::Varint, vec![2]),
+        ]);
+
+        let mut src = vec![11, 16, 1, 27, 34, 3, 102];
+        let mut decoder = Decoder::default();
+        let mut dst = vec![];
+        assert_eq!(decoder.decode(&mut src, &mut dst), Ok(1));
+        assert_eq!((decoder.group_pos, decoder.group_open.clone()), (3, vec![3])); // resumes at field 4
+        for byte in [111, 111, 28, 12, 16, 2] {
+            src.push(byte);
+            decoder.decode(&mut src, &mut dst).unwrap();
+        }
+        assert!(src.is_empty());
+
+                let inner: Vec<_> = FieldIter::new(&dst[0].2).collect::<Result<_, _>>().unwrap();
+        assert_eq!(inner, vec![
+            (2, Typ::Varint, &[1][..]),
+            (3, Typ::StartGroup, &[34, 3, 102, 111, 111][..]),
+        ]);
+
+        let invalid = vec![
+            (vec![11, 20], DecoderError::InvalidInput), // end of tag 2
+            (vec![12], DecoderError::InvalidInput), // no group to close
+            (vec![14], DecoderError::InvalidWireType),
+            (vec![11; 200], DecoderError::DepthOverflow),
+        ];
+        for (mut src, err) in invalid {
+            let mut decoder = Decoder::default();
+            assert_eq!(decoder.decode(&mut src, &mut vec![]), Err(err));
+        }
+    }
 }
@@ -35,6 +35,9 @@ pub fn decode_varint(buf: &[u8], dst: &mut u64) -> Result<usize, DecoderError> {
             Some(b) => *b,
             None => return Err(DecoderError::InputUnderflow),
         };
+        if count == 10 { // longer than 64 bits
+            return Err(DecoderError::InvalidInput);
+        }
         value |= u64::from(byte & 0x7F) << (7 * count);
         count += 1;
         if byte <= 0x7F {
@@ -76,8 +79,6 @@ pub fn decode_key(
     let tag = key as u32 >> 3;
     if tag < Encoder::TAG_MIN {
         return Err(DecoderError::InvalidInput);
-    } else if typ == Typ::Unknown {
-        return Err(DecoderError::InvalidInput);
     }
 
     *dst = (tag, typ);
@@ -92,7 +93,7 @@ pub fn decode_key(
 pub fn decode_bool(buf: &[u8], dst: &mut bool) -> Result<usize, DecoderError> {
     let mut val = 0;
     let size = decode_varint(buf, &mut val)?;
-    *dst = if val == 0u64 { false } else { true };
+    *dst = val != 0u64;
     Ok(size)
 }
 
@@ -622,7 +623,7 @@ pub fn extract_varint(
             Some(b) => *b,
             None => return Err(DecoderError::InputUnderflow),
         };
-        bytes.push(byte.clone());
+        bytes.push(byte);
         count += 1;
         if byte <= 0x7F {
             dst.append(&mut bytes);
@@ -681,6 +682,126 @@ pub fn extract_ld(
     Ok(size)
 }
 
+/// Reads bytes for a `group` field (wire type `3`) with a specific `tag` number
+/// from the provided `buf` and writes the resulting bytes into `dst`.
+/// 
+/// The `buf` must start right after the key that started the group. A group has
+/// no length prefix and it ends with a key of wire type `4` holding the same
+/// tag number. Groups can be nested thus the inner fields are walked until the
+/// matching end key is found. The bytes written into `dst` hold the encoded
+/// fields of the group without the end key.
+/// 
+/// On success, the number of read bytes (including the end key) is returned
+/// otherwise an error is thrown.
+pub fn extract_group(
+    buf: &[u8],
+    tag: u32,
+    dst: &mut Vec<u8>,
+) -> Result<usize, DecoderError> {
+    let (end, size) = scan_group(buf, tag, &mut vec![], &mut 0)?;
+    dst.extend_from_slice(&buf[..end]);
+    Ok(size)
+}
+
+/// Scans a `group` field with a specific `tag` number in the provided `buf`
+/// which starts right after the key that started the group.
+/// 
+/// The scan can be resumed when more bytes are received. The `pos` holds the
+/// number of bytes already walked and the `open` holds the tag numbers of the
+/// nested groups which are open at that position. Both are updated after each
+/// complete field, thus every byte is walked only once no matter how many calls
+/// it takes to receive the whole group.
+/// 
+/// On success, the position where the group value ends and the number of read
+/// bytes (including the end key) are returned otherwise an error is thrown.
+pub(crate) fn scan_group(
+    buf: &[u8],
+    tag: u32,
+    open: &mut Vec<u32>,
+    pos: &mut usize,
+) -> Result<(usize, usize), DecoderError> {
+    loop {
+        let mut inner = (0, Typ::Varint);
+        let size = decode_key(&buf[*pos..], &mut inner)?;
+        match inner.1 {
+            Typ::EndGroup => {
+                if inner.0 != *open.last().unwrap_or(&tag) { // end of a different group
+                    return Err(DecoderError::InvalidInput);
+                }
+                if open.pop().is_none() {
+                    return Ok((*pos, *pos + size));
+                }
+                *pos += size;
+            },
+            Typ::StartGroup => {
+                if open.len() + 1 >= GROUP_DEPTH_MAX {
+                    return Err(DecoderError::DepthOverflow);
+                }
+                open.push(inner.0);
+                *pos += size;
+            },
+            _ => {
+                *pos += size + locate_value(&buf[*pos + size..], inner, 0)?.2;
+            },
+        }
+    }
+}
+
+/// The maximum number of nested groups the decoder walks through.
+const GROUP_DEPTH_MAX: usize = 100;
+
+/// Locates the value of a field with the provided `key` in `buf` which starts
+/// right after the key. The function returns the position where the encoded
+/// value starts, where it ends and where the field ends.
+/// 
+/// The value of a length-delimited field starts after the length while the
+/// value of a group ends before the end key. The `depth` tells how many groups
+/// are already open.
+pub(crate) fn locate_value(
+    buf: &[u8],
+    key: (u32, Typ),
+    depth: usize,
+) -> Result<(usize, usize, usize), DecoderError> {
+    match key.1 {
+        Typ::Varint => match buf.iter().take(10).position(|b| *b <= 0x7F) {
+            Some(index) => Ok((0, index + 1, index + 1)),
+            None if buf.len() < 10 => Err(DecoderError::InputUnderflow),
+            None => Err(DecoderError::InvalidInput), // longer than 64 bits
+        },
+        Typ::Bit32 if buf.len() < 4 => Err(DecoderError::InputUnderflow),
+        Typ::Bit32 => Ok((0, 4, 4)),
+        Typ::Bit64 if buf.len() < 8 => Err(DecoderError::InputUnderflow),
+        Typ::Bit64 => Ok((0, 8, 8)),
+        Typ::LengthDelimited => {
+            let mut len = 0;
+            let start = decode_varint(buf, &mut len)?;
+            match usize::try_from(len).ok().and_then(|len| start.checked_add(len)) {
+                Some(end) if end <= buf.len() => Ok((start, end, end)),
+                _ => Err(DecoderError::InputUnderflow),
+            }
+        },
+        Typ::StartGroup => {
+            if depth >= GROUP_DEPTH_MAX {
+                return Err(DecoderError::DepthOverflow);
+            }
+            let mut pos = 0;
+            loop {
+                let mut inner = (0, Typ::Varint);
+                let size = decode_key(&buf[pos..], &mut inner)?;
+                if inner.1 == Typ::EndGroup {
+                    if inner.0 != key.0 { // end of a different group
+                        return Err(DecoderError::InvalidInput);
+                    }
+                    return Ok((0, pos, pos + size));
+                }
+                pos += size;
+                pos += locate_value(&buf[pos..], inner, depth + 1)?.2;
+            }
+        },
+        Typ::EndGroup => Err(DecoderError::InvalidInput), // no group to close
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -699,13 +820,15 @@ mod test {
         let size = decode_varint(&buf, &mut dst).unwrap();
         assert_eq!(dst, 6789);
         assert_eq!(size, 2);
+        let buf = vec![0xff; 11];
+        assert_eq!(decode_varint(&buf, &mut dst), Err(DecoderError::InvalidInput)); // too long
     }
 
     /// Should decode a header key of a field which consists of field tag number
     /// and field wire type.
     #[test]
     fn decodes_key() {
-        let mut dst = (0, Typ::Unknown);
+        let mut dst = (0, Typ::Varint);
         let buf = vec![0xcd, 0x83, 0x06, 0xaa];
         let size = decode_key(&buf, &mut dst).unwrap();
         assert_eq!(dst.0, 12345);
@@ -720,6 +843,7 @@ mod test {
 
     /// Should decode an encoded numeric value into `bool` data type.
     #[test]
+    #[allow(clippy::bool_assert_comparison)]
     fn decodes_bool() {
         let mut dst = true;
         let buf = vec![0x00];
@@ -0,0 +1,300 @@
+//! Provides a schema-less dump of encoded messages for wire debugging.
+//!
+//! The dump walks the fields of an encoded message the same way as the
+//! `Decoder` does it and writes a tree of tag numbers, values and wire types
+//! without knowing the schema of the message. The bytes of a length-delimited
+//! field can hold a string, raw bytes, an embedded message or a packed
+//! repeated field, so each reading is tried and the most plausible one is
+//! shown:
+//!
+//! * printable UTF-8 text is shown as a string,
+//! * bytes which hold valid fields are shown as an embedded message,
+//! * bytes which hold a sequence of canonical varints are shown as a packed
+//!   array,
+//! * anything else is shown as escaped bytes.
+//!
+//! A message which can not be decoded is dumped up to the invalid field and
+//! the rest of the bytes are shown together with the error. Messages and
+//! groups nested deeper than `DUMP_DEPTH_MAX` levels are shown as bytes.
+//!
+//! ```rust
+//! use httlib_protos::dump::{dump, DumpOptions};
+//!
+//! let buf = [0x08, 0x96, 0x01, 0x12, 0x03, 0x08, 0x01, 0x10];
+//! assert_eq!(dump(&buf, &DumpOptions::default()), concat!(
+//!     "1: 150  # varint\n",
+//!     "2: [8, 1, 16]  # packed varints, 3 bytes\n",
+//! ));
+//!
+//! let options = DumpOptions { offsets: true };
+//! assert_eq!(dump(&[0x1a, 0x02, 0x08, 0x07], &options), concat!(
+//!     "[0x0000] 3 {  # message, 2 bytes\n",
+//!     "[0x0002]   1: 7  # varint\n",
+//!     "         }\n",
+//! ));
+//! ```
+
+use crate::{FieldIter, Typ};
+use crate::decoder::primitives::*;
+
+/// The maximum number of nested messages and groups the dump descends into.
+pub const DUMP_DEPTH_MAX: usize = 100;
+
+/// Provides options of the dump.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DumpOptions {
+    /// Prefixes each field with the offset of its key in the dumped bytes.
+    pub offsets: bool,
+}
+
+/// Returns a readable tree of the fields of the encoded message `bytes`.
+pub fn dump(bytes: &[u8], options: &DumpOptions) -> String {
+    let mut out = String::new();
+    dump_fields(bytes, 0, 0, options, &mut out);
+    out
+}
+
+/// Writes the fields of the encoded `bytes` found at the provided `offset`
+/// into `out`, indented by the `depth` of the message.
+fn dump_fields(bytes: &[u8], offset: usize, depth: usize, options: &DumpOptions, out: &mut String) {
+    let mut iter = FieldIter::new(bytes);
+    loop {
+        let start = iter.position();
+        match iter.next() {
+            Some(Ok((tag, typ, value))) => {
+                let value_offset = if typ == Typ::StartGroup {
+                    let mut key = (0, Typ::Varint);
+                    start + decode_key(&bytes[start..], &mut key).unwrap_or(0)
+                } else {
+                    iter.position() - value.len()
+                };
+                print_prefix(Some(offset + start), depth, options, out);
+                dump_field(tag, typ, value, offset + value_offset, depth, options, out);
+            },
+            Some(Err(err)) => {
+                print_prefix(Some(offset + start), depth, options, out);
+                out.push_str(&format!("# {} Remaining bytes: \"{}\"\n", err, escape(&bytes[start..])));
+                break;
+            },
+            None => break,
+        }
+    }
+}
+
+/// Writes a single field with the value `bytes` found at the provided
+/// `offset` after the prefix of the line.
+fn dump_field(tag: u32, typ: Typ, bytes: &[u8], offset: usize, depth: usize, options: &DumpOptions, out: &mut String) {
+    match typ {
+        Typ::Varint => {
+            let mut val = 0;
+            decode_uint64(bytes, &mut val).ok();
+            if val > i64::MAX as u64 {
+                out.push_str(&format!("{}: {}  # varint, int64 {}\n", tag, val, val as i64));
+            } else {
+                out.push_str(&format!("{}: {}  # varint\n", tag, val));
+            }
+        },
+        Typ::Bit32 => {
+            let mut val = 0;
+            decode_fixed32(bytes, &mut val).ok();
+            out.push_str(&format!("{}: 0x{:08x}  # fixed32, float {}\n", tag, val, f32::from_bits(val)));
+        },
+        Typ::Bit64 => {
+            let mut val = 0;
+            decode_fixed64(bytes, &mut val).ok();
+            out.push_str(&format!("{}: 0x{:016x}  # fixed64, double {}\n", tag, val, f64::from_bits(val)));
+        },
+        Typ::StartGroup if depth + 1 >= DUMP_DEPTH_MAX => {
+            out.push_str(&format!("{}: \"{}\"  # group, {} bytes\n", tag, escape(bytes), bytes.len()));
+        },
+        Typ::StartGroup => {
+            out.push_str(&format!("{} {{  # group\n", tag));
+            dump_fields(bytes, offset, depth + 1, options, out);
+            print_prefix(None, depth, options, out);
+            out.push_str("}\n");
+        },
+        Typ::LengthDelimited => match guess(bytes) {
+            Reading::Message if depth + 1 >= DUMP_DEPTH_MAX => {
+                out.push_str(&format!("{}: \"{}\"  # bytes, {} bytes\n", tag, escape(bytes), bytes.len()));
+            },
+            Reading::Text(text) => {
+                out.push_str(&format!("{}: \"{}\"  # string, {} bytes\n", tag, escape(text.as_bytes()), bytes.len()));
+            },
+            Reading::Message => {
+                out.push_str(&format!("{} {{  # message, {} bytes\n", tag, bytes.len()));
+                dump_fields(bytes, offset, depth + 1, options, out);
+                print_prefix(None, depth, options, out);
+                out.push_str("}\n");
+            },
+            Reading::Packed(vals) => {
+                let vals = vals.iter().map(|v| v.to_string()).collect::<Vec<_>>();
+                out.push_str(&format!("{}: [{}]  # packed varints, {} bytes\n", tag, vals.join(", "), bytes.len()));
+            },
+            Reading::Bytes => {
+                out.push_str(&format!("{}: \"{}\"  # bytes, {} bytes\n", tag, escape(bytes), bytes.len()));
+            },
+        },
+        Typ::EndGroup => {
+            out.push_str(&format!("{}: \"{}\"  # end group\n", tag, escape(bytes)));
+        },
+    }
+}
+
+/// Provides the readings of the bytes of a length-delimited field.
+#[derive(Debug, PartialEq)]
+enum Reading<'a> {
+    /// Printable UTF-8 text.
+    Text(&'a str),
+
+    /// An embedded message holding valid fields.
+    Message,
+
+    /// A packed array of varints.
+    Packed(Vec<u64>),
+
+    /// Raw bytes.
+    Bytes,
+}
+
+/// Returns the most plausible reading of the bytes of a length-delimited
+/// field. Text is preferred over a message since short strings often happen
+/// to hold valid fields.
+fn guess(bytes: &[u8]) -> Reading<'_> {
+    if bytes.is_empty() {
+        return Reading::Text("");
+    }
+    match std::str::from_utf8(bytes) {
+        Ok(text) if text.chars().all(|c| !c.is_control() || c == '\n' || c == '\r' || c == '\t') => {
+            return Reading::Text(text);
+        },
+        _ => {},
+    }
+    if FieldIter::new(bytes).all(|field| field.is_ok()) {
+        return Reading::Message;
+    }
+    match decode_packed(bytes) {
+        Some(vals) => Reading::Packed(vals),
+        None => Reading::Bytes,
+    }
+}
+
+/// Returns the varints of a packed array or `None` when the bytes do not hold
+/// a sequence of canonically encoded varints.
+fn decode_packed(bytes: &[u8]) -> Option<Vec<u64>> {
+    let mut vals = Vec::new();
+    let mut pos = 0;
+    while pos < bytes.len() {
+        let mut val = 0;
+        let size = decode_varint(&bytes[pos..], &mut val).ok()?;
+        if size > 1 && bytes[pos + size - 1] == 0 { // overlong encoding
+            return None;
+        }
+        vals.push(val);
+        pos += size;
+    }
+    Some(vals)
+}
+
+/// Writes the offset of a line when enabled followed by the indentation of
+/// the provided `depth`. Lines without an offset are aligned with the others.
+fn print_prefix(offset: Option<usize>, depth: usize, options: &DumpOptions, out: &mut String) {
+    if options.offsets {
+        match offset {
+            Some(offset) => out.push_str(&format!("[{:#06x}] ", offset)),
+            None => out.push_str("         "),
+        }
+    }
+    for _ in 0..depth {
+        out.push_str("  ");
+    }
+}
+
+/// Returns the provided `bytes` as the content of a quoted string. Bytes which
+/// are not printable ASCII characters are written as octal escapes except for
+/// valid UTF-8 characters.
+fn escape(bytes: &[u8]) -> String {
+    let mut escaped = String::with_capacity(bytes.len());
+    for chunk in bytes.utf8_chunks() {
+        for c in chunk.valid().chars() {
+            match c {
+                '\n' => escaped.push_str("\\n"),
+                '\r' => escaped.push_str("\\r"),
+                '\t' => escaped.push_str("\\t"),
+                '"' => escaped.push_str("\\\""),
+                '\\' => escaped.push_str("\\\\"),
+                c if c.is_control() => escaped.push_str(&format!("\\{:03o}", c as u32)),
+                c => escaped.push(c),
+            }
+        }
+        for b in chunk.invalid() {
+            escaped.push_str(&format!("\\{:03o}", b));
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Should guess the readings of length-delimited fields.
+    #[test]
+    fn guesses_readings() {
+        assert_eq!(guess(b"hi"), Reading::Text("hi"));
+        assert_eq!(guess("\u{e9}\n".as_bytes()), Reading::Text("\u{e9}\n"));
+        assert_eq!(guess(&[0x08, 0x01, 0x12, 0x01, b'a']), Reading::Message);
+        assert_eq!(guess(&[0x01, 0x96, 0x01, 0x03]), Reading::Packed(vec![1, 150, 3]));
+        assert_eq!(guess(&[0x01, 0x80, 0x00]), Reading::Bytes);
+        assert_eq!(guess(&[0xff]), Reading::Bytes);
+    }
+
+    /// Should dump nested messages, groups and invalid bytes.
+    #[test]
+    fn dumps_messages() {
+        let bytes = [
+            0x0d, 0x00, 0x00, 0x80, 0x3f, // 1: fixed32
+            0x10, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x01, // 2: -1
+            0x1a, 0x08, // 3: message
+            0x0a, 0x02, b'h', b'i', // 1: "hi"
+            0x2b, 0x08, 0x02, 0x2c, // 5: group
+            0x22, 0x02, 0xff, 0x00, // 4: bytes
+            0x30, // 6: missing value
+        ];
+        assert_eq!(dump(&bytes, &DumpOptions { offsets: true }), concat!(
+            "[0x0000] 1: 0x3f800000  # fixed32, float 1\n",
+            "[0x0005] 2: 18446744073709551615  # varint, int64 -1\n",
+            "[0x0010] 3 {  # message, 8 bytes\n",
+            "[0x0012]   1: \"hi\"  # string, 2 bytes\n",
+            "[0x0016]   5 {  # group\n",
+            "[0x0017]     1: 2  # varint\n",
+            "           }\n",
+            "         }\n",
+            "[0x001a] 4: \"\\377\\000\"  # bytes, 2 bytes\n",
+            "[0x001e] # Not enough bytes. Remaining bytes: \"0\"\n",
+        ));
+    }
+
+    /// Should stop guessing embedded messages past the depth limit.
+    #[test]
+    fn limits_nesting_depth() {
+        let mut bytes = Vec::new();
+        for _ in 0..20_000 {
+            let mut outer = vec![0x0a];
+            let mut len = bytes.len();
+            while len >= 0x80 {
+                outer.push(len as u8 | 0x80);
+                len >>= 7;
+            }
+            outer.push(len as u8);
+            outer.extend(bytes);
+            bytes = outer;
+        }
+        let out = dump(&bytes, &DumpOptions::default());
+        assert_eq!(out.matches("# message").count(), DUMP_DEPTH_MAX - 1);
+        assert_eq!(out.matches("# bytes").count(), 1);
+
+        let bytes = [0x0b; 300].iter().chain([0x0c; 300].iter()).copied().collect::<Vec<_>>(); // nested groups
+        let out = dump(&bytes, &DumpOptions::default());
+        assert!(out.starts_with("# Fields nested too deep."));
+    }
+}
@@ -0,0 +1,31 @@
+use std::error;
+use std::fmt;
+
+/// Contains error options that can be encountered while accessing the fields of
+/// a dynamic message.
+#[derive(Debug, Clone, PartialEq)]
+pub enum DynamicError {
+    /// Indicates that the schema does not define a message with the provided
+    /// fully-qualified name.
+    UnknownMessage(String),
+
+    /// Indicates that the message does not define a field with the provided
+    /// name.
+    UnknownField(String),
+
+    /// Indicates that the value does not match the type or the cardinality of
+    /// the field with the provided name.
+    UnexpectedValue(String),
+}
+
+impl fmt::Display for DynamicError {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::UnknownMessage(name) => write!(fmt, "Unknown message {}.", name),
+            Self::UnknownField(name) => write!(fmt, "Unknown field {}.", name),
+            Self::UnexpectedValue(name) => write!(fmt, "Unexpected value for field {}.", name),
+        }
+    }
+}
+
+impl error::Error for DynamicError {}
@@ -0,0 +1,831 @@
+//! Provides messages whose fields are described by a schema at runtime.
+//!
+//! A `DynamicMessage` uses a message descriptor of a loaded `Schema` to turn
+//! the encoded fields into named and typed values, without generated code.
+//! Embedded messages, packed and unpacked repeated fields and maps are decoded
+//! recursively. Fields which the descriptor does not know are kept as they are
+//! and written back when the message is encoded.
+//!
+//! ```rust
+//! use httlib_protos::dynamic::{DynamicMessage, Value};
+//! use httlib_protos::schema::Schema;
+//!
+//! let mut schema = Schema::new();
+//! schema.add("point.proto", r#"
+//!     syntax = "proto3";
+//!     message Point {
+//!         sint32 x = 1;
+//!         repeated string tags = 2;
+//!     }
+//! "#).unwrap();
+//!
+//! let mut point = DynamicMessage::new(&schema, ".Point").unwrap();
+//! point.merge(&[0x08, 0x02, 0x12, 0x01, b'a']).unwrap();
+//! assert_eq!(point.get("x"), Some(&Value::Int32(1)));
+//!
+//! point.set("x", -1).unwrap();
+//! let mut dst = Vec::new();
+//! point.encode(&mut dst).unwrap();
+//! assert_eq!(dst, vec![0x08, 0x01, 0x12, 0x01, b'a']);
+//! ```
+
+mod error;
+mod value;
+
+use std::collections::{BTreeMap, VecDeque};
+use std::fmt;
+use std::io;
+use std::str;
+use crate::{DecoderError, Encoder, EncoderError, EncoderLit, FieldIter, MessageEncoder, Typ};
+use crate::decoder::primitives::*;
+use crate::schema::{FieldDescriptor, FieldType, Label, MessageDescriptor, Schema};
+pub use error::*;
+pub use value::*;
+
+/// The maximum number of embedded messages a dynamic message decodes through.
+pub const MESSAGE_DEPTH_MAX: usize = 100;
+
+/// Provides a message of a type that is only known at runtime.
+///
+/// Field values are kept by their tag number. A singular field holding the
+/// default value is left out when the message is encoded, the same way as
+/// typed messages do it.
+#[derive(Clone)]
+pub struct DynamicMessage<'a> {
+    /// The schema which resolves the types of embedded messages.
+    schema: &'a Schema,
+
+    /// The descriptor of the message.
+    descriptor: &'a MessageDescriptor,
+
+    /// The values of the set fields by their tag number.
+    fields: BTreeMap<u32, Value<'a>>,
+
+    /// The fields which are not defined by the descriptor as `(tag, type,
+    /// bytes)` tuples in the order in which they were decoded.
+    unknown: Vec<(u32, Typ, Vec<u8>)>,
+}
+
+impl<'a> DynamicMessage<'a> {
+    /// Returns a new empty message of the type with the provided
+    /// fully-qualified name (e.g. `.pkg.Name`).
+    ///
+    /// On success the message is returned otherwise an error is thrown.
+    pub fn new(schema: &'a Schema, full_name: &str) -> Result<Self, DynamicError> {
+        let descriptor = schema.message(full_name)
+            .ok_or_else(|| DynamicError::UnknownMessage(full_name.to_string()))?;
+        Ok(Self::with_descriptor(schema, descriptor))
+    }
+
+    /// Returns a new empty message described by the `descriptor` of a message
+    /// in the `schema`.
+    pub fn with_descriptor(schema: &'a Schema, descriptor: &'a MessageDescriptor) -> Self {
+        Self {
+            schema,
+            descriptor,
+            fields: BTreeMap::new(),
+            unknown: Vec::new(),
+        }
+    }
+
+    /// Returns the schema of the message.
+    pub fn schema(&self) -> &'a Schema {
+        self.schema
+    }
+
+    /// Returns the descriptor of the message.
+    pub fn descriptor(&self) -> &'a MessageDescriptor {
+        self.descriptor
+    }
+
+    /// Returns the value of the field with the provided `name` if the field is
+    /// set.
+    pub fn get(&self, name: &str) -> Option<&Value<'a>> {
+        let field = self.descriptor.field_by_name(name)?;
+        self.fields.get(&field.number)
+    }
+
+    /// Returns the value of the field with the provided `name` or its default
+    /// value if the field is not set. Returns `None` if the field does not
+    /// exist.
+    pub fn get_or_default(&self, name: &str) -> Option<Value<'a>> {
+        let field = self.descriptor.field_by_name(name)?;
+        match self.fields.get(&field.number) {
+            Some(value) => Some(value.clone()),
+            None => self.default_value(field),
+        }
+    }
+
+    /// Tells whether the field with the provided `name` is set.
+    pub fn has(&self, name: &str) -> bool {
+        self.get(name).is_some()
+    }
+
+    /// Sets the value of the field with the provided `name`. Repeated fields
+    /// take a `List` and map fields take a `Map`. Setting a field of a oneof
+    /// clears the other fields of the same oneof.
+    ///
+    /// On success the function returns otherwise an error is thrown.
+    pub fn set<V: Into<Value<'a>>>(&mut self, name: &str, value: V) -> Result<(), DynamicError> {
+        let field = self.descriptor.field_by_name(name)
+            .ok_or_else(|| DynamicError::UnknownField(name.to_string()))?;
+        let value = value.into();
+        let valid = match (&field.typ, &value) {
+            (FieldType::Map(key, val), Value::Map(map)) => {
+                map.iter().all(|(k, v)| k.to_value().is_type(key) && v.is_type(val))
+            },
+            (FieldType::Map(..), _) => false,
+            (typ, Value::List(vals)) if field.label == Label::Repeated => vals.iter().all(|v| v.is_type(typ)),
+            _ if field.label == Label::Repeated => false,
+            (typ, value) => value.is_type(typ),
+        };
+        if !valid {
+            return Err(DynamicError::UnexpectedValue(name.to_string()));
+        }
+        self.clear_oneof(field);
+        self.fields.insert(field.number, value);
+        Ok(())
+    }
+
+    /// Clears the field with the provided `name` and returns its value if the
+    /// field was set.
+    pub fn remove(&mut self, name: &str) -> Option<Value<'a>> {
+        let field = self.descriptor.field_by_name(name)?;
+        self.fields.remove(&field.number)
+    }
+
+    /// Returns an iterator over the set fields and their values in the order
+    /// of tag numbers.
+    pub fn fields(&self) -> impl Iterator<Item = (&'a FieldDescriptor, &Value<'a>)> + '_ {
+        let descriptor = self.descriptor;
+        self.fields.iter().filter_map(move |(tag, value)| Some((descriptor.field(*tag)?, value)))
+    }
+
+    /// Returns the fields which are not defined by the descriptor as `(tag,
+    /// type, bytes)` tuples.
+    pub fn unknown_fields(&self) -> &[(u32, Typ, Vec<u8>)] {
+        &self.unknown
+    }
+
+    /// Returns the default value of the provided `field` of this message. The
+    /// default value of an embedded message is an empty message.
+    pub fn default_value(&self, field: &FieldDescriptor) -> Option<Value<'a>> {
+        match (&field.typ, field.label) {
+            (FieldType::Map(..), _) => Some(Value::Map(BTreeMap::new())),
+            (_, Label::Repeated) => Some(Value::List(Vec::new())),
+            (typ, _) => default_value(self.schema, typ),
+        }
+    }
+
+    /// Decodes the fields from the provided `buf` and merges them into the
+    /// message.
+    ///
+    /// Following the protocol, singular fields are overwritten, repeated
+    /// fields and maps are extended and embedded messages are merged
+    /// recursively.
+    ///
+    /// Embedded messages can be nested at most `MESSAGE_DEPTH_MAX` levels deep
+    /// otherwise the `DepthOverflow` error is thrown.
+    pub fn merge(&mut self, buf: &[u8]) -> Result<(), DecoderError> {
+        self.merge_nested(buf, 0)
+    }
+
+    /// Merges a single field with a specific `tag` number, wire type `typ` and
+    /// the value `bytes` into the message.
+    pub fn merge_field(&mut self, tag: u32, typ: Typ, bytes: &[u8]) -> Result<(), DecoderError> {
+        self.merge_field_nested(tag, typ, bytes, 0)
+    }
+
+    /// Decodes the fields from the provided `buf` of a message which is
+    /// embedded `depth` levels deep and merges them into the message.
+    fn merge_nested(&mut self, buf: &[u8], depth: usize) -> Result<(), DecoderError> {
+        if depth >= MESSAGE_DEPTH_MAX {
+            return Err(DecoderError::DepthOverflow);
+        }
+        for field in FieldIter::new(buf) {
+            let (tag, typ, bytes) = field?;
+            self.merge_field_nested(tag, typ, bytes, depth)?;
+        }
+        Ok(())
+    }
+
+    /// Merges a single field into the message which is embedded `depth` levels
+    /// deep.
+    fn merge_field_nested(&mut self, tag: u32, typ: Typ, bytes: &[u8], depth: usize) -> Result<(), DecoderError> {
+        let field = match self.descriptor.field(tag) {
+            Some(field) => field,
+            None => {
+                self.unknown.push((tag, typ, bytes.to_vec()));
+                return Ok(());
+            },
+        };
+        self.clear_oneof(field);
+
+        match &field.typ {
+            FieldType::Map(key, val) => {
+                let (key, val) = decode_entry(self.schema, key, val, typ, bytes, depth)?;
+                if let Value::Map(map) = self.fields.entry(tag).or_insert_with(|| Value::Map(BTreeMap::new())) {
+                    map.insert(key, val);
+                }
+            },
+            field_type if field.label == Label::Repeated => {
+                let vals = if typ == Typ::LengthDelimited && field_type.is_packable() {
+                    decode_packed(field_type, bytes)?
+                } else {
+                    vec![decode_value(self.schema, field_type, typ, bytes, depth)?]
+                };
+                if let Value::List(list) = self.fields.entry(tag).or_insert_with(|| Value::List(Vec::new())) {
+                    list.extend(vals);
+                }
+            },
+            FieldType::Message(_) if typ == Typ::LengthDelimited => match self.fields.get_mut(&tag) {
+                Some(Value::Message(message)) => message.merge_nested(bytes, depth + 1)?,
+                _ => {
+                    let value = decode_value(self.schema, &field.typ, typ, bytes, depth)?;
+                    self.fields.insert(tag, value);
+                },
+            },
+            field_type => {
+                let value = decode_value(self.schema, field_type, typ, bytes, depth)?;
+                self.fields.insert(tag, value);
+            },
+        }
+        Ok(())
+    }
+
+    /// Encodes the message and writes the resulting bytes into `dst`.
+    ///
+    /// On success the number of written bytes is returned otherwise an error is
+    /// thrown.
+    pub fn encode<W>(&self, dst: &mut W) -> Result<usize, EncoderError>
+    where
+        W: ?Sized + io::Write,
+    {
+        Encoder.encode_fields(dst, |encoder| self.encode_fields(encoder))
+    }
+
+    /// Returns the number of bytes the encoded message takes.
+    ///
+    /// On success the size is returned otherwise an error is thrown.
+    pub fn encoded_len(&self) -> Result<usize, EncoderError> {
+        let mut sink = io::sink();
+        let mut sizes = VecDeque::new();
+        let mut encoder = MessageEncoder::new(&mut sink, &mut sizes, true);
+        self.encode_fields(&mut encoder)?;
+        Ok(encoder.size())
+    }
+
+    /// Writes the fields of the message through the provided `encoder`. Known
+    /// fields are written in the order of tag numbers followed by the unknown
+    /// fields.
+    pub fn encode_fields(&self, encoder: &mut MessageEncoder) -> Result<(), EncoderError> {
+        for (field, value) in self.fields() {
+            match value {
+                Value::Map(map) => if let FieldType::Map(key_type, val_type) = &field.typ {
+                    for (key, val) in map {
+                        encoder.encode_message(&field.number, |entry| {
+                            encode_value(1, key_type, &key.to_value(), entry)?;
+                            encode_value(2, val_type, val, entry)?;
+                            Ok(())
+                        })?;
+                    }
+                },
+                Value::List(vals) if field.is_packed() => if !vals.is_empty() {
+                    encode_packed(field.number, &field.typ, vals, encoder)?;
+                },
+                Value::List(vals) => for val in vals {
+                    encode_value(field.number, &field.typ, val, encoder)?;
+                },
+                value if field.label == Label::Singular && field.oneof.is_none() && value.is_default() => {},
+                value => {
+                    encode_value(field.number, &field.typ, value, encoder)?;
+                },
+            }
+        }
+        for (tag, typ, bytes) in &self.unknown {
+            encode_unknown(*tag, *typ, bytes, encoder)?;
+        }
+        Ok(())
+    }
+
+    /// Clears the other fields of the oneof the `field` belongs to.
+    fn clear_oneof(&mut self, field: &FieldDescriptor) {
+        if let Some(oneof) = field.oneof {
+            for other in self.descriptor.fields.iter().filter(|f| f.oneof == Some(oneof) && f.number != field.number) {
+                self.fields.remove(&other.number);
+            }
+        }
+    }
+}
+
+impl<'a> fmt::Debug for DynamicMessage<'a> {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.debug_struct("DynamicMessage")
+            .field("name", &self.descriptor.full_name)
+            .field("fields", &self.fields)
+            .field("unknown", &self.unknown)
+            .finish()
+    }
+}
+
+impl<'a> PartialEq for DynamicMessage<'a> {
+    fn eq(&self, other: &Self) -> bool {
+        self.descriptor.full_name == other.descriptor.full_name
+            && self.fields == other.fields
+            && self.unknown == other.unknown
+    }
+}
+
+/// Returns the default value of a single value of the provided type.
+pub(crate) fn default_value<'a>(schema: &'a Schema, typ: &FieldType) -> Option<Value<'a>> {
+    Some(match typ {
+        FieldType::Bool => Value::Bool(false),
+        FieldType::Int32 | FieldType::SInt32 | FieldType::SFixed32 => Value::Int32(0),
+        FieldType::Int64 | FieldType::SInt64 | FieldType::SFixed64 => Value::Int64(0),
+        FieldType::UInt32 | FieldType::Fixed32 => Value::UInt32(0),
+        FieldType::UInt64 | FieldType::Fixed64 => Value::UInt64(0),
+        FieldType::Float => Value::Float(0.0),
+        FieldType::Double => Value::Double(0.0),
+        FieldType::String => Value::String(String::new()),
+        FieldType::Bytes => Value::Bytes(Vec::new()),
+        FieldType::Enum(_) => Value::Enum(0),
+        FieldType::Message(name) => Value::Message(DynamicMessage::with_descriptor(schema, schema.message(name)?)),
+        FieldType::Named(_) | FieldType::Map(..) => return None,
+    })
+}
+
+/// Decodes a single value of the provided type from the value `bytes` of a
+/// field with the wire type `typ`. The `depth` tells how deep the message
+/// holding the field is embedded.
+fn decode_value<'a>(schema: &'a Schema, field_type: &FieldType, typ: Typ, bytes: &[u8], depth: usize) -> Result<Value<'a>, DecoderError> {
+    macro_rules! decode {
+        ($decode:ident, $variant:ident, $init:expr) => {{
+            let mut val = $init;
+            $decode(bytes, &mut val)?;
+            Value::$variant(val)
+        }};
+    }
+
+    if typ != field_type.wire_type() {
+        return Err(DecoderError::UnexpectedWireType);
+    }
+    Ok(match field_type {
+        FieldType::Bool => decode!(decode_bool, Bool, false),
+        FieldType::Int32 => decode!(decode_int32, Int32, 0),
+        FieldType::SInt32 => decode!(decode_sint32, Int32, 0),
+        FieldType::SFixed32 => decode!(decode_sfixed32, Int32, 0),
+        FieldType::Int64 => decode!(decode_int64, Int64, 0),
+        FieldType::SInt64 => decode!(decode_sint64, Int64, 0),
+        FieldType::SFixed64 => decode!(decode_sfixed64, Int64, 0),
+        FieldType::UInt32 => decode!(decode_uint32, UInt32, 0),
+        FieldType::Fixed32 => decode!(decode_fixed32, UInt32, 0),
+        FieldType::UInt64 => decode!(decode_uint64, UInt64, 0),
+        FieldType::Fixed64 => decode!(decode_fixed64, UInt64, 0),
+        FieldType::Float => decode!(decode_float, Float, 0.0),
+        FieldType::Double => decode!(decode_double, Double, 0.0),
+        FieldType::Enum(_) => decode!(decode_int32, Enum, 0),
+        FieldType::String => {
+            let text = str::from_utf8(bytes).map_err(|_| DecoderError::InvalidInput)?;
+            Value::String(text.to_string())
+        },
+        FieldType::Bytes => Value::Bytes(bytes.to_vec()),
+        FieldType::Message(name) => {
+            let descriptor = schema.message(name).ok_or(DecoderError::InvalidInput)?;
+            let mut message = DynamicMessage::with_descriptor(schema, descriptor);
+            message.merge_nested(bytes, depth + 1)?;
+            Value::Message(message)
+        },
+        FieldType::Named(_) | FieldType::Map(..) => return Err(DecoderError::InvalidInput),
+    })
+}
+
+/// Decodes the values of a packed repeated field of the provided type.
+fn decode_packed<'a>(field_type: &FieldType, bytes: &[u8]) -> Result<Vec<Value<'a>>, DecoderError> {
+    macro_rules! decode {
+        ($decode:ident, $variant:ident) => {{
+            let mut vals = Vec::new();
+            if !bytes.is_empty() {
+                $decode(bytes, &mut vals)?;
+            }
+            vals.into_iter().map(Value::$variant).collect()
+        }};
+    }
+
+    Ok(match field_type {
+        FieldType::Bool => decode!(decode_bool_vec, Bool),
+        FieldType::Int32 => decode!(decode_int32_vec, Int32),
+        FieldType::SInt32 => decode!(decode_sint32_vec, Int32),
+        FieldType::SFixed32 => decode!(decode_sfixed32_vec, Int32),
+        FieldType::Int64 => decode!(decode_int64_vec, Int64),
+        FieldType::SInt64 => decode!(decode_sint64_vec, Int64),
+        FieldType::SFixed64 => decode!(decode_sfixed64_vec, Int64),
+        FieldType::UInt32 => decode!(decode_uint32_vec, UInt32),
+        FieldType::Fixed32 => decode!(decode_fixed32_vec, UInt32),
+        FieldType::UInt64 => decode!(decode_uint64_vec, UInt64),
+        FieldType::Fixed64 => decode!(decode_fixed64_vec, UInt64),
+        FieldType::Float => decode!(decode_float_vec, Float),
+        FieldType::Double => decode!(decode_double_vec, Double),
+        FieldType::Enum(_) => decode!(decode_int32_vec, Enum),
+        _ => return Err(DecoderError::UnexpectedWireType),
+    })
+}
+
+/// Decodes a map entry of the provided key and value types. A missing key or
+/// value takes the default value.
+fn decode_entry<'a>(schema: &'a Schema, key_type: &FieldType, val_type: &FieldType, typ: Typ, bytes: &[u8], depth: usize) -> Result<(MapKey, Value<'a>), DecoderError> {
+    if typ != Typ::LengthDelimited {
+        return Err(DecoderError::UnexpectedWireType);
+    }
+    let mut key = None;
+    let mut val = None;
+    for field in FieldIter::new(bytes) {
+        match field? {
+            (1, typ, bytes) => key = Some(decode_value(schema, key_type, typ, bytes, depth)?),
+            (2, typ, bytes) => val = Some(decode_value(schema, val_type, typ, bytes, depth)?),
+            _ => {},
+        }
+    }
+    let key = key.or_else(|| default_value(schema, key_type)).and_then(MapKey::from_value);
+    let val = val.or_else(|| default_value(schema, val_type));
+    match (key, val) {
+        (Some(key), Some(val)) => Ok((key, val)),
+        _ => Err(DecoderError::InvalidInput),
+    }
+}
+
+/// Encodes a single value of a field with a specific `tag` number and type.
+/// Values which do not match the type are skipped since they are validated
+/// when they are set.
+fn encode_value(tag: u32, field_type: &FieldType, value: &Value, encoder: &mut MessageEncoder) -> Result<usize, EncoderError> {
+    match (field_type, value) {
+        (FieldType::Bool, Value::Bool(v)) => encoder.encode((&tag, EncoderLit::Bool(v))),
+        (FieldType::Int32, Value::Int32(v)) => encoder.encode((&tag, EncoderLit::Int32(v))),
+        (FieldType::SInt32, Value::Int32(v)) => encoder.encode((&tag, EncoderLit::SInt32(v))),
+        (FieldType::SFixed32, Value::Int32(v)) => encoder.encode((&tag, EncoderLit::SFixed32(v))),
+        (FieldType::Int64, Value::Int64(v)) => encoder.encode((&tag, EncoderLit::Int64(v))),
+        (FieldType::SInt64, Value::Int64(v)) => encoder.encode((&tag, EncoderLit::SInt64(v))),
+        (FieldType::SFixed64, Value::Int64(v)) => encoder.encode((&tag, EncoderLit::SFixed64(v))),
+        (FieldType::UInt32, Value::UInt32(v)) => encoder.encode((&tag, EncoderLit::UInt32(v))),
+        (FieldType::Fixed32, Value::UInt32(v)) => encoder.encode((&tag, EncoderLit::Fixed32(v))),
+        (FieldType::UInt64, Value::UInt64(v)) => encoder.encode((&tag, EncoderLit::UInt64(v))),
+        (FieldType::Fixed64, Value::UInt64(v)) => encoder.encode((&tag, EncoderLit::Fixed64(v))),
+        (FieldType::Float, Value::Float(v)) => encoder.encode((&tag, EncoderLit::Float(v))),
+        (FieldType::Double, Value::Double(v)) => encoder.encode((&tag, EncoderLit::Double(v))),
+        (FieldType::Enum(_), Value::Enum(v)) => encoder.encode((&tag, EncoderLit::Int32(v))),
+        (FieldType::String, Value::String(v)) => encoder.encode_bytes(&tag, v.as_bytes()),
+        (FieldType::Bytes, Value::Bytes(v)) => encoder.encode_bytes(&tag, v),
+        (FieldType::Message(_), Value::Message(message)) => {
+            encoder.encode_message(&tag, |fields| message.encode_fields(fields))
+        },
+        _ => Ok(0),
+    }
+}
+
+/// Encodes the values of a packed repeated field with a specific `tag` number
+/// and type.
+fn encode_packed(tag: u32, field_type: &FieldType, vals: &[Value], encoder: &mut MessageEncoder) -> Result<usize, EncoderError> {
+    macro_rules! encode {
+        ($variant:ident, $lit:ident) => {{
+            let vals = vals.iter().filter_map(|v| match v {
+                Value::$variant(v) => Some(*v),
+                _ => None,
+            }).collect::<Vec<_>>();
+            encoder.encode((&tag, EncoderLit::$lit(&vals)))
+        }};
+    }
+
+    match field_type {
+        FieldType::Bool => encode!(Bool, BoolVec),
+        FieldType::Int32 => encode!(Int32, Int32Vec),
+        FieldType::SInt32 => encode!(Int32, SInt32Vec),
+        FieldType::SFixed32 => encode!(Int32, SFixed32Vec),
+        FieldType::Int64 => encode!(Int64, Int64Vec),
+        FieldType::SInt64 => encode!(Int64, SInt64Vec),
+        FieldType::SFixed64 => encode!(Int64, SFixed64Vec),
+        FieldType::UInt32 => encode!(UInt32, UInt32Vec),
+        FieldType::Fixed32 => encode!(UInt32, Fixed32Vec),
+        FieldType::UInt64 => encode!(UInt64, UInt64Vec),
+        FieldType::Fixed64 => encode!(UInt64, Fixed64Vec),
+        FieldType::Float => encode!(Float, FloatVec),
+        FieldType::Double => encode!(Double, DoubleVec),
+        FieldType::Enum(_) => encode!(Enum, Int32Vec),
+        _ => Ok(0),
+    }
+}
+
+/// Encodes an unknown field with a specific `tag` number, wire type `typ` and
+/// the value `bytes` as it was decoded.
+fn encode_unknown(tag: u32, typ: Typ, bytes: &[u8], encoder: &mut MessageEncoder) -> Result<usize, EncoderError> {
+    match typ {
+        Typ::Varint => {
+            let mut val = 0;
+            decode_varint(bytes, &mut val).map_or(Ok(0), |_| encoder.encode((&tag, EncoderLit::UInt64(&val))))
+        },
+        Typ::Bit32 => {
+            let mut val = 0;
+            decode_fixed32(bytes, &mut val).map_or(Ok(0), |_| encoder.encode((&tag, EncoderLit::Fixed32(&val))))
+        },
+        Typ::Bit64 => {
+            let mut val = 0;
+            decode_fixed64(bytes, &mut val).map_or(Ok(0), |_| encoder.encode((&tag, EncoderLit::Fixed64(&val))))
+        },
+        Typ::LengthDelimited => encoder.encode_bytes(&tag, bytes),
+        Typ::StartGroup => encoder.encode_group(&tag, |group| {
+            for (tag, typ, bytes) in FieldIter::new(bytes).flatten() {
+                encode_unknown(tag, typ, bytes, group)?;
+            }
+            Ok(())
+        }),
+        Typ::EndGroup => Ok(0),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn schema() -> Schema {
+        let mut schema = Schema::new();
+        schema.add("order.proto", r#"
+            syntax = "proto3";
+            package shop;
+            message Order {
+                enum Status {
+                    UNKNOWN = 0;
+                    PAID = 1;
+                }
+                message Item {
+                    string name = 1;
+                    fixed32 count = 2;
+                }
+                uint64 id = 1;
+                Status status = 2;
+                repeated sint32 amounts = 3;
+                repeated Item items = 4;
+                map<string, sint64> prices = 5;
+                Item main = 6;
+                oneof payment {
+                    string card = 7;
+                    bytes token = 8;
+                }
+            }
+        "#).unwrap();
+        schema
+    }
+
+    /// Should decode fields into named values and encode them back.
+    #[test]
+    fn decodes_dynamic_messages() {
+        let schema = schema();
+        let src = vec![
+            0x08, 0x07, // id: 7
+            0x10, 0x01, // status: PAID
+            0x1a, 0x02, 0x01, 0x04, // amounts: [-1, 2] (packed)
+            0x22, 0x08, 0x0a, 0x01, b'a', 0x15, 0x02, 0x00, 0x00, 0x00, // items: [{ name: "a", count: 2 }]
+            0x2a, 0x05, 0x0a, 0x01, b'b', 0x10, 0x03, // prices: { "b": -2 }
+            0x3a, 0x01, b'x', // card: "x"
+            0x50, 0x01, // unknown field 10
+        ];
+
+        let mut order = DynamicMessage::new(&schema, ".shop.Order").unwrap();
+        order.merge(&src).unwrap();
+        assert_eq!(order.get("id"), Some(&Value::UInt64(7)));
+        assert_eq!(order.get("status"), Some(&Value::Enum(1)));
+        assert_eq!(order.get("amounts"), Some(&Value::List(vec![Value::Int32(-1), Value::Int32(2)])));
+        let item = order.get("items").and_then(|v| v.as_list()).and_then(|l| l[0].as_message()).unwrap();
+        assert_eq!(item.get("name").and_then(|v| v.as_str()), Some("a"));
+        assert_eq!(item.get("count"), Some(&Value::UInt32(2)));
+        let prices = order.get("prices").and_then(|v| v.as_map()).unwrap();
+        assert_eq!(prices.get(&MapKey::from("b")), Some(&Value::Int64(-2)));
+        assert_eq!(order.get("card"), Some(&Value::String("x".to_string())));
+        assert_eq!(order.get("main"), None);
+        assert!(order.get_or_default("main").unwrap().as_message().is_some());
+        assert_eq!(order.unknown_fields(), &[(10, Typ::Varint, vec![0x01])]);
+
+        let mut dst = Vec::new();
+        assert_eq!(order.encode(&mut dst).unwrap(), src.len());
+        assert_eq!(dst, src);
+        assert_eq!(order.encoded_len(), Ok(src.len()));
+
+        let mut order = DynamicMessage::new(&schema, ".shop.Order").unwrap();
+        order.merge(&[0x18, 0x01, 0x18, 0x04]).unwrap(); // unpacked amounts
+        assert_eq!(order.get("amounts"), Some(&Value::List(vec![Value::Int32(-1), Value::Int32(2)])));
+        assert_eq!(order.merge(&[0x0d, 0x00, 0x00, 0x00, 0x00]), Err(DecoderError::UnexpectedWireType));
+    }
+
+    /// Should set fields by name and validate the values.
+    #[test]
+    fn sets_dynamic_fields() {
+        let schema = schema();
+        let mut order = DynamicMessage::new(&schema, ".shop.Order").unwrap();
+        order.set("id", 0u64).unwrap();
+        order.set("card", "x").unwrap();
+        order.set("token", vec![1]).unwrap();
+        assert!(!order.has("card"));
+        let mut item = DynamicMessage::new(&schema, ".shop.Order.Item").unwrap();
+        item.set("count", 1u32).unwrap();
+        order.set("main", item).unwrap();
+        let mut prices = BTreeMap::new();
+        prices.insert(MapKey::from("a"), Value::Int64(1));
+        order.set("prices", prices).unwrap();
+
+        let mut dst = Vec::new();
+        order.encode(&mut dst).unwrap();
+        assert_eq!(dst, vec![
+            0x2a, 0x05, 0x0a, 0x01, b'a', 0x10, 0x02, // prices: { "a": 1 }
+            0x32, 0x05, 0x15, 0x01, 0x00, 0x00, 0x00, // main: { count: 1 }
+            0x42, 0x01, 0x01, // token: [1]
+        ]);
+
+        assert_eq!(order.set("id", 1i32), Err(DynamicError::UnexpectedValue("id".to_string())));
+        assert_eq!(order.set("amounts", 1i32), Err(DynamicError::UnexpectedValue("amounts".to_string())));
+        assert_eq!(order.set("main", DynamicMessage::new(&schema, ".shop.Order").unwrap()), Err(DynamicError::UnexpectedValue("main".to_string())));
+        assert_eq!(order.set("missing", 1i32), Err(DynamicError::UnknownField("missing".to_string())));
+        assert!(DynamicMessage::new(&schema, ".shop.Missing").is_err());
+        assert_eq!(order.remove("token"), Some(Value::Bytes(vec![1])));
+    }
+
+    fn sample_schema() -> Schema {
+        let mut schema = Schema::new();
+        schema.add("sample.proto", r#"
+            syntax = "proto3";
+            package test;
+            enum Kind {
+                KIND_NONE = 0;
+                KIND_A = 1;
+            }
+            message Entry {
+                string name = 1;
+            }
+            message Sample {
+                repeated fixed32 fixed = 1;
+                repeated double ratios = 2;
+                repeated Kind kinds = 3;
+                repeated bool flags = 4;
+                repeated sint64 values = 5;
+                map<int32, Entry> entries = 6;
+                map<bool, bytes> blobs = 7;
+                Entry main = 8;
+                repeated string names = 9;
+            }
+        "#).unwrap();
+        schema
+    }
+
+    /// Should decode packed and unpacked repeated values of the same field
+    /// into one list and merge map entries by their keys.
+    #[test]
+    fn decodes_packed_fields_and_maps() {
+        let schema = sample_schema();
+        let src = vec![
+            0x0a, 0x08, 0x01, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, // fixed: [1, 2] (packed)
+            0x0d, 0x03, 0x00, 0x00, 0x00, // fixed: [3]
+            0x12, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xf8, 0x3f, // ratios: [1.5] (packed)
+            0x1a, 0x02, 0x01, 0x05, // kinds: [KIND_A, 5] (packed)
+            0x20, 0x01, // flags: [true]
+            0x22, 0x02, 0x00, 0x01, // flags: [false, true] (packed)
+            0x2a, 0x01, 0x03, // values: [-2] (packed)
+            0x28, 0x04, // values: [2]
+            0x2a, 0x02, 0x01, 0x02, // values: [-1, 1] (packed)
+            0x32, 0x07, 0x08, 0x01, 0x12, 0x03, 0x0a, 0x01, b'a', // entries: { 1: { name: "a" } }
+            0x32, 0x00, // entries: { 0: {} }
+            0x32, 0x04, 0x12, 0x00, 0x08, 0x02, // entries: { 2: {} } (value before key)
+            0x32, 0x07, 0x08, 0x01, 0x12, 0x03, 0x0a, 0x01, b'b', // entries: { 1: { name: "b" } }
+            0x3a, 0x05, 0x08, 0x01, 0x12, 0x01, 0xff, // blobs: { true: [0xff] }
+        ];
+        let mut sample = DynamicMessage::new(&schema, ".test.Sample").unwrap();
+        sample.merge(&src).unwrap();
+
+        let list = |name: &str| sample.get(name).and_then(|v| v.as_list()).unwrap().to_vec();
+        assert_eq!(list("fixed"), vec![Value::UInt32(1), Value::UInt32(2), Value::UInt32(3)]);
+        assert_eq!(list("ratios"), vec![Value::Double(1.5)]);
+        assert_eq!(list("kinds"), vec![Value::Enum(1), Value::Enum(5)]);
+        assert_eq!(list("flags"), vec![Value::Bool(true), Value::Bool(false), Value::Bool(true)]);
+        assert_eq!(list("values"), vec![Value::Int64(-2), Value::Int64(2), Value::Int64(-1), Value::Int64(1)]);
+
+        let entries = sample.get("entries").and_then(|v| v.as_map()).unwrap();
+        let name = |key: i32| entries.get(&MapKey::Int32(key)).and_then(|v| v.as_message()).unwrap().get_or_default("name");
+        assert_eq!(entries.len(), 3);
+        assert_eq!(name(0), Some(Value::String(String::new())));
+        assert_eq!(name(1), Some(Value::String("b".to_string()))); // the last entry wins
+        assert_eq!(name(2), Some(Value::String(String::new())));
+        let blobs = sample.get("blobs").and_then(|v| v.as_map()).unwrap();
+        assert_eq!(blobs.get(&MapKey::Bool(true)), Some(&Value::Bytes(vec![0xff])));
+
+        let mut dst = Vec::new();
+        sample.encode(&mut dst).unwrap();
+        let mut decoded = DynamicMessage::new(&schema, ".test.Sample").unwrap();
+        decoded.merge(&dst).unwrap();
+        assert_eq!(decoded.fields().count(), sample.fields().count());
+        assert_eq!(decoded.encoded_len(), Ok(dst.len()));
+
+        let mut sample = DynamicMessage::new(&schema, ".test.Sample").unwrap();
+        assert_eq!(sample.merge(&[0x0a, 0x03, 0x01, 0x00, 0x00]), Err(DecoderError::InputUnderflow)); // truncated fixed32
+    }
+
+    /// Should keep unknown fields of any wire type, also in embedded messages,
+    /// and write them back unchanged.
+    #[test]
+    fn preserves_unknown_fields() {
+        let schema = sample_schema();
+        let src = vec![
+            0x42, 0x05, 0x0a, 0x01, b'a', 0x78, 0x01, // main: { name: "a", 15: 1 }
+            0x50, 0x96, 0x01, // 10: 150
+            0x59, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, // 11: fixed64
+            0x62, 0x02, b'h', b'i', // 12: "hi"
+            0x6d, 0x01, 0x02, 0x03, 0x04, // 13: fixed32
+            0x73, 0x08, 0x01, 0x74, // 14: group { 1: 1 }
+        ];
+        let mut sample = DynamicMessage::new(&schema, ".test.Sample").unwrap();
+        sample.merge(&src).unwrap();
+        assert_eq!(sample.unknown_fields(), &[
+            (10, Typ::Varint, vec![0x96, 0x01]),
+            (11, Typ::Bit64, vec![0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08]),
+            (12, Typ::LengthDelimited, vec![b'h', b'i']),
+            (13, Typ::Bit32, vec![0x01, 0x02, 0x03, 0x04]),
+            (14, Typ::StartGroup, vec![0x08, 0x01]),
+        ]);
+        let main = sample.get("main").and_then(|v| v.as_message()).unwrap();
+        assert_eq!(main.unknown_fields(), &[(15, Typ::Varint, vec![0x01])]);
+
+        let mut dst = Vec::new();
+        sample.encode(&mut dst).unwrap();
+        assert_eq!(dst, src);
+        assert_eq!(sample.encoded_len(), Ok(src.len()));
+
+        sample.merge(&[0x42, 0x02, 0x78, 0x02]).unwrap(); // main: { 15: 2 }
+        let main = sample.get("main").and_then(|v| v.as_message()).unwrap();
+        assert_eq!(main.get("name"), Some(&Value::String("a".to_string())));
+        assert_eq!(main.unknown_fields(), &[(15, Typ::Varint, vec![0x01]), (15, Typ::Varint, vec![0x02])]);
+    }
+
+    /// Should refuse values which do not match the type of the field.
+    #[test]
+    fn refuses_mismatched_values() {
+        let schema = sample_schema();
+        let mut sample = DynamicMessage::new(&schema, ".test.Sample").unwrap();
+        let entry = DynamicMessage::new(&schema, ".test.Entry").unwrap();
+        let map = |key: MapKey, value: Value<'static>| vec![(key, value)].into_iter().collect::<BTreeMap<_, _>>();
+
+        sample.set("fixed", Value::List(vec![Value::UInt32(1)])).unwrap();
+        sample.set("kinds", Value::List(vec![Value::Enum(7)])).unwrap();
+        sample.set("blobs", map(MapKey::Bool(true), Value::Bytes(vec![1]))).unwrap();
+        sample.set("entries", vec![(MapKey::Int32(1), Value::Message(entry.clone()))].into_iter().collect::<BTreeMap<_, _>>()).unwrap();
+
+        let invalid = vec![
+            ("fixed", Value::List(vec![Value::Int32(1)])),
+            ("fixed", Value::UInt32(1)), // single value for a repeated field
+            ("ratios", Value::List(vec![Value::Float(1.0)])),
+            ("kinds", Value::List(vec![Value::Int32(1)])),
+            ("names", Value::String("a".to_string())),
+            ("names", Value::List(vec![Value::Bytes(vec![])])),
+            ("blobs", Value::Map(map(MapKey::Int32(1), Value::Bytes(vec![])))), // key type
+            ("blobs", Value::Map(map(MapKey::Bool(true), Value::String(String::new())))), // value type
+            ("main", Value::String(String::new())),
+            ("main", Value::Message(DynamicMessage::new(&schema, ".test.Sample").unwrap())),
+        ];
+        for (name, value) in invalid {
+            let text = format!("{} {:?}", name, value);
+            assert_eq!(sample.set(name, value), Err(DynamicError::UnexpectedValue(name.to_string())), "{}", text);
+        }
+        assert_eq!(sample.get("fixed"), Some(&Value::List(vec![Value::UInt32(1)]))); // unchanged
+    }
+
+    /// Should refuse to decode embedded messages which are nested too deep.
+    #[test]
+    fn limits_message_depth() {
+        let mut schema = Schema::new();
+        schema.add("node.proto", r#"
+            syntax = "proto3";
+            message Node {
+                Node child = 1;
+            }
+        "#).unwrap();
+
+        fn encode_len(mut len: usize, dst: &mut Vec<u8>) {
+            while len >= 0x80 {
+                dst.push(len as u8 | 0x80);
+                len >>= 7;
+            }
+            dst.push(len as u8);
+        }
+
+        fn nest(levels: usize) -> Vec<u8> {
+            let mut buf = Vec::new();
+            for _ in 0..levels {
+                let mut outer = vec![0x0a];
+                encode_len(buf.len(), &mut outer);
+                outer.extend(buf);
+                buf = outer;
+            }
+            buf
+        }
+
+        let mut node = DynamicMessage::new(&schema, ".Node").unwrap();
+        assert!(node.merge(&nest(MESSAGE_DEPTH_MAX - 1)).is_ok());
+        let mut node = DynamicMessage::new(&schema, ".Node").unwrap();
+        assert_eq!(node.merge(&nest(MESSAGE_DEPTH_MAX)), Err(DecoderError::DepthOverflow));
+        let mut node = DynamicMessage::new(&schema, ".Node").unwrap();
+        assert_eq!(node.merge(&nest(20_000)), Err(DecoderError::DepthOverflow));
+    }
+}
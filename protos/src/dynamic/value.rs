@@ -0,0 +1,366 @@
+use std::collections::BTreeMap;
+use crate::schema::FieldType;
+use super::DynamicMessage;
+
+/// Represents the value of a field of a dynamic message.
+///
+/// Scalar values are held by their Rust type, so `int32`, `sint32` and
+/// `sfixed32` fields all hold `Int32` values. The field descriptor decides how
+/// a value is written on the wire.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value<'a> {
+    /// A value of a `bool` field.
+    Bool(bool),
+
+    /// A value of an `int32`, `sint32` or `sfixed32` field.
+    Int32(i32),
+
+    /// A value of an `int64`, `sint64` or `sfixed64` field.
+    Int64(i64),
+
+    /// A value of a `uint32` or `fixed32` field.
+    UInt32(u32),
+
+    /// A value of a `uint64` or `fixed64` field.
+    UInt64(u64),
+
+    /// A value of a `float` field.
+    Float(f32),
+
+    /// A value of a `double` field.
+    Double(f64),
+
+    /// A value of a `string` field.
+    String(String),
+
+    /// A value of a `bytes` field.
+    Bytes(Vec<u8>),
+
+    /// The number of an enum value. Numbers without a matching enum value are
+    /// kept as they are.
+    Enum(i32),
+
+    /// An embedded message.
+    Message(DynamicMessage<'a>),
+
+    /// The values of a repeated field.
+    List(Vec<Value<'a>>),
+
+    /// The entries of a map field ordered by key.
+    Map(BTreeMap<MapKey, Value<'a>>),
+}
+
+impl<'a> Value<'a> {
+    /// Returns the boolean if the value is a `Bool`.
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            Self::Bool(v) => Some(*v),
+            _ => None,
+        }
+    }
+
+    /// Returns the number if the value is an `Int32`.
+    pub fn as_i32(&self) -> Option<i32> {
+        match self {
+            Self::Int32(v) => Some(*v),
+            _ => None,
+        }
+    }
+
+    /// Returns the number if the value is an `Int64`.
+    pub fn as_i64(&self) -> Option<i64> {
+        match self {
+            Self::Int64(v) => Some(*v),
+            _ => None,
+        }
+    }
+
+    /// Returns the number if the value is a `UInt32`.
+    pub fn as_u32(&self) -> Option<u32> {
+        match self {
+            Self::UInt32(v) => Some(*v),
+            _ => None,
+        }
+    }
+
+    /// Returns the number if the value is a `UInt64`.
+    pub fn as_u64(&self) -> Option<u64> {
+        match self {
+            Self::UInt64(v) => Some(*v),
+            _ => None,
+        }
+    }
+
+    /// Returns the number if the value is a `Float`.
+    pub fn as_f32(&self) -> Option<f32> {
+        match self {
+            Self::Float(v) => Some(*v),
+            _ => None,
+        }
+    }
+
+    /// Returns the number if the value is a `Double`.
+    pub fn as_f64(&self) -> Option<f64> {
+        match self {
+            Self::Double(v) => Some(*v),
+            _ => None,
+        }
+    }
+
+    /// Returns the text if the value is a `String`.
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Self::String(v) => Some(v),
+            _ => None,
+        }
+    }
+
+    /// Returns the bytes if the value is `Bytes`.
+    pub fn as_bytes(&self) -> Option<&[u8]> {
+        match self {
+            Self::Bytes(v) => Some(v),
+            _ => None,
+        }
+    }
+
+    /// Returns the number if the value is an `Enum`.
+    pub fn as_enum(&self) -> Option<i32> {
+        match self {
+            Self::Enum(v) => Some(*v),
+            _ => None,
+        }
+    }
+
+    /// Returns the message if the value is a `Message`.
+    pub fn as_message(&self) -> Option<&DynamicMessage<'a>> {
+        match self {
+            Self::Message(v) => Some(v),
+            _ => None,
+        }
+    }
+
+    /// Returns the values if the value is a `List`.
+    pub fn as_list(&self) -> Option<&[Value<'a>]> {
+        match self {
+            Self::List(v) => Some(v),
+            _ => None,
+        }
+    }
+
+    /// Returns the entries if the value is a `Map`.
+    pub fn as_map(&self) -> Option<&BTreeMap<MapKey, Value<'a>>> {
+        match self {
+            Self::Map(v) => Some(v),
+            _ => None,
+        }
+    }
+
+    /// Tells whether the value is the default value of a singular field which
+    /// `proto3` leaves out on the wire. Messages are never default.
+    pub fn is_default(&self) -> bool {
+        match self {
+            Self::Bool(v) => !*v,
+            Self::Int32(v) | Self::Enum(v) => *v == 0,
+            Self::Int64(v) => *v == 0,
+            Self::UInt32(v) => *v == 0,
+            Self::UInt64(v) => *v == 0,
+            Self::Float(v) => v.to_bits() == 0,
+            Self::Double(v) => v.to_bits() == 0,
+            Self::String(v) => v.is_empty(),
+            Self::Bytes(v) => v.is_empty(),
+            Self::Message(_) => false,
+            Self::List(v) => v.is_empty(),
+            Self::Map(v) => v.is_empty(),
+        }
+    }
+
+    /// Tells whether the value can be held by a single (not repeated) field of
+    /// the provided type.
+    pub fn is_type(&self, typ: &FieldType) -> bool {
+        match (typ, self) {
+            (FieldType::Bool, Self::Bool(_))
+                | (FieldType::Int32 | FieldType::SInt32 | FieldType::SFixed32, Self::Int32(_))
+                | (FieldType::Int64 | FieldType::SInt64 | FieldType::SFixed64, Self::Int64(_))
+                | (FieldType::UInt32 | FieldType::Fixed32, Self::UInt32(_))
+                | (FieldType::UInt64 | FieldType::Fixed64, Self::UInt64(_))
+                | (FieldType::Float, Self::Float(_))
+                | (FieldType::Double, Self::Double(_))
+                | (FieldType::String, Self::String(_))
+                | (FieldType::Bytes, Self::Bytes(_))
+                | (FieldType::Enum(_), Self::Enum(_)) => true,
+            (FieldType::Message(name), Self::Message(message)) => message.descriptor().full_name == *name,
+            _ => false,
+        }
+    }
+}
+
+impl<'a> From<bool> for Value<'a> {
+    fn from(v: bool) -> Self {
+        Self::Bool(v)
+    }
+}
+
+impl<'a> From<i32> for Value<'a> {
+    fn from(v: i32) -> Self {
+        Self::Int32(v)
+    }
+}
+
+impl<'a> From<i64> for Value<'a> {
+    fn from(v: i64) -> Self {
+        Self::Int64(v)
+    }
+}
+
+impl<'a> From<u32> for Value<'a> {
+    fn from(v: u32) -> Self {
+        Self::UInt32(v)
+    }
+}
+
+impl<'a> From<u64> for Value<'a> {
+    fn from(v: u64) -> Self {
+        Self::UInt64(v)
+    }
+}
+
+impl<'a> From<f32> for Value<'a> {
+    fn from(v: f32) -> Self {
+        Self::Float(v)
+    }
+}
+
+impl<'a> From<f64> for Value<'a> {
+    fn from(v: f64) -> Self {
+        Self::Double(v)
+    }
+}
+
+impl<'a> From<String> for Value<'a> {
+    fn from(v: String) -> Self {
+        Self::String(v)
+    }
+}
+
+impl<'a> From<&str> for Value<'a> {
+    fn from(v: &str) -> Self {
+        Self::String(v.to_string())
+    }
+}
+
+impl<'a> From<Vec<u8>> for Value<'a> {
+    fn from(v: Vec<u8>) -> Self {
+        Self::Bytes(v)
+    }
+}
+
+impl<'a> From<DynamicMessage<'a>> for Value<'a> {
+    fn from(v: DynamicMessage<'a>) -> Self {
+        Self::Message(v)
+    }
+}
+
+impl<'a> From<Vec<Value<'a>>> for Value<'a> {
+    fn from(v: Vec<Value<'a>>) -> Self {
+        Self::List(v)
+    }
+}
+
+impl<'a> From<BTreeMap<MapKey, Value<'a>>> for Value<'a> {
+    fn from(v: BTreeMap<MapKey, Value<'a>>) -> Self {
+        Self::Map(v)
+    }
+}
+
+/// Represents the key of a map field entry. Only integral, boolean and string
+/// types can be used as keys.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum MapKey {
+    /// A key of a `bool` map.
+    Bool(bool),
+
+    /// A key of an `int32`, `sint32` or `sfixed32` map.
+    Int32(i32),
+
+    /// A key of an `int64`, `sint64` or `sfixed64` map.
+    Int64(i64),
+
+    /// A key of a `uint32` or `fixed32` map.
+    UInt32(u32),
+
+    /// A key of a `uint64` or `fixed64` map.
+    UInt64(u64),
+
+    /// A key of a `string` map.
+    String(String),
+}
+
+impl MapKey {
+    /// Returns the key as a field value.
+    pub fn to_value<'a>(&self) -> Value<'a> {
+        match self {
+            Self::Bool(v) => Value::Bool(*v),
+            Self::Int32(v) => Value::Int32(*v),
+            Self::Int64(v) => Value::Int64(*v),
+            Self::UInt32(v) => Value::UInt32(*v),
+            Self::UInt64(v) => Value::UInt64(*v),
+            Self::String(v) => Value::String(v.clone()),
+        }
+    }
+
+    /// Returns the key of the provided field value if the value can be used as
+    /// a key.
+    pub fn from_value(value: Value) -> Option<Self> {
+        match value {
+            Value::Bool(v) => Some(Self::Bool(v)),
+            Value::Int32(v) => Some(Self::Int32(v)),
+            Value::Int64(v) => Some(Self::Int64(v)),
+            Value::UInt32(v) => Some(Self::UInt32(v)),
+            Value::UInt64(v) => Some(Self::UInt64(v)),
+            Value::String(v) => Some(Self::String(v)),
+            _ => None,
+        }
+    }
+}
+
+impl From<bool> for MapKey {
+    fn from(v: bool) -> Self {
+        Self::Bool(v)
+    }
+}
+
+impl From<i32> for MapKey {
+    fn from(v: i32) -> Self {
+        Self::Int32(v)
+    }
+}
+
+impl From<i64> for MapKey {
+    fn from(v: i64) -> Self {
+        Self::Int64(v)
+    }
+}
+
+impl From<u32> for MapKey {
+    fn from(v: u32) -> Self {
+        Self::UInt32(v)
+    }
+}
+
+impl From<u64> for MapKey {
+    fn from(v: u64) -> Self {
+        Self::UInt64(v)
+    }
+}
+
+impl From<String> for MapKey {
+    fn from(v: String) -> Self {
+        Self::String(v)
+    }
+}
+
+impl From<&str> for MapKey {
+    fn from(v: &str) -> Self {
+        Self::String(v.to_string())
+    }
+}
@@ -18,6 +18,11 @@ pub enum EncoderError {
     /// invalid tag number. A tag number must be unique per message and the
     /// value can be between `1` and `2^29 - 1`.
     InvalidTag,
+
+    /// Indicates that the fields of an embedded message did not produce the
+    /// same number of bytes in both encoding passes. This happens when the
+    /// function writing the fields does not behave the same on each call.
+    SizeMismatch,
 }
 
 impl From<io::Error> for EncoderError {
@@ -38,6 +43,7 @@ impl fmt::Display for EncoderError {
             Self::DataOverflow => write!(fmt, "Available data type size exceeded."),
             Self::Interrupted => write!(fmt, "Write operation interrupted."),
             Self::InvalidTag => write!(fmt, "Found tag with invalid number."),
+            Self::SizeMismatch => write!(fmt, "Embedded message size changed between passes."),
         }
     }
 }
@@ -0,0 +1,139 @@
+use std::collections::VecDeque;
+use std::io;
+use crate::Typ;
+use super::{Encoder, EncoderError, EncoderLit};
+use super::primitives::*;
+
+/// Provides the encoding engine for the fields of an embedded message.
+/// 
+/// An embedded message is a length-delimited field thus its size must be known
+/// before its fields are written. Instead of encoding the message into a
+/// temporary buffer, the fields are passed through the encoder twice. The first
+/// pass only counts the bytes of each embedded message (the output goes into
+/// `io::sink`) and remembers their sizes. The second pass writes the length
+/// prefixes and the fields directly into the final writer.
+/// 
+/// Embedded messages are sized in the order in which they are started, so the
+/// second pass takes the sizes from the front of the list. Each field closure
+/// is thus called once per pass, no matter how deep the messages are nested.
+/// 
+/// The second pass writes into the final writer as it goes. When it fails,
+/// e.g. with the `SizeMismatch` error because a closure wrote different fields
+/// than in the first pass, the part of the message written so far stays in the
+/// writer and has to be discarded by the caller.
+pub struct MessageEncoder<'a> {
+    /// The writer for the current pass.
+    dst: &'a mut dyn io::Write,
+
+    /// The sizes of embedded messages in the order in which they are started.
+    sizes: &'a mut VecDeque<usize>,
+
+    /// Tells whether the encoder runs the sizing (first) pass.
+    sizing: bool,
+
+    /// The number of bytes written for the fields of the current message.
+    size: usize,
+}
+
+impl<'a> MessageEncoder<'a> {
+    /// Returns a new message encoder instance.
+    pub(crate) fn new(dst: &'a mut dyn io::Write, sizes: &'a mut VecDeque<usize>, sizing: bool) -> Self {
+        Self {
+            dst,
+            sizes,
+            sizing,
+            size: 0,
+        }
+    }
+
+    /// Returns the number of bytes written for the fields of the current
+    /// message so far.
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Transforms a `field` into `proto3` binary format and writes the result
+    /// into the message. This function works the same as `Encoder::encode`.
+    /// 
+    /// On success the number of written bytes is returned otherwise an error is 
+    /// thrown.
+    pub fn encode<'b, F>(&mut self, field: (&u32, F)) -> Result<usize, EncoderError>
+    where
+        F: Into<EncoderLit<'b>>,
+    {
+        let size = Encoder.encode(field, self.dst)?;
+        self.size += size;
+        Ok(size)
+    }
+
+    /// Encodes the provided `val` into `bytes` field with a specific `tag`
+    /// number. Use it for `string` fields as well since a string borrows its
+    /// bytes without conversion.
+    /// 
+    /// On success the number of written bytes is returned otherwise an error is 
+    /// thrown.
+    pub fn encode_bytes(&mut self, tag: &u32, val: &[u8]) -> Result<usize, EncoderError> {
+        let size = Encoder.encode_bytes(tag, val, self.dst)?;
+        self.size += size;
+        Ok(size)
+    }
+
+    /// Encodes an embedded message with a specific `tag` number whose fields
+    /// are written by the `fields` function.
+    /// 
+    /// On success the number of written bytes is returned otherwise an error is 
+    /// thrown.
+    pub fn encode_message<F>(&mut self, tag: &u32, fields: F) -> Result<usize, EncoderError>
+    where
+        F: FnOnce(&mut MessageEncoder) -> Result<(), EncoderError>,
+    {
+        let mut prefix = encode_key(*tag, Typ::LengthDelimited, self.dst)?;
+        let len = if self.sizing {
+            let index = self.sizes.len();
+            self.sizes.push_back(0); // reserve the slot before nested messages
+            let len = self.encode_fields(fields)?;
+            self.sizes[index] = len;
+            prefix += encode_varint(len as u64, self.dst)?;
+            len
+        } else {
+            let len = self.sizes.pop_front().ok_or(EncoderError::SizeMismatch)?;
+            prefix += encode_varint(len as u64, self.dst)?;
+            if self.encode_fields(fields)? != len {
+                return Err(EncoderError::SizeMismatch);
+            }
+            len
+        };
+        self.size += prefix;
+        Ok(prefix + len)
+    }
+
+    /// Encodes a `group` field with a specific `tag` number whose fields are
+    /// written by the `fields` function. This function works the same as
+    /// `Encoder::encode_group`.
+    /// 
+    /// On success the number of written bytes is returned otherwise an error is 
+    /// thrown.
+    pub fn encode_group<F>(&mut self, tag: &u32, fields: F) -> Result<usize, EncoderError>
+    where
+        F: FnOnce(&mut MessageEncoder) -> Result<(), EncoderError>,
+    {
+        let mut keys = encode_key(*tag, Typ::StartGroup, self.dst)?;
+        let len = self.encode_fields(fields)?;
+        keys += encode_key(*tag, Typ::EndGroup, self.dst)?;
+        self.size += keys;
+        Ok(keys + len)
+    }
+
+    /// Runs the `fields` function with a nested encoder which shares the writer
+    /// and the sizes, and returns the number of bytes the fields took.
+    fn encode_fields<F>(&mut self, fields: F) -> Result<usize, EncoderError>
+    where
+        F: FnOnce(&mut MessageEncoder) -> Result<(), EncoderError>,
+    {
+        let mut nested = MessageEncoder::new(&mut *self.dst, &mut *self.sizes, self.sizing);
+        fields(&mut nested)?;
+        let size = nested.size;
+        self.size += size;
+        Ok(size)
+    }
+}
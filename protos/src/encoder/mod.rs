@@ -33,15 +33,19 @@
 
 mod error;
 mod lit;
+mod message;
 mod primitives;
 
+use std::collections::VecDeque;
 use std::io;
 use crate::Typ;
 pub use error::*;
 pub use lit::*;
+pub use message::*;
 use primitives::*;
 
 /// Provides the encoding engine for Protocol Buffers.
+#[derive(Default)]
 pub struct Encoder;
 
 impl Encoder {
@@ -408,7 +412,7 @@ impl Encoder {
     pub fn encode_bytes<W>(
         &self,
         tag: &u32,
-        val: &Vec<u8>,
+        val: &[u8],
         dst: &mut W,
     ) -> Result<usize, EncoderError>
     where
@@ -416,7 +420,152 @@ impl Encoder {
     {
         let mut size = 0;
         size += encode_key(*tag, Typ::LengthDelimited, dst)?;
-        size += encode_bytes(val.clone(), dst)?;
+        size += encode_bytes(val.to_vec(), dst)?;
+        Ok(size)
+    }
+
+    /// Encodes a `group` field with a specific `tag` number and writes the
+    /// resulting bytes into `dst`.
+    /// 
+    /// Groups are a deprecated `proto2` feature for nesting messages. Instead of
+    /// a length prefix, the fields of a group are surrounded by a start key
+    /// (wire type `3`) and an end key (wire type `4`). The `fields` function
+    /// writes the fields of the group directly into `dst` and returns the number
+    /// of written bytes.
+    /// 
+    /// ```rust
+    /// use httlib_protos::Encoder;
+    /// 
+    /// let encoder = Encoder::default();
+    /// 
+    /// let mut dst = Vec::new();
+    /// encoder.encode_group(&1, &mut dst, |encoder, dst| {
+    ///     encoder.encode((&2, &150i32), dst)
+    /// }).unwrap();
+    /// assert_eq!(dst, vec![0x0b, 0x10, 0x96, 0x01, 0x0c]);
+    /// ```
+    /// 
+    /// On success the number of written bytes is returned otherwise an error is 
+    /// thrown.
+    pub fn encode_group<W, F>(
+        &self,
+        tag: &u32,
+        dst: &mut W,
+        fields: F,
+    ) -> Result<usize, EncoderError>
+    where
+        W: ?Sized + io::Write,
+        F: FnOnce(&Self, &mut W) -> Result<usize, EncoderError>,
+    {
+        let mut size = 0;
+        size += encode_key(*tag, Typ::StartGroup, dst)?;
+        size += fields(self, dst)?;
+        size += encode_key(*tag, Typ::EndGroup, dst)?;
+        Ok(size)
+    }
+
+    /// Encodes an embedded message with a specific `tag` number and writes the
+    /// resulting bytes into `dst`.
+    /// 
+    /// The `fields` function writes the fields of the message through the
+    /// provided `MessageEncoder`, which can also encode further nested messages.
+    /// The length prefix is computed up front by calling the `fields` function
+    /// twice (first for counting the bytes and then for writing them) so the
+    /// message is written directly into `dst` without intermediate buffers. The
+    /// function must therefore write the same fields on each call.
+    /// 
+    /// Since nothing is buffered, an error thrown while writing (e.g. the
+    /// `SizeMismatch` error when the second call writes different fields)
+    /// leaves a partially written message in `dst` which has to be discarded.
+    /// 
+    /// ```rust
+    /// use httlib_protos::Encoder;
+    /// 
+    /// let encoder = Encoder::default();
+    /// 
+    /// let mut dst = Vec::new();
+    /// encoder.encode_message(&1, &mut dst, |message| {
+    ///     message.encode((&1, &150i32))?;
+    ///     message.encode_message(&2, |nested| {
+    ///         nested.encode((&1, &true))?;
+    ///         Ok(())
+    ///     })?;
+    ///     Ok(())
+    /// }).unwrap();
+    /// assert_eq!(dst, vec![0x0a, 0x07, 0x08, 0x96, 0x01, 0x12, 0x02, 0x08, 0x01]);
+    /// ```
+    /// 
+    /// On success the number of written bytes is returned otherwise an error is 
+    /// thrown.
+    pub fn encode_message<W, F>(
+        &self,
+        tag: &u32,
+        dst: &mut W,
+        mut fields: F,
+    ) -> Result<usize, EncoderError>
+    where
+        W: ?Sized + io::Write,
+        F: FnMut(&mut MessageEncoder) -> Result<(), EncoderError>,
+    {
+        let mut sizes = VecDeque::new();
+
+        let mut sink = io::sink();
+        MessageEncoder::new(&mut sink, &mut sizes, true).encode_message(tag, &mut fields)?;
+
+        let mut dst = dst;
+        MessageEncoder::new(&mut dst, &mut sizes, false).encode_message(tag, &mut fields)
+    }
+
+    /// Encodes the fields of a top-level message and writes the resulting bytes
+    /// into `dst`.
+    /// 
+    /// A top-level message has no key and no length prefix, thus its fields are
+    /// simply written one after another. Nested messages are sized the same way
+    /// as with `encode_message`, which means that the `fields` function is
+    /// called twice and must write the same fields on each call. In case of an
+    /// error, `dst` can hold a partially written message which has to be
+    /// discarded.
+    /// 
+    /// ```rust
+    /// use httlib_protos::Encoder;
+    /// 
+    /// let encoder = Encoder::default();
+    /// 
+    /// let mut dst = Vec::new();
+    /// encoder.encode_fields(&mut dst, |message| {
+    ///     message.encode((&1, &150i32))?;
+    ///     message.encode_message(&2, |nested| {
+    ///         nested.encode((&1, &true))?;
+    ///         Ok(())
+    ///     })?;
+    ///     Ok(())
+    /// }).unwrap();
+    /// assert_eq!(dst, vec![0x08, 0x96, 0x01, 0x12, 0x02, 0x08, 0x01]);
+    /// ```
+    /// 
+    /// On success the number of written bytes is returned otherwise an error is 
+    /// thrown.
+    pub fn encode_fields<W, F>(
+        &self,
+        dst: &mut W,
+        mut fields: F,
+    ) -> Result<usize, EncoderError>
+    where
+        W: ?Sized + io::Write,
+        F: FnMut(&mut MessageEncoder) -> Result<(), EncoderError>,
+    {
+        let mut sizes = VecDeque::new();
+
+        let mut sink = io::sink();
+        fields(&mut MessageEncoder::new(&mut sink, &mut sizes, true))?;
+
+        let mut dst = dst;
+        let mut message = MessageEncoder::new(&mut dst, &mut sizes, false);
+        fields(&mut message)?;
+        let size = message.size();
+        if !sizes.is_empty() {
+            return Err(EncoderError::SizeMismatch);
+        }
         Ok(size)
     }
 
@@ -667,18 +816,13 @@ impl Encoder {
     }
 }
 
-impl Default for Encoder {
-    fn default() -> Self {
-        Self {}
-    }
-}
-
 #[cfg(test)]
 mod test {
     use super::*;
 
     /// Should encode supported data types into Protocol Buffers buffer.
     #[test]
+    #[allow(clippy::default_constructed_unit_structs)]
     fn encodes_supported() {
         let encoder = Encoder::default();
         let mut dst = vec![];
@@ -743,4 +887,66 @@ mod test {
         ]);
         assert_eq!(size, 209);
     }
+
+    /// Should encode nested groups surrounded by start and end keys.
+    #[test]
+    fn encodes_groups() {
+        let encoder = Encoder;
+        let mut dst = vec![];
+        let size = encoder.encode_group(&1, &mut dst, |encoder, dst| {
+            let mut size = encoder.encode((&2, &1i32), dst)?;
+            size += encoder.encode_group(&3, dst, |encoder, dst| {
+                encoder.encode((&4, &b"foo".to_vec()), dst)
+            })?;
+            Ok(size)
+        }).unwrap();
+        assert_eq!(dst, vec![11, 16, 1, 27, 34, 3, 102, 111, 111, 28, 12]);
+        assert_eq!(size, 11);
+        assert_eq!(encoder.encode_group(&0, &mut dst, |_, _| Ok(0)), Err(EncoderError::InvalidTag));
+    }
+
+    /// Should encode nested messages with length prefixes directly into the
+    /// writer by calling each field function once per pass.
+    #[test]
+    fn encodes_messages() {
+        let encoder = Encoder;
+
+        let mut expected = vec![]; // encoded with intermediate buffers
+        let mut inner = vec![];
+        encoder.encode((&1, &b"foo".to_vec()), &mut inner).unwrap();
+        let mut middle = vec![];
+        encoder.encode((&1, &vec![0u8; 200]), &mut middle).unwrap(); // 2-byte length
+        encoder.encode_bytes(&2, &inner, &mut middle).unwrap();
+        encoder.encode_group(&3, &mut middle, |encoder, dst| encoder.encode((&3, &1i32), dst)).unwrap();
+        encoder.encode_bytes(&1, &middle, &mut expected).unwrap();
+        encoder.encode((&2, &true), &mut expected).unwrap();
+
+        let mut calls = 0;
+        let mut dst = vec![];
+        let mut size = encoder.encode_message(&1, &mut dst, |message| {
+            calls += 1;
+            message.encode((&1, &vec![0u8; 200]))?;
+            message.encode_message(&2, |nested| {
+                nested.encode((&1, &b"foo".to_vec()))?;
+                Ok(())
+            })?;
+            message.encode_group(&3, |nested| {
+                nested.encode((&3, &1i32))?;
+                Ok(())
+            })?;
+            Ok(())
+        }).unwrap();
+        size += encoder.encode((&2, &true), &mut dst).unwrap();
+        assert_eq!(dst, expected);
+        assert_eq!(size, expected.len());
+        assert_eq!(calls, 2);
+
+        let mut calls = 0;
+        let res = encoder.encode_message(&1, &mut vec![], |message| {
+            calls += 1;
+            message.encode((&1, &(calls * 100)))?; // writes more bytes on the second pass
+            Ok(())
+        });
+        assert_eq!(res, Err(EncoderError::SizeMismatch));
+    }
 }
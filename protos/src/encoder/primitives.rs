@@ -73,7 +73,7 @@ pub fn encode_key<W>(
 where
     W: ?Sized + io::Write,
 {
-    if tag < Encoder::TAG_MIN || tag > Encoder::TAG_MAX {
+    if !(Encoder::TAG_MIN..=Encoder::TAG_MAX).contains(&tag) {
         return Err(EncoderError::InvalidTag);
     }
 
@@ -344,7 +344,7 @@ where
 /// On success, the number of written bytes is returned otherwise an error is 
 /// thrown.
 pub fn encode_bytes<W>(
-    mut bytes: Vec<u8>,
+    bytes: Vec<u8>,
     buf: &mut W,
 ) -> Result<usize, EncoderError>
 where
@@ -355,7 +355,7 @@ where
         return Err(EncoderError::DataOverflow);
     }
     size += encode_varint(size as u64, buf)?; // number of bytes
-    buf.write_all(&mut bytes)?;
+    buf.write_all(&bytes)?;
     Ok(size)
 }
 
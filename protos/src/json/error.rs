@@ -0,0 +1,66 @@
+use std::error;
+use std::fmt;
+use crate::{DecoderError, EncoderError};
+
+/// Contains error options that can be encountered while converting messages to
+/// and from JSON.
+#[derive(Debug, PartialEq)]
+pub enum JsonError {
+    /// Indicates that the JSON text is malformed at the provided line.
+    InvalidSyntax(usize),
+
+    /// Indicates that the schema does not define a message with the provided
+    /// fully-qualified name or type URL.
+    UnknownMessage(String),
+
+    /// Indicates that the JSON object holds a key which is not a field of the
+    /// message.
+    UnknownField(String),
+
+    /// Indicates that the JSON object sets the field with the provided name
+    /// more than once, e.g. under both its JSON name and its original name.
+    DuplicateField(String),
+
+    /// Indicates that the value of the field with the provided name can not be
+    /// represented in JSON or in the wire format.
+    InvalidValue(String),
+
+    /// Indicates that arrays, objects or embedded messages are nested deeper
+    /// than the conversion allows.
+    DepthOverflow,
+
+    /// Indicates that the wire format bytes could not be decoded.
+    Decoder(DecoderError),
+
+    /// Indicates that the message could not be encoded into the wire format.
+    Encoder(EncoderError),
+}
+
+impl From<DecoderError> for JsonError {
+    fn from(err: DecoderError) -> Self {
+        Self::Decoder(err)
+    }
+}
+
+impl From<EncoderError> for JsonError {
+    fn from(err: EncoderError) -> Self {
+        Self::Encoder(err)
+    }
+}
+
+impl fmt::Display for JsonError {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::InvalidSyntax(line) => write!(fmt, "Invalid JSON syntax at line {}.", line),
+            Self::UnknownMessage(name) => write!(fmt, "Unknown message {}.", name),
+            Self::UnknownField(name) => write!(fmt, "Unknown field {}.", name),
+            Self::DuplicateField(name) => write!(fmt, "Duplicate field {}.", name),
+            Self::InvalidValue(name) => write!(fmt, "Invalid value for field {}.", name),
+            Self::DepthOverflow => write!(fmt, "Values nested too deep."),
+            Self::Decoder(err) => write!(fmt, "{}", err),
+            Self::Encoder(err) => write!(fmt, "{}", err),
+        }
+    }
+}
+
+impl error::Error for JsonError {}
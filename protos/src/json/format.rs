@@ -0,0 +1,294 @@
+//! Provides the string formats of the JSON mapping which are not plain JSON
+//! values.
+
+/// The standard base64 alphabet.
+const BASE64: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// The smallest valid `Timestamp` (`0001-01-01T00:00:00Z`) in seconds.
+const MIN_TIMESTAMP: i64 = -62_135_596_800;
+
+/// The largest valid `Timestamp` (`9999-12-31T23:59:59Z`) in seconds.
+const MAX_TIMESTAMP: i64 = 253_402_300_799;
+
+/// The largest valid `Duration` in seconds (about 10,000 years).
+const MAX_DURATION: i64 = 315_576_000_000;
+
+/// Returns the standard base64 encoding of the provided `bytes` with padding.
+pub(crate) fn encode_base64(bytes: &[u8]) -> String {
+    let mut text = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let n = chunk.iter().enumerate().fold(0u32, |n, (i, b)| n | (*b as u32) << (16 - i * 8));
+        for i in 0..4 {
+            if i <= chunk.len() {
+                text.push(BASE64[(n >> (18 - i * 6)) as usize & 0x3f] as char);
+            } else {
+                text.push('=');
+            }
+        }
+    }
+    text
+}
+
+/// Decodes the base64 `text` in the standard or the URL-safe alphabet. The
+/// padding is optional.
+pub(crate) fn decode_base64(text: &str) -> Option<Vec<u8>> {
+    let text = text.trim_end_matches('=');
+    let mut bytes = Vec::with_capacity(text.len() * 3 / 4);
+    let mut n = 0u32;
+    let mut bits = 0;
+    for c in text.bytes() {
+        let value = match c {
+            b'A'..=b'Z' => c - b'A',
+            b'a'..=b'z' => c - b'a' + 26,
+            b'0'..=b'9' => c - b'0' + 52,
+            b'+' | b'-' => 62,
+            b'/' | b'_' => 63,
+            _ => return None,
+        };
+        n = n << 6 | value as u32;
+        bits += 6;
+        if bits >= 8 {
+            bits -= 8;
+            bytes.push((n >> bits) as u8);
+            n &= (1 << bits) - 1;
+        }
+    }
+    if bits >= 6 { // a single character can not hold a byte
+        return None;
+    }
+    Some(bytes)
+}
+
+/// Returns the fraction of a second for the provided `nanos` with 3, 6 or 9
+/// digits, or an empty string if there are no nanoseconds.
+fn format_nanos(nanos: u32) -> String {
+    if nanos == 0 {
+        String::new()
+    } else if nanos.is_multiple_of(1_000_000) {
+        format!(".{:03}", nanos / 1_000_000)
+    } else if nanos.is_multiple_of(1_000) {
+        format!(".{:06}", nanos / 1_000)
+    } else {
+        format!(".{:09}", nanos)
+    }
+}
+
+/// Parses the digits of a fraction of a second (without the dot) into
+/// nanoseconds.
+fn parse_nanos(digits: &str) -> Option<i32> {
+    if digits.is_empty() || digits.len() > 9 || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    let nanos = digits.parse::<i32>().ok()?;
+    Some(nanos * 10i32.pow(9 - digits.len() as u32))
+}
+
+/// Returns the number of days since the Unix epoch of the provided date in the
+/// proleptic Gregorian calendar.
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = if year >= 0 { year } else { year - 399 } / 400;
+    let yoe = year - era * 400;
+    let doy = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146_097 + doe - 719_468
+}
+
+/// Returns the date `(year, month, day)` of the provided number of days since
+/// the Unix epoch.
+fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    let days = days + 719_468;
+    let era = if days >= 0 { days } else { days - 146_096 } / 146_097;
+    let doe = days - era * 146_097;
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+    (year, month, day)
+}
+
+/// Returns the RFC 3339 form of a `Timestamp` in UTC (e.g.
+/// `1972-01-01T10:00:20.021Z`) or `None` if it is out of range.
+pub(crate) fn format_timestamp(seconds: i64, nanos: i32) -> Option<String> {
+    if !(MIN_TIMESTAMP..=MAX_TIMESTAMP).contains(&seconds) || !(0..1_000_000_000).contains(&nanos) {
+        return None;
+    }
+    let (year, month, day) = civil_from_days(seconds.div_euclid(86_400));
+    let time = seconds.rem_euclid(86_400);
+    Some(format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}{}Z",
+        year, month, day, time / 3_600, time / 60 % 60, time % 60, format_nanos(nanos as u32),
+    ))
+}
+
+/// Parses an RFC 3339 timestamp with an optional fraction of a second and a
+/// `Z` or a numeric offset into the `(seconds, nanos)` of a `Timestamp`.
+pub(crate) fn parse_timestamp(text: &str) -> Option<(i64, i32)> {
+    let bytes = text.as_bytes();
+    let number = |range: std::ops::Range<usize>| -> Option<i64> {
+        let digits = text.get(range)?;
+        if digits.bytes().all(|b| b.is_ascii_digit()) { digits.parse().ok() } else { None }
+    };
+    if bytes.len() < 20 || bytes[4] != b'-' || bytes[7] != b'-' || bytes[10] != b'T' || bytes[13] != b':' || bytes[16] != b':' {
+        return None;
+    }
+    let (year, month, day) = (number(0..4)?, number(5..7)?, number(8..10)?);
+    let (hour, minute, second) = (number(11..13)?, number(14..16)?, number(17..19)?);
+    if !(1..=12).contains(&month) || day < 1 || day > days_in_month(year, month) || hour > 23 || minute > 59 || second > 59 {
+        return None;
+    }
+
+    let mut rest = &text[19..];
+    let mut nanos = 0;
+    if let Some(fraction) = rest.strip_prefix('.') {
+        let end = fraction.find(|c: char| !c.is_ascii_digit()).unwrap_or(fraction.len());
+        nanos = parse_nanos(&fraction[..end])?;
+        rest = &fraction[end..];
+    }
+    let offset = match rest.as_bytes() {
+        b"Z" | b"z" => 0,
+        [sign @ (b'+' | b'-'), _, _, b':', _, _] => {
+            let offset = number(text.len() - 5..text.len() - 3)? * 3_600 + number(text.len() - 2..text.len())? * 60;
+            if *sign == b'-' { -offset } else { offset }
+        },
+        _ => return None,
+    };
+
+    let seconds = days_from_civil(year, month, day) * 86_400 + hour * 3_600 + minute * 60 + second - offset;
+    if (MIN_TIMESTAMP..=MAX_TIMESTAMP).contains(&seconds) {
+        Some((seconds, nanos))
+    } else {
+        None
+    }
+}
+
+/// Returns the number of days of the provided month.
+fn days_in_month(year: i64, month: i64) -> i64 {
+    match month {
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Returns the form of a `Duration` in seconds with a `s` suffix (e.g.
+/// `-1.500s`) or `None` if it is out of range or the signs do not match.
+pub(crate) fn format_duration(seconds: i64, nanos: i32) -> Option<String> {
+    if seconds.unsigned_abs() > MAX_DURATION as u64 || nanos.unsigned_abs() >= 1_000_000_000 || (seconds > 0 && nanos < 0) || (seconds < 0 && nanos > 0) {
+        return None;
+    }
+    let sign = if seconds < 0 || nanos < 0 { "-" } else { "" };
+    Some(format!("{}{}{}s", sign, seconds.unsigned_abs(), format_nanos(nanos.unsigned_abs())))
+}
+
+/// Parses a duration in seconds with a `s` suffix into the `(seconds, nanos)`
+/// of a `Duration`.
+pub(crate) fn parse_duration(text: &str) -> Option<(i64, i32)> {
+    let text = text.strip_suffix('s')?;
+    let (negative, text) = match text.strip_prefix('-') {
+        Some(text) => (true, text),
+        None => (false, text),
+    };
+    let (whole, fraction) = match text.split_once('.') {
+        Some((whole, fraction)) => (whole, Some(fraction)),
+        None => (text, None),
+    };
+    if whole.is_empty() || !whole.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    let seconds = whole.parse::<i64>().ok().filter(|s| *s <= MAX_DURATION)?;
+    let nanos = match fraction {
+        Some(fraction) => parse_nanos(fraction)?,
+        None => 0,
+    };
+    if negative {
+        Some((-seconds, -nanos))
+    } else {
+        Some((seconds, nanos))
+    }
+}
+
+/// Returns the lowerCamelCase form of a `FieldMask` path (e.g. `foo_bar.baz`
+/// becomes `fooBar.baz`) or `None` if the path can not be converted back.
+pub(crate) fn format_field_path(path: &str) -> Option<String> {
+    let mut camel = String::with_capacity(path.len());
+    let mut upper = false;
+    for c in path.chars() {
+        if c.is_ascii_uppercase() || (upper && !c.is_ascii_lowercase()) {
+            return None;
+        } else if c == '_' {
+            upper = true;
+        } else if upper {
+            camel.push(c.to_ascii_uppercase());
+            upper = false;
+        } else {
+            camel.push(c);
+        }
+    }
+    if upper {
+        None
+    } else {
+        Some(camel)
+    }
+}
+
+/// Returns the snake_case form of a lowerCamelCase `FieldMask` path.
+pub(crate) fn parse_field_path(path: &str) -> Option<String> {
+    let mut snake = String::with_capacity(path.len() + 4);
+    for c in path.chars() {
+        if c == '_' {
+            return None;
+        } else if c.is_ascii_uppercase() {
+            snake.push('_');
+            snake.push(c.to_ascii_lowercase());
+        } else {
+            snake.push(c);
+        }
+    }
+    Some(snake)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Should convert values to and from their string formats.
+    #[test]
+    fn converts_formats() {
+        assert_eq!(encode_base64(b""), "");
+        assert_eq!(encode_base64(b"f"), "Zg==");
+        assert_eq!(encode_base64(b"fo"), "Zm8=");
+        assert_eq!(encode_base64(&[0xfb, 0xff]), "+/8=");
+        assert_eq!(decode_base64("Zm8="), Some(b"fo".to_vec()));
+        assert_eq!(decode_base64("Zm8"), Some(b"fo".to_vec()));
+        assert_eq!(decode_base64("-_8"), Some(vec![0xfb, 0xff]));
+        assert_eq!(decode_base64("Z"), None);
+        assert_eq!(decode_base64("Z!"), None);
+
+        assert_eq!(format_timestamp(0, 0), Some("1970-01-01T00:00:00Z".to_string()));
+        assert_eq!(format_timestamp(63_108_020, 21_000_000), Some("1972-01-01T10:00:20.021Z".to_string()));
+        assert_eq!(format_timestamp(MIN_TIMESTAMP, 1_000), Some("0001-01-01T00:00:00.000001Z".to_string()));
+        assert_eq!(format_timestamp(MAX_TIMESTAMP, 1), Some("9999-12-31T23:59:59.000000001Z".to_string()));
+        assert_eq!(format_timestamp(MAX_TIMESTAMP + 1, 0), None);
+        assert_eq!(parse_timestamp("1972-01-01T10:00:20.021Z"), Some((63_108_020, 21_000_000)));
+        assert_eq!(parse_timestamp("1972-01-01T11:00:20.021+01:00"), Some((63_108_020, 21_000_000)));
+        assert_eq!(parse_timestamp("2000-02-29T00:00:00Z"), Some((951_782_400, 0)));
+        assert_eq!(parse_timestamp("2001-02-29T00:00:00Z"), None);
+        assert_eq!(parse_timestamp("1972-01-01 10:00:20Z"), None);
+
+        assert_eq!(format_duration(1, 340_012), Some("1.000340012s".to_string()));
+        assert_eq!(format_duration(-1, -500_000_000), Some("-1.500s".to_string()));
+        assert_eq!(format_duration(0, -1_000), Some("-0.000001s".to_string()));
+        assert_eq!(format_duration(1, -1), None);
+        assert_eq!(parse_duration("1.000340012s"), Some((1, 340_012)));
+        assert_eq!(parse_duration("-0.5s"), Some((0, -500_000_000)));
+        assert_eq!(parse_duration("1"), None);
+
+        assert_eq!(format_field_path("foo_bar.baz"), Some("fooBar.baz".to_string()));
+        assert_eq!(format_field_path("fooBar"), None);
+        assert_eq!(parse_field_path("fooBar.baz"), Some("foo_bar.baz".to_string()));
+    }
+}
@@ -0,0 +1,397 @@
+//! Provides the conversion of messages to and from JSON following the `proto3`
+//! JSON mapping.
+//!
+//! Messages are described by a `Schema` and held as `DynamicMessage` values, so
+//! the wire format bytes of a message type can be converted directly into JSON
+//! text and back. Fields are written under their lowerCamelCase JSON names
+//! while both the JSON names and the original names are accepted when parsing.
+//! 64-bit integers are written as strings, bytes as base64 and enum values by
+//! name. The well-known types `Timestamp`, `Duration`, `FieldMask`, `Struct`,
+//! `Value`, `ListValue` and the wrappers have their special JSON forms and an
+//! `Any` holds the `@type` URL of the packed message.
+//!
+//! ```rust
+//! use httlib_protos::json::{self, JsonOptions};
+//! use httlib_protos::schema::Schema;
+//!
+//! let mut schema = Schema::new();
+//! schema.add("user.proto", r#"
+//!     syntax = "proto3";
+//!     import "google/protobuf/timestamp.proto";
+//!     message User {
+//!         int64 user_id = 1;
+//!         google.protobuf.Timestamp created_at = 2;
+//!     }
+//! "#).unwrap();
+//!
+//! let options = JsonOptions::default();
+//! let bytes = json::to_wire(&schema, ".User", r#"{"userId":"7","createdAt":"1970-01-01T00:00:01Z"}"#, &options).unwrap();
+//! assert_eq!(bytes, vec![0x08, 0x07, 0x12, 0x02, 0x08, 0x01]);
+//!
+//! let text = json::from_wire(&schema, ".User", &bytes, &options).unwrap();
+//! assert_eq!(text, r#"{"userId":"7","createdAt":"1970-01-01T00:00:01Z"}"#);
+//! ```
+
+mod error;
+mod format;
+mod parser;
+mod printer;
+mod reader;
+
+use crate::dynamic::DynamicMessage;
+use crate::schema::Schema;
+use parser::Parser;
+use printer::Printer;
+pub use error::*;
+
+/// The well-known types which have a special JSON form.
+const WELL_KNOWN_TYPES: &[&str] = &[
+    ".google.protobuf.Any",
+    ".google.protobuf.Timestamp",
+    ".google.protobuf.Duration",
+    ".google.protobuf.FieldMask",
+    ".google.protobuf.Struct",
+    ".google.protobuf.Value",
+    ".google.protobuf.ListValue",
+    ".google.protobuf.DoubleValue",
+    ".google.protobuf.FloatValue",
+    ".google.protobuf.Int64Value",
+    ".google.protobuf.UInt64Value",
+    ".google.protobuf.Int32Value",
+    ".google.protobuf.UInt32Value",
+    ".google.protobuf.BoolValue",
+    ".google.protobuf.StringValue",
+    ".google.protobuf.BytesValue",
+];
+
+/// Provides options of the JSON conversion.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct JsonOptions {
+    /// Writes fields without presence (singular scalars, repeated fields and
+    /// maps) even when they hold the default value.
+    pub emit_defaults: bool,
+
+    /// Writes fields under their original names instead of the lowerCamelCase
+    /// JSON names.
+    pub proto_names: bool,
+
+    /// Skips object keys which are not fields of the message instead of
+    /// failing.
+    pub ignore_unknown_fields: bool,
+}
+
+/// Returns the JSON text of the provided `message`.
+///
+/// On success the JSON text is returned otherwise an error is thrown.
+pub fn print(message: &DynamicMessage, options: &JsonOptions) -> Result<String, JsonError> {
+    let mut out = String::new();
+    Printer::new(options).print_message(message, &mut out)?;
+    Ok(out)
+}
+
+/// Returns the message of the type with the provided fully-qualified name
+/// (e.g. `.pkg.Name`) which is built from the `json` text.
+///
+/// On success the message is returned otherwise an error is thrown.
+pub fn parse<'a>(schema: &'a Schema, full_name: &str, json: &str, options: &JsonOptions) -> Result<DynamicMessage<'a>, JsonError> {
+    let descriptor = schema.message(full_name)
+        .ok_or_else(|| JsonError::UnknownMessage(full_name.to_string()))?;
+    let json = reader::parse(json)?;
+    Parser::new(schema, options).parse_message(descriptor, &json)
+}
+
+/// Returns the JSON text of a message of the type with the provided
+/// fully-qualified name which is encoded in `bytes`.
+///
+/// On success the JSON text is returned otherwise an error is thrown.
+pub fn from_wire(schema: &Schema, full_name: &str, bytes: &[u8], options: &JsonOptions) -> Result<String, JsonError> {
+    let mut message = DynamicMessage::new(schema, full_name)
+        .map_err(|_| JsonError::UnknownMessage(full_name.to_string()))?;
+    message.merge(bytes)?;
+    print(&message, options)
+}
+
+/// Returns the wire format bytes of a message of the type with the provided
+/// fully-qualified name which is built from the `json` text.
+///
+/// On success the encoded message is returned otherwise an error is thrown.
+pub fn to_wire(schema: &Schema, full_name: &str, json: &str, options: &JsonOptions) -> Result<Vec<u8>, JsonError> {
+    let message = parse(schema, full_name, json, options)?;
+    let mut dst = Vec::new();
+    message.encode(&mut dst)?;
+    Ok(dst)
+}
+
+/// Tells whether the message with the provided fully-qualified name has a
+/// special JSON form.
+fn is_well_known(full_name: &str) -> bool {
+    WELL_KNOWN_TYPES.contains(&full_name)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::dynamic::Value;
+
+    fn schema() -> Schema {
+        let mut schema = Schema::new();
+        schema.add("test.proto", r#"
+            syntax = "proto3";
+            package test;
+            import "google/protobuf/any.proto";
+            import "google/protobuf/duration.proto";
+            import "google/protobuf/field_mask.proto";
+            import "google/protobuf/struct.proto";
+            import "google/protobuf/timestamp.proto";
+            import "google/protobuf/wrappers.proto";
+            enum Status {
+                STATUS_UNKNOWN = 0;
+                STATUS_ACTIVE = 1;
+            }
+            message Item {
+                string item_name = 1;
+                repeated sint64 counts = 2;
+            }
+            message Record {
+                int32 id = 1;
+                int64 big = 2;
+                uint64 huge = 3;
+                double ratio = 4;
+                float weight = 5;
+                bool flag = 6;
+                bytes data = 7;
+                Status status = 8;
+                repeated Item items = 9;
+                map<int32, string> labels = 10;
+                optional string note = 11;
+                oneof choice {
+                    string text = 12;
+                    Item item = 13;
+                }
+                google.protobuf.Timestamp created_at = 14;
+                google.protobuf.Duration timeout = 15;
+                google.protobuf.FieldMask mask = 16;
+                google.protobuf.Int64Value count = 17;
+                google.protobuf.Struct meta = 18;
+                google.protobuf.Any detail = 19;
+                repeated google.protobuf.Value values = 20;
+            }
+        "#).unwrap();
+        schema
+    }
+
+    /// Should convert messages with scalar, repeated, map and oneof fields to
+    /// and from JSON.
+    #[test]
+    fn converts_messages() {
+        let schema = schema();
+        let options = JsonOptions::default();
+        let json = r#"{"id":-1,"big":"-9007199254740993","huge":"18446744073709551615","ratio":0.5,"weight":"NaN","flag":true,"data":"AQL/","status":"STATUS_ACTIVE","items":[{"itemName":"a","counts":["1","-2"]}],"labels":{"1":"one","2":"two"},"note":"","item":{}}"#;
+        let message = parse(&schema, ".test.Record", json, &options).unwrap();
+        assert_eq!(print(&message, &options).unwrap(), json);
+
+        let bytes = to_wire(&schema, ".test.Record", json, &options).unwrap();
+        assert_eq!(from_wire(&schema, ".test.Record", &bytes, &options).unwrap(), json);
+
+        let message = parse(&schema, ".test.Record", r#"{
+            "id": "5", "big": 1e3, "ratio": "Infinity", "data": "-_8", "status": 1,
+            "items": [{"item_name": "b"}], "text": "t", "labels": null, "weight": null
+        }"#, &options).unwrap();
+        assert_eq!(print(&message, &options).unwrap(), r#"{"id":5,"big":"1000","ratio":"Infinity","data":"+/8=","status":"STATUS_ACTIVE","items":[{"itemName":"b"}],"text":"t"}"#);
+
+        let options = JsonOptions { emit_defaults: true, proto_names: true, ..Default::default() };
+        let message = parse(&schema, ".test.Item", "{}", &options).unwrap();
+        assert_eq!(print(&message, &options).unwrap(), r#"{"item_name":"","counts":[]}"#);
+
+        assert_eq!(parse(&schema, ".test.Item", r#"{"other":1}"#, &options), Err(JsonError::UnknownField("other".to_string())));
+        assert!(parse(&schema, ".test.Item", r#"{"other":1}"#, &JsonOptions { ignore_unknown_fields: true, ..Default::default() }).is_ok());
+        assert_eq!(parse(&schema, ".test.Record", r#"{"id":1.5}"#, &options), Err(JsonError::InvalidValue("id".to_string())));
+        assert_eq!(parse(&schema, ".test.Record", r#"{"id":2147483648}"#, &options), Err(JsonError::InvalidValue("id".to_string())));
+        assert_eq!(parse(&schema, ".test.Record", r#"{"text":"a","item":{}}"#, &options), Err(JsonError::InvalidValue("item".to_string())));
+        assert_eq!(parse(&schema, ".test.Record", "{\n\"id\":}", &options), Err(JsonError::InvalidSyntax(2)));
+    }
+
+    /// Should convert well-known types to and from their special JSON forms.
+    #[test]
+    fn converts_well_known_types() {
+        let schema = schema();
+        let options = JsonOptions::default();
+        let json = r#"{"createdAt":"1972-01-01T10:00:20.021Z","timeout":"-1.500s","mask":"itemName,items.counts","count":"0","meta":{"a":[1,"b",true,null,{}]},"detail":{"@type":"type.googleapis.com/test.Item","itemName":"x"},"values":[null,2.5,{"c":[]}]}"#;
+        let bytes = to_wire(&schema, ".test.Record", json, &options).unwrap();
+        assert_eq!(from_wire(&schema, ".test.Record", &bytes, &options).unwrap(), json);
+
+        let message = parse(&schema, ".test.Record", r#"{"mask":"itemName"}"#, &options).unwrap();
+        let mask = message.get("mask").and_then(|v| v.as_message()).unwrap();
+        assert_eq!(mask.get("paths").and_then(|v| v.as_list()).unwrap()[0].as_str(), Some("item_name"));
+
+        let json = r#"{"detail":{"@type":"type.googleapis.com/google.protobuf.Duration","value":"3s"}}"#;
+        let message = parse(&schema, ".test.Record", json, &options).unwrap();
+        assert_eq!(print(&message, &options).unwrap(), json);
+
+        let message = parse(&schema, ".test.Record", r#"{"createdAt":"1972-01-01T11:00:20+01:00"}"#, &options).unwrap();
+        assert_eq!(print(&message, &options).unwrap(), r#"{"createdAt":"1972-01-01T10:00:20Z"}"#);
+
+        assert_eq!(parse(&schema, ".test.Record", r#"{"timeout":"1"}"#, &options), Err(JsonError::InvalidValue(".google.protobuf.Duration".to_string())));
+        assert_eq!(parse(&schema, ".test.Record", r#"{"detail":{"@type":"x/test.Missing"}}"#, &options), Err(JsonError::UnknownMessage("x/test.Missing".to_string())));
+    }
+
+    /// Should accept valid dates and times only, normalize fractions to 3, 6
+    /// or 9 digits and refuse values outside of the supported ranges.
+    #[test]
+    fn converts_timestamps_and_durations() {
+        let schema = schema();
+        let options = JsonOptions::default();
+        let valid = vec![
+            (r#"{"createdAt":"1972-02-29T00:00:00Z"}"#, r#"{"createdAt":"1972-02-29T00:00:00Z"}"#),
+            (r#"{"createdAt":"2000-02-29T23:59:59Z"}"#, r#"{"createdAt":"2000-02-29T23:59:59Z"}"#),
+            (r#"{"createdAt":"0001-01-01T00:00:00Z"}"#, r#"{"createdAt":"0001-01-01T00:00:00Z"}"#),
+            (r#"{"createdAt":"9999-12-31T23:59:59.999999999Z"}"#, r#"{"createdAt":"9999-12-31T23:59:59.999999999Z"}"#),
+            (r#"{"createdAt":"1969-12-31T23:59:59.999Z"}"#, r#"{"createdAt":"1969-12-31T23:59:59.999Z"}"#),
+            (r#"{"createdAt":"1970-01-01T00:00:00.5Z"}"#, r#"{"createdAt":"1970-01-01T00:00:00.500Z"}"#),
+            (r#"{"createdAt":"1970-01-01T00:00:00.1234Z"}"#, r#"{"createdAt":"1970-01-01T00:00:00.123400Z"}"#),
+            (r#"{"timeout":"-0.5s"}"#, r#"{"timeout":"-0.500s"}"#),
+            (r#"{"timeout":"-1.000000001s"}"#, r#"{"timeout":"-1.000000001s"}"#),
+            (r#"{"timeout":"1.5000s"}"#, r#"{"timeout":"1.500s"}"#),
+            (r#"{"timeout":"0.000001s"}"#, r#"{"timeout":"0.000001s"}"#),
+            (r#"{"timeout":"-0s"}"#, r#"{"timeout":"0s"}"#),
+            (r#"{"timeout":"315576000000.999999999s"}"#, r#"{"timeout":"315576000000.999999999s"}"#),
+            (r#"{"mask":""}"#, r#"{"mask":""}"#),
+            (r#"{"mask":"a.bC,dEf"}"#, r#"{"mask":"a.bC,dEf"}"#),
+        ];
+        for (json, expected) in valid {
+            let message = parse(&schema, ".test.Record", json, &options).unwrap();
+            assert_eq!(print(&message, &options).unwrap(), expected);
+        }
+
+        let invalid = vec![
+            (r#"{"createdAt":"1971-02-29T00:00:00Z"}"#, ".google.protobuf.Timestamp"), // not a leap year
+            (r#"{"createdAt":"1900-02-29T00:00:00Z"}"#, ".google.protobuf.Timestamp"),
+            (r#"{"createdAt":"0000-12-31T23:59:59Z"}"#, ".google.protobuf.Timestamp"),
+            (r#"{"createdAt":"10000-01-01T00:00:00Z"}"#, ".google.protobuf.Timestamp"),
+            (r#"{"createdAt":"1970-13-01T00:00:00Z"}"#, ".google.protobuf.Timestamp"),
+            (r#"{"createdAt":"1970-01-01T24:00:00Z"}"#, ".google.protobuf.Timestamp"),
+            (r#"{"createdAt":"1970-01-01T00:00:00.Z"}"#, ".google.protobuf.Timestamp"),
+            (r#"{"createdAt":"1970-01-01T00:00:00.1234567891Z"}"#, ".google.protobuf.Timestamp"),
+            (r#"{"timeout":"315576000001s"}"#, ".google.protobuf.Duration"),
+            (r#"{"timeout":"-315576000001s"}"#, ".google.protobuf.Duration"),
+            (r#"{"timeout":"1.0000000001s"}"#, ".google.protobuf.Duration"),
+            (r#"{"timeout":"1.s"}"#, ".google.protobuf.Duration"),
+            (r#"{"timeout":"+1s"}"#, ".google.protobuf.Duration"),
+            (r#"{"mask":"a_b"}"#, ".google.protobuf.FieldMask"),
+        ];
+        for (json, name) in invalid {
+            assert_eq!(parse(&schema, ".test.Record", json, &options), Err(JsonError::InvalidValue(name.to_string())), "{}", json);
+        }
+
+        let invalid = vec![
+            (".google.protobuf.Timestamp", 253_402_300_800, 0), // year 10000
+            (".google.protobuf.Timestamp", 0, 1_000_000_000),
+            (".google.protobuf.Duration", 315_576_000_001, 0),
+            (".google.protobuf.Duration", 1, -1), // signs differ
+        ];
+        for (name, seconds, nanos) in invalid {
+            let mut message = DynamicMessage::new(&schema, name).unwrap();
+            message.set("seconds", Value::Int64(seconds)).unwrap();
+            message.set("nanos", Value::Int32(nanos)).unwrap();
+            assert_eq!(print(&message, &options), Err(JsonError::InvalidValue(name.to_string())), "{} {} {}", name, seconds, nanos);
+        }
+    }
+
+    /// Should accept 64-bit integers as numbers or strings, print them as
+    /// strings and keep enum numbers which the schema does not define.
+    #[test]
+    fn converts_integers_and_enums() {
+        let schema = schema();
+        let options = JsonOptions::default();
+        let valid = vec![
+            (r#"{"big":123}"#, r#"{"big":"123"}"#),
+            (r#"{"big":"-123"}"#, r#"{"big":"-123"}"#),
+            (r#"{"big":9007199254740993}"#, r#"{"big":"9007199254740993"}"#), // beyond f64 precision
+            (r#"{"big":-9223372036854775808}"#, r#"{"big":"-9223372036854775808"}"#),
+            (r#"{"huge":"18446744073709551615"}"#, r#"{"huge":"18446744073709551615"}"#),
+            (r#"{"huge":"1e2"}"#, r#"{"huge":"100"}"#),
+            (r#"{"status":5}"#, r#"{"status":5}"#),
+            (r#"{"status":"STATUS_ACTIVE"}"#, r#"{"status":"STATUS_ACTIVE"}"#),
+        ];
+        for (json, expected) in valid {
+            let message = parse(&schema, ".test.Record", json, &options).unwrap();
+            assert_eq!(print(&message, &options).unwrap(), expected);
+        }
+
+        let bytes = [0x40, 0x07]; // status = 7
+        assert_eq!(from_wire(&schema, ".test.Record", &bytes, &options).unwrap(), r#"{"status":7}"#);
+
+        let invalid = vec![
+            (r#"{"big":"9223372036854775808"}"#, "big"),
+            (r#"{"big":"1.5"}"#, "big"),
+            (r#"{"huge":"-1"}"#, "huge"),
+            (r#"{"status":"STATUS_OTHER"}"#, "status"),
+            (r#"{"status":2147483648}"#, "status"),
+        ];
+        for (json, name) in invalid {
+            assert_eq!(parse(&schema, ".test.Record", json, &options), Err(JsonError::InvalidValue(name.to_string())), "{}", json);
+        }
+    }
+
+    /// Should convert an `Any` holding a well-known type through the `value`
+    /// key and refuse fields which are set more than once.
+    #[test]
+    fn converts_any_and_refuses_duplicates() {
+        let schema = schema();
+        let options = JsonOptions::default();
+        let valid = vec![
+            r#"{"detail":{"@type":"type.googleapis.com/google.protobuf.Timestamp","value":"1970-01-01T00:00:01Z"}}"#,
+            r#"{"detail":{"@type":"type.googleapis.com/google.protobuf.Struct","value":{"a":1}}}"#,
+            r#"{"detail":{"@type":"type.googleapis.com/google.protobuf.Value","value":[1,null]}}"#,
+            r#"{"detail":{"@type":"type.googleapis.com/google.protobuf.Any","value":{"@type":"type.googleapis.com/google.protobuf.Int32Value","value":3}}}"#,
+        ];
+        for json in valid {
+            let bytes = to_wire(&schema, ".test.Record", json, &options).unwrap();
+            assert_eq!(from_wire(&schema, ".test.Record", &bytes, &options).unwrap(), json);
+        }
+
+        let json = r#"{"detail":{"@type":"type.googleapis.com/google.protobuf.Duration","value":"3s","x":1}}"#;
+        assert_eq!(parse(&schema, ".test.Record", json, &options), Err(JsonError::UnknownField("x".to_string())));
+        assert!(parse(&schema, ".test.Record", json, &JsonOptions { ignore_unknown_fields: true, ..Default::default() }).is_ok());
+        let json = r#"{"detail":{"@type":"type.googleapis.com/google.protobuf.Duration"}}"#;
+        assert_eq!(parse(&schema, ".test.Record", json, &options), Err(JsonError::InvalidValue("value".to_string())));
+
+        let invalid = vec![
+            (r#"{"id":1,"id":2}"#, "id"),
+            (r#"{"items":[{"itemName":"a","item_name":"b"}]}"#, "item_name"),
+            (r#"{"createdAt":null,"created_at":"1970-01-01T00:00:00Z"}"#, "created_at"),
+        ];
+        for (json, name) in invalid {
+            assert_eq!(parse(&schema, ".test.Record", json, &options), Err(JsonError::DuplicateField(name.to_string())), "{}", json);
+        }
+    }
+
+    /// Should refuse values which are nested too deep instead of exhausting
+    /// the stack.
+    #[test]
+    fn limits_nesting_depth() {
+        let schema = schema();
+        let options = JsonOptions::default();
+        assert_eq!(parse(&schema, ".test.Record", &"[".repeat(200_000), &options), Err(JsonError::DepthOverflow));
+
+        let nested = |levels: usize| format!(r#"{{"meta":{}1{}}}"#, r#"{"a":"#.repeat(levels), "}".repeat(levels));
+        assert!(parse(&schema, ".test.Record", &nested(10), &options).is_ok());
+        assert_eq!(parse(&schema, ".test.Record", &nested(90), &options), Err(JsonError::DepthOverflow));
+
+        let mut bytes = Vec::new();
+        for _ in 0..2_000 {
+            let mut any = vec![0x0a, 39];
+            any.extend_from_slice(b"type.googleapis.com/google.protobuf.Any");
+            any.push(0x12);
+            let mut len = bytes.len();
+            while len >= 0x80 {
+                any.push(len as u8 | 0x80);
+                len >>= 7;
+            }
+            any.push(len as u8);
+            any.extend(bytes);
+            bytes = any;
+        }
+        assert_eq!(from_wire(&schema, ".google.protobuf.Any", &bytes, &options), Err(JsonError::DepthOverflow));
+    }
+}
@@ -0,0 +1,302 @@
+use std::cell::Cell;
+use std::collections::BTreeMap;
+use std::convert::TryFrom;
+use crate::dynamic::{DynamicMessage, MapKey, Value, MESSAGE_DEPTH_MAX};
+use crate::schema::{FieldDescriptor, FieldType, Label, MessageDescriptor, Schema};
+use super::format::*;
+use super::reader::JsonValue;
+use super::{is_well_known, JsonError, JsonOptions};
+
+/// Builds dynamic messages from parsed JSON values following the `proto3` JSON
+/// mapping.
+pub(crate) struct Parser<'a, 'o> {
+    schema: &'a Schema,
+    options: &'o JsonOptions,
+
+    /// The number of messages which are currently being built.
+    depth: Cell<usize>,
+}
+
+impl<'a, 'o> Parser<'a, 'o> {
+    /// Returns a new parser for messages of the `schema`.
+    pub(crate) fn new(schema: &'a Schema, options: &'o JsonOptions) -> Self {
+        Self { schema, options, depth: Cell::new(0) }
+    }
+
+    /// Returns the message described by the `descriptor` which is built from
+    /// the provided `json` value. Messages, including the `Struct` and
+    /// `ListValue` values, can be nested at most `MESSAGE_DEPTH_MAX` levels
+    /// deep so that they can be decoded again.
+    pub(crate) fn parse_message(&self, descriptor: &'a MessageDescriptor, json: &JsonValue) -> Result<DynamicMessage<'a>, JsonError> {
+        let depth = self.depth.get();
+        if depth >= MESSAGE_DEPTH_MAX {
+            return Err(JsonError::DepthOverflow);
+        }
+        self.depth.set(depth + 1);
+        let message = self.parse_nested(descriptor, json);
+        self.depth.set(depth);
+        message
+    }
+
+    /// Builds the message described by the `descriptor` from the provided
+    /// `json` value.
+    fn parse_nested(&self, descriptor: &'a MessageDescriptor, json: &JsonValue) -> Result<DynamicMessage<'a>, JsonError> {
+        let mut message = DynamicMessage::with_descriptor(self.schema, descriptor);
+        if self.parse_well_known(&mut message, json)? {
+            return Ok(message);
+        }
+        match json {
+            JsonValue::Object(members) => self.parse_fields(&mut message, members)?,
+            _ => return Err(JsonError::InvalidValue(descriptor.full_name.clone())),
+        }
+        Ok(message)
+    }
+
+    /// Sets the fields of the `message` from the members of a JSON object.
+    /// Fields are matched by their JSON name or by their original name and
+    /// each field can be set only once.
+    fn parse_fields(&self, message: &mut DynamicMessage<'a>, members: &[(String, JsonValue)]) -> Result<(), JsonError> {
+        let descriptor = message.descriptor();
+        let mut numbers = Vec::new();
+        let mut oneofs = Vec::new();
+        for (key, json) in members {
+            let field = match descriptor.fields.iter().find(|f| f.json_name == *key || f.name == *key) {
+                Some(field) => field,
+                None if self.options.ignore_unknown_fields => continue,
+                None => return Err(JsonError::UnknownField(key.clone())),
+            };
+            if numbers.contains(&field.number) {
+                return Err(JsonError::DuplicateField(field.name.clone()));
+            }
+            numbers.push(field.number);
+            if *json == JsonValue::Null && !accepts_null(&field.typ) {
+                continue; // null leaves the field unset
+            }
+            if let Some(oneof) = field.oneof {
+                if oneofs.contains(&oneof) {
+                    return Err(JsonError::InvalidValue(field.name.clone()));
+                }
+                oneofs.push(oneof);
+            }
+            let value = self.parse_value(field, json)?;
+            message.set(&field.name, value).map_err(|_| JsonError::InvalidValue(field.name.clone()))?;
+        }
+        Ok(())
+    }
+
+    /// Returns the value of a `field` from its JSON form. Repeated fields take
+    /// an array and map fields take an object.
+    fn parse_value(&self, field: &FieldDescriptor, json: &JsonValue) -> Result<Value<'a>, JsonError> {
+        let invalid = || JsonError::InvalidValue(field.name.clone());
+        match (&field.typ, json) {
+            (FieldType::Map(key_type, val_type), JsonValue::Object(members)) => {
+                let mut map = BTreeMap::new();
+                for (key, json) in members {
+                    let key = parse_map_key(key_type, key).ok_or_else(invalid)?;
+                    map.insert(key, self.parse_single(&field.name, val_type, json)?);
+                }
+                Ok(Value::Map(map))
+            },
+            (FieldType::Map(..), _) => Err(invalid()),
+            (typ, JsonValue::Array(items)) if field.label == Label::Repeated => {
+                let vals = items.iter().map(|json| self.parse_single(&field.name, typ, json));
+                Ok(Value::List(vals.collect::<Result<_, _>>()?))
+            },
+            _ if field.label == Label::Repeated => Err(invalid()),
+            (typ, json) => self.parse_single(&field.name, typ, json),
+        }
+    }
+
+    /// Returns a single value of the type `typ` of the field with the provided
+    /// `name` from its JSON form.
+    fn parse_single(&self, name: &str, typ: &FieldType, json: &JsonValue) -> Result<Value<'a>, JsonError> {
+        let invalid = || JsonError::InvalidValue(name.to_string());
+        let value = match (typ, json) {
+            (FieldType::Bool, JsonValue::Bool(v)) => Value::Bool(*v),
+            (FieldType::Int32 | FieldType::SInt32 | FieldType::SFixed32, json) => {
+                Value::Int32(parse_integer(json).and_then(|v| i32::try_from(v).ok()).ok_or_else(invalid)?)
+            },
+            (FieldType::Int64 | FieldType::SInt64 | FieldType::SFixed64, json) => {
+                Value::Int64(parse_integer(json).and_then(|v| i64::try_from(v).ok()).ok_or_else(invalid)?)
+            },
+            (FieldType::UInt32 | FieldType::Fixed32, json) => {
+                Value::UInt32(parse_integer(json).and_then(|v| u32::try_from(v).ok()).ok_or_else(invalid)?)
+            },
+            (FieldType::UInt64 | FieldType::Fixed64, json) => {
+                Value::UInt64(parse_integer(json).and_then(|v| u64::try_from(v).ok()).ok_or_else(invalid)?)
+            },
+            (FieldType::Float, json) => {
+                let v = parse_float(json).filter(|v| !v.is_finite() || v.abs() <= f32::MAX as f64).ok_or_else(invalid)?;
+                Value::Float(v as f32)
+            },
+            (FieldType::Double, json) => Value::Double(parse_float(json).ok_or_else(invalid)?),
+            (FieldType::String, JsonValue::String(v)) => Value::String(v.clone()),
+            (FieldType::Bytes, JsonValue::String(v)) => Value::Bytes(decode_base64(v).ok_or_else(invalid)?),
+            (FieldType::Enum(full_name), json) => Value::Enum(self.parse_enum(full_name, json).ok_or_else(invalid)?),
+            (FieldType::Message(full_name), json) => {
+                let descriptor = self.schema.message(full_name)
+                    .ok_or_else(|| JsonError::UnknownMessage(full_name.clone()))?;
+                Value::Message(self.parse_message(descriptor, json)?)
+            },
+            _ => return Err(invalid()),
+        };
+        Ok(value)
+    }
+
+    /// Returns the number of an enum value from its name or number.
+    fn parse_enum(&self, full_name: &str, json: &JsonValue) -> Option<i32> {
+        match json {
+            JsonValue::Null if full_name == ".google.protobuf.NullValue" => Some(0),
+            JsonValue::String(name) => self.schema.enumeration(full_name)?.value_by_name(name).map(|v| v.number),
+            JsonValue::Number(_) => parse_integer(json).and_then(|v| i32::try_from(v).ok()),
+            _ => None,
+        }
+    }
+
+    /// Sets the fields of a well-known type from its special JSON form.
+    /// Returns `false` when the `message` is not a well-known type with a
+    /// special form.
+    fn parse_well_known(&self, message: &mut DynamicMessage<'a>, json: &JsonValue) -> Result<bool, JsonError> {
+        let descriptor = message.descriptor();
+        let name = descriptor.full_name.as_str();
+        let invalid = || JsonError::InvalidValue(name.to_string());
+        let field = |field: &str| descriptor.field_by_name(field).ok_or_else(invalid);
+        let values = match (name, json) {
+            (".google.protobuf.Any", JsonValue::Object(members)) => self.parse_any(members)?,
+            (".google.protobuf.Timestamp", JsonValue::String(text)) => {
+                let (seconds, nanos) = parse_timestamp(text).ok_or_else(invalid)?;
+                vec![("seconds", Value::Int64(seconds)), ("nanos", Value::Int32(nanos))]
+            },
+            (".google.protobuf.Duration", JsonValue::String(text)) => {
+                let (seconds, nanos) = parse_duration(text).ok_or_else(invalid)?;
+                vec![("seconds", Value::Int64(seconds)), ("nanos", Value::Int32(nanos))]
+            },
+            (".google.protobuf.FieldMask", JsonValue::String(text)) => {
+                let paths = text.split(',').filter(|p| !p.is_empty())
+                    .map(|p| parse_field_path(p).map(Value::String).ok_or_else(invalid));
+                vec![("paths", Value::List(paths.collect::<Result<_, _>>()?))]
+            },
+            (".google.protobuf.Struct", JsonValue::Object(_)) => {
+                vec![("fields", self.parse_value(field("fields")?, json)?)]
+            },
+            (".google.protobuf.ListValue", JsonValue::Array(_)) => {
+                vec![("values", self.parse_value(field("values")?, json)?)]
+            },
+            (".google.protobuf.Value", json) => {
+                let (name, json) = match json {
+                    JsonValue::Null => ("null_value", json),
+                    JsonValue::Bool(_) => ("bool_value", json),
+                    JsonValue::Number(_) => ("number_value", json),
+                    JsonValue::String(_) => ("string_value", json),
+                    JsonValue::Array(_) => ("list_value", json),
+                    JsonValue::Object(_) => ("struct_value", json),
+                };
+                vec![(name, self.parse_value(field(name)?, json)?)]
+            },
+            (".google.protobuf.Any" | ".google.protobuf.Timestamp" | ".google.protobuf.Duration"
+                | ".google.protobuf.FieldMask" | ".google.protobuf.Struct" | ".google.protobuf.ListValue", _) => {
+                return Err(invalid());
+            },
+            (name, json) if is_well_known(name) => { // wrappers
+                vec![("value", self.parse_value(field("value")?, json)?)]
+            },
+            _ => return Ok(false),
+        };
+        for (name, value) in values {
+            message.set(name, value).map_err(|_| JsonError::InvalidValue(name.to_string()))?;
+        }
+        Ok(true)
+    }
+
+    /// Returns the fields of an `Any` from an object holding the `@type` URL
+    /// and the fields of the packed message, or its special form under the
+    /// `value` key.
+    fn parse_any(&self, members: &[(String, JsonValue)]) -> Result<Vec<(&'static str, Value<'a>)>, JsonError> {
+        let type_url = match members.iter().find(|(key, _)| key == "@type") {
+            Some((_, JsonValue::String(type_url))) => type_url,
+            Some(_) => return Err(JsonError::InvalidValue("@type".to_string())),
+            None if members.is_empty() => return Ok(Vec::new()),
+            None => return Err(JsonError::InvalidValue("@type".to_string())),
+        };
+        let name = type_url.rsplit('/').next().unwrap_or(type_url);
+        let descriptor = self.schema.message(&format!(".{}", name))
+            .ok_or_else(|| JsonError::UnknownMessage(type_url.clone()))?;
+
+        let packed = if is_well_known(&descriptor.full_name) {
+            let unknown = members.iter().find(|(key, _)| key != "@type" && key != "value");
+            if let Some((key, _)) = unknown.filter(|_| !self.options.ignore_unknown_fields) {
+                return Err(JsonError::UnknownField(key.clone()));
+            }
+            match members.iter().find(|(key, _)| key == "value") {
+                Some((_, json)) => self.parse_message(descriptor, json)?,
+                None => return Err(JsonError::InvalidValue("value".to_string())),
+            }
+        } else {
+            let members = members.iter().filter(|(key, _)| key != "@type").cloned().collect::<Vec<_>>();
+            self.parse_message(descriptor, &JsonValue::Object(members))?
+        };
+        let mut bytes = Vec::new();
+        packed.encode(&mut bytes)?;
+        Ok(vec![("type_url", Value::String(type_url.clone())), ("value", Value::Bytes(bytes))])
+    }
+}
+
+/// Tells whether a JSON `null` is a value of the type rather than an unset
+/// field.
+fn accepts_null(typ: &FieldType) -> bool {
+    match typ {
+        FieldType::Message(name) => name == ".google.protobuf.Value",
+        FieldType::Enum(name) => name == ".google.protobuf.NullValue",
+        _ => false,
+    }
+}
+
+/// Returns the key of a map entry from the JSON object key.
+fn parse_map_key(typ: &FieldType, key: &str) -> Option<MapKey> {
+    let integer = || parse_integer(&JsonValue::String(key.to_string()));
+    Some(match typ {
+        FieldType::Bool => match key {
+            "true" => MapKey::Bool(true),
+            "false" => MapKey::Bool(false),
+            _ => return None,
+        },
+        FieldType::Int32 | FieldType::SInt32 | FieldType::SFixed32 => MapKey::Int32(i32::try_from(integer()?).ok()?),
+        FieldType::Int64 | FieldType::SInt64 | FieldType::SFixed64 => MapKey::Int64(i64::try_from(integer()?).ok()?),
+        FieldType::UInt32 | FieldType::Fixed32 => MapKey::UInt32(u32::try_from(integer()?).ok()?),
+        FieldType::UInt64 | FieldType::Fixed64 => MapKey::UInt64(u64::try_from(integer()?).ok()?),
+        FieldType::String => MapKey::String(key.to_string()),
+        _ => return None,
+    })
+}
+
+/// Returns the integer of a JSON number or a string holding a number. Numbers
+/// in the exponential or the decimal notation are accepted when they have no
+/// fraction.
+fn parse_integer(json: &JsonValue) -> Option<i128> {
+    let text = match json {
+        JsonValue::Number(text) | JsonValue::String(text) => text,
+        _ => return None,
+    };
+    if let Ok(v) = text.parse::<i128>() {
+        return Some(v);
+    }
+    match text.parse::<f64>() {
+        Ok(v) if v.is_finite() && v.fract() == 0.0 && v.abs() < 2f64.powi(64) => Some(v as i128),
+        _ => None,
+    }
+}
+
+/// Returns the floating-point number of a JSON number or a string holding a
+/// number or one of the special values `NaN`, `Infinity` and `-Infinity`.
+fn parse_float(json: &JsonValue) -> Option<f64> {
+    match json {
+        JsonValue::Number(text) => text.parse::<f64>().ok().filter(|v| v.is_finite()),
+        JsonValue::String(text) => match text.as_str() {
+            "NaN" => Some(f64::NAN),
+            "Infinity" => Some(f64::INFINITY),
+            "-Infinity" => Some(f64::NEG_INFINITY),
+            text => text.parse::<f64>().ok().filter(|v| v.is_finite()),
+        },
+        _ => None,
+    }
+}
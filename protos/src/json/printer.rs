@@ -0,0 +1,275 @@
+use std::cell::Cell;
+use crate::dynamic::{DynamicMessage, MapKey, Value, MESSAGE_DEPTH_MAX};
+use crate::schema::{FieldDescriptor, FieldType, Label, Schema};
+use super::format::*;
+use super::{is_well_known, JsonError, JsonOptions};
+
+/// Writes dynamic messages as JSON text following the `proto3` JSON mapping.
+pub(crate) struct Printer<'o> {
+    options: &'o JsonOptions,
+
+    /// The number of messages which are currently being written.
+    depth: Cell<usize>,
+}
+
+impl<'o> Printer<'o> {
+    /// Returns a new printer with the provided `options`.
+    pub(crate) fn new(options: &'o JsonOptions) -> Self {
+        Self { options, depth: Cell::new(0) }
+    }
+
+    /// Writes the JSON form of the `message` into `out`. Messages, including
+    /// the ones packed into an `Any`, can be nested at most
+    /// `MESSAGE_DEPTH_MAX` levels deep.
+    pub(crate) fn print_message(&self, message: &DynamicMessage, out: &mut String) -> Result<(), JsonError> {
+        let depth = self.depth.get();
+        if depth >= MESSAGE_DEPTH_MAX {
+            return Err(JsonError::DepthOverflow);
+        }
+        self.depth.set(depth + 1);
+        let result = self.print_nested(message, out);
+        self.depth.set(depth);
+        result
+    }
+
+    /// Writes the JSON form of the `message` into `out`.
+    fn print_nested(&self, message: &DynamicMessage, out: &mut String) -> Result<(), JsonError> {
+        if self.print_well_known(message, out)? {
+            return Ok(());
+        }
+        out.push('{');
+        self.print_fields(message, out, true)?;
+        out.push('}');
+        Ok(())
+    }
+
+    /// Writes the fields of the `message` as JSON object members without the
+    /// braces. The `first` flag tells whether a member has already been
+    /// written.
+    fn print_fields(&self, message: &DynamicMessage, out: &mut String, mut first: bool) -> Result<(), JsonError> {
+        for field in &message.descriptor().fields {
+            let value = match message.get(&field.name) {
+                Some(value) if self.options.emit_defaults || !is_omitted(field, value) => value.clone(),
+                None if self.options.emit_defaults && !has_presence(field) => match message.default_value(field) {
+                    Some(value) => value,
+                    None => continue,
+                },
+                _ => continue,
+            };
+            if !first {
+                out.push(',');
+            }
+            first = false;
+            let key = if self.options.proto_names { &field.name } else { &field.json_name };
+            print_string(key, out);
+            out.push(':');
+            self.print_value(message.schema(), field, &value, out)?;
+        }
+        Ok(())
+    }
+
+    /// Writes the JSON form of the `value` of a `field`.
+    fn print_value(&self, schema: &Schema, field: &FieldDescriptor, value: &Value, out: &mut String) -> Result<(), JsonError> {
+        match (value, &field.typ) {
+            (Value::List(vals), typ) => {
+                out.push('[');
+                for (i, val) in vals.iter().enumerate() {
+                    if i > 0 {
+                        out.push(',');
+                    }
+                    self.print_single(schema, &field.name, typ, val, out)?;
+                }
+                out.push(']');
+            },
+            (Value::Map(map), FieldType::Map(_, val_type)) => {
+                out.push('{');
+                for (i, (key, val)) in map.iter().enumerate() {
+                    if i > 0 {
+                        out.push(',');
+                    }
+                    print_string(&map_key(key), out);
+                    out.push(':');
+                    self.print_single(schema, &field.name, val_type, val, out)?;
+                }
+                out.push('}');
+            },
+            (value, typ) => self.print_single(schema, &field.name, typ, value, out)?,
+        }
+        Ok(())
+    }
+
+    /// Writes the JSON form of a single `value` of the type `typ` of the field
+    /// with the provided `name`.
+    fn print_single(&self, schema: &Schema, name: &str, typ: &FieldType, value: &Value, out: &mut String) -> Result<(), JsonError> {
+        match value {
+            Value::Bool(v) => out.push_str(if *v { "true" } else { "false" }),
+            Value::Int32(v) => out.push_str(&v.to_string()),
+            Value::UInt32(v) => out.push_str(&v.to_string()),
+            Value::Int64(v) => print_string(&v.to_string(), out),
+            Value::UInt64(v) => print_string(&v.to_string(), out),
+            Value::Float(v) => print_float(*v as f64, v.to_string(), format!("{:e}", v), out),
+            Value::Double(v) => print_float(*v, v.to_string(), format!("{:e}", v), out),
+            Value::String(v) => print_string(v, out),
+            Value::Bytes(v) => print_string(&encode_base64(v), out),
+            Value::Enum(v) => match typ {
+                FieldType::Enum(name) if name == ".google.protobuf.NullValue" => out.push_str("null"),
+                FieldType::Enum(name) => match schema.enumeration(name).and_then(|e| e.value(*v)) {
+                    Some(value) => print_string(&value.name, out),
+                    None => out.push_str(&v.to_string()),
+                },
+                _ => return Err(JsonError::InvalidValue(name.to_string())),
+            },
+            Value::Message(message) => self.print_message(message, out)?,
+            Value::List(_) | Value::Map(_) => return Err(JsonError::InvalidValue(name.to_string())),
+        }
+        Ok(())
+    }
+
+    /// Writes the special JSON form of a well-known type. Returns `false` when
+    /// the `message` is not a well-known type with a special form.
+    fn print_well_known(&self, message: &DynamicMessage, out: &mut String) -> Result<bool, JsonError> {
+        let name = message.descriptor().full_name.as_str();
+        let get = |field: &str| message.get_or_default(field)
+            .ok_or_else(|| JsonError::InvalidValue(format!("{}.{}", name, field)));
+        match name {
+            ".google.protobuf.Any" => self.print_any(message, out)?,
+            ".google.protobuf.Timestamp" => {
+                let text = format_timestamp(get("seconds")?.as_i64().unwrap_or(0), get("nanos")?.as_i32().unwrap_or(0))
+                    .ok_or_else(|| JsonError::InvalidValue(name.to_string()))?;
+                print_string(&text, out);
+            },
+            ".google.protobuf.Duration" => {
+                let text = format_duration(get("seconds")?.as_i64().unwrap_or(0), get("nanos")?.as_i32().unwrap_or(0))
+                    .ok_or_else(|| JsonError::InvalidValue(name.to_string()))?;
+                print_string(&text, out);
+            },
+            ".google.protobuf.FieldMask" => {
+                let mut paths = Vec::new();
+                for path in get("paths")?.as_list().unwrap_or(&[]) {
+                    let path = path.as_str().and_then(format_field_path)
+                        .ok_or_else(|| JsonError::InvalidValue(name.to_string()))?;
+                    paths.push(path);
+                }
+                print_string(&paths.join(","), out);
+            },
+            ".google.protobuf.Struct" => match message.descriptor().field_by_name("fields") {
+                Some(field) => self.print_value(message.schema(), field, &get("fields")?, out)?,
+                None => return Err(JsonError::InvalidValue(name.to_string())),
+            },
+            ".google.protobuf.ListValue" => match message.descriptor().field_by_name("values") {
+                Some(field) => self.print_value(message.schema(), field, &get("values")?, out)?,
+                None => return Err(JsonError::InvalidValue(name.to_string())),
+            },
+            ".google.protobuf.Value" => match message.fields().next() {
+                Some((_, Value::Double(v))) if !v.is_finite() => return Err(JsonError::InvalidValue(name.to_string())),
+                Some((field, value)) => self.print_value(message.schema(), field, value, out)?,
+                None => return Err(JsonError::InvalidValue(name.to_string())),
+            },
+            name if is_well_known(name) => match message.descriptor().field_by_name("value") { // wrappers
+                Some(field) => self.print_value(message.schema(), field, &get("value")?, out)?,
+                None => return Err(JsonError::InvalidValue(name.to_string())),
+            },
+            _ => return Ok(false),
+        }
+        Ok(true)
+    }
+
+    /// Writes an `Any` as an object holding the `@type` URL and the fields of
+    /// the packed message, or its special form under the `value` key.
+    fn print_any(&self, message: &DynamicMessage, out: &mut String) -> Result<(), JsonError> {
+        let type_url = message.get_or_default("type_url").and_then(|v| v.as_str().map(String::from)).unwrap_or_default();
+        let bytes = message.get_or_default("value").and_then(|v| v.as_bytes().map(Vec::from)).unwrap_or_default();
+        if type_url.is_empty() && bytes.is_empty() {
+            out.push_str("{}");
+            return Ok(());
+        }
+
+        let packed = unpack_any(message.schema(), &type_url, &bytes)?;
+        out.push('{');
+        print_string("@type", out);
+        out.push(':');
+        print_string(&type_url, out);
+        if is_well_known(&packed.descriptor().full_name) {
+            out.push(',');
+            print_string("value", out);
+            out.push(':');
+            self.print_message(&packed, out)?;
+        } else {
+            self.print_fields(&packed, out, false)?;
+        }
+        out.push('}');
+        Ok(())
+    }
+}
+
+/// Returns the message packed into an `Any` with the provided `type_url`. The
+/// type is the fully-qualified name after the last `/` of the URL.
+fn unpack_any<'a>(schema: &'a Schema, type_url: &str, bytes: &[u8]) -> Result<DynamicMessage<'a>, JsonError> {
+    let name = type_url.rsplit('/').next().unwrap_or(type_url);
+    let mut packed = DynamicMessage::new(schema, &format!(".{}", name))
+        .map_err(|_| JsonError::UnknownMessage(type_url.to_string()))?;
+    packed.merge(bytes)?;
+    Ok(packed)
+}
+
+/// Tells whether the presence of the `field` is tracked so that its default
+/// value is never printed for an unset field.
+fn has_presence(field: &FieldDescriptor) -> bool {
+    match field.label {
+        Label::Repeated => false,
+        Label::Optional => true,
+        Label::Singular => field.oneof.is_some() || matches!(field.typ, FieldType::Message(_)),
+    }
+}
+
+/// Tells whether the set `value` of a `field` is left out because it is not
+/// distinguishable from an unset field.
+fn is_omitted(field: &FieldDescriptor, value: &Value) -> bool {
+    !has_presence(field) && value.is_default()
+}
+
+/// Returns the JSON object key of a map entry.
+fn map_key(key: &MapKey) -> String {
+    match key {
+        MapKey::Bool(v) => v.to_string(),
+        MapKey::Int32(v) => v.to_string(),
+        MapKey::Int64(v) => v.to_string(),
+        MapKey::UInt32(v) => v.to_string(),
+        MapKey::UInt64(v) => v.to_string(),
+        MapKey::String(v) => v.clone(),
+    }
+}
+
+/// Writes a floating-point number from its shortest decimal and exponential
+/// forms. Special values are written as strings and very large or small
+/// numbers in the exponential notation.
+fn print_float(value: f64, decimal: String, exponential: String, out: &mut String) {
+    if value.is_nan() {
+        out.push_str("\"NaN\"");
+    } else if value.is_infinite() {
+        out.push_str(if value > 0.0 { "\"Infinity\"" } else { "\"-Infinity\"" });
+    } else if value != 0.0 && (value.abs() >= 1e21 || value.abs() < 1e-7) {
+        out.push_str(&exponential);
+    } else {
+        out.push_str(&decimal);
+    }
+}
+
+/// Writes the provided `text` as a quoted JSON string.
+pub(crate) fn print_string(text: &str, out: &mut String) {
+    out.push('"');
+    for c in text.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            '\u{8}' => out.push_str("\\b"),
+            '\u{c}' => out.push_str("\\f"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+}
@@ -34,6 +34,9 @@
 //! transmitting typed objects over the wire. It offers the full implementation
 //! of the Protocol Buffer's binary protocol.
 //! 
+//! Rust types for `.proto` files, including the well-known types in `wkt`, are
+//! generated with the companion [httlib-protos-build] crate.
+//! 
 //! ## Usage
 //!
 //! **Encoding example:**
@@ -67,20 +70,179 @@
 //! }
 //! ```
 //! 
+//! **Zero-copy decoding example:**
+//! 
+//! ```rust
+//! use httlib_protos::FieldIter;
+//! use httlib_protos::decoder::primitives::decode_int32;
+//! 
+//! let buf = [0x08, 0x96, 0x01];
+//! 
+//! for field in FieldIter::new(&buf) {
+//!     let (tag, typ, byt) = field.unwrap(); // `byt` borrows from `buf`
+//!     if tag == 1 {
+//!         let mut val = 0;
+//!         decode_int32(byt, &mut val).unwrap();
+//!     }
+//! }
+//! ```
+//! 
+//! **Typed message example (requires the `derive` feature):**
+//! 
+//! ```rust
+//! # #[cfg(feature = "derive")] {
+//! use httlib_protos::Message;
+//! 
+//! #[derive(Debug, Default, PartialEq, Message)]
+//! struct Point {
+//!     #[proto(tag = 1, kind = "sint32")]
+//!     x: i32,
+//!     #[proto(tag = 2, kind = "sint32")]
+//!     ys: Vec<i32>,
+//! }
+//! 
+//! let point = Point { x: 1, ys: vec![-1] };
+//! 
+//! let mut dst = Vec::new();
+//! point.encode(&mut dst).unwrap();
+//! assert_eq!(dst, vec![0x08, 0x02, 0x12, 0x01, 0x01]);
+//! assert_eq!(Point::decode(&dst).unwrap(), point);
+//! # }
+//! ```
+//! 
+//! **Schema parsing example:**
+//! 
+//! ```rust
+//! use httlib_protos::schema::Schema;
+//! 
+//! let mut schema = Schema::new();
+//! schema.include("./protos"); // directories where imports are searched for
+//! schema.add("point.proto", r#"
+//!     syntax = "proto3";
+//!     package geo;
+//!     message Point {
+//!         sint32 x = 1;
+//!         sint32 y = 2;
+//!     }
+//! "#).unwrap();
+//! 
+//! let point = schema.message(".geo.Point").unwrap();
+//! assert_eq!(point.fields.len(), 2);
+//! ```
+//! 
+//! **Dynamic message example:**
+//! 
+//! ```rust
+//! use httlib_protos::dynamic::{DynamicMessage, Value};
+//! use httlib_protos::schema::Schema;
+//! 
+//! let mut schema = Schema::new();
+//! schema.add("point.proto", r#"
+//!     syntax = "proto3";
+//!     package geo;
+//!     message Point {
+//!         sint32 x = 1;
+//!         sint32 y = 2;
+//!     }
+//! "#).unwrap();
+//! 
+//! let mut point = DynamicMessage::new(&schema, ".geo.Point").unwrap();
+//! point.merge(&[0x08, 0x02, 0x10, 0x01]).unwrap();
+//! assert_eq!(point.get("x"), Some(&Value::Int32(1)));
+//! 
+//! point.set("y", 5).unwrap();
+//! let mut dst = Vec::new();
+//! point.encode(&mut dst).unwrap();
+//! ```
+//! 
+//! **JSON example:**
+//! 
+//! ```rust
+//! use httlib_protos::json::{self, JsonOptions};
+//! use httlib_protos::schema::Schema;
+//! 
+//! let mut schema = Schema::new();
+//! schema.add("point.proto", r#"
+//!     syntax = "proto3";
+//!     package geo;
+//!     message Point {
+//!         sint32 x = 1;
+//!         int64 big_y = 2;
+//!     }
+//! "#).unwrap();
+//! 
+//! let options = JsonOptions::default();
+//! let bytes = json::to_wire(&schema, ".geo.Point", r#"{"x":1,"bigY":"5"}"#, &options).unwrap();
+//! let text = json::from_wire(&schema, ".geo.Point", &bytes, &options).unwrap();
+//! assert_eq!(text, r#"{"x":1,"bigY":"5"}"#);
+//! ```
+//! 
+//! **Text format example:**
+//! 
+//! ```rust
+//! use httlib_protos::schema::Schema;
+//! use httlib_protos::text;
+//! 
+//! let mut schema = Schema::new();
+//! schema.add("point.proto", r#"
+//!     syntax = "proto3";
+//!     package geo;
+//!     message Point {
+//!         sint32 x = 1;
+//!         sint32 y = 2;
+//!     }
+//! "#).unwrap();
+//! 
+//! let bytes = text::to_wire(&schema, ".geo.Point", "x: 1 y: -1").unwrap();
+//! assert_eq!(text::from_wire(&schema, ".geo.Point", &bytes).unwrap(), "x: 1\ny: -1\n");
+//! assert_eq!(text::from_wire_raw(&bytes).unwrap(), "1: 2\n2: 1\n");
+//! ```
+//! 
+//! **Dump example:**
+//! 
+//! ```rust
+//! use httlib_protos::dump::{dump, DumpOptions};
+//! 
+//! let buf = [0x08, 0x96, 0x01, 0x1a, 0x02, 0x08, 0x07];
+//! assert_eq!(dump(&buf, &DumpOptions::default()), concat!(
+//!     "1: 150  # varint\n",
+//!     "3 {  # message, 2 bytes\n",
+//!     "  1: 7  # varint\n",
+//!     "}\n",
+//! ));
+//! ```
+//! 
+//! The same dump is printed for a file or the standard input by the
+//! `httlib-protos-dump [--offsets] [--hex] [FILE]` command.
+//! 
 //! ## Articles
 //! 
 //! * [Deep dive into the binary algorithm of Protocol Buffers](https://dev.to/xpepermint/deep-dive-into-the-binary-algorithm-of-protocol-buffers-7j2)
 //! 
 //! [Protocol Buffers]: https://en.wikipedia.org/wiki/Protocol_Buffers
+//! [httlib-protos-build]: https://docs.rs/httlib-protos-build
 //! [gRPC]: https://grpc.io/
 //! [REST]: https://en.wikipedia.org/wiki/Representational_state_transfer
 //! [RPC]: https://en.wikipedia.org/wiki/Remote_procedure_call
 //! [JSON]: https://en.wikipedia.org/wiki/JSON
 
 pub mod decoder;
+pub mod dump;
+pub mod dynamic;
 pub mod encoder;
+pub mod json;
+pub mod message;
+pub mod schema;
+pub mod text;
 mod typ;
+pub mod wkt;
 
 pub use decoder::*;
 pub use encoder::*;
+pub use message::{Enumeration, Message, Oneof};
 pub use typ::*;
+
+#[cfg(feature = "derive")]
+pub use httlib_protos_derive::{Enumeration, Message, Oneof};
+
+extern crate self as httlib_protos;
@@ -0,0 +1,138 @@
+//! Provides the encoding and decoding of message fields by their cardinality.
+//!
+//! A field of kind `K` can be singular (`K::Value`), optional
+//! (`Option<K::Value>`) or repeated (`Vec<K::Value>`). Repeated fields of
+//! numeric kinds are packed by default. While decoding, a repeated numeric
+//! field accepts both the packed and the unpacked format as required by the
+//! protocol.
+//!
+//! Map fields (`HashMap<K::Value, V::Value>`) are written as repeated embedded
+//! messages where each entry holds the key under tag `1` and the value under
+//! tag `2`.
+
+use std::collections::HashMap;
+use std::hash::Hash;
+use crate::{DecoderError, EncoderError, FieldIter, MessageEncoder, Typ};
+use super::kind::{Kind, Packed};
+
+/// Encodes a singular field with a specific `tag` number. The field is left out
+/// when it holds the default value.
+///
+/// On success the number of written bytes is returned otherwise an error is
+/// thrown.
+pub fn encode<K: Kind>(tag: u32, val: &K::Value, encoder: &mut MessageEncoder) -> Result<usize, EncoderError> {
+    if K::is_default(val) {
+        Ok(0)
+    } else {
+        K::encode(tag, val, encoder)
+    }
+}
+
+/// Encodes an optional field with a specific `tag` number. The field is written
+/// whenever it is set, even if it holds the default value.
+///
+/// On success the number of written bytes is returned otherwise an error is
+/// thrown.
+pub fn encode_optional<K: Kind>(tag: u32, val: &Option<K::Value>, encoder: &mut MessageEncoder) -> Result<usize, EncoderError> {
+    match val {
+        Some(val) => K::encode(tag, val, encoder),
+        None => Ok(0),
+    }
+}
+
+/// Encodes a repeated field with a specific `tag` number where each value is
+/// written as a separate field.
+///
+/// On success the number of written bytes is returned otherwise an error is
+/// thrown.
+pub fn encode_repeated<K: Kind>(tag: u32, vals: &[K::Value], encoder: &mut MessageEncoder) -> Result<usize, EncoderError> {
+    let mut size = 0;
+    for val in vals {
+        size += K::encode(tag, val, encoder)?;
+    }
+    Ok(size)
+}
+
+/// Encodes a packed repeated field with a specific `tag` number. An empty list
+/// is left out.
+///
+/// On success the number of written bytes is returned otherwise an error is
+/// thrown.
+#[allow(clippy::ptr_arg)]
+pub fn encode_packed<K: Packed>(tag: u32, vals: &Vec<K::Value>, encoder: &mut MessageEncoder) -> Result<usize, EncoderError> {
+    if vals.is_empty() {
+        Ok(0)
+    } else {
+        K::encode_packed(tag, vals, encoder)
+    }
+}
+
+/// Encodes a map field with a specific `tag` number where each entry is
+/// written as an embedded message.
+///
+/// On success the number of written bytes is returned otherwise an error is
+/// thrown.
+pub fn encode_map<K: Kind, V: Kind>(tag: u32, map: &HashMap<K::Value, V::Value>, encoder: &mut MessageEncoder) -> Result<usize, EncoderError> {
+    let mut size = 0;
+    for (key, val) in map {
+        size += encoder.encode_message(&tag, |entry| {
+            K::encode(1, key, entry)?;
+            V::encode(2, val, entry)?;
+            Ok(())
+        })?;
+    }
+    Ok(size)
+}
+
+/// Decodes the value `bytes` of a singular field and merges it into `val`.
+pub fn merge<K: Kind>(val: &mut K::Value, typ: Typ, bytes: &[u8]) -> Result<(), DecoderError> {
+    K::merge(val, typ, bytes)
+}
+
+/// Decodes the value `bytes` of an optional field and merges it into `val`
+/// which becomes set.
+pub fn merge_optional<K: Kind>(val: &mut Option<K::Value>, typ: Typ, bytes: &[u8]) -> Result<(), DecoderError> {
+    K::merge(val.get_or_insert_with(Default::default), typ, bytes)
+}
+
+/// Decodes the value `bytes` of a repeated field and appends it to `vals`.
+pub fn merge_repeated<K: Kind>(vals: &mut Vec<K::Value>, typ: Typ, bytes: &[u8]) -> Result<(), DecoderError> {
+    let mut val = K::Value::default();
+    K::merge(&mut val, typ, bytes)?;
+    vals.push(val);
+    Ok(())
+}
+
+/// Decodes the value `bytes` of a repeated numeric field in either the packed
+/// or the unpacked format and appends the values to `vals`.
+pub fn merge_packed<K: Packed>(vals: &mut Vec<K::Value>, typ: Typ, bytes: &[u8]) -> Result<(), DecoderError> {
+    if typ == Typ::LengthDelimited {
+        K::merge_packed(vals, bytes)
+    } else {
+        merge_repeated::<K>(vals, typ, bytes)
+    }
+}
+
+/// Decodes the value `bytes` of a map entry and inserts the entry into `map`.
+/// A missing key or value takes the default value.
+pub fn merge_map<K, V>(map: &mut HashMap<K::Value, V::Value>, typ: Typ, bytes: &[u8]) -> Result<(), DecoderError>
+where
+    K: Kind,
+    V: Kind,
+    K::Value: Eq + Hash,
+{
+    if typ != Typ::LengthDelimited {
+        return Err(DecoderError::UnexpectedWireType);
+    }
+    let mut key = K::Value::default();
+    let mut val = V::Value::default();
+    for field in FieldIter::new(bytes) {
+        match field? {
+            (1, typ, bytes) => K::merge(&mut key, typ, bytes)?,
+            (2, typ, bytes) => V::merge(&mut val, typ, bytes)?,
+            _ => {},
+        }
+    }
+    map.insert(key, val);
+    Ok(())
+}
@@ -0,0 +1,276 @@
+//! Provides field kinds which map `proto3` scalar types to Rust types.
+//!
+//! Each kind is a marker type implementing the `Kind` trait which knows how a
+//! single value of the kind is written to and read from the wire. Numeric kinds
+//! also implement the `Packed` trait for repeated fields which are by default
+//! encoded as a single length-delimited field in `proto3`.
+//!
+//! | Kind | Rust type | Wire type |
+//! |-|-|-|
+//! | `Bool` | `bool` | `0` |
+//! | `Int32`, `SInt32` | `i32` | `0` |
+//! | `Int64`, `SInt64` | `i64` | `0` |
+//! | `UInt32` | `u32` | `0` |
+//! | `UInt64` | `u64` | `0` |
+//! | `Fixed32` | `u32` | `5` |
+//! | `SFixed32` | `i32` | `5` |
+//! | `Float` | `f32` | `5` |
+//! | `Fixed64` | `u64` | `1` |
+//! | `SFixed64` | `i64` | `1` |
+//! | `Double` | `f64` | `1` |
+//! | `String` | `String` | `2` |
+//! | `Bytes` | `Vec<u8>` | `2` |
+//! | `Message<M>` | `M: Message` | `2` |
+//! | `Enumeration<E>` | `E: Enumeration` | `0` |
+
+use std::marker::PhantomData;
+use std::str;
+use crate::{DecoderError, EncoderError, EncoderLit, MessageEncoder, Typ};
+use crate::decoder::primitives::*;
+
+/// Provides the encoding and decoding of a single value of a field kind.
+pub trait Kind {
+    /// The Rust type of the value.
+    type Value: Default;
+
+    /// Encodes the provided `val` into a field with a specific `tag` number.
+    ///
+    /// On success the number of written bytes is returned otherwise an error is
+    /// thrown.
+    fn encode(tag: u32, val: &Self::Value, encoder: &mut MessageEncoder) -> Result<usize, EncoderError>;
+
+    /// Decodes the value `bytes` of wire type `typ` and merges it into `val`.
+    fn merge(val: &mut Self::Value, typ: Typ, bytes: &[u8]) -> Result<(), DecoderError>;
+
+    /// Tells whether the value equals the default value of the kind. Fields
+    /// without presence holding the default value are not written.
+    fn is_default(val: &Self::Value) -> bool;
+}
+
+/// Provides the packed encoding of repeated fields of a numeric kind.
+pub trait Packed: Kind {
+    /// Encodes the provided `vals` into a single length-delimited field with a
+    /// specific `tag` number.
+    ///
+    /// On success the number of written bytes is returned otherwise an error is
+    /// thrown.
+    #[allow(clippy::ptr_arg)]
+    fn encode_packed(tag: u32, vals: &Vec<Self::Value>, encoder: &mut MessageEncoder) -> Result<usize, EncoderError>;
+
+    /// Decodes the packed value `bytes` and appends the values to `vals`.
+    fn merge_packed(vals: &mut Vec<Self::Value>, bytes: &[u8]) -> Result<(), DecoderError>;
+}
+
+/// Validates that the wire type `typ` matches the `expected` one.
+fn expect(typ: Typ, expected: Typ) -> Result<(), DecoderError> {
+    if typ == expected {
+        Ok(())
+    } else {
+        Err(DecoderError::UnexpectedWireType)
+    }
+}
+
+macro_rules! scalar {
+    ($(#[$doc:meta])* $kind:ident, $ty:ty, $typ:ident, $lit:ident, $vec:ident, $decode:ident, $decode_vec:ident, $is_default:expr) => {
+        $(#[$doc])*
+        #[derive(Debug)]
+        pub struct $kind;
+
+        impl Kind for $kind {
+            type Value = $ty;
+
+            fn encode(tag: u32, val: &$ty, encoder: &mut MessageEncoder) -> Result<usize, EncoderError> {
+                encoder.encode((&tag, EncoderLit::$lit(val)))
+            }
+
+            fn merge(val: &mut $ty, typ: Typ, bytes: &[u8]) -> Result<(), DecoderError> {
+                expect(typ, Typ::$typ)?;
+                $decode(bytes, val)?;
+                Ok(())
+            }
+
+            fn is_default(val: &$ty) -> bool {
+                let is_default: fn(&$ty) -> bool = $is_default;
+                is_default(val)
+            }
+        }
+
+        impl Packed for $kind {
+            fn encode_packed(tag: u32, vals: &Vec<$ty>, encoder: &mut MessageEncoder) -> Result<usize, EncoderError> {
+                encoder.encode((&tag, EncoderLit::$vec(vals)))
+            }
+
+            fn merge_packed(vals: &mut Vec<$ty>, bytes: &[u8]) -> Result<(), DecoderError> {
+                if !bytes.is_empty() {
+                    $decode_vec(bytes, vals)?;
+                }
+                Ok(())
+            }
+        }
+    };
+}
+
+scalar!(
+    /// Represents the `bool` kind.
+    Bool, bool, Varint, Bool, BoolVec, decode_bool, decode_bool_vec, |v| !*v
+);
+scalar!(
+    /// Represents the `int32` kind.
+    Int32, i32, Varint, Int32, Int32Vec, decode_int32, decode_int32_vec, |v| *v == 0
+);
+scalar!(
+    /// Represents the `int64` kind.
+    Int64, i64, Varint, Int64, Int64Vec, decode_int64, decode_int64_vec, |v| *v == 0
+);
+scalar!(
+    /// Represents the `uint32` kind.
+    UInt32, u32, Varint, UInt32, UInt32Vec, decode_uint32, decode_uint32_vec, |v| *v == 0
+);
+scalar!(
+    /// Represents the `uint64` kind.
+    UInt64, u64, Varint, UInt64, UInt64Vec, decode_uint64, decode_uint64_vec, |v| *v == 0
+);
+scalar!(
+    /// Represents the `sint32` kind.
+    SInt32, i32, Varint, SInt32, SInt32Vec, decode_sint32, decode_sint32_vec, |v| *v == 0
+);
+scalar!(
+    /// Represents the `sint64` kind.
+    SInt64, i64, Varint, SInt64, SInt64Vec, decode_sint64, decode_sint64_vec, |v| *v == 0
+);
+scalar!(
+    /// Represents the `fixed32` kind.
+    Fixed32, u32, Bit32, Fixed32, Fixed32Vec, decode_fixed32, decode_fixed32_vec, |v| *v == 0
+);
+scalar!(
+    /// Represents the `fixed64` kind.
+    Fixed64, u64, Bit64, Fixed64, Fixed64Vec, decode_fixed64, decode_fixed64_vec, |v| *v == 0
+);
+scalar!(
+    /// Represents the `sfixed32` kind.
+    SFixed32, i32, Bit32, SFixed32, SFixed32Vec, decode_sfixed32, decode_sfixed32_vec, |v| *v == 0
+);
+scalar!(
+    /// Represents the `sfixed64` kind.
+    SFixed64, i64, Bit64, SFixed64, SFixed64Vec, decode_sfixed64, decode_sfixed64_vec, |v| *v == 0
+);
+scalar!(
+    /// Represents the `float` kind. A negative zero is not the default value.
+    Float, f32, Bit32, Float, FloatVec, decode_float, decode_float_vec, |v| v.to_bits() == 0
+);
+scalar!(
+    /// Represents the `double` kind. A negative zero is not the default value.
+    Double, f64, Bit64, Double, DoubleVec, decode_double, decode_double_vec, |v| v.to_bits() == 0
+);
+
+/// Represents the `string` kind. The value must be a valid UTF-8 string.
+#[derive(Debug)]
+pub struct String;
+
+impl Kind for String {
+    type Value = std::string::String;
+
+    fn encode(tag: u32, val: &Self::Value, encoder: &mut MessageEncoder) -> Result<usize, EncoderError> {
+        encoder.encode_bytes(&tag, val.as_bytes())
+    }
+
+    fn merge(val: &mut Self::Value, typ: Typ, bytes: &[u8]) -> Result<(), DecoderError> {
+        expect(typ, Typ::LengthDelimited)?;
+        let text = str::from_utf8(bytes).map_err(|_| DecoderError::InvalidInput)?;
+        val.clear();
+        val.push_str(text);
+        Ok(())
+    }
+
+    fn is_default(val: &Self::Value) -> bool {
+        val.is_empty()
+    }
+}
+
+/// Represents the `bytes` kind.
+#[derive(Debug)]
+pub struct Bytes;
+
+impl Kind for Bytes {
+    type Value = Vec<u8>;
+
+    fn encode(tag: u32, val: &Self::Value, encoder: &mut MessageEncoder) -> Result<usize, EncoderError> {
+        encoder.encode_bytes(&tag, val)
+    }
+
+    fn merge(val: &mut Self::Value, typ: Typ, bytes: &[u8]) -> Result<(), DecoderError> {
+        expect(typ, Typ::LengthDelimited)?;
+        val.clear();
+        val.extend_from_slice(bytes);
+        Ok(())
+    }
+
+    fn is_default(val: &Self::Value) -> bool {
+        val.is_empty()
+    }
+}
+
+/// Represents the kind of an embedded message `M`.
+///
+/// Embedded messages have presence, thus a message is never treated as the
+/// default value. Wrap the field into an `Option` to leave it out.
+#[derive(Debug)]
+pub struct Message<M>(PhantomData<M>);
+
+impl<M: crate::Message> Kind for Message<M> {
+    type Value = M;
+
+    fn encode(tag: u32, val: &M, encoder: &mut MessageEncoder) -> Result<usize, EncoderError> {
+        encoder.encode_message(&tag, |message| val.encode_fields(message))
+    }
+
+    fn merge(val: &mut M, typ: Typ, bytes: &[u8]) -> Result<(), DecoderError> {
+        expect(typ, Typ::LengthDelimited)?;
+        val.merge(bytes)
+    }
+
+    fn is_default(_val: &M) -> bool {
+        false
+    }
+}
+
+/// Represents the kind of an enumeration `E` which is encoded as `int32`.
+#[derive(Debug)]
+pub struct Enumeration<E>(PhantomData<E>);
+
+impl<E: crate::Enumeration> Kind for Enumeration<E> {
+    type Value = E;
+
+    fn encode(tag: u32, val: &E, encoder: &mut MessageEncoder) -> Result<usize, EncoderError> {
+        encoder.encode((&tag, EncoderLit::Int32(&val.to_i32())))
+    }
+
+    fn merge(val: &mut E, typ: Typ, bytes: &[u8]) -> Result<(), DecoderError> {
+        expect(typ, Typ::Varint)?;
+        let mut num = 0;
+        decode_int32(bytes, &mut num)?;
+        *val = E::from_i32(num).unwrap_or_default();
+        Ok(())
+    }
+
+    fn is_default(val: &E) -> bool {
+        val.to_i32() == 0
+    }
+}
+
+impl<E: crate::Enumeration> Packed for Enumeration<E> {
+    fn encode_packed(tag: u32, vals: &Vec<E>, encoder: &mut MessageEncoder) -> Result<usize, EncoderError> {
+        let nums = vals.iter().map(|val| val.to_i32()).collect::<Vec<i32>>();
+        encoder.encode((&tag, EncoderLit::Int32Vec(&nums)))
+    }
+
+    fn merge_packed(vals: &mut Vec<E>, bytes: &[u8]) -> Result<(), DecoderError> {
+        if bytes.is_empty() {
+            return Ok(());
+        }
+        let mut nums = vec![];
+        decode_int32_vec(bytes, &mut nums)?;
+        vals.extend(nums.into_iter().map(|num| E::from_i32(num).unwrap_or_default()));
+        Ok(())
+    }
+}
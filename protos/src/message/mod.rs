@@ -0,0 +1,284 @@
+//! Provides typed messages on top of the encoder and the decoder.
+//!
+//! A message is a Rust structure whose fields map to the fields of a `proto3`
+//! message. The `Message` trait describes how the fields are written through
+//! the `MessageEncoder` and how they are read back from the wire. The trait is
+//! usually not implemented by hand but derived with the `derive` feature which
+//! takes care of field kinds and tag numbers.
+//!
+//! ```rust
+//! use httlib_protos::{Message, MessageEncoder, EncoderError, DecoderError, Typ};
+//! use httlib_protos::message::{field, kind};
+//!
+//! #[derive(Debug, Default, PartialEq)]
+//! struct Point {
+//!     x: i32,
+//!     y: i32,
+//! }
+//!
+//! impl Message for Point {
+//!     fn encode_fields(&self, encoder: &mut MessageEncoder) -> Result<(), EncoderError> {
+//!         field::encode::<kind::SInt32>(1, &self.x, encoder)?;
+//!         field::encode::<kind::SInt32>(2, &self.y, encoder)?;
+//!         Ok(())
+//!     }
+//!
+//!     fn merge_field(&mut self, tag: u32, typ: Typ, bytes: &[u8]) -> Result<(), DecoderError> {
+//!         match tag {
+//!             1 => field::merge::<kind::SInt32>(&mut self.x, typ, bytes),
+//!             2 => field::merge::<kind::SInt32>(&mut self.y, typ, bytes),
+//!             _ => Ok(()),
+//!         }
+//!     }
+//! }
+//!
+//! let point = Point { x: 1, y: -1 };
+//!
+//! let mut dst = Vec::new();
+//! point.encode(&mut dst).unwrap();
+//! assert_eq!(dst, vec![0x08, 0x02, 0x10, 0x01]);
+//! assert_eq!(Point::decode(&dst).unwrap(), point);
+//! ```
+
+pub mod field;
+pub mod kind;
+
+use std::collections::VecDeque;
+use std::io;
+use crate::{DecoderError, Encoder, EncoderError, FieldIter, MessageEncoder, Typ};
+
+/// Provides the encoding and decoding of a typed `proto3` message.
+///
+/// Only `encode_fields` and `merge_field` have to be implemented. The rest of
+/// the functions are built on top of them.
+pub trait Message: Default {
+    /// Writes the fields of the message through the provided `encoder`.
+    ///
+    /// The function is called twice per encoding (see `Encoder::encode_fields`)
+    /// and must write the same fields on each call.
+    fn encode_fields(&self, encoder: &mut MessageEncoder) -> Result<(), EncoderError>;
+
+    /// Merges a single field with a specific `tag` number, wire type `typ` and
+    /// the value `bytes` into the message. Fields with unknown tag numbers
+    /// should be ignored.
+    fn merge_field(&mut self, tag: u32, typ: Typ, bytes: &[u8]) -> Result<(), DecoderError>;
+
+    /// Encodes the message and writes the resulting bytes into `dst`.
+    ///
+    /// On success the number of written bytes is returned otherwise an error is
+    /// thrown.
+    fn encode<W>(&self, dst: &mut W) -> Result<usize, EncoderError>
+    where
+        W: ?Sized + io::Write,
+        Self: Sized,
+    {
+        Encoder.encode_fields(dst, |encoder| self.encode_fields(encoder))
+    }
+
+    /// Returns the number of bytes the encoded message takes.
+    ///
+    /// The fields are only counted, nothing is written. On success the size is
+    /// returned otherwise the error of the failed field is thrown.
+    fn encoded_len(&self) -> Result<usize, EncoderError> {
+        let mut sink = io::sink();
+        let mut sizes = VecDeque::new();
+        let mut encoder = MessageEncoder::new(&mut sink, &mut sizes, true);
+        self.encode_fields(&mut encoder)?;
+        Ok(encoder.size())
+    }
+
+    /// Decodes a new message from the provided `buf`.
+    ///
+    /// On success the message is returned otherwise an error is thrown.
+    fn decode(buf: &[u8]) -> Result<Self, DecoderError>
+    where
+        Self: Sized,
+    {
+        let mut message = Self::default();
+        message.merge(buf)?;
+        Ok(message)
+    }
+
+    /// Decodes the fields from the provided `buf` and merges them into the
+    /// message.
+    ///
+    /// Following the protocol, singular fields are overwritten, repeated
+    /// fields are extended and embedded messages are merged recursively.
+    fn merge(&mut self, buf: &[u8]) -> Result<(), DecoderError> {
+        for field in FieldIter::new(buf) {
+            let (tag, typ, bytes) = field?;
+            self.merge_field(tag, typ, bytes)?;
+        }
+        Ok(())
+    }
+
+    /// Resets all fields of the message to their default values.
+    fn clear(&mut self) {
+        *self = Self::default();
+    }
+}
+
+impl<M: Message> Message for Box<M> {
+    fn encode_fields(&self, encoder: &mut MessageEncoder) -> Result<(), EncoderError> {
+        (**self).encode_fields(encoder)
+    }
+
+    fn merge_field(&mut self, tag: u32, typ: Typ, bytes: &[u8]) -> Result<(), DecoderError> {
+        (**self).merge_field(tag, typ, bytes)
+    }
+}
+
+/// Provides the conversion of a Rust enum to and from a `proto3` enumeration.
+///
+/// Enumerations are encoded as `int32` values. Because `proto3` enumerations
+/// are open, a value without a matching variant can be found on the wire. Such
+/// values are decoded as the default variant.
+pub trait Enumeration: Default + Copy {
+    /// Returns the numeric value of the variant.
+    fn to_i32(&self) -> i32;
+
+    /// Returns the variant for the numeric `value` if it exists.
+    fn from_i32(value: i32) -> Option<Self>;
+}
+
+/// Provides the encoding and decoding of a `oneof` field.
+///
+/// A oneof is represented by a Rust enum where each variant wraps a single
+/// field with its own tag number. The message holds the oneof as an `Option`
+/// where `None` means that none of the fields is set.
+pub trait Oneof: Sized {
+    /// Writes the field of the set variant through the provided `encoder`.
+    fn encode_fields(&self, encoder: &mut MessageEncoder) -> Result<(), EncoderError>;
+
+    /// Merges a field with a specific `tag` number, wire type `typ` and the
+    /// value `bytes` into the `oneof`. A field of a different variant replaces
+    /// the current value.
+    fn merge_field(oneof: &mut Option<Self>, tag: u32, typ: Typ, bytes: &[u8]) -> Result<(), DecoderError>;
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use httlib_protos_derive::{Enumeration, Message, Oneof};
+    use crate::EncoderLit;
+
+    #[derive(Debug, Clone, Copy, Default, PartialEq, Enumeration)]
+    enum Status {
+        #[default]
+        Unknown = 0,
+        Active = 1,
+        Blocked = 3,
+    }
+
+    #[derive(Debug, Default, PartialEq, Message)]
+    struct Address {
+        #[proto(tag = 1, kind = "string")]
+        city: String,
+        #[proto(tag = 2, kind = "fixed32")]
+        zip: u32,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Oneof)]
+    enum Contact {
+        #[proto(tag = 10, kind = "string")]
+        Email(String),
+        #[proto(tag = 11, kind = "bytes")]
+        Phone(Vec<u8>),
+    }
+
+    #[derive(Debug, Default, PartialEq, Message)]
+    struct User {
+        #[proto(tag = 1, kind = "sint32")]
+        id: i32,
+        #[proto(tag = 2, kind = "bool")]
+        admin: Option<bool>,
+        #[proto(tag = 3, kind = "int64")]
+        scores: Vec<i64>,
+        #[proto(tag = 4, kind = "double", packed = false)]
+        weights: Vec<f64>,
+        #[proto(tag = 5, kind = "string")]
+        tags: Vec<String>,
+        #[proto(tag = 6, kind = "message")]
+        address: Option<Address>,
+        #[proto(tag = 7, kind = "message")]
+        history: Vec<Address>,
+        #[proto(tag = 8, kind = "enum")]
+        status: Status,
+        #[proto(tag = 9, kind = "enum")]
+        flags: Vec<Status>,
+        #[proto(oneof, tags = "10, 11")]
+        contact: Option<Contact>,
+    }
+
+    fn user() -> User {
+        User {
+            id: -150,
+            admin: Some(false),
+            scores: vec![1, 2],
+            weights: vec![0.5],
+            tags: vec!["a".to_string(), "".to_string()],
+            address: Some(Address { city: "Ljubljana".to_string(), zip: 1000 }),
+            history: vec![Address::default()],
+            status: Status::Blocked,
+            flags: vec![Status::Active, Status::Unknown],
+            contact: Some(Contact::Phone(vec![1, 2])),
+        }
+    }
+
+    /// Should encode derived messages the same way as the encoder encodes the
+    /// fields one by one.
+    #[test]
+    fn encodes_derived_messages() {
+        let encoder = Encoder;
+        let mut address = Vec::new();
+        encoder.encode((&1, &b"Ljubljana".to_vec()), &mut address).unwrap();
+        encoder.encode((&2, EncoderLit::Fixed32(&1000)), &mut address).unwrap();
+        let mut expected = Vec::new();
+        encoder.encode((&1, EncoderLit::SInt32(&-150)), &mut expected).unwrap();
+        encoder.encode((&2, &false), &mut expected).unwrap(); // optional field is set
+        encoder.encode((&3, &vec![1i64, 2]), &mut expected).unwrap();
+        encoder.encode((&4, &0.5f64), &mut expected).unwrap();
+        encoder.encode((&5, &b"a".to_vec()), &mut expected).unwrap();
+        encoder.encode((&5, &Vec::<u8>::new()), &mut expected).unwrap();
+        encoder.encode((&6, &address), &mut expected).unwrap();
+        encoder.encode((&7, &Vec::<u8>::new()), &mut expected).unwrap();
+        encoder.encode((&8, &3i32), &mut expected).unwrap();
+        encoder.encode((&9, &vec![1i32, 0]), &mut expected).unwrap();
+        encoder.encode((&11, &vec![1u8, 2]), &mut expected).unwrap();
+
+        let user = user();
+        let mut dst = Vec::new();
+        assert_eq!(user.encode(&mut dst).unwrap(), expected.len());
+        assert_eq!(dst, expected);
+        assert_eq!(user.encoded_len(), Ok(expected.len()));
+
+        let mut dst = Vec::new();
+        assert_eq!(User::default().encode(&mut dst).unwrap(), 0); // defaults are left out
+        assert_eq!(User::default().encoded_len(), Ok(0));
+    }
+
+    /// Should decode derived messages and merge fields following the protocol.
+    #[test]
+    fn decodes_derived_messages() {
+        let user = user();
+        let mut buf = Vec::new();
+        user.encode(&mut buf).unwrap();
+        assert_eq!(User::decode(&buf).unwrap(), user);
+
+        let mut merged = User::decode(&buf).unwrap();
+        merged.merge(&[0x08, 0x01, 0x18, 0x07, 0x32, 0x05, 0x15, 0x01, 0x00, 0x00, 0x00, 0x52, 0x01, 0x78]).unwrap();
+        assert_eq!(merged.id, -1); // overwritten
+        assert_eq!(merged.scores, vec![1, 2, 7]); // unpacked value appended
+        assert_eq!(merged.address, Some(Address { city: "Ljubljana".to_string(), zip: 1 })); // merged
+        assert_eq!(merged.contact, Some(Contact::Email("x".to_string()))); // replaced
+        merged.clear();
+        assert_eq!(merged, User::default());
+
+        assert_eq!(User::decode(&[0x98, 0x06, 0x01]).unwrap(), User::default()); // unknown tag
+        assert_eq!(User::decode(&[0x48, 0x02]).unwrap().flags, vec![Status::Unknown]); // unknown enum value
+
+        assert_eq!(User::decode(&[0x08]), Err(DecoderError::InputUnderflow));
+        assert_eq!(User::decode(&[0x0a, 0x00]), Err(DecoderError::UnexpectedWireType));
+        assert_eq!(User::decode(&[0x2a, 0x01, 0xff]), Err(DecoderError::InvalidInput)); // invalid UTF-8
+    }
+}
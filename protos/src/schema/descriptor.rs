@@ -0,0 +1,407 @@
+use crate::Typ;
+use super::Location;
+
+/// Represents a constant value of an option.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Constant {
+    /// An identifier such as an enum value name.
+    Ident(String),
+
+    /// A negative integer.
+    Int(i64),
+
+    /// A non-negative integer.
+    UInt(u64),
+
+    /// A floating-point number including `inf` and `nan`.
+    Float(f64),
+
+    /// A string.
+    Str(String),
+
+    /// A boolean.
+    Bool(bool),
+
+    /// A message literal in text format which is kept as written, including
+    /// the surrounding braces.
+    Aggregate(String),
+}
+
+/// Provides import options.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImportKind {
+    /// Makes the definitions of the imported file visible to the importing
+    /// file.
+    Default,
+
+    /// Makes the definitions also visible to the files importing this file.
+    Public,
+
+    /// Marks an import which is allowed to be missing.
+    Weak,
+}
+
+/// Represents an `import` statement.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ImportDescriptor {
+    /// The path of the imported file relative to the include paths.
+    pub path: String,
+
+    /// The kind of the import.
+    pub kind: ImportKind,
+
+    /// The location of the statement.
+    pub location: Location,
+}
+
+/// Provides field cardinality options.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Label {
+    /// A field without explicit presence which is left out when it holds the
+    /// default value.
+    Singular,
+
+    /// A field marked as `optional` which tracks its presence.
+    Optional,
+
+    /// A `repeated` field. Map fields are repeated as well since each entry is
+    /// written as a separate field.
+    Repeated,
+}
+
+/// Provides field types.
+#[derive(Debug, Clone, PartialEq)]
+pub enum FieldType {
+    Double,
+    Float,
+    Int64,
+    UInt64,
+    Int32,
+    Fixed64,
+    Fixed32,
+    Bool,
+    String,
+    Bytes,
+    UInt32,
+    SFixed32,
+    SFixed64,
+    SInt32,
+    SInt64,
+
+    /// A type name as written in the file which has not been resolved yet.
+    Named(String),
+
+    /// A message with the provided fully-qualified name (e.g. `.pkg.Name`).
+    Message(String),
+
+    /// An enum with the provided fully-qualified name (e.g. `.pkg.Name`).
+    Enum(String),
+
+    /// A map with the provided key and value types.
+    Map(Box<FieldType>, Box<FieldType>),
+}
+
+impl FieldType {
+    /// Returns the scalar type for the provided keyword if it exists.
+    pub fn from_name(name: &str) -> Option<Self> {
+        Some(match name {
+            "double" => Self::Double,
+            "float" => Self::Float,
+            "int64" => Self::Int64,
+            "uint64" => Self::UInt64,
+            "int32" => Self::Int32,
+            "fixed64" => Self::Fixed64,
+            "fixed32" => Self::Fixed32,
+            "bool" => Self::Bool,
+            "string" => Self::String,
+            "bytes" => Self::Bytes,
+            "uint32" => Self::UInt32,
+            "sfixed32" => Self::SFixed32,
+            "sfixed64" => Self::SFixed64,
+            "sint32" => Self::SInt32,
+            "sint64" => Self::SInt64,
+            _ => return None,
+        })
+    }
+
+    /// Returns the wire type of a single value of this type.
+    pub fn wire_type(&self) -> Typ {
+        match self {
+            Self::Double | Self::Fixed64 | Self::SFixed64 => Typ::Bit64,
+            Self::Float | Self::Fixed32 | Self::SFixed32 => Typ::Bit32,
+            Self::Int64 | Self::UInt64 | Self::Int32 | Self::Bool | Self::UInt32
+                | Self::SInt32 | Self::SInt64 | Self::Enum(_) => Typ::Varint,
+            _ => Typ::LengthDelimited,
+        }
+    }
+
+    /// Tells whether repeated values of this type can be packed.
+    pub fn is_packable(&self) -> bool {
+        self.wire_type() != Typ::LengthDelimited
+    }
+
+    /// Tells whether the type can be used as a map key.
+    pub fn is_map_key(&self) -> bool {
+        matches!(self, Self::Int32 | Self::Int64 | Self::UInt32 | Self::UInt64
+            | Self::SInt32 | Self::SInt64 | Self::Fixed32 | Self::Fixed64
+            | Self::SFixed32 | Self::SFixed64 | Self::Bool | Self::String)
+    }
+}
+
+/// Represents a field of a message or an extension.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FieldDescriptor {
+    /// The name of the field.
+    pub name: String,
+
+    /// The tag number of the field.
+    pub number: u32,
+
+    /// The cardinality of the field.
+    pub label: Label,
+
+    /// The type of the field.
+    pub typ: FieldType,
+
+    /// The index of the `oneof` in the message this field belongs to.
+    pub oneof: Option<usize>,
+
+    /// The name of the field in JSON. It equals the `json_name` option or the
+    /// lowerCamelCase form of the name.
+    pub json_name: String,
+
+    /// The options of the field.
+    pub options: Vec<(String, Constant)>,
+
+    /// The location of the field definition.
+    pub location: Location,
+}
+
+impl FieldDescriptor {
+    /// Tells whether the field is a packed repeated field. Repeated scalar
+    /// numeric fields are packed unless the `packed` option is `false`.
+    pub fn is_packed(&self) -> bool {
+        self.label == Label::Repeated
+            && self.typ.is_packable()
+            && !self.options.iter().any(|(n, v)| n == "packed" && *v == Constant::Bool(false))
+    }
+}
+
+/// Represents a `oneof` of a message.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OneofDescriptor {
+    /// The name of the oneof.
+    pub name: String,
+
+    /// The options of the oneof.
+    pub options: Vec<(String, Constant)>,
+}
+
+/// Represents a message.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct MessageDescriptor {
+    /// The name of the message.
+    pub name: String,
+
+    /// The fully-qualified name of the message (e.g. `.pkg.Outer.Name`).
+    pub full_name: String,
+
+    /// The fields of the message in the order of definition.
+    pub fields: Vec<FieldDescriptor>,
+
+    /// The oneofs of the message.
+    pub oneofs: Vec<OneofDescriptor>,
+
+    /// The nested messages.
+    pub messages: Vec<MessageDescriptor>,
+
+    /// The nested enums.
+    pub enums: Vec<EnumDescriptor>,
+
+    /// The extensions defined in the scope of the message.
+    pub extensions: Vec<ExtensionDescriptor>,
+
+    /// The reserved ranges of tag numbers (both ends inclusive).
+    pub reserved: Vec<(i64, i64)>,
+
+    /// The reserved field names.
+    pub reserved_names: Vec<String>,
+
+    /// The options of the message.
+    pub options: Vec<(String, Constant)>,
+
+    /// The location of the message definition.
+    pub location: Location,
+}
+
+impl MessageDescriptor {
+    /// Returns the field with the provided tag `number`.
+    pub fn field(&self, number: u32) -> Option<&FieldDescriptor> {
+        self.fields.iter().find(|f| f.number == number)
+    }
+
+    /// Returns the field with the provided `name`.
+    pub fn field_by_name(&self, name: &str) -> Option<&FieldDescriptor> {
+        self.fields.iter().find(|f| f.name == name)
+    }
+}
+
+/// Represents a value of an enum.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EnumValueDescriptor {
+    /// The name of the value.
+    pub name: String,
+
+    /// The number of the value.
+    pub number: i32,
+
+    /// The options of the value.
+    pub options: Vec<(String, Constant)>,
+
+    /// The location of the value definition.
+    pub location: Location,
+}
+
+/// Represents an enum.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct EnumDescriptor {
+    /// The name of the enum.
+    pub name: String,
+
+    /// The fully-qualified name of the enum (e.g. `.pkg.Name`).
+    pub full_name: String,
+
+    /// The values of the enum in the order of definition.
+    pub values: Vec<EnumValueDescriptor>,
+
+    /// The reserved ranges of numbers (both ends inclusive).
+    pub reserved: Vec<(i64, i64)>,
+
+    /// The reserved value names.
+    pub reserved_names: Vec<String>,
+
+    /// The options of the enum.
+    pub options: Vec<(String, Constant)>,
+
+    /// The location of the enum definition.
+    pub location: Location,
+}
+
+impl EnumDescriptor {
+    /// Returns the first value with the provided `number`.
+    pub fn value(&self, number: i32) -> Option<&EnumValueDescriptor> {
+        self.values.iter().find(|v| v.number == number)
+    }
+
+    /// Returns the value with the provided `name`.
+    pub fn value_by_name(&self, name: &str) -> Option<&EnumValueDescriptor> {
+        self.values.iter().find(|v| v.name == name)
+    }
+}
+
+/// Represents a method of a service.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MethodDescriptor {
+    /// The name of the method.
+    pub name: String,
+
+    /// The input message type. It holds the fully-qualified name once the file
+    /// is resolved.
+    pub input: String,
+
+    /// The output message type. It holds the fully-qualified name once the
+    /// file is resolved.
+    pub output: String,
+
+    /// Tells whether the client sends a stream of messages.
+    pub client_streaming: bool,
+
+    /// Tells whether the server sends a stream of messages.
+    pub server_streaming: bool,
+
+    /// The options of the method.
+    pub options: Vec<(String, Constant)>,
+
+    /// The location of the method definition.
+    pub location: Location,
+}
+
+/// Represents a service.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ServiceDescriptor {
+    /// The name of the service.
+    pub name: String,
+
+    /// The fully-qualified name of the service (e.g. `.pkg.Name`).
+    pub full_name: String,
+
+    /// The methods of the service.
+    pub methods: Vec<MethodDescriptor>,
+
+    /// The options of the service.
+    pub options: Vec<(String, Constant)>,
+
+    /// The location of the service definition.
+    pub location: Location,
+}
+
+/// Represents an `extend` block which adds fields to another message. In
+/// `proto3` this is only used for defining custom options.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExtensionDescriptor {
+    /// The extended message type. It holds the fully-qualified name once the
+    /// file is resolved.
+    pub extendee: String,
+
+    /// The extension fields.
+    pub fields: Vec<FieldDescriptor>,
+
+    /// The location of the block.
+    pub location: Location,
+}
+
+/// Represents a parsed `.proto` file.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct FileDescriptor {
+    /// The name of the file as it was loaded or imported.
+    pub name: String,
+
+    /// The package of the file.
+    pub package: Option<String>,
+
+    /// The imports of the file.
+    pub imports: Vec<ImportDescriptor>,
+
+    /// The file-level options.
+    pub options: Vec<(String, Constant)>,
+
+    /// The top-level messages.
+    pub messages: Vec<MessageDescriptor>,
+
+    /// The top-level enums.
+    pub enums: Vec<EnumDescriptor>,
+
+    /// The services.
+    pub services: Vec<ServiceDescriptor>,
+
+    /// The top-level extensions.
+    pub extensions: Vec<ExtensionDescriptor>,
+}
+
+/// Returns the lowerCamelCase JSON name of the field with the provided `name`
+/// the same way `protoc` does it.
+pub fn json_name(name: &str) -> String {
+    let mut json = String::with_capacity(name.len());
+    let mut upper = false;
+    for c in name.chars() {
+        if c == '_' {
+            upper = true;
+        } else if upper {
+            json.push(c.to_ascii_uppercase());
+            upper = false;
+        } else {
+            json.push(c);
+        }
+    }
+    json
+}
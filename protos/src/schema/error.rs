@@ -0,0 +1,92 @@
+use std::error;
+use std::fmt;
+
+/// Represents a position in a `.proto` file. Lines and columns are 1-based.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Location {
+    /// The name of the file as it was loaded or imported.
+    pub file: String,
+
+    /// The line number.
+    pub line: usize,
+
+    /// The column number counted in characters.
+    pub column: usize,
+}
+
+impl Location {
+    /// Returns a new location instance.
+    pub fn new<F: Into<String>>(file: F, line: usize, column: usize) -> Self {
+        Self {
+            file: file.into(),
+            line,
+            column,
+        }
+    }
+}
+
+impl fmt::Display for Location {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        write!(fmt, "{}:{}:{}", self.file, self.line, self.column)
+    }
+}
+
+/// Contains error options that can be encountered while parsing `.proto` files
+/// and resolving their imports and types.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SchemaError {
+    /// Indicates that the source is malformed at the provided location. The
+    /// string describes what the parser expected.
+    InvalidSyntax(Location, String),
+
+    /// Indicates that a number literal is malformed or out of range.
+    InvalidNumber(Location),
+
+    /// Indicates that a string literal holds an invalid escape sequence or is
+    /// not valid UTF-8.
+    InvalidString(Location),
+
+    /// Indicates that the provided tag number is out of range, reserved or used
+    /// more than once.
+    InvalidTag(Location, i64),
+
+    /// Indicates that the provided name is reserved or defined more than once.
+    DuplicateName(Location, String),
+
+    /// Indicates that the provided type name can not be resolved from the
+    /// scope it is used in.
+    UnresolvedType(Location, String),
+
+    /// Indicates that the file uses a syntax other than `proto3` or a construct
+    /// which `proto3` does not allow.
+    UnsupportedSyntax(Location, String),
+
+    /// Indicates that the provided import can not be found in any of the
+    /// include paths.
+    ImportNotFound(Location, String),
+
+    /// Indicates that the provided import imports itself through other files.
+    ImportCycle(Location, String),
+
+    /// Indicates that the file at the provided path can not be read.
+    UnreadableFile(String),
+}
+
+impl fmt::Display for SchemaError {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::InvalidSyntax(loc, expected) => write!(fmt, "{}: Invalid syntax, expected {}.", loc, expected),
+            Self::InvalidNumber(loc) => write!(fmt, "{}: Invalid number.", loc),
+            Self::InvalidString(loc) => write!(fmt, "{}: Invalid string.", loc),
+            Self::InvalidTag(loc, tag) => write!(fmt, "{}: Invalid or duplicated number {}.", loc, tag),
+            Self::DuplicateName(loc, name) => write!(fmt, "{}: Name \"{}\" is reserved or already defined.", loc, name),
+            Self::UnresolvedType(loc, name) => write!(fmt, "{}: Type \"{}\" is not defined.", loc, name),
+            Self::UnsupportedSyntax(loc, what) => write!(fmt, "{}: Unsupported {}.", loc, what),
+            Self::ImportNotFound(loc, path) => write!(fmt, "{}: Import \"{}\" not found.", loc, path),
+            Self::ImportCycle(loc, path) => write!(fmt, "{}: Import \"{}\" forms a cycle.", loc, path),
+            Self::UnreadableFile(path) => write!(fmt, "File \"{}\" can not be read.", path),
+        }
+    }
+}
+
+impl error::Error for SchemaError {}
@@ -0,0 +1,279 @@
+use super::{Location, SchemaError};
+
+/// Represents a lexical token of a `.proto` file.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum Token {
+    /// An identifier or a keyword.
+    Ident(String),
+
+    /// A decimal, octal or hexadecimal integer literal.
+    Int(u64),
+
+    /// A floating-point literal.
+    Float(f64),
+
+    /// A string literal with resolved escape sequences.
+    Str(String),
+
+    /// A single punctuation character.
+    Symbol(char),
+
+    /// The end of the input.
+    Eof,
+}
+
+/// Represents a token together with its position in the source.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Spanned {
+    pub token: Token,
+    pub line: usize,
+    pub column: usize,
+    pub offset: usize, // byte offset of the first character
+}
+
+/// Splits the `.proto` `source` of the `file` into tokens. Whitespace and
+/// comments are skipped. The last token is always `Token::Eof`.
+pub(crate) fn tokenize(file: &str, source: &str) -> Result<Vec<Spanned>, SchemaError> {
+    let mut lexer = Lexer {
+        file,
+        chars: source.char_indices().collect(),
+        len: source.len(),
+        pos: 0,
+        line: 1,
+        column: 1,
+    };
+    let mut tokens = Vec::new();
+    loop {
+        lexer.skip_trivia()?;
+        let (line, column, offset) = (lexer.line, lexer.column, lexer.offset());
+        let token = match lexer.peek(0) {
+            None => Token::Eof,
+            Some(c) if c.is_ascii_alphabetic() || c == '_' => lexer.read_ident(),
+            Some(c) if c.is_ascii_digit() => lexer.read_number()?,
+            Some('.') if lexer.peek(1).is_some_and(|c| c.is_ascii_digit()) => lexer.read_number()?,
+            Some(c) if c == '"' || c == '\'' => lexer.read_string()?,
+            Some(c) => {
+                lexer.bump();
+                Token::Symbol(c)
+            },
+        };
+        let eof = token == Token::Eof;
+        tokens.push(Spanned { token, line, column, offset });
+        if eof {
+            return Ok(tokens);
+        }
+    }
+}
+
+/// A simple character reader which tracks the current line and column.
+struct Lexer<'a> {
+    file: &'a str,
+    chars: Vec<(usize, char)>,
+    len: usize,
+    pos: usize,
+    line: usize,
+    column: usize,
+}
+
+impl<'a> Lexer<'a> {
+    /// Returns the character `ahead` positions from the current one.
+    fn peek(&self, ahead: usize) -> Option<char> {
+        self.chars.get(self.pos + ahead).map(|(_, c)| *c)
+    }
+
+    /// Returns the byte offset of the current character.
+    fn offset(&self) -> usize {
+        self.chars.get(self.pos).map_or(self.len, |(i, _)| *i)
+    }
+
+    /// Consumes the current character.
+    fn bump(&mut self) -> Option<char> {
+        let c = self.peek(0)?;
+        self.pos += 1;
+        if c == '\n' {
+            self.line += 1;
+            self.column = 1;
+        } else {
+            self.column += 1;
+        }
+        Some(c)
+    }
+
+    /// Returns the location of the current character.
+    fn location(&self) -> Location {
+        Location::new(self.file, self.line, self.column)
+    }
+
+    /// Skips whitespace, line comments and block comments.
+    fn skip_trivia(&mut self) -> Result<(), SchemaError> {
+        loop {
+            match (self.peek(0), self.peek(1)) {
+                (Some(c), _) if c.is_whitespace() => {
+                    self.bump();
+                },
+                (Some('/'), Some('/')) => {
+                    while !matches!(self.peek(0), None | Some('\n')) {
+                        self.bump();
+                    }
+                },
+                (Some('/'), Some('*')) => {
+                    let loc = self.location();
+                    self.bump();
+                    self.bump();
+                    loop {
+                        match (self.peek(0), self.peek(1)) {
+                            (Some('*'), Some('/')) => {
+                                self.bump();
+                                self.bump();
+                                break;
+                            },
+                            (Some(_), _) => {
+                                self.bump();
+                            },
+                            (None, _) => return Err(SchemaError::InvalidSyntax(loc, "end of comment".to_string())),
+                        }
+                    }
+                },
+                _ => return Ok(()),
+            }
+        }
+    }
+
+    /// Reads an identifier.
+    fn read_ident(&mut self) -> Token {
+        let mut ident = String::new();
+        while let Some(c) = self.peek(0).filter(|c| c.is_ascii_alphanumeric() || *c == '_') {
+            ident.push(c);
+            self.bump();
+        }
+        Token::Ident(ident)
+    }
+
+    /// Reads an integer or a floating-point literal.
+    fn read_number(&mut self) -> Result<Token, SchemaError> {
+        let loc = self.location();
+        let mut text = String::new();
+        if self.peek(0) == Some('0') && matches!(self.peek(1), Some('x') | Some('X')) {
+            self.bump();
+            self.bump();
+            while let Some(c) = self.peek(0).filter(|c| c.is_ascii_alphanumeric()) {
+                text.push(c);
+                self.bump();
+            }
+            return u64::from_str_radix(&text, 16).map(Token::Int).map_err(|_| SchemaError::InvalidNumber(loc));
+        }
+
+        let mut float = false;
+        while let Some(c) = self.peek(0) {
+            if c.is_ascii_digit() {
+                text.push(c);
+            } else if c == '.' && !float {
+                float = true;
+                text.push(c);
+            } else if c == 'e' || c == 'E' {
+                float = true;
+                text.push(c);
+                if let Some(sign) = self.peek(1).filter(|c| *c == '+' || *c == '-') {
+                    self.bump();
+                    text.push(sign);
+                }
+            } else if c.is_ascii_alphanumeric() || c == '_' || c == '.' {
+                return Err(SchemaError::InvalidNumber(loc));
+            } else {
+                break;
+            }
+            self.bump();
+        }
+
+        if float {
+            text.parse().map(Token::Float).map_err(|_| SchemaError::InvalidNumber(loc))
+        } else if text.len() > 1 && text.starts_with('0') {
+            u64::from_str_radix(&text[1..], 8).map(Token::Int).map_err(|_| SchemaError::InvalidNumber(loc))
+        } else {
+            text.parse().map(Token::Int).map_err(|_| SchemaError::InvalidNumber(loc))
+        }
+    }
+
+    /// Reads a string literal and resolves its escape sequences.
+    fn read_string(&mut self) -> Result<Token, SchemaError> {
+        let loc = self.location();
+        let quote = self.bump();
+        let mut bytes = Vec::new();
+        loop {
+            let c = match self.bump() {
+                None | Some('\n') => return Err(SchemaError::InvalidString(loc)),
+                Some(c) => c,
+            };
+            if Some(c) == quote {
+                break;
+            } else if c != '\\' {
+                let mut buf = [0; 4];
+                bytes.extend_from_slice(c.encode_utf8(&mut buf).as_bytes());
+                continue;
+            }
+            let escape = self.bump().ok_or_else(|| SchemaError::InvalidString(loc.clone()))?;
+            match escape {
+                'a' => bytes.push(0x07),
+                'b' => bytes.push(0x08),
+                'f' => bytes.push(0x0c),
+                'n' => bytes.push(b'\n'),
+                'r' => bytes.push(b'\r'),
+                't' => bytes.push(b'\t'),
+                'v' => bytes.push(0x0b),
+                '\\' | '\'' | '"' | '?' => bytes.push(escape as u8),
+                'x' | 'X' => {
+                    let val = self.read_digits(16, 2).ok_or_else(|| SchemaError::InvalidString(loc.clone()))?;
+                    bytes.push(val as u8);
+                },
+                '0'..='7' => {
+                    let mut val = escape.to_digit(8).unwrap_or(0);
+                    for _ in 0..2 {
+                        match self.peek(0).and_then(|c| c.to_digit(8)) {
+                            Some(digit) => {
+                                val = val * 8 + digit;
+                                self.bump();
+                            },
+                            None => break,
+                        }
+                    }
+                    if val > 0xff {
+                        return Err(SchemaError::InvalidString(loc));
+                    }
+                    bytes.push(val as u8);
+                },
+                'u' | 'U' => {
+                    let count = if escape == 'u' { 4 } else { 8 };
+                    let c = self.read_digits(16, count)
+                        .and_then(std::char::from_u32)
+                        .ok_or_else(|| SchemaError::InvalidString(loc.clone()))?;
+                    let mut buf = [0; 4];
+                    bytes.extend_from_slice(c.encode_utf8(&mut buf).as_bytes());
+                },
+                _ => return Err(SchemaError::InvalidString(loc)),
+            }
+        }
+        String::from_utf8(bytes).map(Token::Str).map_err(|_| SchemaError::InvalidString(loc))
+    }
+
+    /// Reads up to `max` digits of the provided `radix` and returns their value.
+    /// Unicode escapes (`max` above 2) require exactly `max` digits.
+    fn read_digits(&mut self, radix: u32, max: usize) -> Option<u32> {
+        let mut val: u32 = 0;
+        let mut count = 0;
+        while count < max {
+            match self.peek(0).and_then(|c| c.to_digit(radix)) {
+                Some(digit) => {
+                    val = val.checked_mul(radix)?.checked_add(digit)?;
+                    self.bump();
+                    count += 1;
+                },
+                None => break,
+            }
+        }
+        if count == 0 || (max > 2 && count != max) {
+            None
+        } else {
+            Some(val)
+        }
+    }
+}
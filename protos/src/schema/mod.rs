@@ -0,0 +1,779 @@
+//! Provides a parser for `.proto` schema files of the `proto3` syntax.
+//!
+//! A schema file is parsed into a `FileDescriptor` which describes its
+//! package, imports, options, messages (with nested types, oneofs, maps and
+//! reserved ranges), enums, services and extensions. The `Schema` loads files
+//! from a list of include paths, follows their imports and resolves type names
+//! into fully-qualified names (e.g. `.pkg.Outer.Name`). The well-known types
+//! under `google/protobuf/` are built in.
+//!
+//! ```rust
+//! use httlib_protos::schema::{FieldType, Schema};
+//!
+//! let mut schema = Schema::new();
+//! schema.add("user.proto", r#"
+//!     syntax = "proto3";
+//!     package app;
+//!     import "google/protobuf/timestamp.proto";
+//!
+//!     message User {
+//!         string name = 1;
+//!         google.protobuf.Timestamp created_at = 2;
+//!     }
+//! "#).unwrap();
+//!
+//! let user = schema.message(".app.User").unwrap();
+//! let field = user.field_by_name("created_at").unwrap();
+//! assert_eq!(field.typ, FieldType::Message(".google.protobuf.Timestamp".to_string()));
+//! assert_eq!(field.json_name, "createdAt");
+//! ```
+//!
+//! Errors point to the file, line and column where the problem was found.
+
+mod descriptor;
+mod error;
+mod lexer;
+mod parser;
+mod wkt;
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+pub use descriptor::*;
+pub use error::*;
+pub use parser::parse;
+
+/// A constant holding the maximum tag number of a message field.
+const FIELD_MAX: u32 = (1 << 29) - 1;
+
+/// A constant holding the range of tag numbers reserved by the protocol.
+const FIELD_RESERVED: (u32, u32) = (19000, 19999);
+
+/// Provides kinds of named definitions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Symbol {
+    Package,
+    Message,
+    Enum,
+    Service,
+}
+
+/// Provides a set of loaded `.proto` files with resolved types.
+///
+/// Files are kept in dependency order, which means that each file comes after
+/// the files it imports.
+#[derive(Debug, Clone, Default)]
+pub struct Schema {
+    /// The directories where imported files are searched for.
+    include_paths: Vec<PathBuf>,
+
+    /// The loaded files.
+    files: Vec<FileDescriptor>,
+}
+
+impl Schema {
+    /// Returns a new schema instance without files.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a directory where files are searched for. Directories are searched
+    /// in the order in which they are added.
+    pub fn include<P: Into<PathBuf>>(&mut self, path: P) {
+        self.include_paths.push(path.into());
+    }
+
+    /// Returns the loaded files in dependency order.
+    pub fn files(&self) -> &[FileDescriptor] {
+        &self.files
+    }
+
+    /// Returns the loaded file with the provided `name`.
+    pub fn file(&self, name: &str) -> Option<&FileDescriptor> {
+        self.files.iter().find(|f| f.name == name)
+    }
+
+    /// Returns the message with the provided fully-qualified name.
+    pub fn message(&self, full_name: &str) -> Option<&MessageDescriptor> {
+        fn find<'a>(messages: &'a [MessageDescriptor], name: &str) -> Option<&'a MessageDescriptor> {
+            messages.iter().find_map(|m| {
+                if m.full_name == name {
+                    Some(m)
+                } else if name.starts_with(&m.full_name) {
+                    find(&m.messages, name)
+                } else {
+                    None
+                }
+            })
+        }
+        self.files.iter().find_map(|f| find(&f.messages, full_name))
+    }
+
+    /// Returns the enum with the provided fully-qualified name.
+    pub fn enumeration(&self, full_name: &str) -> Option<&EnumDescriptor> {
+        fn find<'a>(messages: &'a [MessageDescriptor], name: &str) -> Option<&'a EnumDescriptor> {
+            messages.iter()
+                .filter(|m| name.starts_with(&m.full_name))
+                .find_map(|m| m.enums.iter().find(|e| e.full_name == name).or_else(|| find(&m.messages, name)))
+        }
+        self.files.iter().find_map(|f| {
+            f.enums.iter().find(|e| e.full_name == full_name).or_else(|| find(&f.messages, full_name))
+        })
+    }
+
+    /// Returns the service with the provided fully-qualified name.
+    pub fn service(&self, full_name: &str) -> Option<&ServiceDescriptor> {
+        self.files.iter().find_map(|f| f.services.iter().find(|s| s.full_name == full_name))
+    }
+
+    /// Loads the file at the provided `path` relative to the include paths
+    /// together with its imports.
+    ///
+    /// On success the resolved file is returned otherwise an error is thrown.
+    pub fn load(&mut self, path: &str) -> Result<&FileDescriptor, SchemaError> {
+        let source = self.read(path)?.ok_or_else(|| SchemaError::UnreadableFile(path.to_string()))?;
+        self.add(path, &source)
+    }
+
+    /// Parses the provided `source` as a file with the provided `name` and
+    /// adds it to the schema. Imports are searched for among the already loaded
+    /// files, in the include paths and among the well-known types.
+    ///
+    /// On success the resolved file is returned otherwise an error is thrown.
+    pub fn add(&mut self, name: &str, source: &str) -> Result<&FileDescriptor, SchemaError> {
+        let index = self.add_file(name, source, &mut Vec::new())?;
+        Ok(&self.files[index])
+    }
+
+    /// Reads the file at `path` from the include paths or the well-known
+    /// types. Returns `None` if the file does not exist.
+    fn read(&self, path: &str) -> Result<Option<String>, SchemaError> {
+        for dir in &self.include_paths {
+            let file = dir.join(path);
+            if file.is_file() {
+                let source = fs::read_to_string(&file).map_err(|_| SchemaError::UnreadableFile(file.display().to_string()))?;
+                return Ok(Some(source));
+            }
+        }
+        Ok(wkt::source(path).map(String::from))
+    }
+
+    /// Parses, loads the imports of, resolves and adds a file. The `stack`
+    /// holds the names of the files being loaded for detecting import cycles.
+    fn add_file(&mut self, name: &str, source: &str, stack: &mut Vec<String>) -> Result<usize, SchemaError> {
+        if let Some(index) = self.files.iter().position(|f| f.name == name) {
+            return Ok(index);
+        }
+        let mut file = parse(name, source)?;
+
+        stack.push(name.to_string());
+        for import in &file.imports {
+            if stack.contains(&import.path) {
+                return Err(SchemaError::ImportCycle(import.location.clone(), import.path.clone()));
+            } else if self.file(&import.path).is_some() {
+                continue;
+            }
+            match self.read(&import.path)? {
+                Some(source) => {
+                    self.add_file(&import.path, &source, stack)?;
+                },
+                None if import.kind == ImportKind::Weak => {},
+                None => return Err(SchemaError::ImportNotFound(import.location.clone(), import.path.clone())),
+            }
+        }
+        stack.pop();
+
+        let symbols = self.symbols(&file)?;
+        Resolver { symbols: &symbols }.resolve_file(&mut file)?;
+        validate_file(&file)?;
+        self.files.push(file);
+        Ok(self.files.len() - 1)
+    }
+
+    /// Returns the indexes of the loaded files whose definitions are visible
+    /// from the provided `file`: its imports and their public imports.
+    fn visible(&self, file: &FileDescriptor) -> Vec<usize> {
+        let mut visible = Vec::new();
+        let mut queue = file.imports.iter().map(|i| i.path.as_str()).collect::<Vec<_>>();
+        while let Some(path) = queue.pop() {
+            if let Some(index) = self.files.iter().position(|f| f.name == path) {
+                if !visible.contains(&index) {
+                    visible.push(index);
+                    let public = self.files[index].imports.iter().filter(|i| i.kind == ImportKind::Public);
+                    queue.extend(public.map(|i| i.path.as_str()));
+                }
+            }
+        }
+        visible
+    }
+
+    /// Returns the symbols visible from the provided `file`. Definitions of the
+    /// file which clash with definitions of any loaded file are reported.
+    fn symbols(&self, file: &FileDescriptor) -> Result<HashMap<String, Symbol>, SchemaError> {
+        let mut all = HashMap::new();
+        for other in &self.files {
+            collect_symbols(other, &mut all, &mut |_, _| Ok(()))?;
+        }
+        collect_symbols(file, &mut all, &mut |name, location| {
+            Err(SchemaError::DuplicateName(location.clone(), name.to_string()))
+        })?;
+
+        let mut symbols = HashMap::new();
+        for index in self.visible(file) {
+            collect_symbols(&self.files[index], &mut symbols, &mut |_, _| Ok(()))?;
+        }
+        collect_symbols(file, &mut symbols, &mut |_, _| Ok(()))?;
+        Ok(symbols)
+    }
+}
+
+/// Adds the definitions of the `file` to the `symbols`. The `clash` function is
+/// called for definitions which already exist.
+fn collect_symbols<F>(file: &FileDescriptor, symbols: &mut HashMap<String, Symbol>, clash: &mut F) -> Result<(), SchemaError>
+where
+    F: FnMut(&str, &Location) -> Result<(), SchemaError>,
+{
+    fn insert<F>(symbols: &mut HashMap<String, Symbol>, name: &str, symbol: Symbol, location: &Location, clash: &mut F) -> Result<(), SchemaError>
+    where
+        F: FnMut(&str, &Location) -> Result<(), SchemaError>,
+    {
+        if symbols.insert(name.to_string(), symbol).is_some() {
+            clash(name, location)?;
+        }
+        Ok(())
+    }
+
+    fn messages<F>(list: &[MessageDescriptor], symbols: &mut HashMap<String, Symbol>, clash: &mut F) -> Result<(), SchemaError>
+    where
+        F: FnMut(&str, &Location) -> Result<(), SchemaError>,
+    {
+        for message in list {
+            insert(symbols, &message.full_name, Symbol::Message, &message.location, clash)?;
+            for enumeration in &message.enums {
+                insert(symbols, &enumeration.full_name, Symbol::Enum, &enumeration.location, clash)?;
+            }
+            messages(&message.messages, symbols, clash)?;
+        }
+        Ok(())
+    }
+
+    if let Some(package) = &file.package {
+        let mut name = String::new();
+        for part in package.split('.') {
+            name.push('.');
+            name.push_str(part);
+            symbols.insert(name.clone(), Symbol::Package);
+        }
+    }
+    messages(&file.messages, symbols, clash)?;
+    for enumeration in &file.enums {
+        insert(symbols, &enumeration.full_name, Symbol::Enum, &enumeration.location, clash)?;
+    }
+    for service in &file.services {
+        insert(symbols, &service.full_name, Symbol::Service, &service.location, clash)?;
+    }
+    Ok(())
+}
+
+/// Resolves type names of a file into fully-qualified names.
+struct Resolver<'a> {
+    symbols: &'a HashMap<String, Symbol>,
+}
+
+impl<'a> Resolver<'a> {
+    /// Resolves all type references of the `file`.
+    fn resolve_file(&self, file: &mut FileDescriptor) -> Result<(), SchemaError> {
+        let scope = file.package.as_ref().map(|p| format!(".{}", p)).unwrap_or_default();
+        for message in file.messages.iter_mut() {
+            self.resolve_message(message)?;
+        }
+        for extension in file.extensions.iter_mut() {
+            self.resolve_extension(extension, &scope)?;
+        }
+        for service in file.services.iter_mut() {
+            for method in service.methods.iter_mut() {
+                method.input = self.resolve_message_name(&method.input, &scope, &method.location)?;
+                method.output = self.resolve_message_name(&method.output, &scope, &method.location)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Resolves the field types of the `message` and its nested messages.
+    fn resolve_message(&self, message: &mut MessageDescriptor) -> Result<(), SchemaError> {
+        for field in message.fields.iter_mut() {
+            field.typ = self.resolve_type(&field.typ, &message.full_name, &field.location)?;
+        }
+        for extension in message.extensions.iter_mut() {
+            self.resolve_extension(extension, &message.full_name)?;
+        }
+        for nested in message.messages.iter_mut() {
+            self.resolve_message(nested)?;
+        }
+        Ok(())
+    }
+
+    /// Resolves the extendee and the field types of an `extension`.
+    fn resolve_extension(&self, extension: &mut ExtensionDescriptor, scope: &str) -> Result<(), SchemaError> {
+        extension.extendee = self.resolve_message_name(&extension.extendee, scope, &extension.location)?;
+        for field in extension.fields.iter_mut() {
+            field.typ = self.resolve_type(&field.typ, scope, &field.location)?;
+        }
+        Ok(())
+    }
+
+    /// Resolves a field type used in the provided `scope`.
+    fn resolve_type(&self, typ: &FieldType, scope: &str, location: &Location) -> Result<FieldType, SchemaError> {
+        match typ {
+            FieldType::Named(name) => match self.lookup(name, scope) {
+                Some((full_name, Symbol::Message)) => Ok(FieldType::Message(full_name)),
+                Some((full_name, Symbol::Enum)) => Ok(FieldType::Enum(full_name)),
+                _ => Err(SchemaError::UnresolvedType(location.clone(), name.clone())),
+            },
+            FieldType::Map(key, value) => {
+                if !key.is_map_key() {
+                    return Err(SchemaError::InvalidSyntax(location.clone(), "map key of integral or string type".to_string()));
+                }
+                let value = self.resolve_type(value, scope, location)?;
+                Ok(FieldType::Map(key.clone(), Box::new(value)))
+            },
+            typ => Ok(typ.clone()),
+        }
+    }
+
+    /// Resolves the name of a message used in the provided `scope`.
+    fn resolve_message_name(&self, name: &str, scope: &str, location: &Location) -> Result<String, SchemaError> {
+        match self.lookup(name, scope) {
+            Some((full_name, Symbol::Message)) => Ok(full_name),
+            _ => Err(SchemaError::UnresolvedType(location.clone(), name.to_string())),
+        }
+    }
+
+    /// Looks up the type `name` from the `scope` the same way `protoc` does.
+    ///
+    /// Scopes are searched from the innermost to the outermost one. Once the
+    /// first component of the name is found as a package or a message, the
+    /// whole name must exist in that scope.
+    fn lookup(&self, name: &str, scope: &str) -> Option<(String, Symbol)> {
+        if name.starts_with('.') {
+            return self.symbols.get(name).map(|s| (name.to_string(), *s));
+        }
+        let first = name.split('.').next().unwrap_or(name);
+        let mut scope = scope;
+        loop {
+            match self.symbols.get(&format!("{}.{}", scope, first)) {
+                Some(Symbol::Package) | Some(Symbol::Message) => {
+                    let full_name = format!("{}.{}", scope, name);
+                    return self.symbols.get(&full_name).map(|s| (full_name, *s));
+                },
+                Some(Symbol::Enum) if first == name => {
+                    return Some((format!("{}.{}", scope, name), Symbol::Enum));
+                },
+                _ => {},
+            }
+            match scope.rfind('.') {
+                Some(index) => scope = &scope[..index],
+                None => return None,
+            }
+        }
+    }
+}
+
+/// Validates tag numbers and names of all messages and enums of the `file`.
+fn validate_file(file: &FileDescriptor) -> Result<(), SchemaError> {
+    fn messages(list: &[MessageDescriptor]) -> Result<(), SchemaError> {
+        for message in list {
+            validate_message(message)?;
+            for enumeration in &message.enums {
+                validate_enum(enumeration)?;
+            }
+            messages(&message.messages)?;
+        }
+        Ok(())
+    }
+    messages(&file.messages)?;
+    for enumeration in &file.enums {
+        validate_enum(enumeration)?;
+    }
+    Ok(())
+}
+
+/// Validates the fields of a `message`.
+fn validate_message(message: &MessageDescriptor) -> Result<(), SchemaError> {
+    for (index, field) in message.fields.iter().enumerate() {
+        let number = field.number;
+        let others = &message.fields[..index];
+        if !(1..=FIELD_MAX).contains(&number)
+            || (FIELD_RESERVED.0..=FIELD_RESERVED.1).contains(&number)
+            || others.iter().any(|f| f.number == number)
+            || message.reserved.iter().any(|(s, e)| (*s..=*e).contains(&(number as i64)))
+        {
+            return Err(SchemaError::InvalidTag(field.location.clone(), number as i64));
+        } else if others.iter().any(|f| f.name == field.name) || message.reserved_names.contains(&field.name) {
+            return Err(SchemaError::DuplicateName(field.location.clone(), field.name.clone()));
+        }
+    }
+    Ok(())
+}
+
+/// Validates the values of an `enumeration`.
+fn validate_enum(enumeration: &EnumDescriptor) -> Result<(), SchemaError> {
+    let allow_alias = enumeration.options.iter().any(|(n, v)| n == "allow_alias" && *v == Constant::Bool(true));
+    match enumeration.values.first() {
+        None => return Err(SchemaError::InvalidSyntax(enumeration.location.clone(), "at least one enum value".to_string())),
+        Some(value) if value.number != 0 => {
+            return Err(SchemaError::UnsupportedSyntax(value.location.clone(), "first enum value other than zero".to_string()));
+        },
+        _ => {},
+    }
+    for (index, value) in enumeration.values.iter().enumerate() {
+        let others = &enumeration.values[..index];
+        let number = value.number as i64;
+        if others.iter().any(|v| v.name == value.name) || enumeration.reserved_names.contains(&value.name) {
+            return Err(SchemaError::DuplicateName(value.location.clone(), value.name.clone()));
+        } else if (!allow_alias && others.iter().any(|v| v.number == value.number))
+            || enumeration.reserved.iter().any(|(s, e)| (*s..=*e).contains(&number))
+        {
+            return Err(SchemaError::InvalidTag(value.location.clone(), number));
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::env;
+
+    const SOURCE: &str = r#"
+        // Comments are ignored.
+        syntax = "proto3";
+        package app.v1;
+
+        import "google/protobuf/timestamp.proto";
+        import public "google/protobuf/empty.proto";
+        option java_package = "com." "app";
+        option (custom.opt).name = { a: 1 nested { b: "}" } };
+
+        /* A user. */
+        message User {
+            option deprecated = true;
+            reserved 4, 8 to 10, 20 to max;
+            reserved "old";
+
+            enum Role {
+                option allow_alias = true;
+                ROLE_UNSPECIFIED = 0;
+                ROLE_ADMIN = 1;
+                ROLE_ROOT = 1 [deprecated = true];
+                reserved -5;
+            }
+            message Address {
+                string city = 1;
+            }
+
+            sint32 id = 1;
+            optional string user_name = 2 [json_name = "login"];
+            repeated int64 scores = 3 [packed = false];
+            repeated fixed32 flags = 5;
+            Role role = 6;
+            map<string, Address> addresses = 7;
+            .google.protobuf.Timestamp created_at = 11;
+            oneof contact {
+                string email = 12;
+                bytes phone = 13;
+            }
+        }
+
+        enum Status {
+            STATUS_UNKNOWN = 0;
+            STATUS_ACTIVE = 0x1;
+            STATUS_NEG = -1;
+        }
+
+        service Users {
+            rpc Get (User) returns (User);
+            rpc Watch (stream google.protobuf.Empty) returns (stream User.Address) {
+                option idempotency_level = NO_SIDE_EFFECTS;
+            }
+        }
+    "#;
+
+    /// Should parse a `proto3` schema into a descriptor tree with resolved
+    /// types.
+    #[test]
+    fn parses_schema_files() {
+        let mut schema = Schema::new();
+        let file = schema.add("app/user.proto", SOURCE).unwrap().clone();
+        assert_eq!(file.package, Some("app.v1".to_string()));
+        assert_eq!(file.imports.len(), 2);
+        assert_eq!(file.imports[1].kind, ImportKind::Public);
+        assert_eq!(file.options, vec![
+            ("java_package".to_string(), Constant::Str("com.app".to_string())),
+            ("(custom.opt).name".to_string(), Constant::Aggregate("{ a: 1 nested { b: \"}\" } }".to_string())),
+        ]);
+        assert_eq!(schema.files().len(), 3); // imports come first
+        assert_eq!(schema.files()[2].name, "app/user.proto");
+
+        let user = schema.message(".app.v1.User").unwrap();
+        assert_eq!(user.location, Location::new("app/user.proto", 12, 9));
+        assert_eq!(user.options, vec![("deprecated".to_string(), Constant::Bool(true))]);
+        assert_eq!(user.reserved, vec![(4, 4), (8, 10), (20, (1 << 29) - 1)]);
+        assert_eq!(user.reserved_names, vec!["old".to_string()]);
+        assert_eq!(user.fields.len(), 9);
+        assert_eq!(user.oneofs[0].name, "contact");
+
+        let id = user.field(1).unwrap();
+        assert_eq!((id.label, &id.typ, id.oneof), (Label::Singular, &FieldType::SInt32, None));
+        let name = user.field(2).unwrap();
+        assert_eq!((name.label, name.json_name.as_str()), (Label::Optional, "login"));
+        assert!(!user.field(3).unwrap().is_packed());
+        assert!(user.field(5).unwrap().is_packed());
+        assert_eq!(user.field(6).unwrap().typ, FieldType::Enum(".app.v1.User.Role".to_string()));
+        assert_eq!(user.field(7).unwrap().typ, FieldType::Map(
+            Box::new(FieldType::String),
+            Box::new(FieldType::Message(".app.v1.User.Address".to_string())),
+        ));
+        let created = user.field(11).unwrap();
+        assert_eq!(created.typ, FieldType::Message(".google.protobuf.Timestamp".to_string()));
+        assert_eq!(created.json_name, "createdAt");
+        assert_eq!(user.field(13).unwrap().oneof, Some(0));
+
+        let role = schema.enumeration(".app.v1.User.Role").unwrap();
+        assert_eq!(role.value(1).unwrap().name, "ROLE_ADMIN");
+        assert_eq!(role.reserved, vec![(-5, -5)]);
+        let status = schema.enumeration(".app.v1.Status").unwrap();
+        assert_eq!(status.value_by_name("STATUS_NEG").unwrap().number, -1);
+        assert!(status.value(1).is_some());
+
+        let users = schema.service(".app.v1.Users").unwrap();
+        let watch = &users.methods[1];
+        assert_eq!(watch.input, ".google.protobuf.Empty");
+        assert_eq!(watch.output, ".app.v1.User.Address");
+        assert!(watch.client_streaming && watch.server_streaming);
+        assert_eq!(watch.options, vec![("idempotency_level".to_string(), Constant::Ident("NO_SIDE_EFFECTS".to_string()))]);
+    }
+
+    /// Should load files from include paths and resolve names across imports.
+    #[test]
+    fn resolves_imports() {
+        let dir = env::temp_dir().join(format!("httlib-protos-schema-{}", std::process::id()));
+        fs::create_dir_all(dir.join("common")).unwrap();
+        fs::write(dir.join("common/base.proto"), r#"
+            syntax = "proto3";
+            package common;
+            import public "common/id.proto";
+            message Base { Id id = 1; }
+        "#).unwrap();
+        fs::write(dir.join("common/id.proto"), r#"
+            syntax = "proto3";
+            package common;
+            message Id { uint64 value = 1; }
+        "#).unwrap();
+        fs::write(dir.join("main.proto"), r#"
+            syntax = "proto3";
+            package common.app;
+            import "common/base.proto";
+            import weak "missing.proto";
+            message Main {
+                Base base = 1;
+                common.Id id = 2;
+            }
+        "#).unwrap();
+        fs::write(dir.join("cycle_a.proto"), "syntax = \"proto3\";\nimport \"cycle_b.proto\";\n").unwrap();
+        fs::write(dir.join("cycle_b.proto"), "syntax = \"proto3\";\nimport \"cycle_a.proto\";\n").unwrap();
+
+        let mut schema = Schema::new();
+        schema.include(&dir);
+        schema.load("main.proto").unwrap();
+        let names = schema.files().iter().map(|f| f.name.as_str()).collect::<Vec<_>>();
+        assert_eq!(names, vec!["common/id.proto", "common/base.proto", "main.proto"]);
+        let main = schema.message(".common.app.Main").unwrap();
+        assert_eq!(main.field(1).unwrap().typ, FieldType::Message(".common.Base".to_string()));
+        assert_eq!(main.field(2).unwrap().typ, FieldType::Message(".common.Id".to_string())); // public import
+
+        assert_eq!(schema.load("cycle_a.proto").unwrap_err(), SchemaError::ImportCycle(
+            Location::new("cycle_b.proto", 2, 1),
+            "cycle_a.proto".to_string(),
+        ));
+        assert_eq!(schema.load("none.proto").unwrap_err(), SchemaError::UnreadableFile("none.proto".to_string()));
+        assert_eq!(
+            schema.add("x.proto", "syntax = \"proto3\";\nimport \"none.proto\";").unwrap_err(),
+            SchemaError::ImportNotFound(Location::new("x.proto", 2, 1), "none.proto".to_string()),
+        );
+        assert_eq!( // `Id` is not visible without importing `id.proto`
+            schema.add("y.proto", "syntax = \"proto3\";\nmessage Y {\n  common.Id id = 1;\n}").unwrap_err(),
+            SchemaError::UnresolvedType(Location::new("y.proto", 3, 3), "common.Id".to_string()),
+        );
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    /// Should report invalid schemas with the location of the problem.
+    #[test]
+    fn fails_on_invalid_schemas() {
+        let cases = vec![
+            ("message A {}", SchemaError::UnsupportedSyntax(
+                Location::new("f.proto", 1, 1),
+                "syntax \"proto2\" (missing `syntax = \"proto3\";`)".to_string(),
+            )),
+            ("syntax = \"proto3\";\nmessage A {\n  int32 a = 1\n}", SchemaError::InvalidSyntax(
+                Location::new("f.proto", 4, 1),
+                "\";\"".to_string(),
+            )),
+            ("syntax = \"proto3\";\nmessage A { required int32 a = 1; }", SchemaError::UnsupportedSyntax(
+                Location::new("f.proto", 2, 13),
+                "label \"required\"".to_string(),
+            )),
+            ("syntax = \"proto3\";\nmessage A { int32 a = 1; int32 b = 1; }", SchemaError::InvalidTag(
+                Location::new("f.proto", 2, 26),
+                1,
+            )),
+            ("syntax = \"proto3\";\nmessage A { reserved 2; int32 a = 2; }", SchemaError::InvalidTag(
+                Location::new("f.proto", 2, 25),
+                2,
+            )),
+            ("syntax = \"proto3\";\nmessage A { int32 a = 19000; }", SchemaError::InvalidTag(
+                Location::new("f.proto", 2, 13),
+                19000,
+            )),
+            ("syntax = \"proto3\";\nmessage A { int32 a = 1; string a = 2; }", SchemaError::DuplicateName(
+                Location::new("f.proto", 2, 26),
+                "a".to_string(),
+            )),
+            ("syntax = \"proto3\";\nmessage A {}\nenum A { X = 0; }", SchemaError::DuplicateName(
+                Location::new("f.proto", 3, 1),
+                ".A".to_string(),
+            )),
+            ("syntax = \"proto3\";\nenum E { X = 1; }", SchemaError::UnsupportedSyntax(
+                Location::new("f.proto", 2, 10),
+                "first enum value other than zero".to_string(),
+            )),
+            ("syntax = \"proto3\";\nmessage A { map<double, A> m = 1; }", SchemaError::InvalidSyntax(
+                Location::new("f.proto", 2, 13),
+                "map key of integral or string type".to_string(),
+            )),
+            ("syntax = \"proto3\";\nmessage A { Missing m = 1; }", SchemaError::UnresolvedType(
+                Location::new("f.proto", 2, 13),
+                "Missing".to_string(),
+            )),
+            ("syntax = \"proto3\";\nmessage A { int32 a = 09; }", SchemaError::InvalidNumber(
+                Location::new("f.proto", 2, 23),
+            )),
+            ("syntax = \"proto3\";\noption a = \"\\q\";", SchemaError::InvalidString(
+                Location::new("f.proto", 2, 12),
+            )),
+            ("syntax = \"proto3\";\n/* open", SchemaError::InvalidSyntax(
+                Location::new("f.proto", 2, 1),
+                "end of comment".to_string(),
+            )),
+        ];
+        for (source, err) in cases {
+            assert_eq!(Schema::new().add("f.proto", source).unwrap_err(), err, "{}", source);
+        }
+    }
+
+    /// Should reject out of range, reserved and duplicated numbers and names of
+    /// fields and enum values at the location of the offending definition.
+    #[test]
+    fn fails_on_invalid_numbers_and_names() {
+        let cases = vec![
+            ("syntax = \"proto3\";\nmessage A {\n  message B {\n    int32 a = 1;\n    int64 b = 1;\n  }\n}", SchemaError::InvalidTag(
+                Location::new("f.proto", 5, 5),
+                1,
+            )),
+            ("syntax = \"proto3\";\nmessage A {\n  oneof o {\n    int32 a = 1;\n  }\n  int32 b = 1;\n}", SchemaError::InvalidTag(
+                Location::new("f.proto", 6, 3),
+                1,
+            )),
+            ("syntax = \"proto3\";\nmessage A { int32 a = 0; }", SchemaError::InvalidTag(
+                Location::new("f.proto", 2, 13),
+                0,
+            )),
+            ("syntax = \"proto3\";\nmessage A { int32 a = 536870912; }", SchemaError::InvalidTag(
+                Location::new("f.proto", 2, 13),
+                536870912,
+            )),
+            ("syntax = \"proto3\";\nmessage A { int32 a = 19999; }", SchemaError::InvalidTag(
+                Location::new("f.proto", 2, 13),
+                19999,
+            )),
+            ("syntax = \"proto3\";\nmessage A { reserved 5 to 10; int32 a = 10; }", SchemaError::InvalidTag(
+                Location::new("f.proto", 2, 31),
+                10,
+            )),
+            ("syntax = \"proto3\";\nmessage A { reserved 5 to max; int32 a = 7; }", SchemaError::InvalidTag(
+                Location::new("f.proto", 2, 32),
+                7,
+            )),
+            ("syntax = \"proto3\";\nmessage A {\n  reserved \"foo\";\n  string foo = 1;\n}", SchemaError::DuplicateName(
+                Location::new("f.proto", 4, 3),
+                "foo".to_string(),
+            )),
+            ("syntax = \"proto3\";\nmessage A {\n  oneof o {\n    int32 a = 1;\n  }\n  int32 a = 2;\n}", SchemaError::DuplicateName(
+                Location::new("f.proto", 6, 3),
+                "a".to_string(),
+            )),
+            ("syntax = \"proto3\";\nenum E {\n  X = 0;\n  Y = 0;\n}", SchemaError::InvalidTag(
+                Location::new("f.proto", 4, 3),
+                0,
+            )),
+            ("syntax = \"proto3\";\nenum E {\n  X = 0;\n  reserved 2 to 3;\n  Y = 3;\n}", SchemaError::InvalidTag(
+                Location::new("f.proto", 5, 3),
+                3,
+            )),
+            ("syntax = \"proto3\";\nenum E {\n  X = 0;\n  reserved \"Y\";\n  Y = 1;\n}", SchemaError::DuplicateName(
+                Location::new("f.proto", 5, 3),
+                "Y".to_string(),
+            )),
+        ];
+        for (source, err) in cases {
+            assert_eq!(Schema::new().add("f.proto", source).unwrap_err(), err, "{}", source);
+        }
+
+        let source = "syntax = \"proto3\";\nenum E {\n  option allow_alias = true;\n  X = 0;\n  Y = 0;\n}";
+        assert!(Schema::new().add("f.proto", source).is_ok());
+    }
+
+    /// Should reject other syntaxes, bad map key types and types which are not
+    /// visible from the file.
+    #[test]
+    fn fails_on_unsupported_syntax_and_types() {
+        let cases = vec![
+            ("syntax = \"proto2\";\nmessage A {}", SchemaError::UnsupportedSyntax(
+                Location::new("f.proto", 1, 1),
+                "syntax \"proto2\"".to_string(),
+            )),
+            ("syntax = \"proto3\";\nmessage A { map<float, int32> m = 1; }", SchemaError::InvalidSyntax(
+                Location::new("f.proto", 2, 13),
+                "map key of integral or string type".to_string(),
+            )),
+            ("syntax = \"proto3\";\nmessage A { map<bytes, int32> m = 1; }", SchemaError::InvalidSyntax(
+                Location::new("f.proto", 2, 13),
+                "map key of integral or string type".to_string(),
+            )),
+            ("syntax = \"proto3\";\nenum E { X = 0; }\nmessage A { map<E, int32> m = 1; }", SchemaError::InvalidSyntax(
+                Location::new("f.proto", 3, 13),
+                "map key of integral or string type".to_string(),
+            )),
+            ("syntax = \"proto3\";\nmessage A {\n  message B {}\n  C c = 1;\n}", SchemaError::UnresolvedType(
+                Location::new("f.proto", 4, 3),
+                "C".to_string(),
+            )),
+        ];
+        for (source, err) in cases {
+            assert_eq!(Schema::new().add("f.proto", source).unwrap_err(), err, "{}", source);
+        }
+
+        let mut schema = Schema::new();
+        schema.add("a.proto", "syntax = \"proto3\";\nmessage A {}").unwrap();
+        schema.add("b.proto", "syntax = \"proto3\";\nimport \"a.proto\";\nmessage B { A a = 1; }").unwrap();
+        assert_eq!( // `a.proto` is not imported publicly by `b.proto`
+            schema.add("c.proto", "syntax = \"proto3\";\nimport \"b.proto\";\nmessage C { A a = 1; }").unwrap_err(),
+            SchemaError::UnresolvedType(Location::new("c.proto", 3, 13), "A".to_string()),
+        );
+        assert_eq!(
+            schema.add("d.proto", "syntax = \"proto3\";\nimport \"a.proto\";\nmessage A {}").unwrap_err(),
+            SchemaError::DuplicateName(Location::new("d.proto", 3, 1), ".A".to_string()),
+        );
+    }
+}
@@ -0,0 +1,643 @@
+use super::descriptor::*;
+use super::lexer::{tokenize, Spanned, Token};
+use super::{Location, SchemaError};
+
+/// A constant holding the maximum tag number of a message field.
+const FIELD_MAX: i64 = (1 << 29) - 1;
+
+/// Parses the `.proto` `source` of the file with the provided `name` into a
+/// `FileDescriptor`.
+///
+/// Type names are left as written (`FieldType::Named`) and are resolved when
+/// the file is added to a `Schema`. Fully-qualified names of definitions are
+/// assigned from the package and the nesting.
+pub fn parse(name: &str, source: &str) -> Result<FileDescriptor, SchemaError> {
+    let mut parser = Parser {
+        file: name,
+        source,
+        tokens: tokenize(name, source)?,
+        pos: 0,
+    };
+    let mut file = parser.parse_file()?;
+    let scope = file.package.as_ref().map(|p| format!(".{}", p)).unwrap_or_default();
+    for message in file.messages.iter_mut() {
+        assign_message_names(message, &scope);
+    }
+    for enumeration in file.enums.iter_mut() {
+        enumeration.full_name = format!("{}.{}", scope, enumeration.name);
+    }
+    for service in file.services.iter_mut() {
+        service.full_name = format!("{}.{}", scope, service.name);
+    }
+    Ok(file)
+}
+
+/// Assigns fully-qualified names to the `message` and its nested types.
+fn assign_message_names(message: &mut MessageDescriptor, scope: &str) {
+    message.full_name = format!("{}.{}", scope, message.name);
+    for enumeration in message.enums.iter_mut() {
+        enumeration.full_name = format!("{}.{}", message.full_name, enumeration.name);
+    }
+    let scope = message.full_name.clone();
+    for nested in message.messages.iter_mut() {
+        assign_message_names(nested, &scope);
+    }
+}
+
+/// A recursive descent parser over the tokens of a single file.
+struct Parser<'a> {
+    file: &'a str,
+    source: &'a str,
+    tokens: Vec<Spanned>,
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    /// Returns the token `ahead` positions from the current one.
+    fn peek_at(&self, ahead: usize) -> &Token {
+        let index = (self.pos + ahead).min(self.tokens.len() - 1);
+        &self.tokens[index].token
+    }
+
+    /// Returns the current token.
+    fn peek(&self) -> &Token {
+        self.peek_at(0)
+    }
+
+    /// Returns the location of the current token.
+    fn location(&self) -> Location {
+        let token = &self.tokens[self.pos.min(self.tokens.len() - 1)];
+        Location::new(self.file, token.line, token.column)
+    }
+
+    /// Consumes and returns the current token.
+    fn next(&mut self) -> Token {
+        let token = self.peek().clone();
+        if token != Token::Eof {
+            self.pos += 1;
+        }
+        token
+    }
+
+    /// Returns a syntax error at the current token.
+    fn error<T>(&self, expected: &str) -> Result<T, SchemaError> {
+        Err(SchemaError::InvalidSyntax(self.location(), expected.to_string()))
+    }
+
+    /// Tells whether the current token is the identifier `word`.
+    fn is_ident(&self, word: &str) -> bool {
+        matches!(self.peek(), Token::Ident(i) if i == word)
+    }
+
+    /// Tells whether the token `ahead` positions from the current one is the
+    /// symbol `c`.
+    fn is_symbol_at(&self, ahead: usize, c: char) -> bool {
+        *self.peek_at(ahead) == Token::Symbol(c)
+    }
+
+    /// Consumes the symbol `c` if it is the current token.
+    fn eat_symbol(&mut self, c: char) -> bool {
+        if self.is_symbol_at(0, c) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Consumes the symbol `c` or fails.
+    fn expect_symbol(&mut self, c: char) -> Result<(), SchemaError> {
+        if self.eat_symbol(c) {
+            Ok(())
+        } else {
+            self.error(&format!("\"{}\"", c))
+        }
+    }
+
+    /// Consumes the identifier `word` or fails.
+    fn expect_keyword(&mut self, word: &str) -> Result<(), SchemaError> {
+        if self.is_ident(word) {
+            self.pos += 1;
+            Ok(())
+        } else {
+            self.error(&format!("\"{}\"", word))
+        }
+    }
+
+    /// Consumes an identifier or fails.
+    fn expect_ident(&mut self) -> Result<String, SchemaError> {
+        match self.peek().clone() {
+            Token::Ident(ident) => {
+                self.pos += 1;
+                Ok(ident)
+            },
+            _ => self.error("identifier"),
+        }
+    }
+
+    /// Consumes a dot-separated identifier (e.g. `foo.bar`).
+    fn full_ident(&mut self) -> Result<String, SchemaError> {
+        let mut ident = self.expect_ident()?;
+        while self.is_symbol_at(0, '.') {
+            self.pos += 1;
+            ident.push('.');
+            ident.push_str(&self.expect_ident()?);
+        }
+        Ok(ident)
+    }
+
+    /// Consumes a type name which can start with a dot (e.g. `.foo.Bar`).
+    fn type_name(&mut self) -> Result<String, SchemaError> {
+        if self.eat_symbol('.') {
+            Ok(format!(".{}", self.full_ident()?))
+        } else {
+            self.full_ident()
+        }
+    }
+
+    /// Consumes one or more adjacent string literals and joins them.
+    fn expect_str(&mut self) -> Result<String, SchemaError> {
+        let mut text = match self.peek().clone() {
+            Token::Str(text) => text,
+            _ => return self.error("string"),
+        };
+        self.pos += 1;
+        while let Token::Str(next) = self.peek().clone() {
+            text.push_str(&next);
+            self.pos += 1;
+        }
+        Ok(text)
+    }
+
+    /// Consumes an integer with an optional sign and checks that it lies
+    /// between `min` and `max`.
+    fn int_value(&mut self, min: i64, max: i64) -> Result<i64, SchemaError> {
+        let loc = self.location();
+        let negative = self.eat_symbol('-');
+        let value = match self.next() {
+            Token::Int(value) => value as i128,
+            _ => return Err(SchemaError::InvalidSyntax(loc, "integer".to_string())),
+        };
+        let value = if negative { -value } else { value };
+        if value < min as i128 || value > max as i128 {
+            return Err(SchemaError::InvalidNumber(loc));
+        }
+        Ok(value as i64)
+    }
+
+    /// Consumes a field tag number.
+    fn tag_value(&mut self) -> Result<u32, SchemaError> {
+        let loc = self.location();
+        match self.next() {
+            Token::Int(value) if value <= u32::MAX as u64 => Ok(value as u32),
+            Token::Int(value) => Err(SchemaError::InvalidTag(loc, value.min(i64::MAX as u64) as i64)),
+            _ => Err(SchemaError::InvalidSyntax(loc, "field number".to_string())),
+        }
+    }
+
+    /// Parses a whole file.
+    fn parse_file(&mut self) -> Result<FileDescriptor, SchemaError> {
+        let mut file = FileDescriptor {
+            name: self.file.to_string(),
+            ..Default::default()
+        };
+
+        let loc = self.location();
+        if self.is_ident("syntax") {
+            self.pos += 1;
+            self.expect_symbol('=')?;
+            let syntax = self.expect_str()?;
+            self.expect_symbol(';')?;
+            if syntax != "proto3" {
+                return Err(SchemaError::UnsupportedSyntax(loc, format!("syntax \"{}\"", syntax)));
+            }
+        } else if self.is_ident("edition") {
+            return Err(SchemaError::UnsupportedSyntax(loc, "editions".to_string()));
+        } else {
+            return Err(SchemaError::UnsupportedSyntax(loc, "syntax \"proto2\" (missing `syntax = \"proto3\";`)".to_string()));
+        }
+
+        loop {
+            let loc = self.location();
+            match self.peek().clone() {
+                Token::Eof => return Ok(file),
+                Token::Symbol(';') => self.pos += 1,
+                Token::Ident(word) => match word.as_str() {
+                    "import" => {
+                        self.pos += 1;
+                        let kind = if self.is_ident("public") {
+                            self.pos += 1;
+                            ImportKind::Public
+                        } else if self.is_ident("weak") {
+                            self.pos += 1;
+                            ImportKind::Weak
+                        } else {
+                            ImportKind::Default
+                        };
+                        let path = self.expect_str()?;
+                        self.expect_symbol(';')?;
+                        file.imports.push(ImportDescriptor { path, kind, location: loc });
+                    },
+                    "package" => {
+                        self.pos += 1;
+                        if file.package.is_some() {
+                            return Err(SchemaError::DuplicateName(loc, "package".to_string()));
+                        }
+                        file.package = Some(self.full_ident()?);
+                        self.expect_symbol(';')?;
+                    },
+                    "option" => file.options.push(self.option_statement()?),
+                    "message" => file.messages.push(self.message()?),
+                    "enum" => file.enums.push(self.enumeration()?),
+                    "service" => file.services.push(self.service()?),
+                    "extend" => file.extensions.push(self.extension()?),
+                    _ => return self.error("top-level definition"),
+                },
+                _ => return self.error("top-level definition"),
+            }
+        }
+    }
+
+    /// Parses an option name such as `java_package` or `(foo.bar).baz`.
+    fn option_name(&mut self) -> Result<String, SchemaError> {
+        let mut name = String::new();
+        loop {
+            if self.eat_symbol('(') {
+                name.push('(');
+                name.push_str(&self.type_name()?);
+                self.expect_symbol(')')?;
+                name.push(')');
+            } else {
+                name.push_str(&self.expect_ident()?);
+            }
+            if !self.eat_symbol('.') {
+                return Ok(name);
+            }
+            name.push('.');
+        }
+    }
+
+    /// Parses a constant value of an option.
+    fn constant(&mut self) -> Result<Constant, SchemaError> {
+        let loc = self.location();
+        let sign = if self.eat_symbol('-') {
+            Some(true)
+        } else if self.eat_symbol('+') {
+            Some(false)
+        } else {
+            None
+        };
+        let negative = sign == Some(true);
+        let value = match self.peek().clone() {
+            Token::Int(value) => {
+                self.pos += 1;
+                if !negative {
+                    Constant::UInt(value)
+                } else if value <= i64::MAX as u64 + 1 {
+                    Constant::Int((value as i128).wrapping_neg() as i64)
+                } else {
+                    return Err(SchemaError::InvalidNumber(loc));
+                }
+            },
+            Token::Float(value) => {
+                self.pos += 1;
+                Constant::Float(if negative { -value } else { value })
+            },
+            Token::Ident(word) if word == "inf" || word == "nan" => {
+                self.pos += 1;
+                let value = if word == "inf" { f64::INFINITY } else { f64::NAN };
+                Constant::Float(if negative { -value } else { value })
+            },
+            _ if sign.is_some() => return Err(SchemaError::InvalidSyntax(loc, "number".to_string())),
+            Token::Ident(word) if word == "true" || word == "false" => {
+                self.pos += 1;
+                Constant::Bool(word == "true")
+            },
+            Token::Ident(_) => Constant::Ident(self.full_ident()?),
+            Token::Str(_) => Constant::Str(self.expect_str()?),
+            Token::Symbol('{') => Constant::Aggregate(self.aggregate()?),
+            _ => return self.error("constant"),
+        };
+        Ok(value)
+    }
+
+    /// Consumes a message literal in braces and returns its source text.
+    fn aggregate(&mut self) -> Result<String, SchemaError> {
+        let start = self.tokens[self.pos].offset;
+        let mut depth = 0;
+        loop {
+            match self.next() {
+                Token::Symbol('{') => depth += 1,
+                Token::Symbol('}') => {
+                    depth -= 1;
+                    if depth == 0 {
+                        let end = self.tokens[self.pos - 1].offset + 1;
+                        return Ok(self.source[start..end].to_string());
+                    }
+                },
+                Token::Eof => return self.error("\"}\""),
+                _ => {},
+            }
+        }
+    }
+
+    /// Parses an `option name = value;` statement.
+    fn option_statement(&mut self) -> Result<(String, Constant), SchemaError> {
+        self.expect_keyword("option")?;
+        let name = self.option_name()?;
+        self.expect_symbol('=')?;
+        let value = self.constant()?;
+        self.expect_symbol(';')?;
+        Ok((name, value))
+    }
+
+    /// Parses optional field options in brackets (e.g. `[packed = false]`).
+    fn field_options(&mut self) -> Result<Vec<(String, Constant)>, SchemaError> {
+        let mut options = Vec::new();
+        if self.eat_symbol('[') {
+            loop {
+                let name = self.option_name()?;
+                self.expect_symbol('=')?;
+                options.push((name, self.constant()?));
+                if !self.eat_symbol(',') {
+                    break;
+                }
+            }
+            self.expect_symbol(']')?;
+        }
+        Ok(options)
+    }
+
+    /// Parses the type of a field.
+    fn field_type(&mut self) -> Result<FieldType, SchemaError> {
+        let name = self.type_name()?;
+        Ok(FieldType::from_name(&name).unwrap_or(FieldType::Named(name)))
+    }
+
+    /// Parses the rest of a field definition after its type.
+    fn field_rest(&mut self, label: Label, typ: FieldType, oneof: Option<usize>, location: Location) -> Result<FieldDescriptor, SchemaError> {
+        let name = self.expect_ident()?;
+        self.expect_symbol('=')?;
+        let number = self.tag_value()?;
+        let options = self.field_options()?;
+        self.expect_symbol(';')?;
+        let json_name = options.iter()
+            .find_map(|(n, v)| match v {
+                Constant::Str(s) if n == "json_name" => Some(s.clone()),
+                _ => None,
+            })
+            .unwrap_or_else(|| json_name(&name));
+        Ok(FieldDescriptor { name, number, label, typ, oneof, json_name, options, location })
+    }
+
+    /// Parses a normal field with an optional label.
+    fn field(&mut self) -> Result<FieldDescriptor, SchemaError> {
+        let location = self.location();
+        let label = if self.is_ident("repeated") && !self.is_symbol_at(1, '.') {
+            self.pos += 1;
+            Label::Repeated
+        } else if self.is_ident("optional") && !self.is_symbol_at(1, '.') {
+            self.pos += 1;
+            Label::Optional
+        } else if self.is_ident("required") && !self.is_symbol_at(1, '.') {
+            return Err(SchemaError::UnsupportedSyntax(location, "label \"required\"".to_string()));
+        } else {
+            Label::Singular
+        };
+        if self.is_ident("group") && matches!(self.peek_at(1), Token::Ident(_)) && !self.is_symbol_at(2, '=') {
+            return Err(SchemaError::UnsupportedSyntax(location, "group field".to_string()));
+        }
+        let typ = self.field_type()?;
+        self.field_rest(label, typ, None, location)
+    }
+
+    /// Parses a `map<K, V>` field.
+    fn map_field(&mut self) -> Result<FieldDescriptor, SchemaError> {
+        let location = self.location();
+        self.expect_keyword("map")?;
+        self.expect_symbol('<')?;
+        let key = self.field_type()?;
+        self.expect_symbol(',')?;
+        let value = self.field_type()?;
+        self.expect_symbol('>')?;
+        let typ = FieldType::Map(Box::new(key), Box::new(value));
+        self.field_rest(Label::Repeated, typ, None, location)
+    }
+
+    /// Parses a `reserved` statement with ranges up to `max` or names.
+    fn reserved(&mut self, min: i64, max: i64, ranges: &mut Vec<(i64, i64)>, names: &mut Vec<String>) -> Result<(), SchemaError> {
+        self.expect_keyword("reserved")?;
+        loop {
+            match self.peek() {
+                Token::Str(_) => names.push(self.expect_str()?),
+                Token::Ident(_) => names.push(self.expect_ident()?),
+                _ => {
+                    let loc = self.location();
+                    let start = self.int_value(min, max)?;
+                    let end = if self.is_ident("to") {
+                        self.pos += 1;
+                        if self.is_ident("max") {
+                            self.pos += 1;
+                            max
+                        } else {
+                            self.int_value(min, max)?
+                        }
+                    } else {
+                        start
+                    };
+                    if end < start {
+                        return Err(SchemaError::InvalidNumber(loc));
+                    }
+                    ranges.push((start, end));
+                },
+            }
+            if !self.eat_symbol(',') {
+                break;
+            }
+        }
+        self.expect_symbol(';')
+    }
+
+    /// Tells whether the current token starts a definition with the keyword
+    /// `word` (e.g. `message Foo {`), which distinguishes definitions from
+    /// fields using the keyword as a type name.
+    fn is_definition(&self, word: &str) -> bool {
+        self.is_ident(word) && !self.is_symbol_at(2, '=')
+            && (word == "extend" || !self.is_symbol_at(1, '.'))
+    }
+
+    /// Parses a message definition.
+    fn message(&mut self) -> Result<MessageDescriptor, SchemaError> {
+        let location = self.location();
+        self.expect_keyword("message")?;
+        let mut message = MessageDescriptor {
+            name: self.expect_ident()?,
+            location,
+            ..Default::default()
+        };
+        self.expect_symbol('{')?;
+        while !self.eat_symbol('}') {
+            let loc = self.location();
+            match self.peek() {
+                Token::Eof => return self.error("\"}\""),
+                Token::Symbol(';') => self.pos += 1,
+                _ if self.is_definition("message") => message.messages.push(self.message()?),
+                _ if self.is_definition("enum") => message.enums.push(self.enumeration()?),
+                _ if self.is_definition("oneof") => self.oneof(&mut message)?,
+                _ if self.is_definition("extend") => message.extensions.push(self.extension()?),
+                _ if self.is_ident("option") => message.options.push(self.option_statement()?),
+                _ if self.is_ident("reserved") => {
+                    self.reserved(1, FIELD_MAX, &mut message.reserved, &mut message.reserved_names)?;
+                },
+                _ if self.is_ident("extensions") => {
+                    return Err(SchemaError::UnsupportedSyntax(loc, "extension ranges".to_string()));
+                },
+                _ if self.is_ident("map") && self.is_symbol_at(1, '<') => message.fields.push(self.map_field()?),
+                _ => message.fields.push(self.field()?),
+            }
+        }
+        Ok(message)
+    }
+
+    /// Parses a `oneof` and adds its fields to the `message`.
+    fn oneof(&mut self, message: &mut MessageDescriptor) -> Result<(), SchemaError> {
+        self.expect_keyword("oneof")?;
+        let index = message.oneofs.len();
+        let mut oneof = OneofDescriptor {
+            name: self.expect_ident()?,
+            options: Vec::new(),
+        };
+        self.expect_symbol('{')?;
+        while !self.eat_symbol('}') {
+            let loc = self.location();
+            match self.peek() {
+                Token::Eof => return self.error("\"}\""),
+                Token::Symbol(';') => self.pos += 1,
+                _ if self.is_ident("option") => oneof.options.push(self.option_statement()?),
+                _ if ["repeated", "optional", "required"].iter().any(|w| self.is_ident(w)) && !self.is_symbol_at(1, '.') => {
+                    return Err(SchemaError::UnsupportedSyntax(loc, "label in oneof".to_string()));
+                },
+                _ if self.is_ident("map") && self.is_symbol_at(1, '<') => {
+                    return Err(SchemaError::UnsupportedSyntax(loc, "map in oneof".to_string()));
+                },
+                _ => {
+                    let typ = self.field_type()?;
+                    let field = self.field_rest(Label::Singular, typ, Some(index), loc)?;
+                    message.fields.push(field);
+                },
+            }
+        }
+        message.oneofs.push(oneof);
+        Ok(())
+    }
+
+    /// Parses an enum definition.
+    fn enumeration(&mut self) -> Result<EnumDescriptor, SchemaError> {
+        let location = self.location();
+        self.expect_keyword("enum")?;
+        let mut enumeration = EnumDescriptor {
+            name: self.expect_ident()?,
+            location,
+            ..Default::default()
+        };
+        self.expect_symbol('{')?;
+        while !self.eat_symbol('}') {
+            let location = self.location();
+            match self.peek() {
+                Token::Eof => return self.error("\"}\""),
+                Token::Symbol(';') => self.pos += 1,
+                _ if self.is_ident("option") && !self.is_symbol_at(1, '=') => {
+                    enumeration.options.push(self.option_statement()?);
+                },
+                _ if self.is_ident("reserved") && !self.is_symbol_at(1, '=') => {
+                    let (min, max) = (i32::MIN as i64, i32::MAX as i64);
+                    self.reserved(min, max, &mut enumeration.reserved, &mut enumeration.reserved_names)?;
+                },
+                _ => {
+                    let name = self.expect_ident()?;
+                    self.expect_symbol('=')?;
+                    let number = self.int_value(i32::MIN as i64, i32::MAX as i64)? as i32;
+                    let options = self.field_options()?;
+                    self.expect_symbol(';')?;
+                    enumeration.values.push(EnumValueDescriptor { name, number, options, location });
+                },
+            }
+        }
+        Ok(enumeration)
+    }
+
+    /// Parses a service definition.
+    fn service(&mut self) -> Result<ServiceDescriptor, SchemaError> {
+        let location = self.location();
+        self.expect_keyword("service")?;
+        let mut service = ServiceDescriptor {
+            name: self.expect_ident()?,
+            location,
+            ..Default::default()
+        };
+        self.expect_symbol('{')?;
+        while !self.eat_symbol('}') {
+            match self.peek() {
+                Token::Eof => return self.error("\"}\""),
+                Token::Symbol(';') => self.pos += 1,
+                _ if self.is_ident("option") => service.options.push(self.option_statement()?),
+                _ if self.is_ident("rpc") => service.methods.push(self.method()?),
+                _ => return self.error("\"rpc\" or \"option\""),
+            }
+        }
+        Ok(service)
+    }
+
+    /// Parses a `stream`-able message type of a method in parentheses.
+    fn method_type(&mut self) -> Result<(String, bool), SchemaError> {
+        self.expect_symbol('(')?;
+        let streaming = self.is_ident("stream") && !self.is_symbol_at(1, ')') && !self.is_symbol_at(1, '.');
+        if streaming {
+            self.pos += 1;
+        }
+        let name = self.type_name()?;
+        self.expect_symbol(')')?;
+        Ok((name, streaming))
+    }
+
+    /// Parses an `rpc` definition.
+    fn method(&mut self) -> Result<MethodDescriptor, SchemaError> {
+        let location = self.location();
+        self.expect_keyword("rpc")?;
+        let name = self.expect_ident()?;
+        let (input, client_streaming) = self.method_type()?;
+        self.expect_keyword("returns")?;
+        let (output, server_streaming) = self.method_type()?;
+        let mut options = Vec::new();
+        if self.eat_symbol('{') {
+            while !self.eat_symbol('}') {
+                match self.peek() {
+                    Token::Eof => return self.error("\"}\""),
+                    Token::Symbol(';') => self.pos += 1,
+                    _ => options.push(self.option_statement()?),
+                }
+            }
+            self.eat_symbol(';');
+        } else {
+            self.expect_symbol(';')?;
+        }
+        Ok(MethodDescriptor { name, input, output, client_streaming, server_streaming, options, location })
+    }
+
+    /// Parses an `extend` block.
+    fn extension(&mut self) -> Result<ExtensionDescriptor, SchemaError> {
+        let location = self.location();
+        self.expect_keyword("extend")?;
+        let extendee = self.type_name()?;
+        let mut fields = Vec::new();
+        self.expect_symbol('{')?;
+        while !self.eat_symbol('}') {
+            match self.peek() {
+                Token::Eof => return self.error("\"}\""),
+                Token::Symbol(';') => self.pos += 1,
+                _ => fields.push(self.field()?),
+            }
+        }
+        Ok(ExtensionDescriptor { extendee, fields, location })
+    }
+}
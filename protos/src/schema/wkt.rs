@@ -0,0 +1,111 @@
+//! Provides the sources of the well-known types so that they can be imported
+//! without adding the `protoc` include directory to the include paths. The
+//! `descriptor.proto` file only declares the option messages which are
+//! extended by custom options.
+
+const ANY: &str = r#"
+syntax = "proto3";
+package google.protobuf;
+message Any {
+  string type_url = 1;
+  bytes value = 2;
+}
+"#;
+
+const DESCRIPTOR: &str = r#"
+syntax = "proto3";
+package google.protobuf;
+message FileOptions {}
+message MessageOptions {}
+message FieldOptions {}
+message OneofOptions {}
+message EnumOptions {}
+message EnumValueOptions {}
+message ServiceOptions {}
+message MethodOptions {}
+"#;
+
+const DURATION: &str = r#"
+syntax = "proto3";
+package google.protobuf;
+message Duration {
+  int64 seconds = 1;
+  int32 nanos = 2;
+}
+"#;
+
+const EMPTY: &str = r#"
+syntax = "proto3";
+package google.protobuf;
+message Empty {}
+"#;
+
+const FIELD_MASK: &str = r#"
+syntax = "proto3";
+package google.protobuf;
+message FieldMask {
+  repeated string paths = 1;
+}
+"#;
+
+const STRUCT: &str = r#"
+syntax = "proto3";
+package google.protobuf;
+message Struct {
+  map<string, Value> fields = 1;
+}
+message Value {
+  oneof kind {
+    NullValue null_value = 1;
+    double number_value = 2;
+    string string_value = 3;
+    bool bool_value = 4;
+    Struct struct_value = 5;
+    ListValue list_value = 6;
+  }
+}
+enum NullValue {
+  NULL_VALUE = 0;
+}
+message ListValue {
+  repeated Value values = 1;
+}
+"#;
+
+const TIMESTAMP: &str = r#"
+syntax = "proto3";
+package google.protobuf;
+message Timestamp {
+  int64 seconds = 1;
+  int32 nanos = 2;
+}
+"#;
+
+const WRAPPERS: &str = r#"
+syntax = "proto3";
+package google.protobuf;
+message DoubleValue { double value = 1; }
+message FloatValue { float value = 1; }
+message Int64Value { int64 value = 1; }
+message UInt64Value { uint64 value = 1; }
+message Int32Value { int32 value = 1; }
+message UInt32Value { uint32 value = 1; }
+message BoolValue { bool value = 1; }
+message StringValue { string value = 1; }
+message BytesValue { bytes value = 1; }
+"#;
+
+/// Returns the source of the well-known type file at the provided `path`.
+pub(crate) fn source(path: &str) -> Option<&'static str> {
+    match path {
+        "google/protobuf/any.proto" => Some(ANY),
+        "google/protobuf/descriptor.proto" => Some(DESCRIPTOR),
+        "google/protobuf/duration.proto" => Some(DURATION),
+        "google/protobuf/empty.proto" => Some(EMPTY),
+        "google/protobuf/field_mask.proto" => Some(FIELD_MASK),
+        "google/protobuf/struct.proto" => Some(STRUCT),
+        "google/protobuf/timestamp.proto" => Some(TIMESTAMP),
+        "google/protobuf/wrappers.proto" => Some(WRAPPERS),
+        _ => None,
+    }
+}
@@ -0,0 +1,61 @@
+use std::error;
+use std::fmt;
+use crate::{DecoderError, EncoderError};
+
+/// Contains error options that can be encountered while converting messages to
+/// and from the text format.
+#[derive(Debug, PartialEq)]
+pub enum TextError {
+    /// Indicates that the text is malformed at the provided line.
+    InvalidSyntax(usize),
+
+    /// Indicates that the schema does not define a message with the provided
+    /// fully-qualified name or type URL.
+    UnknownMessage(String),
+
+    /// Indicates that the text holds a field name which is not a field of the
+    /// message.
+    UnknownField(String),
+
+    /// Indicates that the value of the field with the provided name does not
+    /// match the type or the cardinality of the field.
+    InvalidValue(String),
+
+    /// Indicates that embedded messages are nested deeper than the conversion
+    /// allows.
+    DepthOverflow,
+
+    /// Indicates that the wire format bytes could not be decoded.
+    Decoder(DecoderError),
+
+    /// Indicates that the message could not be encoded into the wire format.
+    Encoder(EncoderError),
+}
+
+impl From<DecoderError> for TextError {
+    fn from(err: DecoderError) -> Self {
+        Self::Decoder(err)
+    }
+}
+
+impl From<EncoderError> for TextError {
+    fn from(err: EncoderError) -> Self {
+        Self::Encoder(err)
+    }
+}
+
+impl fmt::Display for TextError {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::InvalidSyntax(line) => write!(fmt, "Invalid text format syntax at line {}.", line),
+            Self::UnknownMessage(name) => write!(fmt, "Unknown message {}.", name),
+            Self::UnknownField(name) => write!(fmt, "Unknown field {}.", name),
+            Self::InvalidValue(name) => write!(fmt, "Invalid value for field {}.", name),
+            Self::DepthOverflow => write!(fmt, "Messages nested too deep."),
+            Self::Decoder(err) => write!(fmt, "{}", err),
+            Self::Encoder(err) => write!(fmt, "{}", err),
+        }
+    }
+}
+
+impl error::Error for TextError {}
@@ -0,0 +1,387 @@
+//! Provides the conversion of messages to and from the text format.
+//!
+//! The text format is the human-readable form of messages printed by `protoc
+//! --decode`. Messages are described by a `Schema` and held as
+//! `DynamicMessage` values, so the wire format bytes of a message type can be
+//! converted directly into the text format and back. Each field is written on
+//! its own line under its original name, embedded messages and map entries are
+//! written in braces, enum values by name and bytes as quoted strings with
+//! octal escapes. An `Any` is expanded into the type URL in brackets and the
+//! fields of the packed message.
+//!
+//! Messages without a schema are written with `print_raw`, which names fields
+//! by their tag numbers the same way as `protoc --decode_raw` does it.
+//!
+//! ```rust
+//! use httlib_protos::schema::Schema;
+//! use httlib_protos::text;
+//!
+//! let mut schema = Schema::new();
+//! schema.add("user.proto", r#"
+//!     syntax = "proto3";
+//!     message User {
+//!         string name = 1;
+//!         repeated int32 scores = 2;
+//!     }
+//! "#).unwrap();
+//!
+//! let bytes = text::to_wire(&schema, ".User", r#"name: "John" scores: [1, 2]"#).unwrap();
+//! assert_eq!(bytes, vec![0x0a, 0x04, b'J', b'o', b'h', b'n', 0x12, 0x02, 0x01, 0x02]);
+//!
+//! let text = text::from_wire(&schema, ".User", &bytes).unwrap();
+//! assert_eq!(text, "name: \"John\"\nscores: 1\nscores: 2\n");
+//! ```
+
+mod error;
+mod parser;
+mod printer;
+mod raw;
+
+use crate::{FieldIter, Typ};
+use crate::dynamic::DynamicMessage;
+use crate::schema::Schema;
+use parser::Parser;
+pub use error::*;
+
+/// Returns the text format of the provided `message`.
+pub fn print(message: &DynamicMessage) -> String {
+    let mut out = String::new();
+    printer::print_message(message, 0, &mut out);
+    out
+}
+
+/// Returns the message of the type with the provided fully-qualified name
+/// (e.g. `.pkg.Name`) which is built from the `text`.
+///
+/// On success the message is returned otherwise an error is thrown.
+pub fn parse<'a>(schema: &'a Schema, full_name: &str, text: &str) -> Result<DynamicMessage<'a>, TextError> {
+    let descriptor = schema.message(full_name)
+        .ok_or_else(|| TextError::UnknownMessage(full_name.to_string()))?;
+    Parser::new(schema, text)?.parse(descriptor)
+}
+
+/// Returns the text format of a message of the type with the provided
+/// fully-qualified name which is encoded in `bytes`.
+///
+/// On success the text is returned otherwise an error is thrown.
+pub fn from_wire(schema: &Schema, full_name: &str, bytes: &[u8]) -> Result<String, TextError> {
+    let mut message = DynamicMessage::new(schema, full_name)
+        .map_err(|_| TextError::UnknownMessage(full_name.to_string()))?;
+    message.merge(bytes)?;
+    Ok(print(&message))
+}
+
+/// Returns the wire format bytes of a message of the type with the provided
+/// fully-qualified name which is built from the `text`.
+///
+/// On success the encoded message is returned otherwise an error is thrown.
+pub fn to_wire(schema: &Schema, full_name: &str, text: &str) -> Result<Vec<u8>, TextError> {
+    let message = parse(schema, full_name, text)?;
+    let mut dst = Vec::new();
+    message.encode(&mut dst)?;
+    Ok(dst)
+}
+
+/// Returns the text format of the fields returned by the `Decoder` without a
+/// schema. Fields are named by their tag numbers, varints are written as
+/// unsigned numbers and fixed-size values in hex. A length-delimited field is
+/// written as an embedded message when its bytes hold valid fields and as a
+/// quoted string otherwise.
+///
+/// ```rust
+/// use httlib_protos::Decoder;
+/// use httlib_protos::text;
+///
+/// let mut buf = vec![0x08, 0x96, 0x01, 0x12, 0x04, b'J', b'o', b'h', b'n'];
+/// let mut fields = Vec::new();
+/// Decoder::default().decode(&mut buf, &mut fields).unwrap();
+///
+/// assert_eq!(text::print_raw(&fields), "1: 150\n2: \"John\"\n");
+/// ```
+pub fn print_raw(fields: &[(u32, Typ, Vec<u8>)]) -> String {
+    let mut out = String::new();
+    for (tag, typ, bytes) in fields {
+        raw::print_raw_field(*tag, *typ, bytes, 0, &mut out);
+    }
+    out
+}
+
+/// Returns the text format of the encoded message `bytes` without a schema.
+/// See `print_raw` for details.
+///
+/// On success the text is returned otherwise an error is thrown.
+pub fn from_wire_raw(bytes: &[u8]) -> Result<String, TextError> {
+    let mut out = String::new();
+    for field in FieldIter::new(bytes) {
+        let (tag, typ, bytes) = field?;
+        raw::print_raw_field(tag, typ, bytes, 0, &mut out);
+    }
+    Ok(out)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::dump::DUMP_DEPTH_MAX;
+    use crate::dynamic::{Value, MESSAGE_DEPTH_MAX};
+
+    fn schema() -> Schema {
+        let mut schema = Schema::new();
+        schema.add("test.proto", r#"
+            syntax = "proto3";
+            package test;
+            import "google/protobuf/any.proto";
+            enum Status {
+                STATUS_UNKNOWN = 0;
+                STATUS_ACTIVE = 1;
+            }
+            message Item {
+                string name = 1;
+                repeated sint64 counts = 2;
+            }
+            message Record {
+                int32 id = 1;
+                double ratio = 2;
+                bool flag = 3;
+                bytes data = 4;
+                Status status = 5;
+                repeated Item items = 6;
+                map<string, Item> index = 7;
+                optional string note = 8;
+                oneof choice {
+                    string text = 9;
+                    Item item = 10;
+                }
+                google.protobuf.Any detail = 11;
+            }
+        "#).unwrap();
+        schema
+    }
+
+    /// Should convert messages to and from the text format.
+    #[test]
+    fn converts_messages() {
+        let schema = schema();
+        let text = concat!(
+            "id: -1\n",
+            "ratio: 0.5\n",
+            "flag: true\n",
+            "data: \"\\001a\\\"\\377\"\n",
+            "status: STATUS_ACTIVE\n",
+            "items {\n",
+            "  name: \"\u{e9}\\n\"\n",
+            "  counts: 1\n",
+            "  counts: -2\n",
+            "}\n",
+            "index {\n",
+            "  key: \"a\"\n",
+            "  value {\n",
+            "  }\n",
+            "}\n",
+            "note: \"\"\n",
+            "item {\n",
+            "  name: \"x\"\n",
+            "}\n",
+            "detail {\n",
+            "  [type.googleapis.com/test.Item] {\n",
+            "    name: \"y\"\n",
+            "  }\n",
+            "}\n",
+        );
+        let bytes = to_wire(&schema, ".test.Record", text).unwrap();
+        assert_eq!(from_wire(&schema, ".test.Record", &bytes).unwrap(), text);
+
+        let message = parse(&schema, ".test.Record", r#"
+            # a comment
+            id: 0x10, ratio: -inf; status: 1
+            items < name: 'a' "b" counts: [3, -4] >
+            items: { counts: 5 }
+            index: [{ key: "k" value: { name: "v" } }, { key: "e" }]
+            text: "t"
+        "#).unwrap();
+        assert_eq!(print(&message), concat!(
+            "id: 16\n",
+            "ratio: -inf\n",
+            "status: STATUS_ACTIVE\n",
+            "items {\n",
+            "  name: \"ab\"\n",
+            "  counts: 3\n",
+            "  counts: -4\n",
+            "}\n",
+            "items {\n",
+            "  counts: 5\n",
+            "}\n",
+            "index {\n",
+            "  key: \"e\"\n",
+            "  value {\n",
+            "  }\n",
+            "}\n",
+            "index {\n",
+            "  key: \"k\"\n",
+            "  value {\n",
+            "    name: \"v\"\n",
+            "  }\n",
+            "}\n",
+            "text: \"t\"\n",
+        ));
+
+        assert_eq!(parse(&schema, ".test.Record", "other: 1"), Err(TextError::UnknownField("other".to_string())));
+        assert_eq!(parse(&schema, ".test.Record", "id: 1 id: 2"), Err(TextError::InvalidValue("id".to_string())));
+        assert_eq!(parse(&schema, ".test.Record", "text: \"a\" item {}"), Err(TextError::InvalidValue("item".to_string())));
+        assert_eq!(parse(&schema, ".test.Record", "status: OTHER"), Err(TextError::InvalidValue("status".to_string())));
+        assert_eq!(parse(&schema, ".test.Record", "id: 1\nitems {"), Err(TextError::InvalidSyntax(2)));
+    }
+
+    /// Should unescape octal, hexadecimal and Unicode escapes, print bytes
+    /// which read back to the same value and refuse invalid escapes.
+    #[test]
+    fn converts_escapes() {
+        let schema = schema();
+        let cases = vec![
+            (r#"data: "\101\1\12\0012""#, "data: \"A\\001\\n\\0012\"\n", &b"A\x01\n\x012"[..]),
+            (r#"data: "\x41\x4\x7fz""#, "data: \"A\\004\\177z\"\n", &b"A\x04\x7fz"[..]),
+            (r#"data: "\a\b\f\v\?\'""#, "data: \"\\007\\010\\014\\013?\\'\"\n", &b"\x07\x08\x0c\x0b?'"[..]),
+            (r#"data: "é\U0001F600""#, "data: \"\\303\\251\\360\\237\\230\\200\"\n", "\u{e9}\u{1f600}".as_bytes()),
+            (r#"note: "é\U0001F600\x41\101""#, "note: \"\u{e9}\u{1f600}AA\"\n", "\u{e9}\u{1f600}AA".as_bytes()),
+        ];
+        for (text, printed, value) in cases {
+            let message = parse(&schema, ".test.Record", text).unwrap();
+            let field = message.descriptor().fields.iter().find(|f| text.starts_with(&f.name)).unwrap();
+            match message.get(&field.name).unwrap() {
+                Value::Bytes(v) => assert_eq!(v.as_slice(), value),
+                Value::String(v) => assert_eq!(v.as_bytes(), value),
+                _ => unreachable!(),
+            }
+            assert_eq!(print(&message), printed);
+            assert_eq!(print(&parse(&schema, ".test.Record", printed).unwrap()), printed);
+        }
+
+        let invalid = vec![
+            r#"data: "\400""#,
+            r#"data: "\q""#,
+            r#"data: "\x""#,
+            r#"data: "\u12""#,
+            r#"data: "\ud800""#,
+            r#"data: "\U00110000""#,
+            "data: \"a\nb\"",
+            r#"data: "abc"#,
+        ];
+        for text in invalid {
+            let text = format!("id: 1\n{}", text);
+            assert_eq!(parse(&schema, ".test.Record", &text), Err(TextError::InvalidSyntax(2)), "{}", text);
+        }
+        assert_eq!(parse(&schema, ".test.Record", r#"note: "\377""#), Err(TextError::InvalidValue("note".to_string())));
+    }
+
+    /// Should accept messages and expanded `Any` values with or without the
+    /// `:` separator and report errors at the line where they occur.
+    #[test]
+    fn parses_message_forms() {
+        let schema = schema();
+        let any = "detail {\n  [type.googleapis.com/test.Item] {\n    name: \"y\"\n  }\n}\n";
+        let texts = vec![
+            r#"detail { [type.googleapis.com/test.Item] { name: "y" } }"#,
+            r#"detail { [type.googleapis.com/test.Item]: { name: "y" } }"#,
+            r#"detail: { [type.googleapis.com/test.Item] < name: "y" > }"#,
+        ];
+        for text in texts {
+            assert_eq!(print(&parse(&schema, ".test.Record", text).unwrap()), any);
+        }
+        let text = r#"detail { [example.com/x/test.Item] { } }"#;
+        assert_eq!(print(&parse(&schema, ".test.Record", text).unwrap()), "detail {\n  [example.com/x/test.Item] {\n  }\n}\n");
+
+        let item = "item {\n  name: \"x\"\n}\n";
+        for text in [r#"item { name: "x" }"#, r#"item: { name: "x" }"#, r#"item < name: "x" >"#] {
+            assert_eq!(print(&parse(&schema, ".test.Record", text).unwrap()), item);
+        }
+
+        let invalid = vec![
+            ("detail {\n  [type.googleapis.com/test.Missing] {}\n}", TextError::UnknownMessage("type.googleapis.com/test.Missing".to_string())),
+            ("detail {\n  [type.googleapis.com/test.Item {}\n}", TextError::InvalidSyntax(2)),
+            ("id: 1\nitem { name: \"x\" >", TextError::InvalidSyntax(2)),
+            ("id: 1\n\nid 1", TextError::InvalidSyntax(3)),
+            ("id: 1\nitems {\n  name: \"a\"\n", TextError::InvalidSyntax(3)),
+            ("\n\nid: 1 $", TextError::InvalidSyntax(3)),
+            ("id: 1\n\n  }", TextError::InvalidSyntax(3)),
+            ("id: 1\nitems {\n  counts: x\n}\n", TextError::InvalidValue("counts".to_string())),
+        ];
+        for (text, err) in invalid {
+            assert_eq!(parse(&schema, ".test.Record", text), Err(err), "{}", text);
+        }
+    }
+
+    /// Should print encoded messages without a schema.
+    #[test]
+    fn prints_raw_messages() {
+        let bytes = [
+            0x08, 0x96, 0x01, // 1: 150
+            0x15, 0x01, 0x00, 0x00, 0x00, // 2: fixed32
+            0x1a, 0x03, 0x08, 0x01, 0x10, // 3: invalid message
+            0x22, 0x02, 0x08, 0x01, // 4: message
+            0x2b, 0x08, 0x02, 0x2c, // 5: group
+        ];
+        assert_eq!(from_wire_raw(&bytes).unwrap(), concat!(
+            "1: 150\n",
+            "2: 0x00000001\n",
+            "3: \"\\010\\001\\020\"\n",
+            "4 {\n",
+            "  1: 1\n",
+            "}\n",
+            "5 {\n",
+            "  1: 2\n",
+            "}\n",
+        ));
+        assert!(from_wire_raw(&[0x08]).is_err());
+    }
+
+    /// Should refuse messages which are nested too deep instead of exhausting
+    /// the stack.
+    #[test]
+    fn limits_nesting_depth() {
+        let mut schema = schema();
+        schema.add("node.proto", "syntax = \"proto3\";\nmessage Node { Node child = 1; }").unwrap();
+        let nested = |levels: usize| format!("{}{}", "child {\n".repeat(levels), "}\n".repeat(levels));
+        assert!(parse(&schema, ".Node", &nested(MESSAGE_DEPTH_MAX - 1)).is_ok());
+        assert_eq!(parse(&schema, ".Node", &nested(MESSAGE_DEPTH_MAX)), Err(TextError::DepthOverflow));
+        assert_eq!(parse(&schema, ".Node", &nested(200_000)), Err(TextError::DepthOverflow));
+
+        let mut bytes = Vec::new();
+        for _ in 0..2_000 {
+            let mut any = vec![0x0a, 39];
+            any.extend_from_slice(b"type.googleapis.com/google.protobuf.Any");
+            any.push(0x12);
+            let mut len = bytes.len();
+            while len >= 0x80 {
+                any.push(len as u8 | 0x80);
+                len >>= 7;
+            }
+            any.push(len as u8);
+            any.extend(bytes);
+            bytes = any;
+        }
+        let text = from_wire(&schema, ".google.protobuf.Any", &bytes).unwrap(); // packed messages
+        assert_eq!(text.matches("[type.googleapis.com/google.protobuf.Any]").count(), MESSAGE_DEPTH_MAX - 1);
+    }
+
+    /// Should print messages nested too deep as bytes instead of exhausting
+    /// the stack.
+    #[test]
+    fn limits_raw_nesting_depth() {
+        let mut bytes = Vec::new();
+        for _ in 0..20_000 {
+            let mut outer = vec![0x0a];
+            let mut len = bytes.len();
+            while len >= 0x80 {
+                outer.push(len as u8 | 0x80);
+                len >>= 7;
+            }
+            outer.push(len as u8);
+            outer.extend(bytes);
+            bytes = outer;
+        }
+        let text = from_wire_raw(&bytes).unwrap();
+        assert_eq!(text.matches(" {\n").count(), DUMP_DEPTH_MAX - 1);
+        assert_eq!(text.matches(": \"").count(), 1);
+    }
+}
+
@@ -0,0 +1,480 @@
+use std::collections::BTreeMap;
+use std::convert::TryFrom;
+use crate::dynamic::{default_value, DynamicMessage, MapKey, Value, MESSAGE_DEPTH_MAX};
+use crate::schema::{FieldType, Label, MessageDescriptor, Schema};
+use super::TextError;
+
+/// Represents a token of the text format.
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    /// A field name, an enum value name or a literal such as `true` or `inf`.
+    Ident(String),
+
+    /// The text of an unsigned number.
+    Number(String),
+
+    /// The unescaped bytes of a quoted string.
+    String(Vec<u8>),
+
+    /// A punctuation character.
+    Symbol(char),
+}
+
+/// Splits the text format `data` into `(token, line)` tuples. Comments
+/// starting with `#` are skipped.
+fn tokenize(data: &str) -> Result<Vec<(Token, usize)>, TextError> {
+    let bytes = data.as_bytes();
+    let mut tokens = Vec::new();
+    let mut pos = 0;
+    let mut line = 1;
+    while let Some(&b) = bytes.get(pos) {
+        match b {
+            b'\n' => {
+                line += 1;
+                pos += 1;
+            },
+            b' ' | b'\t' | b'\r' => pos += 1,
+            b'#' => while pos < bytes.len() && bytes[pos] != b'\n' {
+                pos += 1;
+            },
+            b'a'..=b'z' | b'A'..=b'Z' | b'_' => {
+                let start = pos;
+                while pos < bytes.len() && (bytes[pos].is_ascii_alphanumeric() || bytes[pos] == b'_') {
+                    pos += 1;
+                }
+                tokens.push((Token::Ident(data[start..pos].to_string()), line));
+            },
+            b'0'..=b'9' | b'.' if b != b'.' || bytes.get(pos + 1).is_some_and(|b| b.is_ascii_digit()) => {
+                let start = pos;
+                while let Some(&b) = bytes.get(pos) {
+                    let exponent = (b == b'-' || b == b'+') && matches!(bytes[pos - 1], b'e' | b'E') && !data[start..pos].starts_with("0x");
+                    if b.is_ascii_alphanumeric() || b == b'.' || exponent {
+                        pos += 1;
+                    } else {
+                        break;
+                    }
+                }
+                tokens.push((Token::Number(data[start..pos].to_string()), line));
+            },
+            b'"' | b'\'' => {
+                let (text, size) = read_string(&bytes[pos..], line)?;
+                tokens.push((Token::String(text), line));
+                pos += size;
+            },
+            b'{' | b'}' | b'<' | b'>' | b'[' | b']' | b':' | b',' | b';' | b'/' | b'.' | b'-' => {
+                tokens.push((Token::Symbol(b as char), line));
+                pos += 1;
+            },
+            _ => return Err(TextError::InvalidSyntax(line)),
+        }
+    }
+    Ok(tokens)
+}
+
+/// Reads a quoted string from the beginning of `bytes` and returns the
+/// unescaped bytes and the number of bytes read.
+fn read_string(bytes: &[u8], line: usize) -> Result<(Vec<u8>, usize), TextError> {
+    let quote = bytes[0];
+    let mut text = Vec::new();
+    let mut pos = 1;
+    let digits = |pos: usize, max: usize, radix: u32| -> usize {
+        bytes[pos..].iter().take(max).take_while(|b| (**b as char).is_digit(radix)).count()
+    };
+    let number = |pos: usize, len: usize, radix: u32| -> Option<u32> {
+        std::str::from_utf8(&bytes[pos..pos + len]).ok().and_then(|d| u32::from_str_radix(d, radix).ok())
+    };
+    loop {
+        match bytes.get(pos) {
+            Some(b) if *b == quote => return Ok((text, pos + 1)),
+            Some(b'\\') => {
+                pos += 1;
+                let b = *bytes.get(pos).ok_or(TextError::InvalidSyntax(line))?;
+                pos += 1;
+                match b {
+                    b'n' => text.push(b'\n'),
+                    b'r' => text.push(b'\r'),
+                    b't' => text.push(b'\t'),
+                    b'a' => text.push(0x07),
+                    b'b' => text.push(0x08),
+                    b'f' => text.push(0x0c),
+                    b'v' => text.push(0x0b),
+                    b'\\' | b'\'' | b'"' | b'?' => text.push(b),
+                    b'0'..=b'7' => {
+                        let len = 1 + digits(pos, 2, 8);
+                        let value = number(pos - 1, len, 8).filter(|v| *v <= 0xff).ok_or(TextError::InvalidSyntax(line))?;
+                        text.push(value as u8);
+                        pos += len - 1;
+                    },
+                    b'x' | b'X' => {
+                        let len = digits(pos, 2, 16);
+                        let value = number(pos, len, 16).ok_or(TextError::InvalidSyntax(line))?;
+                        text.push(value as u8);
+                        pos += len;
+                    },
+                    b'u' | b'U' => {
+                        let len = if b == b'u' { 4 } else { 8 };
+                        let c = if digits(pos, len, 16) == len { number(pos, len, 16) } else { None }
+                            .and_then(std::char::from_u32)
+                            .ok_or(TextError::InvalidSyntax(line))?;
+                        text.extend_from_slice(c.encode_utf8(&mut [0; 4]).as_bytes());
+                        pos += len;
+                    },
+                    _ => return Err(TextError::InvalidSyntax(line)),
+                }
+            },
+            Some(b'\n') | None => return Err(TextError::InvalidSyntax(line)),
+            Some(b) => {
+                text.push(*b);
+                pos += 1;
+            },
+        }
+    }
+}
+
+/// Builds dynamic messages from the text format.
+pub(crate) struct Parser<'a> {
+    schema: &'a Schema,
+    tokens: Vec<(Token, usize)>,
+    pos: usize,
+
+    /// The number of messages which are currently being built.
+    depth: usize,
+}
+
+impl<'a> Parser<'a> {
+    /// Returns a new parser of the text format `data` for messages of the
+    /// `schema`.
+    pub(crate) fn new(schema: &'a Schema, data: &str) -> Result<Self, TextError> {
+        Ok(Self { schema, tokens: tokenize(data)?, pos: 0, depth: 0 })
+    }
+
+    /// Returns the message described by the `descriptor` which is built from
+    /// the whole text.
+    pub(crate) fn parse(&mut self, descriptor: &'a MessageDescriptor) -> Result<DynamicMessage<'a>, TextError> {
+        self.parse_message(descriptor, None)
+    }
+
+    /// Returns the message described by the `descriptor` which is built from
+    /// the fields up to the `close` symbol or the end of the text. Messages
+    /// can be nested at most `MESSAGE_DEPTH_MAX` levels deep so that they can
+    /// be decoded again.
+    fn parse_message(&mut self, descriptor: &'a MessageDescriptor, close: Option<char>) -> Result<DynamicMessage<'a>, TextError> {
+        if self.depth >= MESSAGE_DEPTH_MAX {
+            return Err(TextError::DepthOverflow);
+        }
+        self.depth += 1;
+        let message = self.parse_nested(descriptor, close);
+        self.depth -= 1;
+        message
+    }
+
+    /// Builds the message described by the `descriptor` from the fields up to
+    /// the `close` symbol or the end of the text.
+    fn parse_nested(&mut self, descriptor: &'a MessageDescriptor, close: Option<char>) -> Result<DynamicMessage<'a>, TextError> {
+        let mut message = DynamicMessage::with_descriptor(self.schema, descriptor);
+        let mut seen = Vec::new();
+        loop {
+            match (self.peek(), close) {
+                (None, None) => break,
+                (Some(Token::Symbol(c)), Some(close)) if *c == close => {
+                    self.pos += 1;
+                    break;
+                },
+                (None, Some(_)) => return Err(self.error()),
+                (Some(Token::Symbol('[')), _) if descriptor.full_name == ".google.protobuf.Any" => {
+                    self.parse_any(&mut message)?;
+                },
+                _ => self.parse_field(&mut message, &mut seen)?,
+            }
+            self.skip_separator();
+        }
+        Ok(message)
+    }
+
+    /// Parses a single field and sets or appends its value in the `message`.
+    /// The `seen` list holds the numbers of singular fields which have already
+    /// been set.
+    fn parse_field(&mut self, message: &mut DynamicMessage<'a>, seen: &mut Vec<u32>) -> Result<(), TextError> {
+        let descriptor = message.descriptor();
+        let name = self.ident()?;
+        let field = descriptor.field_by_name(&name)
+            .ok_or_else(|| TextError::UnknownField(name.clone()))?;
+        let invalid = || TextError::InvalidValue(field.name.clone());
+
+        if !self.eat(':') && !matches!(field.typ, FieldType::Message(_) | FieldType::Map(..)) {
+            return Err(self.error());
+        }
+        match &field.typ {
+            FieldType::Map(key_type, val_type) => {
+                let (entries, _) = self.parse_list(|parser| parser.parse_entry(&field.name, key_type, val_type))?;
+                let mut map = match message.remove(&field.name) {
+                    Some(Value::Map(map)) => map,
+                    _ => BTreeMap::new(),
+                };
+                map.extend(entries);
+                message.set(&field.name, map).map_err(|_| invalid())?;
+            },
+            typ if field.label == Label::Repeated => {
+                let (vals, _) = self.parse_list(|parser| parser.parse_single(&field.name, typ))?;
+                let mut list = match message.remove(&field.name) {
+                    Some(Value::List(list)) => list,
+                    _ => Vec::new(),
+                };
+                list.extend(vals);
+                message.set(&field.name, list).map_err(|_| invalid())?;
+            },
+            typ => {
+                let oneof = descriptor.fields.iter()
+                    .filter(|f| f.oneof.is_some() && f.oneof == field.oneof)
+                    .any(|f| seen.contains(&f.number));
+                if seen.contains(&field.number) || oneof || self.peek() == Some(&Token::Symbol('[')) {
+                    return Err(invalid());
+                }
+                seen.push(field.number);
+                let val = self.parse_single(&field.name, typ)?;
+                message.set(&field.name, val).map_err(|_| invalid())?;
+            },
+        }
+        Ok(())
+    }
+
+    /// Parses a single value with the provided `parse` function or a list of
+    /// values in brackets. The returned flag tells whether a list was read.
+    fn parse_list<T, F>(&mut self, mut parse: F) -> Result<(Vec<T>, bool), TextError>
+    where
+        F: FnMut(&mut Self) -> Result<T, TextError>,
+    {
+        if !self.eat('[') {
+            return Ok((vec![parse(self)?], false));
+        }
+        let mut vals = Vec::new();
+        while !self.eat(']') {
+            if !vals.is_empty() && !self.eat(',') {
+                return Err(self.error());
+            }
+            vals.push(parse(self)?);
+        }
+        Ok((vals, true))
+    }
+
+    /// Parses a map entry of the field with the provided `name` holding the
+    /// `key` and the `value` fields. Missing fields take the default value.
+    fn parse_entry(&mut self, name: &str, key_type: &FieldType, val_type: &FieldType) -> Result<(MapKey, Value<'a>), TextError> {
+        let invalid = || TextError::InvalidValue(name.to_string());
+        let close = self.open()?;
+        let (mut key, mut val) = (None, None);
+        while !self.eat(close) {
+            let field = self.ident()?;
+            let typ = match field.as_str() {
+                "key" => key_type,
+                "value" => val_type,
+                _ => return Err(TextError::UnknownField(field)),
+            };
+            if !self.eat(':') && !matches!(typ, FieldType::Message(_)) {
+                return Err(self.error());
+            }
+            let value = self.parse_single(name, typ)?;
+            if field == "key" {
+                key = Some(value);
+            } else {
+                val = Some(value);
+            }
+            self.skip_separator();
+        }
+        let key = match key {
+            Some(key) => key,
+            None => default_value(self.schema, key_type).ok_or_else(invalid)?,
+        };
+        let val = match val {
+            Some(val) => val,
+            None => default_value(self.schema, val_type).ok_or_else(invalid)?,
+        };
+        Ok((MapKey::from_value(key).ok_or_else(invalid)?, val))
+    }
+
+    /// Parses a single value of the type `typ` of the field with the provided
+    /// `name`.
+    fn parse_single(&mut self, name: &str, typ: &FieldType) -> Result<Value<'a>, TextError> {
+        let invalid = || TextError::InvalidValue(name.to_string());
+        let value = match typ {
+            FieldType::Bool => match self.next() {
+                Some(Token::Ident(v)) if v == "true" || v == "True" || v == "t" => Value::Bool(true),
+                Some(Token::Ident(v)) if v == "false" || v == "False" || v == "f" => Value::Bool(false),
+                Some(Token::Number(v)) if v == "1" => Value::Bool(true),
+                Some(Token::Number(v)) if v == "0" => Value::Bool(false),
+                _ => return Err(invalid()),
+            },
+            FieldType::Int32 | FieldType::SInt32 | FieldType::SFixed32 => {
+                Value::Int32(self.integer().and_then(|v| i32::try_from(v).ok()).ok_or_else(invalid)?)
+            },
+            FieldType::Int64 | FieldType::SInt64 | FieldType::SFixed64 => {
+                Value::Int64(self.integer().and_then(|v| i64::try_from(v).ok()).ok_or_else(invalid)?)
+            },
+            FieldType::UInt32 | FieldType::Fixed32 => {
+                Value::UInt32(self.integer().and_then(|v| u32::try_from(v).ok()).ok_or_else(invalid)?)
+            },
+            FieldType::UInt64 | FieldType::Fixed64 => {
+                Value::UInt64(self.integer().and_then(|v| u64::try_from(v).ok()).ok_or_else(invalid)?)
+            },
+            FieldType::Float => Value::Float(self.float().ok_or_else(invalid)? as f32),
+            FieldType::Double => Value::Double(self.float().ok_or_else(invalid)?),
+            FieldType::String => Value::String(String::from_utf8(self.string().ok_or_else(invalid)?).map_err(|_| invalid())?),
+            FieldType::Bytes => Value::Bytes(self.string().ok_or_else(invalid)?),
+            FieldType::Enum(full_name) => match self.peek() {
+                Some(Token::Ident(v)) => {
+                    let value = self.schema.enumeration(full_name).and_then(|e| e.value_by_name(v));
+                    let number = value.ok_or_else(invalid)?.number;
+                    self.pos += 1;
+                    Value::Enum(number)
+                },
+                _ => Value::Enum(self.integer().and_then(|v| i32::try_from(v).ok()).ok_or_else(invalid)?),
+            },
+            FieldType::Message(full_name) => {
+                let descriptor = self.schema.message(full_name)
+                    .ok_or_else(|| TextError::UnknownMessage(full_name.clone()))?;
+                let close = self.open()?;
+                Value::Message(self.parse_message(descriptor, Some(close))?)
+            },
+            _ => return Err(invalid()),
+        };
+        Ok(value)
+    }
+
+    /// Parses the expanded form of an `Any` holding the type URL in brackets
+    /// and the fields of the packed message.
+    fn parse_any(&mut self, message: &mut DynamicMessage<'a>) -> Result<(), TextError> {
+        self.eat('[');
+        let mut type_url = String::new();
+        loop {
+            match self.next() {
+                Some(Token::Symbol(']')) => break,
+                Some(Token::Symbol(c)) if c == '.' || c == '/' => type_url.push(c),
+                Some(Token::Ident(v)) | Some(Token::Number(v)) => type_url.push_str(&v),
+                _ => return Err(self.error()),
+            }
+        }
+        let name = type_url.rsplit('/').next().unwrap_or(&type_url);
+        let descriptor = self.schema.message(&format!(".{}", name))
+            .ok_or_else(|| TextError::UnknownMessage(type_url.clone()))?;
+        self.eat(':');
+        let close = self.open()?;
+        let packed = self.parse_message(descriptor, Some(close))?;
+
+        let mut bytes = Vec::new();
+        packed.encode(&mut bytes)?;
+        let full_name = message.descriptor().full_name.clone();
+        let invalid = |_| TextError::InvalidValue(full_name.clone());
+        message.set("type_url", type_url.clone()).map_err(invalid)?;
+        message.set("value", bytes).map_err(invalid)?;
+        Ok(())
+    }
+
+    /// Reads an integer with an optional sign in the decimal, the hexadecimal
+    /// (`0x`) or the octal (leading `0`) notation.
+    fn integer(&mut self) -> Option<i128> {
+        let negative = self.eat('-');
+        let text = match self.next()? {
+            Token::Number(text) => text,
+            _ => return None,
+        };
+        let value = if let Some(hex) = text.strip_prefix("0x").or_else(|| text.strip_prefix("0X")) {
+            i128::from_str_radix(hex, 16).ok()?
+        } else if text.len() > 1 && text.starts_with('0') {
+            i128::from_str_radix(&text[1..], 8).ok()?
+        } else {
+            text.parse().ok()?
+        };
+        Some(if negative { -value } else { value })
+    }
+
+    /// Reads a floating-point number with an optional sign. The special values
+    /// `inf`, `infinity` and `nan` are accepted in any case.
+    fn float(&mut self) -> Option<f64> {
+        let negative = self.eat('-');
+        let value = match self.next()? {
+            Token::Number(text) => {
+                let text = text.strip_suffix(|c| c == 'f' || c == 'F').unwrap_or(&text);
+                text.parse::<f64>().ok().filter(|v| v.is_finite())?
+            },
+            Token::Ident(text) => match text.to_ascii_lowercase().as_str() {
+                "inf" | "infinity" => f64::INFINITY,
+                "nan" => f64::NAN,
+                _ => return None,
+            },
+            _ => return None,
+        };
+        Some(if negative { -value } else { value })
+    }
+
+    /// Reads one or more adjacent quoted strings and returns their bytes.
+    fn string(&mut self) -> Option<Vec<u8>> {
+        let mut bytes = match self.next()? {
+            Token::String(bytes) => bytes,
+            _ => return None,
+        };
+        while let Some(Token::String(_)) = self.peek() {
+            if let Some(Token::String(more)) = self.next() {
+                bytes.extend(more);
+            }
+        }
+        Some(bytes)
+    }
+
+    /// Reads a field name.
+    fn ident(&mut self) -> Result<String, TextError> {
+        match self.next() {
+            Some(Token::Ident(name)) => Ok(name),
+            _ => Err(self.error()),
+        }
+    }
+
+    /// Reads the opening brace of an embedded message and returns the matching
+    /// closing brace.
+    fn open(&mut self) -> Result<char, TextError> {
+        if self.eat('{') {
+            Ok('}')
+        } else if self.eat('<') {
+            Ok('>')
+        } else {
+            Err(self.error())
+        }
+    }
+
+    /// Skips the optional `,` or `;` after a field.
+    fn skip_separator(&mut self) {
+        if !self.eat(',') {
+            self.eat(';');
+        }
+    }
+
+    /// Consumes the next token if it is the provided `symbol`.
+    fn eat(&mut self, symbol: char) -> bool {
+        if self.peek() == Some(&Token::Symbol(symbol)) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos).map(|(token, _)| token)
+    }
+
+    fn next(&mut self) -> Option<Token> {
+        let token = self.peek().cloned();
+        if token.is_some() {
+            self.pos += 1;
+        }
+        token
+    }
+
+    /// Returns a syntax error at the line of the current token.
+    fn error(&self) -> TextError {
+        let line = self.tokens.get(self.pos)
+            .or_else(|| self.tokens.last())
+            .map(|(_, line)| *line)
+            .unwrap_or(1);
+        TextError::InvalidSyntax(line)
+    }
+}
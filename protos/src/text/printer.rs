@@ -0,0 +1,163 @@
+use crate::dynamic::{DynamicMessage, Value, MESSAGE_DEPTH_MAX};
+use crate::schema::{FieldDescriptor, FieldType, Label, Schema};
+use super::raw::print_raw_field;
+
+/// Writes the fields of the `message` in the text format into `out`, one field
+/// per line indented by the `depth` of the message.
+pub(crate) fn print_message(message: &DynamicMessage, depth: usize, out: &mut String) {
+    if message.descriptor().full_name == ".google.protobuf.Any" && print_any(message, depth, out) {
+        return;
+    }
+    for (field, value) in message.fields() {
+        match value {
+            Value::List(vals) => for val in vals {
+                print_field(message.schema(), &field.name, &field.typ, val, depth, out);
+            },
+            Value::Map(map) => if let FieldType::Map(key_type, val_type) = &field.typ {
+                for (key, val) in map {
+                    print_indent(depth, out);
+                    out.push_str(&field.name);
+                    out.push_str(" {\n");
+                    print_field(message.schema(), "key", key_type, &key.to_value(), depth + 1, out);
+                    print_field(message.schema(), "value", val_type, val, depth + 1, out);
+                    print_indent(depth, out);
+                    out.push_str("}\n");
+                }
+            },
+            value if is_omitted(field, value) => {},
+            value => print_field(message.schema(), &field.name, &field.typ, value, depth, out),
+        }
+    }
+    for (tag, typ, bytes) in message.unknown_fields() {
+        print_raw_field(*tag, *typ, bytes, depth, out);
+    }
+}
+
+/// Writes an `Any` as its expanded form holding the type URL in brackets and
+/// the fields of the packed message. Returns `false` when the packed message
+/// can not be resolved or decoded or when it would be nested deeper than
+/// `MESSAGE_DEPTH_MAX` levels.
+fn print_any(message: &DynamicMessage, depth: usize, out: &mut String) -> bool {
+    if depth + 1 >= MESSAGE_DEPTH_MAX {
+        return false;
+    }
+    let type_url = match message.get("type_url").and_then(|v| v.as_str()) {
+        Some(type_url) => type_url,
+        None => return false,
+    };
+    let bytes = message.get("value").and_then(|v| v.as_bytes()).unwrap_or(&[]);
+    let name = type_url.rsplit('/').next().unwrap_or(type_url);
+    let mut packed = match DynamicMessage::new(message.schema(), &format!(".{}", name)) {
+        Ok(packed) => packed,
+        Err(_) => return false,
+    };
+    if packed.merge(bytes).is_err() {
+        return false;
+    }
+    print_indent(depth, out);
+    out.push('[');
+    out.push_str(type_url);
+    out.push_str("] {\n");
+    print_message(&packed, depth + 1, out);
+    print_indent(depth, out);
+    out.push_str("}\n");
+    true
+}
+
+/// Writes a single field with the provided `name` and `value` on its own line.
+/// Embedded messages span multiple lines.
+fn print_field(schema: &Schema, name: &str, typ: &FieldType, value: &Value, depth: usize, out: &mut String) {
+    print_indent(depth, out);
+    out.push_str(name);
+    match value {
+        Value::Message(message) => {
+            out.push_str(" {\n");
+            print_message(message, depth + 1, out);
+            print_indent(depth, out);
+            out.push('}');
+        },
+        Value::Bool(v) => out.push_str(if *v { ": true" } else { ": false" }),
+        Value::Int32(v) => out.push_str(&format!(": {}", v)),
+        Value::Int64(v) => out.push_str(&format!(": {}", v)),
+        Value::UInt32(v) => out.push_str(&format!(": {}", v)),
+        Value::UInt64(v) => out.push_str(&format!(": {}", v)),
+        Value::Float(v) => out.push_str(&format!(": {}", format_float(*v as f64, v.to_string(), format!("{:e}", v)))),
+        Value::Double(v) => out.push_str(&format!(": {}", format_float(*v, v.to_string(), format!("{:e}", v)))),
+        Value::String(v) => out.push_str(&format!(": \"{}\"", escape_string(v))),
+        Value::Bytes(v) => out.push_str(&format!(": \"{}\"", escape_bytes(v))),
+        Value::Enum(v) => match typ {
+            FieldType::Enum(name) => match schema.enumeration(name).and_then(|e| e.value(*v)) {
+                Some(value) => out.push_str(&format!(": {}", value.name)),
+                None => out.push_str(&format!(": {}", v)),
+            },
+            _ => out.push_str(&format!(": {}", v)),
+        },
+        Value::List(_) | Value::Map(_) => {}, // only single values are printed
+    }
+    out.push('\n');
+}
+
+/// Tells whether the set `value` of a `field` is left out because it is not
+/// distinguishable from an unset field.
+fn is_omitted(field: &FieldDescriptor, value: &Value) -> bool {
+    field.label == Label::Singular && field.oneof.is_none() && value.is_default()
+}
+
+/// Writes the indentation of the provided `depth`.
+pub(crate) fn print_indent(depth: usize, out: &mut String) {
+    for _ in 0..depth {
+        out.push_str("  ");
+    }
+}
+
+/// Returns a floating-point number from its shortest decimal and exponential
+/// forms. Very large or small numbers use the exponential notation.
+pub(crate) fn format_float(value: f64, decimal: String, exponential: String) -> String {
+    if value.is_nan() {
+        "nan".to_string()
+    } else if value.is_infinite() {
+        if value > 0.0 { "inf" } else { "-inf" }.to_string()
+    } else if value != 0.0 && (value.abs() >= 1e21 || value.abs() < 1e-7) {
+        exponential
+    } else {
+        decimal
+    }
+}
+
+/// Returns the escaped content of a quoted `string` value. Characters outside
+/// of ASCII are kept as they are.
+pub(crate) fn escape_string(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        if c.is_ascii() {
+            escape_byte(c as u8, &mut escaped);
+        } else {
+            escaped.push(c);
+        }
+    }
+    escaped
+}
+
+/// Returns the escaped content of a quoted `bytes` value. Bytes which are not
+/// printable ASCII characters are written as octal escapes.
+pub(crate) fn escape_bytes(bytes: &[u8]) -> String {
+    let mut escaped = String::with_capacity(bytes.len());
+    for b in bytes {
+        escape_byte(*b, &mut escaped);
+    }
+    escaped
+}
+
+/// Writes the provided byte into `out` escaping it when needed.
+fn escape_byte(b: u8, out: &mut String) {
+    match b {
+        b'\n' => out.push_str("\\n"),
+        b'\r' => out.push_str("\\r"),
+        b'\t' => out.push_str("\\t"),
+        b'"' => out.push_str("\\\""),
+        b'\'' => out.push_str("\\'"),
+        b'\\' => out.push_str("\\\\"),
+        0x20..=0x7e => out.push(b as char),
+        b => out.push_str(&format!("\\{:03o}", b)),
+    }
+}
@@ -0,0 +1,60 @@
+use crate::{FieldIter, Typ};
+use crate::decoder::primitives::*;
+use crate::dump::DUMP_DEPTH_MAX;
+use super::printer::{escape_bytes, print_indent};
+
+/// Writes a field without a schema in the text format into `out`, the same way
+/// as `protoc --decode_raw` does it. Fields are named by their tag number.
+/// A length-delimited field is written as an embedded message when its bytes
+/// hold valid fields and as a quoted string otherwise. Messages and groups
+/// nested deeper than `DUMP_DEPTH_MAX` levels are written as quoted strings.
+pub(crate) fn print_raw_field(tag: u32, typ: Typ, bytes: &[u8], depth: usize, out: &mut String) {
+    print_indent(depth, out);
+    match typ {
+        Typ::Varint => {
+            let mut val = 0;
+            match decode_uint64(bytes, &mut val) {
+                Ok(_) => out.push_str(&format!("{}: {}\n", tag, val)),
+                Err(_) => out.push_str(&format!("{}: \"{}\"\n", tag, escape_bytes(bytes))),
+            }
+        },
+        Typ::Bit32 => {
+            let mut val = 0;
+            match decode_fixed32(bytes, &mut val) {
+                Ok(_) => out.push_str(&format!("{}: 0x{:08x}\n", tag, val)),
+                Err(_) => out.push_str(&format!("{}: \"{}\"\n", tag, escape_bytes(bytes))),
+            }
+        },
+        Typ::Bit64 => {
+            let mut val = 0;
+            match decode_fixed64(bytes, &mut val) {
+                Ok(_) => out.push_str(&format!("{}: 0x{:016x}\n", tag, val)),
+                Err(_) => out.push_str(&format!("{}: \"{}\"\n", tag, escape_bytes(bytes))),
+            }
+        },
+        Typ::StartGroup | Typ::LengthDelimited if depth + 1 >= DUMP_DEPTH_MAX => {
+            out.push_str(&format!("{}: \"{}\"\n", tag, escape_bytes(bytes)));
+        },
+        Typ::StartGroup => print_raw_message(tag, bytes, depth, out),
+        Typ::LengthDelimited if is_message(bytes) => print_raw_message(tag, bytes, depth, out),
+        Typ::LengthDelimited | Typ::EndGroup => {
+            out.push_str(&format!("{}: \"{}\"\n", tag, escape_bytes(bytes)));
+        },
+    }
+}
+
+/// Writes the encoded fields of an embedded message or a group in braces.
+fn print_raw_message(tag: u32, bytes: &[u8], depth: usize, out: &mut String) {
+    out.push_str(&format!("{} {{\n", tag));
+    for (tag, typ, bytes) in FieldIter::new(bytes).flatten() {
+        print_raw_field(tag, typ, bytes, depth + 1, out);
+    }
+    print_indent(depth, out);
+    out.push_str("}\n");
+}
+
+/// Tells whether the provided `bytes` hold a non-empty sequence of valid
+/// fields.
+pub(crate) fn is_message(bytes: &[u8]) -> bool {
+    !bytes.is_empty() && FieldIter::new(bytes).all(|field| field.is_ok())
+}
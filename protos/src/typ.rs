@@ -1,4 +1,5 @@
 use core::convert::TryFrom;
+use crate::DecoderError;
 
 /// Provides available wire types supported by proto3.
 #[derive(Clone, Copy, Debug, PartialEq)]
@@ -15,24 +16,33 @@ pub enum Typ {
     /// `string`, `bytes`, `embedded messages` and `packed repeated fields`.
     LengthDelimited = 2,
 
+    /// Represents the deprecated wire type `3` which marks the start of a
+    /// `group` field. Groups come from `proto2` and are not supported by
+    /// `proto3` schemas, but they can still be found on the wire.
+    StartGroup = 3,
+
+    /// Represents the deprecated wire type `4` which marks the end of a `group`
+    /// field. The key holds the same tag number as the key that started the
+    /// group.
+    EndGroup = 4,
+
     /// Represents the wire type `5` which allows for encoding of data formats
     /// `fixed32`, `sfixed32` and `float`.
     Bit32 = 5,
-
-    /// Represents un unknown wire type.
-    Unknown = -1,
 }
 
 impl TryFrom<u64> for Typ {
-    type Error = std::io::Error;
+    type Error = DecoderError;
 
     fn try_from(value: u64) -> Result<Self, Self::Error> {
         match value {
             0 => Ok(Typ::Varint),
             1 => Ok(Typ::Bit64),
             2 => Ok(Typ::LengthDelimited),
+            3 => Ok(Typ::StartGroup),
+            4 => Ok(Typ::EndGroup),
             5 => Ok(Typ::Bit32),
-            _ => Ok(Typ::Unknown),
+            _ => Err(DecoderError::InvalidWireType),
         }
     }
 }
@@ -0,0 +1,453 @@
+// This file is generated by httlib-protos-build. Do not edit.
+
+pub mod google {
+    pub mod protobuf {
+        #[derive(Debug, Clone, PartialEq, Default)]
+        pub struct Any {
+            pub type_url: ::std::string::String,
+            pub value: ::std::vec::Vec<u8>,
+        }
+
+        impl ::httlib_protos::Message for Any {
+            fn encode_fields(&self, encoder: &mut ::httlib_protos::MessageEncoder) -> ::std::result::Result<(), ::httlib_protos::EncoderError> {
+                ::httlib_protos::message::field::encode::<::httlib_protos::message::kind::String>(1, &self.type_url, encoder)?;
+                ::httlib_protos::message::field::encode::<::httlib_protos::message::kind::Bytes>(2, &self.value, encoder)?;
+                ::std::result::Result::Ok(())
+            }
+
+            fn merge_field(&mut self, tag: u32, typ: ::httlib_protos::Typ, bytes: &[u8]) -> ::std::result::Result<(), ::httlib_protos::DecoderError> {
+                match tag {
+                    1 => ::httlib_protos::message::field::merge::<::httlib_protos::message::kind::String>(&mut self.type_url, typ, bytes),
+                    2 => ::httlib_protos::message::field::merge::<::httlib_protos::message::kind::Bytes>(&mut self.value, typ, bytes),
+                    _ => ::std::result::Result::Ok(()),
+                }
+            }
+        }
+
+        #[derive(Debug, Clone, PartialEq, Default)]
+        pub struct Duration {
+            pub seconds: i64,
+            pub nanos: i32,
+        }
+
+        impl ::httlib_protos::Message for Duration {
+            fn encode_fields(&self, encoder: &mut ::httlib_protos::MessageEncoder) -> ::std::result::Result<(), ::httlib_protos::EncoderError> {
+                ::httlib_protos::message::field::encode::<::httlib_protos::message::kind::Int64>(1, &self.seconds, encoder)?;
+                ::httlib_protos::message::field::encode::<::httlib_protos::message::kind::Int32>(2, &self.nanos, encoder)?;
+                ::std::result::Result::Ok(())
+            }
+
+            fn merge_field(&mut self, tag: u32, typ: ::httlib_protos::Typ, bytes: &[u8]) -> ::std::result::Result<(), ::httlib_protos::DecoderError> {
+                match tag {
+                    1 => ::httlib_protos::message::field::merge::<::httlib_protos::message::kind::Int64>(&mut self.seconds, typ, bytes),
+                    2 => ::httlib_protos::message::field::merge::<::httlib_protos::message::kind::Int32>(&mut self.nanos, typ, bytes),
+                    _ => ::std::result::Result::Ok(()),
+                }
+            }
+        }
+
+        #[derive(Debug, Clone, PartialEq, Default)]
+        pub struct Empty {}
+
+        impl ::httlib_protos::Message for Empty {
+            fn encode_fields(&self, _encoder: &mut ::httlib_protos::MessageEncoder) -> ::std::result::Result<(), ::httlib_protos::EncoderError> {
+                ::std::result::Result::Ok(())
+            }
+
+            fn merge_field(&mut self, _tag: u32, _typ: ::httlib_protos::Typ, _bytes: &[u8]) -> ::std::result::Result<(), ::httlib_protos::DecoderError> {
+                ::std::result::Result::Ok(())
+            }
+        }
+
+        #[derive(Debug, Clone, PartialEq, Default)]
+        pub struct FieldMask {
+            pub paths: ::std::vec::Vec<::std::string::String>,
+        }
+
+        impl ::httlib_protos::Message for FieldMask {
+            fn encode_fields(&self, encoder: &mut ::httlib_protos::MessageEncoder) -> ::std::result::Result<(), ::httlib_protos::EncoderError> {
+                ::httlib_protos::message::field::encode_repeated::<::httlib_protos::message::kind::String>(1, &self.paths, encoder)?;
+                ::std::result::Result::Ok(())
+            }
+
+            fn merge_field(&mut self, tag: u32, typ: ::httlib_protos::Typ, bytes: &[u8]) -> ::std::result::Result<(), ::httlib_protos::DecoderError> {
+                match tag {
+                    1 => ::httlib_protos::message::field::merge_repeated::<::httlib_protos::message::kind::String>(&mut self.paths, typ, bytes),
+                    _ => ::std::result::Result::Ok(()),
+                }
+            }
+        }
+
+        #[derive(Debug, Clone, PartialEq, Default)]
+        pub struct Struct {
+            pub fields: ::std::collections::HashMap<::std::string::String, Value>,
+        }
+
+        impl ::httlib_protos::Message for Struct {
+            fn encode_fields(&self, encoder: &mut ::httlib_protos::MessageEncoder) -> ::std::result::Result<(), ::httlib_protos::EncoderError> {
+                ::httlib_protos::message::field::encode_map::<::httlib_protos::message::kind::String, ::httlib_protos::message::kind::Message<Value>>(1, &self.fields, encoder)?;
+                ::std::result::Result::Ok(())
+            }
+
+            fn merge_field(&mut self, tag: u32, typ: ::httlib_protos::Typ, bytes: &[u8]) -> ::std::result::Result<(), ::httlib_protos::DecoderError> {
+                match tag {
+                    1 => ::httlib_protos::message::field::merge_map::<::httlib_protos::message::kind::String, ::httlib_protos::message::kind::Message<Value>>(&mut self.fields, typ, bytes),
+                    _ => ::std::result::Result::Ok(()),
+                }
+            }
+        }
+
+        #[derive(Debug, Clone, PartialEq, Default)]
+        pub struct Value {
+            pub kind: ::std::option::Option<value::Kind>,
+        }
+
+        impl ::httlib_protos::Message for Value {
+            fn encode_fields(&self, encoder: &mut ::httlib_protos::MessageEncoder) -> ::std::result::Result<(), ::httlib_protos::EncoderError> {
+                if let ::std::option::Option::Some(oneof) = &self.kind {
+                    ::httlib_protos::Oneof::encode_fields(oneof, encoder)?;
+                }
+                ::std::result::Result::Ok(())
+            }
+
+            fn merge_field(&mut self, tag: u32, typ: ::httlib_protos::Typ, bytes: &[u8]) -> ::std::result::Result<(), ::httlib_protos::DecoderError> {
+                match tag {
+                    1..=6 => ::httlib_protos::Oneof::merge_field(&mut self.kind, tag, typ, bytes),
+                    _ => ::std::result::Result::Ok(()),
+                }
+            }
+        }
+
+        pub mod value {
+            #[derive(Debug, Clone, PartialEq)]
+            #[allow(clippy::enum_variant_names)]
+            pub enum Kind {
+                NullValue(super::NullValue),
+                NumberValue(f64),
+                StringValue(::std::string::String),
+                BoolValue(bool),
+                StructValue(super::Struct),
+                ListValue(super::ListValue),
+            }
+
+            impl ::httlib_protos::Oneof for Kind {
+                fn encode_fields(&self, encoder: &mut ::httlib_protos::MessageEncoder) -> ::std::result::Result<(), ::httlib_protos::EncoderError> {
+                    match self {
+                        Self::NullValue(value) => {
+                            <::httlib_protos::message::kind::Enumeration<super::NullValue> as ::httlib_protos::message::kind::Kind>::encode(1, value, encoder)?;
+                        },
+                        Self::NumberValue(value) => {
+                            <::httlib_protos::message::kind::Double as ::httlib_protos::message::kind::Kind>::encode(2, value, encoder)?;
+                        },
+                        Self::StringValue(value) => {
+                            <::httlib_protos::message::kind::String as ::httlib_protos::message::kind::Kind>::encode(3, value, encoder)?;
+                        },
+                        Self::BoolValue(value) => {
+                            <::httlib_protos::message::kind::Bool as ::httlib_protos::message::kind::Kind>::encode(4, value, encoder)?;
+                        },
+                        Self::StructValue(value) => {
+                            <::httlib_protos::message::kind::Message<super::Struct> as ::httlib_protos::message::kind::Kind>::encode(5, value, encoder)?;
+                        },
+                        Self::ListValue(value) => {
+                            <::httlib_protos::message::kind::Message<super::ListValue> as ::httlib_protos::message::kind::Kind>::encode(6, value, encoder)?;
+                        },
+                    }
+                    ::std::result::Result::Ok(())
+                }
+
+                fn merge_field(oneof: &mut ::std::option::Option<Self>, tag: u32, typ: ::httlib_protos::Typ, bytes: &[u8]) -> ::std::result::Result<(), ::httlib_protos::DecoderError> {
+                    match tag {
+                        1 => match oneof {
+                            ::std::option::Option::Some(Self::NullValue(value)) => <::httlib_protos::message::kind::Enumeration<super::NullValue> as ::httlib_protos::message::kind::Kind>::merge(value, typ, bytes),
+                            _ => {
+                                let mut value = ::std::default::Default::default();
+                                <::httlib_protos::message::kind::Enumeration<super::NullValue> as ::httlib_protos::message::kind::Kind>::merge(&mut value, typ, bytes)?;
+                                *oneof = ::std::option::Option::Some(Self::NullValue(value));
+                                ::std::result::Result::Ok(())
+                            },
+                        },
+                        2 => match oneof {
+                            ::std::option::Option::Some(Self::NumberValue(value)) => <::httlib_protos::message::kind::Double as ::httlib_protos::message::kind::Kind>::merge(value, typ, bytes),
+                            _ => {
+                                let mut value = ::std::default::Default::default();
+                                <::httlib_protos::message::kind::Double as ::httlib_protos::message::kind::Kind>::merge(&mut value, typ, bytes)?;
+                                *oneof = ::std::option::Option::Some(Self::NumberValue(value));
+                                ::std::result::Result::Ok(())
+                            },
+                        },
+                        3 => match oneof {
+                            ::std::option::Option::Some(Self::StringValue(value)) => <::httlib_protos::message::kind::String as ::httlib_protos::message::kind::Kind>::merge(value, typ, bytes),
+                            _ => {
+                                let mut value = ::std::default::Default::default();
+                                <::httlib_protos::message::kind::String as ::httlib_protos::message::kind::Kind>::merge(&mut value, typ, bytes)?;
+                                *oneof = ::std::option::Option::Some(Self::StringValue(value));
+                                ::std::result::Result::Ok(())
+                            },
+                        },
+                        4 => match oneof {
+                            ::std::option::Option::Some(Self::BoolValue(value)) => <::httlib_protos::message::kind::Bool as ::httlib_protos::message::kind::Kind>::merge(value, typ, bytes),
+                            _ => {
+                                let mut value = ::std::default::Default::default();
+                                <::httlib_protos::message::kind::Bool as ::httlib_protos::message::kind::Kind>::merge(&mut value, typ, bytes)?;
+                                *oneof = ::std::option::Option::Some(Self::BoolValue(value));
+                                ::std::result::Result::Ok(())
+                            },
+                        },
+                        5 => match oneof {
+                            ::std::option::Option::Some(Self::StructValue(value)) => <::httlib_protos::message::kind::Message<super::Struct> as ::httlib_protos::message::kind::Kind>::merge(value, typ, bytes),
+                            _ => {
+                                let mut value = ::std::default::Default::default();
+                                <::httlib_protos::message::kind::Message<super::Struct> as ::httlib_protos::message::kind::Kind>::merge(&mut value, typ, bytes)?;
+                                *oneof = ::std::option::Option::Some(Self::StructValue(value));
+                                ::std::result::Result::Ok(())
+                            },
+                        },
+                        6 => match oneof {
+                            ::std::option::Option::Some(Self::ListValue(value)) => <::httlib_protos::message::kind::Message<super::ListValue> as ::httlib_protos::message::kind::Kind>::merge(value, typ, bytes),
+                            _ => {
+                                let mut value = ::std::default::Default::default();
+                                <::httlib_protos::message::kind::Message<super::ListValue> as ::httlib_protos::message::kind::Kind>::merge(&mut value, typ, bytes)?;
+                                *oneof = ::std::option::Option::Some(Self::ListValue(value));
+                                ::std::result::Result::Ok(())
+                            },
+                        },
+                        _ => ::std::result::Result::Ok(()),
+                    }
+                }
+            }
+        }
+
+        #[derive(Debug, Clone, PartialEq, Default)]
+        pub struct ListValue {
+            pub values: ::std::vec::Vec<Value>,
+        }
+
+        impl ::httlib_protos::Message for ListValue {
+            fn encode_fields(&self, encoder: &mut ::httlib_protos::MessageEncoder) -> ::std::result::Result<(), ::httlib_protos::EncoderError> {
+                ::httlib_protos::message::field::encode_repeated::<::httlib_protos::message::kind::Message<Value>>(1, &self.values, encoder)?;
+                ::std::result::Result::Ok(())
+            }
+
+            fn merge_field(&mut self, tag: u32, typ: ::httlib_protos::Typ, bytes: &[u8]) -> ::std::result::Result<(), ::httlib_protos::DecoderError> {
+                match tag {
+                    1 => ::httlib_protos::message::field::merge_repeated::<::httlib_protos::message::kind::Message<Value>>(&mut self.values, typ, bytes),
+                    _ => ::std::result::Result::Ok(()),
+                }
+            }
+        }
+
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+        #[allow(clippy::enum_variant_names)]
+        #[repr(i32)]
+        pub enum NullValue {
+            #[default]
+            NullValue = 0,
+        }
+
+        impl ::httlib_protos::Enumeration for NullValue {
+            fn to_i32(&self) -> i32 {
+                *self as i32
+            }
+
+            fn from_i32(value: i32) -> ::std::option::Option<Self> {
+                match value {
+                    0 => ::std::option::Option::Some(Self::NullValue),
+                    _ => ::std::option::Option::None,
+                }
+            }
+        }
+
+        #[derive(Debug, Clone, PartialEq, Default)]
+        pub struct Timestamp {
+            pub seconds: i64,
+            pub nanos: i32,
+        }
+
+        impl ::httlib_protos::Message for Timestamp {
+            fn encode_fields(&self, encoder: &mut ::httlib_protos::MessageEncoder) -> ::std::result::Result<(), ::httlib_protos::EncoderError> {
+                ::httlib_protos::message::field::encode::<::httlib_protos::message::kind::Int64>(1, &self.seconds, encoder)?;
+                ::httlib_protos::message::field::encode::<::httlib_protos::message::kind::Int32>(2, &self.nanos, encoder)?;
+                ::std::result::Result::Ok(())
+            }
+
+            fn merge_field(&mut self, tag: u32, typ: ::httlib_protos::Typ, bytes: &[u8]) -> ::std::result::Result<(), ::httlib_protos::DecoderError> {
+                match tag {
+                    1 => ::httlib_protos::message::field::merge::<::httlib_protos::message::kind::Int64>(&mut self.seconds, typ, bytes),
+                    2 => ::httlib_protos::message::field::merge::<::httlib_protos::message::kind::Int32>(&mut self.nanos, typ, bytes),
+                    _ => ::std::result::Result::Ok(()),
+                }
+            }
+        }
+
+        #[derive(Debug, Clone, PartialEq, Default)]
+        pub struct DoubleValue {
+            pub value: f64,
+        }
+
+        impl ::httlib_protos::Message for DoubleValue {
+            fn encode_fields(&self, encoder: &mut ::httlib_protos::MessageEncoder) -> ::std::result::Result<(), ::httlib_protos::EncoderError> {
+                ::httlib_protos::message::field::encode::<::httlib_protos::message::kind::Double>(1, &self.value, encoder)?;
+                ::std::result::Result::Ok(())
+            }
+
+            fn merge_field(&mut self, tag: u32, typ: ::httlib_protos::Typ, bytes: &[u8]) -> ::std::result::Result<(), ::httlib_protos::DecoderError> {
+                match tag {
+                    1 => ::httlib_protos::message::field::merge::<::httlib_protos::message::kind::Double>(&mut self.value, typ, bytes),
+                    _ => ::std::result::Result::Ok(()),
+                }
+            }
+        }
+
+        #[derive(Debug, Clone, PartialEq, Default)]
+        pub struct FloatValue {
+            pub value: f32,
+        }
+
+        impl ::httlib_protos::Message for FloatValue {
+            fn encode_fields(&self, encoder: &mut ::httlib_protos::MessageEncoder) -> ::std::result::Result<(), ::httlib_protos::EncoderError> {
+                ::httlib_protos::message::field::encode::<::httlib_protos::message::kind::Float>(1, &self.value, encoder)?;
+                ::std::result::Result::Ok(())
+            }
+
+            fn merge_field(&mut self, tag: u32, typ: ::httlib_protos::Typ, bytes: &[u8]) -> ::std::result::Result<(), ::httlib_protos::DecoderError> {
+                match tag {
+                    1 => ::httlib_protos::message::field::merge::<::httlib_protos::message::kind::Float>(&mut self.value, typ, bytes),
+                    _ => ::std::result::Result::Ok(()),
+                }
+            }
+        }
+
+        #[derive(Debug, Clone, PartialEq, Default)]
+        pub struct Int64Value {
+            pub value: i64,
+        }
+
+        impl ::httlib_protos::Message for Int64Value {
+            fn encode_fields(&self, encoder: &mut ::httlib_protos::MessageEncoder) -> ::std::result::Result<(), ::httlib_protos::EncoderError> {
+                ::httlib_protos::message::field::encode::<::httlib_protos::message::kind::Int64>(1, &self.value, encoder)?;
+                ::std::result::Result::Ok(())
+            }
+
+            fn merge_field(&mut self, tag: u32, typ: ::httlib_protos::Typ, bytes: &[u8]) -> ::std::result::Result<(), ::httlib_protos::DecoderError> {
+                match tag {
+                    1 => ::httlib_protos::message::field::merge::<::httlib_protos::message::kind::Int64>(&mut self.value, typ, bytes),
+                    _ => ::std::result::Result::Ok(()),
+                }
+            }
+        }
+
+        #[derive(Debug, Clone, PartialEq, Default)]
+        pub struct UInt64Value {
+            pub value: u64,
+        }
+
+        impl ::httlib_protos::Message for UInt64Value {
+            fn encode_fields(&self, encoder: &mut ::httlib_protos::MessageEncoder) -> ::std::result::Result<(), ::httlib_protos::EncoderError> {
+                ::httlib_protos::message::field::encode::<::httlib_protos::message::kind::UInt64>(1, &self.value, encoder)?;
+                ::std::result::Result::Ok(())
+            }
+
+            fn merge_field(&mut self, tag: u32, typ: ::httlib_protos::Typ, bytes: &[u8]) -> ::std::result::Result<(), ::httlib_protos::DecoderError> {
+                match tag {
+                    1 => ::httlib_protos::message::field::merge::<::httlib_protos::message::kind::UInt64>(&mut self.value, typ, bytes),
+                    _ => ::std::result::Result::Ok(()),
+                }
+            }
+        }
+
+        #[derive(Debug, Clone, PartialEq, Default)]
+        pub struct Int32Value {
+            pub value: i32,
+        }
+
+        impl ::httlib_protos::Message for Int32Value {
+            fn encode_fields(&self, encoder: &mut ::httlib_protos::MessageEncoder) -> ::std::result::Result<(), ::httlib_protos::EncoderError> {
+                ::httlib_protos::message::field::encode::<::httlib_protos::message::kind::Int32>(1, &self.value, encoder)?;
+                ::std::result::Result::Ok(())
+            }
+
+            fn merge_field(&mut self, tag: u32, typ: ::httlib_protos::Typ, bytes: &[u8]) -> ::std::result::Result<(), ::httlib_protos::DecoderError> {
+                match tag {
+                    1 => ::httlib_protos::message::field::merge::<::httlib_protos::message::kind::Int32>(&mut self.value, typ, bytes),
+                    _ => ::std::result::Result::Ok(()),
+                }
+            }
+        }
+
+        #[derive(Debug, Clone, PartialEq, Default)]
+        pub struct UInt32Value {
+            pub value: u32,
+        }
+
+        impl ::httlib_protos::Message for UInt32Value {
+            fn encode_fields(&self, encoder: &mut ::httlib_protos::MessageEncoder) -> ::std::result::Result<(), ::httlib_protos::EncoderError> {
+                ::httlib_protos::message::field::encode::<::httlib_protos::message::kind::UInt32>(1, &self.value, encoder)?;
+                ::std::result::Result::Ok(())
+            }
+
+            fn merge_field(&mut self, tag: u32, typ: ::httlib_protos::Typ, bytes: &[u8]) -> ::std::result::Result<(), ::httlib_protos::DecoderError> {
+                match tag {
+                    1 => ::httlib_protos::message::field::merge::<::httlib_protos::message::kind::UInt32>(&mut self.value, typ, bytes),
+                    _ => ::std::result::Result::Ok(()),
+                }
+            }
+        }
+
+        #[derive(Debug, Clone, PartialEq, Default)]
+        pub struct BoolValue {
+            pub value: bool,
+        }
+
+        impl ::httlib_protos::Message for BoolValue {
+            fn encode_fields(&self, encoder: &mut ::httlib_protos::MessageEncoder) -> ::std::result::Result<(), ::httlib_protos::EncoderError> {
+                ::httlib_protos::message::field::encode::<::httlib_protos::message::kind::Bool>(1, &self.value, encoder)?;
+                ::std::result::Result::Ok(())
+            }
+
+            fn merge_field(&mut self, tag: u32, typ: ::httlib_protos::Typ, bytes: &[u8]) -> ::std::result::Result<(), ::httlib_protos::DecoderError> {
+                match tag {
+                    1 => ::httlib_protos::message::field::merge::<::httlib_protos::message::kind::Bool>(&mut self.value, typ, bytes),
+                    _ => ::std::result::Result::Ok(()),
+                }
+            }
+        }
+
+        #[derive(Debug, Clone, PartialEq, Default)]
+        pub struct StringValue {
+            pub value: ::std::string::String,
+        }
+
+        impl ::httlib_protos::Message for StringValue {
+            fn encode_fields(&self, encoder: &mut ::httlib_protos::MessageEncoder) -> ::std::result::Result<(), ::httlib_protos::EncoderError> {
+                ::httlib_protos::message::field::encode::<::httlib_protos::message::kind::String>(1, &self.value, encoder)?;
+                ::std::result::Result::Ok(())
+            }
+
+            fn merge_field(&mut self, tag: u32, typ: ::httlib_protos::Typ, bytes: &[u8]) -> ::std::result::Result<(), ::httlib_protos::DecoderError> {
+                match tag {
+                    1 => ::httlib_protos::message::field::merge::<::httlib_protos::message::kind::String>(&mut self.value, typ, bytes),
+                    _ => ::std::result::Result::Ok(()),
+                }
+            }
+        }
+
+        #[derive(Debug, Clone, PartialEq, Default)]
+        pub struct BytesValue {
+            pub value: ::std::vec::Vec<u8>,
+        }
+
+        impl ::httlib_protos::Message for BytesValue {
+            fn encode_fields(&self, encoder: &mut ::httlib_protos::MessageEncoder) -> ::std::result::Result<(), ::httlib_protos::EncoderError> {
+                ::httlib_protos::message::field::encode::<::httlib_protos::message::kind::Bytes>(1, &self.value, encoder)?;
+                ::std::result::Result::Ok(())
+            }
+
+            fn merge_field(&mut self, tag: u32, typ: ::httlib_protos::Typ, bytes: &[u8]) -> ::std::result::Result<(), ::httlib_protos::DecoderError> {
+                match tag {
+                    1 => ::httlib_protos::message::field::merge::<::httlib_protos::message::kind::Bytes>(&mut self.value, typ, bytes),
+                    _ => ::std::result::Result::Ok(()),
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,45 @@
+//! Provides the well-known types of the `google.protobuf` package (e.g.
+//! `Timestamp`, `Duration`, `Struct` and the wrappers) as typed messages.
+//!
+//! The types are generated by `httlib-protos-build` from the bundled schema
+//! sources. Code generated for schemas which import the well-known types
+//! references the types of this module.
+
+mod generated;
+
+pub use generated::google::protobuf::*;
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Message;
+
+    /// Should encode and decode well-known types including maps, oneofs and
+    /// enumerations.
+    #[test]
+    fn encodes_well_known_types() {
+        let timestamp = Timestamp { seconds: 1, nanos: 2 };
+        let mut dst = Vec::new();
+        timestamp.encode(&mut dst).unwrap();
+        assert_eq!(dst, vec![0x08, 0x01, 0x10, 0x02]);
+        assert_eq!(Timestamp::decode(&dst).unwrap(), timestamp);
+
+        let mut object = Struct::default();
+        object.fields.insert("a".to_string(), Value { kind: Some(value::Kind::NumberValue(1.0)) });
+        object.fields.insert("b".to_string(), Value { kind: Some(value::Kind::NullValue(NullValue::NullValue)) });
+        object.fields.insert("c".to_string(), Value {
+            kind: Some(value::Kind::ListValue(ListValue {
+                values: vec![Value { kind: Some(value::Kind::StringValue("x".to_string())) }],
+            })),
+        });
+        let mut dst = Vec::new();
+        let size = object.encode(&mut dst).unwrap();
+        assert_eq!(size, dst.len());
+        assert_eq!(object.encoded_len(), Ok(dst.len()));
+        assert_eq!(Struct::decode(&dst).unwrap(), object);
+
+        let entry = vec![0x0a, 0x07, 0x0a, 0x01, b'a', 0x12, 0x02, 0x20, 0x01]; // "a": true
+        let object = Struct::decode(&entry).unwrap();
+        assert_eq!(object.fields["a"].kind, Some(value::Kind::BoolValue(true)));
+    }
+}